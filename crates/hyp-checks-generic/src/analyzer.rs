@@ -1,20 +1,34 @@
 //! Main analyzer orchestration
 
 use crate::{
+    cache::AnalysisCache,
     checker::Checker,
-    config::{AnalyzerConfig, CheckerCategory},
-    violation::Violation,
+    config::{AnalyzerConfig, CheckerCategory, SeverityLevel, CONFIG_FILE_NAME},
+    hypignore::HypIgnore,
+    module_path::module_path_at,
+    violation::{Severity, Violation},
     AnalyzerError, Result,
 };
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
-use walkdir::WalkDir;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use regex::Regex;
+use syn::spanned::Spanned;
+use walkdir::{DirEntry, WalkDir};
+
+/// Code of the synthetic violation reported for files that fail to parse
+pub const PARSE_ERROR_CODE: &str = "E0001";
+
+/// Virtual file path of violations reported by [`Analyzer::preview_checker`]
+pub const PREVIEW_PATH: &str = "preview.rs";
 
 /// Filtering options for the analyzer
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct AnalyzerFilters {
-    /// Minimum severity level (1-3). If set, only checkers with this severity or higher will run
+    /// Minimum severity level (1-3). If set, only checkers with this severity or higher run,
+    /// and only violations with this severity or higher are reported
     pub min_severity: Option<u8>,
 
     /// Specific categories to include. If set, only checkers in these categories will run
@@ -22,6 +36,47 @@ pub struct AnalyzerFilters {
 
     /// Whether to check unit tests (default: false, tests are skipped)
     pub check_tests: bool,
+
+    /// Patterns from `.hypignore`. Matching files are excluded from analysis
+    pub ignore: Option<HypIgnore>,
+
+    /// Follow symbolic links while walking directories (default: false, avoids symlink cycles)
+    pub follow_symlinks: bool,
+
+    /// Skip `.`-prefixed directories while walking (default: true)
+    pub skip_hidden: bool,
+
+    /// Report files that fail to parse as `E0001` violations instead of errors (default: false)
+    pub report_parse_errors: bool,
+
+    /// Fill in `module_path` for every violation, descending into inline `mod` blocks (default: false)
+    pub module_paths: bool,
+
+    /// Drop violations of checkers subsumed by a more specific one reporting at the same position (default: true)
+    pub dedupe_overlapping: bool,
+}
+
+impl Default for AnalyzerFilters {
+    fn default() -> Self {
+        Self {
+            min_severity: None,
+            categories: None,
+            check_tests: false,
+            ignore: None,
+            follow_symlinks: false,
+            skip_hidden: true,
+            report_parse_errors: false,
+            module_paths: false,
+            dedupe_overlapping: true,
+        }
+    }
+}
+
+/// Whether a walk entry below the root is a `.`-prefixed directory
+fn is_hidden_dir(entry: &DirEntry) -> bool {
+    entry.depth() > 0
+        && entry.file_type().is_dir()
+        && entry.file_name().to_str().is_some_and(|name| name.starts_with('.'))
 }
 
 /// Information about a checker for display purposes
@@ -53,52 +108,211 @@ pub struct Analyzer {
     config: AnalyzerConfig,
     checkers: Vec<Box<dyn Checker>>,
     filters: AnalyzerFilters,
+    cache: Option<AnalysisCache>,
+    /// Compiled `include_paths` / `exclude_paths` of the checkers that set them, by code
+    path_scopes: HashMap<String, PathScope>,
+    /// Analyzers for files under another `Hyp.toml`, see [`Analyzer::with_nested_configs`]
+    nested: Option<NestedConfigs>,
+}
+
+/// Builds the analyzer for a nested `Hyp.toml`
+type NestedConfigBuilder = Box<dyn Fn(&Path) -> Result<Analyzer> + Send + Sync>;
+
+/// Per-directory configurations below the analyzer's own
+struct NestedConfigs {
+    /// Directory of the configuration the analyzer itself was built from
+    root_dir: PathBuf,
+    build: NestedConfigBuilder,
+    /// Analyzers (or the error building them) by configuration file, built on first use
+    analyzers: Mutex<HashMap<PathBuf, std::result::Result<Arc<Analyzer>, String>>>,
+}
+
+impl NestedConfigs {
+    /// Nearest `Hyp.toml` above `file`, unless the walk reaches the root configuration's directory first
+    fn config_for(&self, file: &Path) -> Option<PathBuf> {
+        let file = fs::canonicalize(file).ok()?;
+        file.ancestors()
+            .skip(1)
+            .take_while(|dir| *dir != self.root_dir)
+            .map(|dir| dir.join(CONFIG_FILE_NAME))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Analyzer for the nested configuration that applies to `file`, if any
+    fn analyzer_for(&self, file: &Path) -> Result<Option<Arc<Analyzer>>> {
+        let Some(config_path) = self.config_for(file) else {
+            return Ok(None);
+        };
+        let mut analyzers = self.analyzers.lock().unwrap_or_else(|e| e.into_inner());
+        let analyzer = analyzers.entry(config_path).or_insert_with_key(|path| match (self.build)(path) {
+            Ok(analyzer) => Ok(Arc::new(analyzer)),
+            Err(AnalyzerError::Config(message)) => Err(message),
+            Err(e) => Err(e.to_string()),
+        });
+        match analyzer {
+            Ok(analyzer) => Ok(Some(Arc::clone(analyzer))),
+            Err(message) => Err(AnalyzerError::Config(message.clone())),
+        }
+    }
+}
+
+/// Check that a checker's `include_paths` / `exclude_paths` are valid regexes
+pub(crate) fn validate_path_patterns(checker: &dyn Checker) -> Result<()> {
+    PathScope::new(checker).map(|_| ())
+}
+
+/// Files a checker runs on, from its `include_paths` / `exclude_paths` config
+struct PathScope {
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+}
+
+impl PathScope {
+    /// Compile a checker's path patterns; `None` if it runs on every file
+    fn new(checker: &dyn Checker) -> Result<Option<Self>> {
+        if checker.include_paths().is_empty() && checker.exclude_paths().is_empty() {
+            return Ok(None);
+        }
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| {
+                    Regex::new(p).map_err(|e| {
+                        AnalyzerError::Config(format!("Invalid path pattern '{}' for {}: {}", p, checker.code(), e))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        Ok(Some(Self {
+            include: compile(checker.include_paths())?,
+            exclude: compile(checker.exclude_paths())?,
+        }))
+    }
+
+    /// Whether the checker runs on `file_path` (matched with `/` separators)
+    fn matches(&self, file_path: &str) -> bool {
+        let path = file_path.replace('\\', "/");
+        (self.include.is_empty() || self.include.iter().any(|re| re.is_match(&path)))
+            && !self.exclude.iter().any(|re| re.is_match(&path))
+    }
 }
 
 /// Check if a file/module has the #[cfg(test)] attribute
 fn is_test_module(file: &syn::File) -> bool {
-    file.attrs.iter().any(|attr| {
-        if attr.path().is_ident("cfg") {
-            if let syn::Meta::List(meta_list) = &attr.meta {
-                let tokens = meta_list.tokens.to_string();
-                return tokens.contains("test");
-            }
-        }
-        false
-    })
+    has_cfg_test(&file.attrs)
+}
+
+/// Check for `#[cfg(test)]`, including `#[cfg(all(test, ..))]`
+fn has_cfg_test(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("cfg") && attr.parse_args::<syn::Meta>().is_ok_and(|meta| cfg_requires_test(&meta)))
+}
+
+/// Whether a cfg predicate can only hold when compiling tests
+fn cfg_requires_test(meta: &syn::Meta) -> bool {
+    match meta {
+        syn::Meta::Path(path) => path.is_ident("test"),
+        syn::Meta::List(list) if list.path.is_ident("all") => list
+            .parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+            .is_ok_and(|predicates| predicates.iter().any(cfg_requires_test)),
+        _ => false,
+    }
 }
 
-/// Check if an item is a test function or test module
+/// Attributes of any item kind
+fn item_attrs(item: &syn::Item) -> &[syn::Attribute] {
+    match item {
+        syn::Item::Const(item) => &item.attrs,
+        syn::Item::Enum(item) => &item.attrs,
+        syn::Item::ExternCrate(item) => &item.attrs,
+        syn::Item::Fn(item) => &item.attrs,
+        syn::Item::ForeignMod(item) => &item.attrs,
+        syn::Item::Impl(item) => &item.attrs,
+        syn::Item::Macro(item) => &item.attrs,
+        syn::Item::Mod(item) => &item.attrs,
+        syn::Item::Static(item) => &item.attrs,
+        syn::Item::Struct(item) => &item.attrs,
+        syn::Item::Trait(item) => &item.attrs,
+        syn::Item::TraitAlias(item) => &item.attrs,
+        syn::Item::Type(item) => &item.attrs,
+        syn::Item::Union(item) => &item.attrs,
+        syn::Item::Use(item) => &item.attrs,
+        _ => &[],
+    }
+}
+
+/// Check if an item is a `#[test]` function or anything annotated `#[cfg(test)]`
 fn is_test_item(item: &syn::Item) -> bool {
+    let is_test_fn = matches!(item, syn::Item::Fn(func) if func.attrs.iter().any(|attr| attr.path().is_ident("test")));
+    is_test_fn || has_cfg_test(item_attrs(item))
+}
+
+/// Whether an inline module contains test items at any depth
+fn contains_test_items(item: &syn::Item) -> bool {
     match item {
-        syn::Item::Fn(func) => {
-            // Check for #[test] or #[cfg(test)] attributes
-            func.attrs.iter().any(|attr| {
-                attr.path().is_ident("test")
-                    || (attr.path().is_ident("cfg") && {
-                        if let syn::Meta::List(meta_list) = &attr.meta {
-                            meta_list.tokens.to_string().contains("test")
-                        } else {
-                            false
-                        }
-                    })
-            })
-        }
-        syn::Item::Mod(module) => {
-            // Check for #[cfg(test)] attribute on module
-            module.attrs.iter().any(|attr| {
-                if attr.path().is_ident("cfg") {
-                    if let syn::Meta::List(meta_list) = &attr.meta {
-                        return meta_list.tokens.to_string().contains("test");
-                    }
-                }
-                false
-            })
-        }
+        syn::Item::Mod(syn::ItemMod {
+            content: Some((_, items)), ..
+        }) => items.iter().any(|inner| is_test_item(inner) || contains_test_items(inner)),
         _ => false,
     }
 }
 
+/// The item with test items pruned from its inline modules, at any depth
+///
+/// Checkers walk nested modules themselves, so skipping top-level test items
+/// alone would still analyze `mod api { #[cfg(test)] mod tests { .. } }`.
+/// Items are only cloned when there is something to prune.
+fn without_test_items(item: &syn::Item) -> Cow<'_, syn::Item> {
+    if !contains_test_items(item) {
+        return Cow::Borrowed(item);
+    }
+    let mut item = item.clone();
+    if let syn::Item::Mod(syn::ItemMod {
+        content: Some((_, items)), ..
+    }) = &mut item
+    {
+        *items = items
+            .iter()
+            .filter(|inner| !is_test_item(inner))
+            .map(|inner| without_test_items(inner).into_owned())
+            .collect();
+    }
+    Cow::Owned(item)
+}
+
+/// The file with test items removed, at any depth
+fn without_test_items_in_file(file: &syn::File) -> Cow<'_, syn::File> {
+    if !file.items.iter().any(|item| is_test_item(item) || contains_test_items(item)) {
+        return Cow::Borrowed(file);
+    }
+    let mut file = file.clone();
+    file.items = file
+        .items
+        .iter()
+        .filter(|item| !is_test_item(item))
+        .map(|item| without_test_items(item).into_owned())
+        .collect();
+    Cow::Owned(file)
+}
+
+/// First and last line of every test item, at any depth
+fn test_item_lines(items: &[syn::Item]) -> Vec<(usize, usize)> {
+    let mut lines = Vec::new();
+    for item in items {
+        if is_test_item(item) {
+            let span = item.span();
+            lines.push((span.start().line, span.end().line));
+        } else if let syn::Item::Mod(syn::ItemMod {
+            content: Some((_, inner)), ..
+        }) = item
+        {
+            lines.extend(test_item_lines(inner));
+        }
+    }
+    lines
+}
+
 impl Analyzer {
     /// Get information about all available checkers (regardless of config/filters)
     pub fn all_checkers() -> Vec<CheckerInfo> {
@@ -174,10 +388,25 @@ impl Analyzer {
         checker_factories: Vec<crate::registry::CheckerRegistration>,
     ) -> Result<Self> {
         let mut all_checkers: Vec<Box<dyn Checker>> = Vec::new();
+        let mut filters = filters;
+        if config.global.check_tests == Some(true) {
+            filters.check_tests = true;
+        }
+        if config.global.module_paths == Some(true) {
+            filters.module_paths = true;
+        }
+        if config.global.dedupe_overlapping == Some(false) {
+            filters.dedupe_overlapping = false;
+        }
+        let global_min_severity = config.global.min_severity.map(SeverityLevel::as_u8);
 
         // Create checkers using the provided factories
         for registration in &checker_factories {
             match (registration.factory)(&config) {
+                // `[global] min_severity` hides a checker unless its own table enables it
+                Ok(Some(checker))
+                    if global_min_severity.is_some_and(|min| checker.severity().as_u8() < min)
+                        && !config.is_explicitly_enabled(registration.config_entry_name) => {}
                 Ok(Some(checker)) => all_checkers.push(checker),
                 Ok(None) => {} // Checker disabled
                 Err(e) => return Err(AnalyzerError::Config(e)),
@@ -204,12 +433,22 @@ impl Analyzer {
 
                 true
             })
-            .collect();
+            .collect::<Vec<_>>();
+
+        let mut path_scopes = HashMap::new();
+        for checker in &checkers {
+            if let Some(scope) = PathScope::new(checker.as_ref())? {
+                path_scopes.insert(checker.code().to_string(), scope);
+            }
+        }
 
         Ok(Self {
             config,
             checkers,
             filters,
+            cache: None,
+            path_scopes,
+            nested: None,
         })
     }
 
@@ -218,14 +457,138 @@ impl Analyzer {
         Self::new(AnalyzerConfig::default())
     }
 
+    /// Reuse per-file results from `cache` for files whose content is unchanged
+    ///
+    /// Entries are keyed on the file content plus a fingerprint of this analyzer's
+    /// enabled checkers and configuration, so a config change invalidates them.
+    pub fn with_cache(mut self, mut cache: AnalysisCache) -> Self {
+        cache.fingerprint = self.fingerprint();
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Analyze each file under the nearest `Hyp.toml` above it
+    ///
+    /// `config_path` is the configuration this analyzer was built from. A file
+    /// with another `Hyp.toml` between it and that file's directory is analyzed
+    /// by the analyzer `build` returns for the nearest one; it is built the first
+    /// time a file under it is analyzed and reused afterwards. All other files,
+    /// and in-memory source, use this analyzer.
+    pub fn with_nested_configs<F>(mut self, config_path: &Path, build: F) -> Self
+    where
+        F: Fn(&Path) -> Result<Analyzer> + Send + Sync + 'static,
+    {
+        let root_dir = match config_path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        self.nested = Some(NestedConfigs {
+            root_dir: fs::canonicalize(root_dir).unwrap_or_else(|_| root_dir.to_path_buf()),
+            build: Box::new(build),
+            analyzers: Mutex::new(HashMap::new()),
+        });
+        self
+    }
+
+    /// Base URL of the violations' `doc_url`
+    fn doc_url_base(&self) -> &str {
+        self.config
+            .global
+            .doc_url_base
+            .as_deref()
+            .unwrap_or(crate::checker::DEFAULT_DOC_URL_BASE)
+    }
+
+    /// Fingerprint of everything besides file content that affects the results
+    fn fingerprint(&self) -> String {
+        let checkers: Vec<String> = self
+            .checkers
+            .iter()
+            .map(|c| {
+                let categories: Vec<&str> = c.categories().iter().map(|cat| cat.as_str()).collect();
+                format!("{}:{}:{}", c.code(), c.severity().as_u8(), categories.join(","))
+            })
+            .collect();
+        // serde_json objects are key-ordered, so this is stable across HashMap iteration orders
+        let config = serde_json::to_value(&self.config.checkers)
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+        format!(
+            "hyp {}\n{}\ncheck_tests={}\nreport_parse_errors={}\nmodule_paths={}\ndedupe_overlapping={}\ndoc_url_base={}\n{}",
+            env!("CARGO_PKG_VERSION"),
+            checkers.join(";"),
+            self.filters.check_tests,
+            self.filters.report_parse_errors,
+            self.filters.module_paths,
+            self.filters.dedupe_overlapping,
+            self.doc_url_base(),
+            config
+        )
+    }
+
     /// Analyze a single Rust source file
+    ///
+    /// With [`Analyzer::with_nested_configs`], a file under a nested `Hyp.toml` is
+    /// analyzed by that configuration's analyzer.
     pub fn analyze_file(&self, path: &Path) -> Result<Vec<Violation>> {
+        if let Some(nested) = &self.nested {
+            if let Some(analyzer) = nested.analyzer_for(path)? {
+                return analyzer.analyze_file(path);
+            }
+        }
+
         let content = fs::read_to_string(path).map_err(AnalyzerError::Io)?;
+        let file_path = path.to_string_lossy();
+
+        let Some(cache) = &self.cache else {
+            return self.analyze_source(&content, &file_path);
+        };
+
+        // Results depend on where the file is (path-scoped and path-based checkers, messages)
+        let key = cache.key(&format!("{}\n{}", file_path, content));
+        if let Some(violations) = cache.get(&key) {
+            return Ok(violations);
+        }
+
+        let violations = self.analyze_source(&content, &file_path)?;
+        if let Err(e) = cache.put(&key, &violations) {
+            eprintln!("Warning: Failed to write analysis cache: {}", e);
+        }
+        Ok(violations)
+    }
 
-        let syntax = syn::parse_file(&content).map_err(|e| AnalyzerError::Parse(e.to_string()))?;
+    /// Checkers whose `include_paths` / `exclude_paths` let them run on `file_path`
+    fn checkers_for_path(&self, file_path: &str) -> Vec<&dyn Checker> {
+        self.checkers
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(|checker| {
+                self.path_scopes
+                    .get(checker.code())
+                    .is_none_or(|scope| scope.matches(file_path))
+            })
+            .collect()
+    }
+
+    /// Analyze Rust source held in memory (e.g. an unsaved editor buffer)
+    ///
+    /// `virtual_path` is only used as the `file_path` of reported violations;
+    /// nothing is read from disk. Source that fails to parse is an error, or a
+    /// single `E0001` violation when `report_parse_errors` is set.
+    pub fn analyze_source(&self, content: &str, virtual_path: &str) -> Result<Vec<Violation>> {
+        let syntax = match syn::parse_file(content) {
+            Ok(syntax) => syntax,
+            Err(e) if self.filters.report_parse_errors => {
+                let mut violation = parse_error_violation(&e, virtual_path);
+                violation.set_utf16_columns(content);
+                return Ok(vec![violation]);
+            }
+            Err(e) => return Err(AnalyzerError::Parse(e.to_string())),
+        };
 
-        let file_path = path.to_string_lossy().to_string();
         let mut violations = Vec::new();
+        let checkers = self.checkers_for_path(virtual_path);
+        let doc_url_base = self.doc_url_base();
 
         // Detect if this is a test module/file
         let is_test_file = is_test_module(&syntax);
@@ -241,37 +604,105 @@ impl Analyzer {
                 continue;
             }
 
-            for checker in &self.checkers {
-                violations.extend(checker.check_item(item, &file_path)?);
+            let item = if self.filters.check_tests {
+                Cow::Borrowed(item)
+            } else {
+                without_test_items(item)
+            };
+            for checker in &checkers {
+                violations.extend(with_checker_metadata(*checker, doc_url_base, checker.check_item(&item, virtual_path)?));
+            }
+        }
+
+        // Run source-text and whole-file checks once per file (comments, `#![...]` attributes),
+        // leaving out test items just like the per-item checks
+        if !is_test_file || self.filters.check_tests {
+            let (file, test_lines) = if self.filters.check_tests {
+                (Cow::Borrowed(&syntax), Vec::new())
+            } else {
+                (without_test_items_in_file(&syntax), test_item_lines(&syntax.items))
+            };
+            let outside_tests = |violation: &Violation| {
+                !test_lines.iter().any(|(start, end)| (*start..=*end).contains(&violation.line))
+            };
+            for checker in &checkers {
+                let mut source_violations = checker.check_source(content, virtual_path)?;
+                source_violations.retain(outside_tests);
+                violations.extend(with_checker_metadata(*checker, doc_url_base, source_violations));
+                violations.extend(with_checker_metadata(*checker, doc_url_base, checker.check_file(&file, virtual_path)?));
             }
         }
 
+        // Checkers report character columns; add the UTF-16 ones editors speaking LSP need
+        for violation in &mut violations {
+            violation.set_utf16_columns(content);
+            if self.filters.module_paths && violation.module_path.is_none() {
+                violation.module_path = Some(module_path_at(&syntax, virtual_path, violation.line));
+            }
+        }
+
+        if self.filters.dedupe_overlapping {
+            drop_subsumed(&checkers, &mut violations);
+        }
+
+        // Checker selection already honors `min_severity`; this keeps the output strict as well
+        if let Some(min_sev) = self.filters.min_severity {
+            violations.retain(|violation| violation.severity.as_u8() >= min_sev);
+        }
+
         Ok(violations)
     }
 
+    /// Run a single checker with an ad-hoc configuration against in-memory source
+    ///
+    /// Meant for tooling that previews a rule while it is being edited (e.g. an
+    /// E1904 regex typed into a config editor) without writing `Hyp.toml` or
+    /// building the full analyzer. `config_override` is the checker's table as it
+    /// would appear under its config entry name; `null` means the defaults. The
+    /// checker runs even if the table leaves `enabled` unset, and test code is
+    /// checked too.
+    ///
+    /// # Errors
+    /// Returns [`AnalyzerError::Config`] for an unknown code or a table the checker
+    /// cannot deserialize (the message names the offending field), and
+    /// [`AnalyzerError::Parse`] if `source` is not valid Rust.
+    pub fn preview_checker(code: &str, config_override: serde_json::Value, source: &str) -> Result<Vec<Violation>> {
+        let registration = crate::registry::registration_for_code(code)
+            .ok_or_else(|| AnalyzerError::Config(format!("Unknown checker code '{}'", code)))?;
+
+        let mut table = match config_override {
+            serde_json::Value::Null => serde_json::Map::new(),
+            serde_json::Value::Object(table) => table,
+            other => {
+                return Err(AnalyzerError::Config(format!(
+                    "Configuration for checker '{}' must be a table, got: {}",
+                    registration.config_entry_name, other
+                )))
+            }
+        };
+        table.entry("enabled").or_insert(serde_json::Value::Bool(true));
+
+        let mut config = AnalyzerConfig::default();
+        config
+            .checkers
+            .insert(registration.config_entry_name.to_string(), serde_json::Value::Object(table));
+        let filters = AnalyzerFilters {
+            check_tests: true,
+            ..AnalyzerFilters::default()
+        };
+        let analyzer = Self::new_with_checkers_skip_unknown_validation(config, filters, vec![registration])?;
+        analyzer.analyze_source(source, PREVIEW_PATH)
+    }
+
     /// Analyze all Rust files in a directory recursively
     pub fn analyze_directory(&self, path: &Path) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
 
-        for entry in WalkDir::new(path)
-            .follow_links(true)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-
-            // Only analyze .rs files
-            if path.extension().and_then(|s| s.to_str()) == Some("rs") {
-                // Skip target directory
-                if path.components().any(|c| c.as_os_str() == "target") {
-                    continue;
-                }
-
-                match self.analyze_file(path) {
-                    Ok(file_violations) => violations.extend(file_violations),
-                    Err(e) => {
-                        eprintln!("Warning: Failed to analyze {}: {}", path.display(), e);
-                    }
+        for path in self.rust_files(path) {
+            match self.analyze_file(&path) {
+                Ok(file_violations) => violations.extend(file_violations),
+                Err(e) => {
+                    eprintln!("Warning: Failed to analyze {}: {}", path.display(), e);
                 }
             }
         }
@@ -279,9 +710,56 @@ impl Analyzer {
         Ok(violations)
     }
 
+    /// Collect the Rust files under `path` that would be analyzed
+    ///
+    /// Applies the walk filters (`follow_symlinks`, `skip_hidden`), skips `target`
+    /// directories and `.hypignore` matches. A file path is returned as-is unless ignored.
+    pub fn rust_files(&self, path: &Path) -> Vec<PathBuf> {
+        if path.is_file() {
+            return if self.is_ignored(path) {
+                Vec::new()
+            } else {
+                vec![path.to_path_buf()]
+            };
+        }
+
+        WalkDir::new(path)
+            .follow_links(self.filters.follow_symlinks)
+            .into_iter()
+            .filter_entry(|e| !(self.filters.skip_hidden && is_hidden_dir(e)))
+            .filter_map(|e| e.ok())
+            .map(|e| e.into_path())
+            .filter(|path| self.is_rust_source(path))
+            .collect()
+    }
+
+    /// Whether `path` below the directory `root` is a file [`Analyzer::rust_files`] would return for `root`
+    ///
+    /// Only looks at the path, so it also answers for files that were just
+    /// deleted (e.g. paths reported by a file watcher).
+    pub fn is_rust_file_under(&self, root: &Path, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(root) else {
+            return false;
+        };
+        let in_hidden_dir = relative
+            .parent()
+            .is_some_and(|dir| dir.components().any(|c| c.as_os_str().to_string_lossy().starts_with('.')));
+        !(self.filters.skip_hidden && in_hidden_dir) && self.is_rust_source(path)
+    }
+
+    /// Only .rs files, skipping the target directory and .hypignore matches
+    fn is_rust_source(&self, path: &Path) -> bool {
+        path.extension().and_then(|s| s.to_str()) == Some("rs")
+            && !path.components().any(|c| c.as_os_str() == "target")
+            && !self.is_ignored(path)
+    }
+
     /// Analyze a path (file or directory)
     pub fn analyze(&self, path: &Path) -> Result<Vec<Violation>> {
         if path.is_file() {
+            if self.is_ignored(path) {
+                return Ok(Vec::new());
+            }
             self.analyze_file(path)
         } else if path.is_dir() {
             self.analyze_directory(path)
@@ -293,6 +771,14 @@ impl Analyzer {
         }
     }
 
+    /// Whether a path is excluded by the `.hypignore` patterns in the filters
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.filters
+            .ignore
+            .as_ref()
+            .is_some_and(|ignore| ignore.is_ignored(path))
+    }
+
     /// Get the current configuration
     pub fn config(&self) -> &AnalyzerConfig {
         &self.config
@@ -303,6 +789,14 @@ impl Analyzer {
         self.checkers.len()
     }
 
+    /// Look up an enabled checker by its code (e.g. "E1001")
+    pub fn checker_for_code(&self, code: &str) -> Option<&dyn Checker> {
+        self.checkers
+            .iter()
+            .find(|c| c.code() == code)
+            .map(|c| c.as_ref())
+    }
+
     /// Get information about currently enabled checkers
     pub fn enabled_checkers(&self) -> Vec<CheckerInfo> {
         self.checkers
@@ -333,6 +827,59 @@ impl Analyzer {
     }
 }
 
+/// Tag each violation with the checker's categories and documentation URL, and
+/// add its configured `message_prefix` / `message_suffix` around the message
+fn with_checker_metadata(checker: &dyn Checker, doc_url_base: &str, mut violations: Vec<Violation>) -> Vec<Violation> {
+    let (prefix, suffix) = (checker.message_prefix(), checker.message_suffix());
+    for violation in &mut violations {
+        violation.category = checker.categories().to_vec();
+        violation.doc_url = Some(crate::checker::doc_url(doc_url_base, &violation.code));
+        if !prefix.is_empty() || !suffix.is_empty() {
+            violation.message = format!("{}{}{}", prefix, violation.message, suffix);
+        }
+    }
+    violations
+}
+
+/// Drop violations whose checker is subsumed by one that reported at the same line and column
+fn drop_subsumed(checkers: &[&dyn Checker], violations: &mut Vec<Violation>) {
+    let subsumed_by: HashMap<&str, &[&str]> = checkers
+        .iter()
+        .filter(|checker| !checker.subsumed_by().is_empty())
+        .map(|checker| (checker.code(), checker.subsumed_by()))
+        .collect();
+    if subsumed_by.is_empty() {
+        return;
+    }
+
+    let reported: HashSet<(String, usize, usize)> = violations
+        .iter()
+        .map(|v| (v.code.clone(), v.line, v.column))
+        .collect();
+    violations.retain(|v| {
+        subsumed_by.get(v.code.as_str()).is_none_or(|codes| {
+            !codes
+                .iter()
+                .any(|code| reported.contains(&(code.to_string(), v.line, v.column)))
+        })
+    });
+}
+
+/// Build the `E0001` violation for a file `syn` could not parse, at the position the error points to
+pub fn parse_error_violation(error: &syn::Error, file_path: &str) -> Violation {
+    let start = error.span().start();
+    Violation::new(
+        PARSE_ERROR_CODE,
+        "Parse error",
+        Severity::High,
+        format!("File could not be parsed: {}", error),
+        file_path,
+        start.line.max(1),
+        start.column + 1,
+    )
+    .with_suggestion("Fix the syntax error; no checkers ran on this file")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -373,6 +920,280 @@ mod tests {
         assert!(violations.iter().any(|v| v.code == "E1106"));
     }
 
+    #[test]
+    fn test_analyze_source_uses_virtual_path() {
+        let analyzer = Analyzer::with_defaults().unwrap();
+        let violations = analyzer
+            .analyze_source("fn f() { panic!(\"boom\"); }", "buffer://untitled.rs")
+            .unwrap();
+
+        let panic = violations.iter().find(|v| v.code == "E1001").unwrap();
+        assert_eq!(panic.file_path, "buffer://untitled.rs");
+    }
+
+    #[test]
+    fn test_analyze_source_runs_source_text_checks() {
+        let analyzer = Analyzer::with_defaults().unwrap();
+        let violations = analyzer
+            .analyze_source("// TODO: remove\nfn run() {}", "todo.rs")
+            .unwrap();
+
+        assert!(violations.iter().any(|v| v.code == "E1114" && v.line == 1));
+    }
+
+    #[test]
+    fn test_analyze_source_runs_whole_file_checks() {
+        let analyzer = Analyzer::with_defaults().unwrap();
+        let violations = analyzer
+            .analyze_source("#![allow(clippy::unwrap_used)]\nfn run() {}", "lib.rs")
+            .unwrap();
+
+        assert!(violations.iter().any(|v| v.code == "E1901" && v.line == 1));
+    }
+
+    #[test]
+    fn test_analyze_file_uses_cache() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let source = dir.path().join("lib.rs");
+        std::fs::write(&source, "fn f() { panic!(\"boom\"); }").unwrap();
+        let cache = AnalysisCache::new(dir.path().join("hyp-cache"));
+
+        let analyzer = Analyzer::with_defaults().unwrap().with_cache(cache.clone());
+        let first = analyzer.analyze_file(&source).unwrap();
+        assert!(first.iter().any(|v| v.code == "E1001"));
+        assert_eq!(std::fs::read_dir(cache.dir()).unwrap().count(), 1);
+
+        // A planted entry under the same key proves the second run is served from the cache
+        let analyzer = Analyzer::with_defaults().unwrap().with_cache(cache);
+        let key = analyzer.cache.as_ref().unwrap().key(&format!(
+            "{}\n{}",
+            source.to_string_lossy(),
+            "fn f() { panic!(\"boom\"); }"
+        ));
+        let planted = Violation::new("E0000", "cached", crate::Severity::Low, "m", "old.rs", 1, 1);
+        analyzer.cache.as_ref().unwrap().put(&key, &[planted]).unwrap();
+        let second = analyzer.analyze_file(&source).unwrap();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].code, "E0000");
+    }
+
+    #[test]
+    fn test_cache_keeps_identical_files_apart() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let allowed = dir.path().join("src/aok/util.rs");
+        let misplaced = dir.path().join("src/bad/util.rs");
+        for path in [&allowed, &misplaced] {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "fn util() {}").unwrap();
+        }
+        let config = AnalyzerConfig::from_toml(
+            r#"
+            [[checkers.e1906_file_location.rules]]
+            filename_pattern = "^util\\.rs$"
+            allowed_paths = ["src/aok/util\\.rs$"]
+            "#,
+        )
+        .unwrap();
+        let cache = AnalysisCache::new(dir.path().join("hyp-cache"));
+
+        // Both orders: the first file analyzed must not decide the other's results
+        for order in [[&allowed, &misplaced], [&misplaced, &allowed]] {
+            let analyzer = Analyzer::new(config.clone()).unwrap().with_cache(cache.clone());
+            for path in order {
+                let flagged = analyzer
+                    .analyze_file(path)
+                    .unwrap()
+                    .iter()
+                    .any(|v| v.code == "E1906");
+                assert_eq!(flagged, *path == misplaced, "{}", path.display());
+            }
+        }
+    }
+
+    #[test]
+    fn test_cache_fingerprint_changes_with_checker_set() {
+        let cache = AnalysisCache::new("unused");
+        let all = Analyzer::with_defaults().unwrap().with_cache(cache.clone());
+        let filters = AnalyzerFilters {
+            min_severity: Some(3),
+            ..AnalyzerFilters::default()
+        };
+        let high_only = Analyzer::new_with_filters(AnalyzerConfig::default(), filters)
+            .unwrap()
+            .with_cache(cache);
+
+        let content = "fn f() {}";
+        assert_ne!(
+            all.cache.as_ref().unwrap().key(content),
+            high_only.cache.as_ref().unwrap().key(content)
+        );
+    }
+
+    #[test]
+    fn test_analyze_source_reports_parse_error() {
+        let analyzer = Analyzer::with_defaults().unwrap();
+        let result = analyzer.analyze_source("fn f( {", "unsaved.rs");
+
+        assert!(matches!(result, Err(AnalyzerError::Parse(_))));
+    }
+
+    #[test]
+    fn test_analyze_source_reports_parse_error_as_violation() {
+        let filters = AnalyzerFilters {
+            report_parse_errors: true,
+            ..Default::default()
+        };
+        let analyzer = Analyzer::new_with_filters(AnalyzerConfig::default(), filters).unwrap();
+        let violations = analyzer
+            .analyze_source("fn ok() {}\nfn f( {\n}\n", "broken.rs")
+            .unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, PARSE_ERROR_CODE);
+        assert_eq!(violations[0].severity, Severity::High);
+        assert_eq!(violations[0].file_path, "broken.rs");
+        assert_eq!(violations[0].line, 2);
+        assert!(violations[0].message.starts_with("File could not be parsed: "));
+    }
+
+    #[test]
+    fn test_analyze_directory_skips_hypignore_matches() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("generated")).unwrap();
+        let panic_fn = "fn f() { panic!(\"boom\"); }";
+        std::fs::write(dir.path().join("lib.rs"), panic_fn).unwrap();
+        std::fs::write(dir.path().join("generated/proto.rs"), panic_fn).unwrap();
+
+        let filters = AnalyzerFilters {
+            ignore: Some(HypIgnore::from_patterns(dir.path(), "generated/").unwrap()),
+            ..Default::default()
+        };
+        let analyzer = Analyzer::new_with_filters(AnalyzerConfig::default(), filters).unwrap();
+        let violations = analyzer.analyze(dir.path()).unwrap();
+
+        assert!(violations.iter().any(|v| v.file_path.ends_with("lib.rs")));
+        assert!(!violations.iter().any(|v| v.file_path.contains("generated")));
+    }
+
+    #[test]
+    fn test_nested_configs_build_once_per_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("sub/deeper")).unwrap();
+        let panic_fn = "fn f() { panic!(\"boom\"); }";
+        std::fs::write(dir.path().join("Hyp.toml"), "").unwrap();
+        std::fs::write(dir.path().join("sub/Hyp.toml"), "").unwrap();
+        for file in ["lib.rs", "sub/a.rs", "sub/deeper/b.rs"] {
+            std::fs::write(dir.path().join(file), panic_fn).unwrap();
+        }
+
+        let builds = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::clone(&builds);
+        let analyzer = Analyzer::with_defaults()
+            .unwrap()
+            .with_nested_configs(&dir.path().join("Hyp.toml"), move |config_path| {
+                assert!(config_path.ends_with("sub/Hyp.toml"));
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let mut config = AnalyzerConfig::default();
+                config
+                    .checkers
+                    .insert("e1001_direct_panic".to_string(), serde_json::json!({ "enabled": false }));
+                Analyzer::new(config)
+            });
+        let violations = analyzer.analyze(dir.path()).unwrap();
+
+        let panics: Vec<&str> = violations
+            .iter()
+            .filter(|v| v.code == "E1001")
+            .map(|v| v.file_path.as_str())
+            .collect();
+        assert_eq!(panics.len(), 1);
+        assert!(panics[0].ends_with("lib.rs"));
+        assert_eq!(builds.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    /// Temp tree with `lib.rs`, `.hidden/secret.rs` and (on unix) `linked -> outside/`
+    fn walk_fixture() -> (tempfile::TempDir, tempfile::TempDir) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let outside = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join(".hidden")).unwrap();
+        std::fs::write(dir.path().join("lib.rs"), "").unwrap();
+        std::fs::write(dir.path().join(".hidden/secret.rs"), "").unwrap();
+        std::fs::write(outside.path().join("external.rs"), "").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(outside.path(), dir.path().join("linked")).unwrap();
+        (dir, outside)
+    }
+
+    fn file_names(files: &[PathBuf]) -> Vec<String> {
+        let mut names: Vec<String> = files
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_walk_defaults_skip_hidden_and_symlinks() {
+        let (dir, _outside) = walk_fixture();
+        let analyzer = Analyzer::with_defaults().unwrap();
+
+        assert_eq!(file_names(&analyzer.rust_files(dir.path())), vec!["lib.rs"]);
+    }
+
+    #[test]
+    fn test_walk_flags_include_hidden_and_symlinks() {
+        let (dir, _outside) = walk_fixture();
+        let filters = AnalyzerFilters {
+            follow_symlinks: true,
+            skip_hidden: false,
+            ..Default::default()
+        };
+        let analyzer = Analyzer::new_with_filters(AnalyzerConfig::default(), filters).unwrap();
+
+        let mut expected = vec!["lib.rs", "secret.rs"];
+        if cfg!(unix) {
+            expected.insert(0, "external.rs");
+        }
+        assert_eq!(file_names(&analyzer.rust_files(dir.path())), expected);
+    }
+
+    #[test]
+    fn test_is_rust_file_under() {
+        let root = Path::new("/project");
+        let analyzer = Analyzer::with_defaults().unwrap();
+        assert!(analyzer.is_rust_file_under(root, Path::new("/project/src/lib.rs")));
+        assert!(analyzer.is_rust_file_under(root, Path::new("/project/.rustfmt.rs")));
+        assert!(!analyzer.is_rust_file_under(root, Path::new("/project/src/lib.rs~")));
+        assert!(!analyzer.is_rust_file_under(root, Path::new("/project/target/debug/build.rs")));
+        assert!(!analyzer.is_rust_file_under(root, Path::new("/project/.git/hooks/x.rs")));
+        assert!(!analyzer.is_rust_file_under(root, Path::new("/elsewhere/lib.rs")));
+
+        let filters = AnalyzerFilters {
+            skip_hidden: false,
+            ..Default::default()
+        };
+        let analyzer = Analyzer::new_with_filters(AnalyzerConfig::default(), filters).unwrap();
+        assert!(analyzer.is_rust_file_under(root, Path::new("/project/.git/hooks/x.rs")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_symlink_cycle_terminates() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("lib.rs"), "").unwrap();
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("loop")).unwrap();
+
+        for follow_symlinks in [false, true] {
+            let filters = AnalyzerFilters {
+                follow_symlinks,
+                ..Default::default()
+            };
+            let analyzer = Analyzer::new_with_filters(AnalyzerConfig::default(), filters).unwrap();
+            assert_eq!(file_names(&analyzer.rust_files(dir.path())), vec!["lib.rs"]);
+        }
+    }
+
     #[test]
     fn test_unknown_checker_name_rejected() {
         let toml = r#"
@@ -424,4 +1245,280 @@ mod tests {
 
         assert!(result.is_ok(), "Valid config should be accepted: {:?}", result.err());
     }
+
+    #[test]
+    fn test_global_min_severity_hides_low_checkers() {
+        let toml = r#"
+            [global]
+            min_severity = 2
+
+            [checkers.e1106_long_function]
+            enabled = true
+        "#;
+        let analyzer = Analyzer::new(AnalyzerConfig::from_toml(toml).unwrap()).unwrap();
+        let codes: Vec<String> = analyzer.enabled_checkers().into_iter().map(|c| c.code).collect();
+
+        // E1112 (Low) is hidden; E1106 (Low) is re-enabled in its own table
+        assert!(!codes.iter().any(|c| c == "E1112"));
+        assert!(codes.iter().any(|c| c == "E1106"));
+        assert!(codes.iter().any(|c| c == "E1001"));
+    }
+
+    #[test]
+    fn test_min_severity_hides_violations_of_low_configured_checker() {
+        let config = AnalyzerConfig::from_toml("[checkers.e1001_direct_panic]\nseverity = \"low\"").unwrap();
+        let code = "pub fn f() { panic!(\"x\"); }\n";
+
+        let all = Analyzer::new(config.clone()).unwrap().analyze_source(code, "lib.rs").unwrap();
+        let panic = all.iter().find(|v| v.code == "E1001").expect("E1001 should be reported");
+        assert_eq!(panic.severity, Severity::Low);
+
+        let filters = AnalyzerFilters {
+            min_severity: Some(2),
+            ..AnalyzerFilters::default()
+        };
+        let violations = Analyzer::new_with_filters(config, filters)
+            .unwrap()
+            .analyze_source(code, "lib.rs")
+            .unwrap();
+        assert!(violations.iter().all(|v| v.severity.as_u8() >= 2));
+        assert!(!violations.iter().any(|v| v.code == "E1001"));
+    }
+
+    #[test]
+    fn test_lock_unwrap_reported_once_by_specific_checker() {
+        let code = "pub fn read(m: &std::sync::Mutex<u32>) -> u32 {\n    *m.lock().unwrap()\n}\n";
+        let codes = |config: AnalyzerConfig| -> Vec<String> {
+            let violations = Analyzer::new(config).unwrap().analyze_source(code, "lib.rs").unwrap();
+            violations.into_iter().map(|v| v.code).collect()
+        };
+
+        let deduped = codes(AnalyzerConfig::default());
+        assert!(deduped.iter().any(|c| c == "E1016"));
+        assert!(!deduped.iter().any(|c| c == "E1002"));
+
+        let all = codes(AnalyzerConfig::from_toml("[global]\ndedupe_overlapping = false").unwrap());
+        assert!(all.iter().any(|c| c == "E1016"));
+        assert!(all.iter().any(|c| c == "E1002"));
+
+        // Without the specific checker the generic one still reports
+        let generic_only = codes(AnalyzerConfig::from_toml("[checkers.e1016_mutex_unwrap]\nenabled = false").unwrap());
+        assert!(generic_only.iter().any(|c| c == "E1002"));
+    }
+
+    #[test]
+    fn test_doc_url_follows_configured_base() {
+        let code = "pub fn f() { panic!(\"x\"); }\n";
+        let doc_url = |config: AnalyzerConfig| -> Option<String> {
+            let violations = Analyzer::new(config).unwrap().analyze_source(code, "lib.rs").unwrap();
+            violations.into_iter().find(|v| v.code == "E1001").and_then(|v| v.doc_url)
+        };
+
+        let default = doc_url(AnalyzerConfig::default()).unwrap();
+        assert_eq!(default, format!("{}E1001", crate::checker::DEFAULT_DOC_URL_BASE));
+
+        let config = AnalyzerConfig::from_toml("[global]\ndoc_url_base = \"https://internal/docs/\"").unwrap();
+        assert_eq!(doc_url(config).as_deref(), Some("https://internal/docs/E1001"));
+    }
+
+    #[test]
+    fn test_readme_has_anchor_for_every_checker() {
+        // Default doc URLs point at `README.md#<code>`
+        let readme = include_str!("../README.md");
+        let missing: Vec<String> = crate::registry::get_all_checkers()
+            .into_iter()
+            .map(|registration| registration.descriptor.code)
+            .filter(|code| !readme.contains(&format!("<a id=\"{}\"></a>", code)))
+            .collect();
+        assert!(missing.is_empty(), "README.md has no anchor for {:?}", missing);
+    }
+
+    #[test]
+    fn test_global_check_tests() {
+        let analyzer = Analyzer::new(AnalyzerConfig::from_toml("[global]\ncheck_tests = true").unwrap()).unwrap();
+        let violations = analyzer
+            .analyze_source("#[test]\nfn t() { panic!(\"x\"); }\n", "lib.rs")
+            .unwrap();
+        assert!(violations.iter().any(|v| v.code == "E1001"));
+    }
+
+    #[test]
+    fn test_cfg_test_items_skipped() {
+        let code = r#"
+pub struct Server;
+
+mod api {
+    pub fn handler() {}
+
+    #[cfg(test)]
+    mod tests {
+        pub struct FakeClient { pub inner: Vec<String> }
+        fn fake() { panic!("a"); }
+    }
+}
+
+#[cfg(test)]
+pub struct Fixture { pub name: String }
+
+#[cfg(all(test, unix))]
+fn unix_fixture() { panic!("b"); }
+
+#[cfg(not(test))]
+fn release_only() { panic!("c"); }
+"#;
+        let analyzer = Analyzer::with_defaults().unwrap();
+        let violations = analyzer.analyze_source(code, "lib.rs").unwrap();
+        assert!(
+            violations.iter().all(|v| v.line < 7 || v.line > 18),
+            "test items were analyzed: {:?}",
+            violations.iter().map(|v| (&v.code, v.line)).collect::<Vec<_>>()
+        );
+        assert!(violations.iter().any(|v| v.code == "E1001" && v.line == 21));
+
+        let config = AnalyzerConfig::from_toml("[global]\ncheck_tests = true").unwrap();
+        let violations = Analyzer::new(config).unwrap().analyze_source(code, "lib.rs").unwrap();
+        assert!(violations.iter().any(|v| v.code == "E1001" && v.line == 10));
+        assert!(violations.iter().any(|v| v.code == "E1001" && v.line == 18));
+    }
+
+    #[test]
+    fn test_whole_file_checks_skip_test_items() {
+        let code = r#"
+pub fn run() {}
+
+#[cfg(test)]
+mod tests {
+    // TODO: cover the error path
+    fn setup() {
+        let (tx, rx) = std::sync::mpsc::channel::<u8>();
+    }
+}
+"#;
+        let codes = |check_tests: bool| -> Vec<(String, usize)> {
+            let config = AnalyzerConfig::from_toml(&format!("[global]\ncheck_tests = {}", check_tests)).unwrap();
+            let violations = Analyzer::new(config).unwrap().analyze_source(code, "lib.rs").unwrap();
+            violations.into_iter().map(|v| (v.code, v.line)).collect()
+        };
+
+        let skipped = codes(false);
+        assert!(!skipped.iter().any(|(code, _)| code == "E1114" || code == "E1520"), "{:?}", skipped);
+
+        let checked = codes(true);
+        assert!(checked.contains(&("E1114".to_string(), 6)), "{:?}", checked);
+        assert!(checked.iter().any(|(code, line)| code == "E1520" && *line == 8), "{:?}", checked);
+    }
+
+    #[test]
+    fn test_message_prefix_and_suffix() {
+        let toml = r#"
+            [checkers.e1001_direct_panic]
+            message_prefix = "[core] "
+            message_suffix = " See https://wiki.example/panics"
+        "#;
+        let analyzer = Analyzer::new(AnalyzerConfig::from_toml(toml).unwrap()).unwrap();
+        let violations = analyzer
+            .analyze_source("fn f() { panic!(\"boom\"); }", "lib.rs")
+            .unwrap();
+
+        let panic = violations.iter().find(|v| v.code == "E1001").unwrap();
+        assert!(panic.message.starts_with("[core] "));
+        assert!(panic.message.ends_with(" See https://wiki.example/panics"));
+        assert_eq!(panic.category, vec![CheckerCategory::Operations]);
+        let other = violations.iter().find(|v| v.code != "E1001").unwrap();
+        assert!(!other.message.starts_with("[core] "));
+    }
+
+    #[test]
+    fn test_checker_path_scope() {
+        let toml = r#"
+            [checkers.e1002_direct_unwrap_expect]
+            include_paths = ["^src/"]
+            exclude_paths = ["^src/bin/"]
+        "#;
+        let analyzer = Analyzer::new(AnalyzerConfig::from_toml(toml).unwrap()).unwrap();
+        let code = "fn f(x: Option<u8>) -> u8 { x.unwrap() }\n";
+        let unwraps = |path: &str| {
+            let violations = analyzer.analyze_source(code, path).unwrap();
+            violations.iter().filter(|v| v.code == "E1002").count()
+        };
+
+        assert_eq!(unwraps("src/lib.rs"), 1);
+        assert_eq!(unwraps("src\\net\\client.rs"), 1);
+        assert_eq!(unwraps("benches/parse.rs"), 0);
+        assert_eq!(unwraps("examples/demo.rs"), 0);
+        assert_eq!(unwraps("src/bin/tool.rs"), 0);
+
+        // Other checkers are not affected
+        let violations = analyzer.analyze_source("fn f() { panic!(\"x\"); }", "benches/parse.rs").unwrap();
+        assert!(violations.iter().any(|v| v.code == "E1001"));
+
+        let bad = AnalyzerConfig::from_toml("[checkers.e1002_direct_unwrap_expect]\ninclude_paths = [\"(\"]").unwrap();
+        let err = Analyzer::new(bad).err().unwrap().to_string();
+        assert!(err.contains("Invalid path pattern '(' for E1002"), "{}", err);
+    }
+
+    #[test]
+    fn test_module_paths() {
+        let code = "fn top() { panic!(\"a\"); }\nmod api {\n    fn f() { panic!(\"b\"); }\n}\n";
+
+        let analyzer = Analyzer::with_defaults().unwrap();
+        let violations = analyzer.analyze_source(code, "src/server.rs").unwrap();
+        assert!(violations.iter().all(|v| v.module_path.is_none()));
+
+        let config = AnalyzerConfig::from_toml("[global]\nmodule_paths = true").unwrap();
+        let analyzer = Analyzer::new(config).unwrap();
+        let violations = analyzer.analyze_source(code, "src/server.rs").unwrap();
+        let panics: Vec<_> = violations.iter().filter(|v| v.code == "E1001").collect();
+        assert_eq!(panics[0].module_path.as_deref(), Some("crate::server"));
+        assert_eq!(panics[1].module_path.as_deref(), Some("crate::server::api"));
+    }
+
+    #[test]
+    fn test_checker_for_code() {
+        let analyzer = Analyzer::with_defaults().unwrap();
+        let checker = analyzer.checker_for_code("E1001").unwrap();
+        assert_eq!(checker.code(), "E1001");
+        assert!(analyzer.checker_for_code("E0000").is_none());
+
+        let registration = crate::registry::registration_for_code("E1001").unwrap();
+        assert_eq!(registration.config_entry_name, "e1001_direct_panic");
+        let checker = (registration.factory)(&AnalyzerConfig::default()).unwrap().unwrap();
+        let syntax = syn::parse_file("fn f() { panic!(\"x\"); }").unwrap();
+        assert_eq!(checker.check_item(&syntax.items[0], "test.rs").unwrap().len(), 1);
+        assert!(crate::registry::registration_for_code("E0000").is_none());
+    }
+
+    #[test]
+    fn test_preview_checker() {
+        let source = "pub struct UserDTO { id: i32 }\n#[cfg(test)]\nmod tests { struct MockDTO; }\n";
+        let rule = serde_json::json!({
+            "rules": [{
+                "item_types": ["struct"],
+                "reference_type": "define",
+                "name_patterns": [".*DTO$"],
+                "allowed_paths": ["^src/api/"],
+                "message": "DTO outside api"
+            }]
+        });
+        let violations = Analyzer::preview_checker("E1904", rule, source).unwrap();
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.code == "E1904" && v.file_path == PREVIEW_PATH));
+        assert_eq!(violations[0].line, 1);
+
+        // Defaults only: E1904 has no rules, so nothing matches
+        assert!(Analyzer::preview_checker("E1904", serde_json::Value::Null, source).unwrap().is_empty());
+
+        let bad_type = serde_json::json!({ "rules": [{ "item_types": ["structs"], "name_patterns": [] }] });
+        let err = Analyzer::preview_checker("E1904", bad_type, source).unwrap_err().to_string();
+        assert!(err.contains("Unknown item_type 'structs'"), "{}", err);
+
+        let err = Analyzer::preview_checker("E1904", serde_json::json!(["x"]), source).unwrap_err().to_string();
+        assert!(err.contains("must be a table"), "{}", err);
+        let err = Analyzer::preview_checker("E0000", serde_json::Value::Null, source).unwrap_err().to_string();
+        assert!(err.contains("Unknown checker code 'E0000'"), "{}", err);
+        assert!(matches!(
+            Analyzer::preview_checker("E1001", serde_json::Value::Null, "fn ("),
+            Err(AnalyzerError::Parse(_))
+        ));
+    }
 }
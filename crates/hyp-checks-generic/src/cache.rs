@@ -0,0 +1,123 @@
+//! On-disk cache of per-file analysis results
+//!
+//! Entries are keyed on a blake3 hash of the file content together with a
+//! fingerprint of the analyzer (hyp version, enabled checkers, configuration),
+//! so editing a file or changing the configuration never serves stale results.
+
+use crate::{violation::Violation, AnalyzerError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default cache location, relative to the working directory
+pub const DEFAULT_CACHE_DIR: &str = "target/hyp-cache";
+
+/// Version of the cached `Violation` layout; bump it when fields are added so
+/// older entries (which would deserialize with defaults) are not served
+const FORMAT_VERSION: &str = "2";
+
+/// Cache of serialized `Vec<Violation>` results, one JSON file per entry
+#[derive(Debug, Clone)]
+pub struct AnalysisCache {
+    dir: PathBuf,
+    /// Fingerprint of the analyzer using this cache (set by `Analyzer::with_cache`)
+    pub(crate) fingerprint: String,
+}
+
+impl AnalysisCache {
+    /// Create a cache stored under `dir` (created on first write)
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            fingerprint: String::new(),
+        }
+    }
+
+    /// Directory holding the cache entries
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Cache key for a file's path and content under the current analyzer fingerprint
+    pub fn key(&self, content: &str) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(FORMAT_VERSION.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(self.fingerprint.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(content.as_bytes());
+        hasher.finalize().to_hex().to_string()
+    }
+
+    /// Cached violations for `key`; a missing or unreadable entry is a miss
+    pub fn get(&self, key: &str) -> Option<Vec<Violation>> {
+        let data = fs::read_to_string(self.entry_path(key)).ok()?;
+        serde_json::from_str(&data).ok()
+    }
+
+    /// Store the violations for `key`
+    pub fn put(&self, key: &str, violations: &[Violation]) -> Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        // Write then rename so a concurrent reader never sees a partial entry
+        let tmp = self.dir.join(format!("{}.tmp", key));
+        fs::write(&tmp, serde_json::to_string(violations)?)?;
+        fs::rename(&tmp, self.entry_path(key))?;
+        Ok(())
+    }
+
+    /// Delete every cache entry, returning how many were removed
+    pub fn clear(&self) -> Result<usize> {
+        if !self.dir.exists() {
+            return Ok(0);
+        }
+        let removed = fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+            .count();
+        fs::remove_dir_all(&self.dir).map_err(|e| {
+            AnalyzerError::Io(std::io::Error::new(
+                e.kind(),
+                format!("{}: {}", self.dir.display(), e),
+            ))
+        })?;
+        Ok(removed)
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::violation::Severity;
+
+    #[test]
+    fn test_put_get_and_clear() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cache = AnalysisCache::new(dir.path().join("hyp-cache"));
+        let key = cache.key("fn main() {}");
+        assert!(cache.get(&key).is_none());
+
+        let violation = Violation::new("E1001", "panic", Severity::High, "msg", "a.rs", 1, 1);
+        cache.put(&key, &[violation]).unwrap();
+        let cached = cache.get(&key).unwrap();
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].code, "E1001");
+
+        assert_eq!(cache.clear().unwrap(), 1);
+        assert!(!cache.dir().exists());
+        assert_eq!(cache.clear().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_key_depends_on_content_and_fingerprint() {
+        let mut cache = AnalysisCache::new("unused");
+        let key = cache.key("fn a() {}");
+        assert_eq!(key, cache.key("fn a() {}"));
+        assert_ne!(key, cache.key("fn b() {}"));
+
+        cache.fingerprint = "other-config".to_string();
+        assert_ne!(key, cache.key("fn a() {}"));
+    }
+}
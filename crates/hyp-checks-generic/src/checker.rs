@@ -3,6 +3,14 @@
 use crate::{config::CheckerCategory, violation::CheckerSeverity, Result, Violation};
 use syn::Item;
 
+/// Where checker documentation lives unless `[global] doc_url_base` says otherwise
+pub const DEFAULT_DOC_URL_BASE: &str = "https://github.com/hypernetix/hyp/blob/main/crates/hyp-checks-generic/README.md#";
+
+/// Documentation URL of the checker `code` under `base` (e.g. `https://internal/docs/E1402`)
+pub fn doc_url(base: &str, code: &str) -> String {
+    format!("{}{}", base, code)
+}
+
 /// Descriptor for registering a checker with default metadata
 #[derive(Debug, Clone)]
 pub struct CheckerDescriptor {
@@ -14,6 +22,12 @@ pub struct CheckerDescriptor {
     pub default_severity: CheckerSeverity,
     /// Default categories (can be overridden in config)
     pub default_categories: Vec<CheckerCategory>,
+    /// Suggested fix shown with every violation
+    pub suggestions: String,
+    /// Longer explanation of why the pattern is a problem, shown by `hyp explain`
+    pub rationale: String,
+    /// Documentation page for editor hovers, under [`DEFAULT_DOC_URL_BASE`]
+    pub doc_url: Option<String>,
 }
 
 /// Types of AST items that checkers can analyze
@@ -55,6 +69,11 @@ pub trait Checker: Send + Sync {
     /// Suggestion for how to fix violations from this checker
     fn suggestions(&self) -> &str;
 
+    /// Why the reported pattern is a problem, in a few sentences (defaults to the suggestion)
+    fn rationale(&self) -> &str {
+        self.suggestions()
+    }
+
     /// Severity level (1-3)
     fn severity(&self) -> CheckerSeverity;
 
@@ -67,11 +86,58 @@ pub trait Checker: Send + Sync {
     /// Check an AST item and return any violations found
     fn check_item(&self, item: &Item, file_path: &str) -> Result<Vec<Violation>>;
 
+    /// Check the raw source text of a file and return any violations found.
+    ///
+    /// Called once per file in addition to `check_item`. Use this for information
+    /// that `syn` drops, such as ordinary `//` comments. Unless tests are checked,
+    /// violations on lines of test items are dropped. Defaults to no violations.
+    fn check_source(&self, _source: &str, _file_path: &str) -> Result<Vec<Violation>> {
+        Ok(Vec::new())
+    }
+
+    /// Check the whole parsed file
+    ///
+    /// Called once per file in addition to `check_item`. Use this for file-level
+    /// syntax that no item carries, such as inner `#![...]` attributes. Unless
+    /// tests are checked, the file has its test items removed, as for `check_item`.
+    /// Defaults to no violations.
+    fn check_file(&self, _file: &syn::File, _file_path: &str) -> Result<Vec<Violation>> {
+        Ok(Vec::new())
+    }
+
     /// Whether this checker is enabled
     fn is_enabled(&self) -> bool {
         true
     }
 
+    /// Text the analyzer prepends to every message this checker reports
+    fn message_prefix(&self) -> &str {
+        ""
+    }
+
+    /// Text the analyzer appends to every message this checker reports
+    fn message_suffix(&self) -> &str {
+        ""
+    }
+
+    /// Codes of more specific checkers that make this one redundant
+    ///
+    /// When one of them reports a violation at the same line and column, the
+    /// analyzer drops this checker's violation (unless `dedupe_overlapping = false`).
+    fn subsumed_by(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Path regexes limiting the files this checker runs on (empty = all files)
+    fn include_paths(&self) -> &[String] {
+        &[]
+    }
+
+    /// Path regexes of files this checker never runs on
+    fn exclude_paths(&self) -> &[String] {
+        &[]
+    }
+
     /// Update the configuration for this checker
     fn set_config(&mut self, _config: Box<dyn std::any::Any>) -> Result<()> {
         Ok(())
@@ -84,6 +150,9 @@ pub trait Checker: Send + Sync {
             name: self.name().to_string(),
             default_severity: self.severity(),
             default_categories: self.categories().to_vec(),
+            suggestions: self.suggestions().to_string(),
+            rationale: self.rationale().to_string(),
+            doc_url: Some(doc_url(DEFAULT_DOC_URL_BASE, self.code())),
         }
     }
 }
@@ -7,8 +7,19 @@
 /// - The checker struct with config field
 /// - A `CONFIG_ENTRY_NAME` constant for registry use
 /// - Complete `Checker` trait implementation
-/// - Auto-generated methods: `severity()`, `categories()`, `is_enabled()`, `set_config()`
-/// - User provides only the `check_item` implementation
+/// - Auto-generated methods: `severity()`, `categories()`, `is_enabled()`, `set_config()`,
+///   `message_prefix()`, `message_suffix()`, `include_paths()`, `exclude_paths()`
+/// - `message_prefix` / `message_suffix` config fields (empty by default) that the
+///   analyzer adds around every message the checker reports
+/// - `include_paths` / `exclude_paths` config fields (empty by default): path regexes
+///   the analyzer uses to decide which files the checker runs on
+/// - Optional `rationale = ".."`: the longer explanation `hyp explain` prints
+///   (defaults to the suggestion)
+/// - Optional `subsumed_by = [..]`: codes of more specific checkers whose violation at
+///   the same position replaces this checker's (see `Checker::subsumed_by`)
+/// - User provides only the `check_item` implementation, plus optional
+///   `check_source` (raw file text) and `check_file` (whole parsed file)
+///   implementations
 ///
 /// # Example
 ///
@@ -19,6 +30,8 @@
 ///     code = "E1001",
 ///     name = "Direct panic() call",
 ///     suggestions = "Return Result<T, E> instead of panicking",
+///     // Optional: longer explanation for `hyp explain`
+///     rationale = "A panic unwinds or aborts the thread; callers cannot recover from it.",
 ///     target_items = [Function],
 ///     config_entry_name = "e1001_direct_panic",
 ///     /// Configuration for E1001
@@ -34,6 +47,14 @@
 ///         // Your check logic here
 ///         Ok(violations)
 ///     }
+///     // Optional: runs once per file on the raw source text
+///     check_source(self, source, file_path) {
+///         Ok(violations)
+///     }
+///     // Optional: runs once per file on the parsed `syn::File` (e.g. `#![...]` attributes)
+///     check_file(self, file, file_path) {
+///         Ok(violations)
+///     }
 /// }
 /// ```
 #[macro_export]
@@ -44,7 +65,9 @@ macro_rules! define_checker {
         code = $code:expr,
         name = $name:expr,
         suggestions = $suggestions:expr,
+        $(rationale = $rationale:expr,)?
         target_items = [$($target:ident),* $(,)?],
+        $(subsumed_by = [$($subsumer:expr),* $(,)?],)?
         config_entry_name = $config_entry_name:expr,
         $(#[$config_meta:meta])*
         config = $config_name:ident {
@@ -55,6 +78,14 @@ macro_rules! define_checker {
         },
         $(#[$check_item_comment:meta])*
         check_item($self:ident, $item:ident, $file_path:ident) $check_body:block
+        $(
+            $(#[$check_source_comment:meta])*
+            check_source($source_self:ident, $source:ident, $source_file_path:ident) $check_source_body:block
+        )?
+        $(
+            $(#[$check_file_comment:meta])*
+            check_file($file_self:ident, $file:ident, $file_file_path:ident) $check_file_body:block
+        )?
     ) => {
         // ============================================================
         // Generate config struct
@@ -68,6 +99,18 @@ macro_rules! define_checker {
                 $(#[$field_meta])*
                 pub $field: $type,
             )*
+            /// Text prepended to every violation message of this checker
+            #[serde(skip_serializing_if = "String::is_empty")]
+            pub message_prefix: String,
+            /// Text appended to every violation message of this checker
+            #[serde(skip_serializing_if = "String::is_empty")]
+            pub message_suffix: String,
+            /// Only run this checker on files whose path matches one of these regexes (empty = all files)
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            pub include_paths: Vec<String>,
+            /// Never run this checker on files whose path matches one of these regexes
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            pub exclude_paths: Vec<String>,
         }
 
         impl Default for $config_name {
@@ -76,6 +119,10 @@ macro_rules! define_checker {
                     $(
                         $field: $default,
                     )*
+                    message_prefix: String::new(),
+                    message_suffix: String::new(),
+                    include_paths: Vec::new(),
+                    exclude_paths: Vec::new(),
                 }
             }
         }
@@ -134,6 +181,12 @@ macro_rules! define_checker {
                 $suggestions
             }
 
+            $(
+                fn rationale(&self) -> &str {
+                    $rationale
+                }
+            )?
+
             fn severity(&self) -> $crate::violation::CheckerSeverity {
                 self.config.severity.into()
             }
@@ -146,12 +199,44 @@ macro_rules! define_checker {
                 &[$($crate::checker::ItemType::$target),*]
             }
 
+            $(
+                fn subsumed_by(&self) -> &[&str] {
+                    &[$($subsumer),*]
+                }
+            )?
+
             fn check_item(&$self, $item: &syn::Item, $file_path: &str) -> $crate::Result<Vec<$crate::violation::Violation>>
                 $check_body
 
+            $(
+                fn check_source(&$source_self, $source: &str, $source_file_path: &str) -> $crate::Result<Vec<$crate::violation::Violation>>
+                    $check_source_body
+            )?
+
+            $(
+                fn check_file(&$file_self, $file: &syn::File, $file_file_path: &str) -> $crate::Result<Vec<$crate::violation::Violation>>
+                    $check_file_body
+            )?
+
             fn is_enabled(&self) -> bool {
                 self.config.enabled
             }
+
+            fn message_prefix(&self) -> &str {
+                &self.config.message_prefix
+            }
+
+            fn message_suffix(&self) -> &str {
+                &self.config.message_suffix
+            }
+
+            fn include_paths(&self) -> &[String] {
+                &self.config.include_paths
+            }
+
+            fn exclude_paths(&self) -> &[String] {
+                &self.config.exclude_paths
+            }
         }
     };
 }
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1001",
     name = "Direct panic() call",
     suggestions = "Return Result<T, E> instead of panicking",
+    rationale = "panic!() unwinds the thread (or aborts the process with panic = \"abort\"), and the caller gets no chance to recover, retry or report the problem in its own terms. In a server one bad request takes down a worker; in a library the decision to crash is taken away from the application. Reserve panics for broken invariants and return Result for everything that can fail at runtime.",
     target_items = [Function],
     config_entry_name = "e1001_direct_panic",
     /// Configuration for E1001: Direct panic checker
@@ -45,16 +46,18 @@ struct PanicVisitor<'a> {
 
 impl<'a> PanicVisitor<'a> {
     /// Create a violation for a panic macro at the given span
-    fn create_violation(&self, span: proc_macro2::LineColumn) -> Violation {
+    fn create_violation(&self, span: proc_macro2::Span) -> Violation {
+        let start = span.start();
         Violation::new(
             self.checker.code(),
             self.checker.name(),
             self.checker.severity().into(),
             "Direct call to panic!() crashes the program. Use Result<T, E> to allow callers to handle errors gracefully.",
             self.file_path,
-            span.line,
-            span.column.saturating_add(1), // Convert 0-indexed to 1-indexed
+            start.line,
+            start.column.saturating_add(1), // Convert 0-indexed to 1-indexed
         )
+        .with_span(start, span.end())
         .with_suggestion(self.checker.suggestions())
     }
 }
@@ -72,7 +75,7 @@ impl<'a> Visit<'a> for PanicVisitor<'a> {
                     .map(|seg| seg.ident.span())
                     .unwrap_or_else(|| stmt_macro.mac.path.span());
 
-                self.violations.push(self.create_violation(span.start()));
+                self.violations.push(self.create_violation(span));
             }
         }
 
@@ -92,7 +95,7 @@ impl<'a> Visit<'a> for PanicVisitor<'a> {
                     .map(|seg| seg.ident.span())
                     .unwrap_or_else(|| expr_macro.mac.path.span());
 
-                self.violations.push(self.create_violation(span.start()));
+                self.violations.push(self.create_violation(span));
             }
         }
 
@@ -344,6 +347,7 @@ mod tests {
             enabled: false,
             severity: crate::config::SeverityLevel::High,
             categories: vec![crate::config::CheckerCategory::Operations],
+            ..E1001Config::default()
         };
         let checker = {
             let mut c = E1001DirectPanic::default();
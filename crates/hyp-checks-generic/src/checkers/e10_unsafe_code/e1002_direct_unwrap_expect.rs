@@ -24,6 +24,13 @@
 //! ```
 //!
 //! Prefer `?` operator, `if let`, `match`, or combinators like `unwrap_or_default()`.
+//!
+//! Calls inside `fn main` and inside `#[test]`/`#[tokio::test]` functions are
+//! exempt by default (`exempt_main`, `exempt_test_fns`): crashing there only
+//! ends the binary or fails the test. Helpers called from them are still checked.
+//!
+//! `mutex.lock().unwrap()` is also reported by the more specific E1016; when
+//! both are enabled only the E1016 violation is kept.
 
 use crate::{checker::Checker, define_checker, violation::Violation};
 
@@ -35,7 +42,9 @@ define_checker! {
     code = "E1002",
     name = "Direct use of unwrap/expect crashes program",
     suggestions = "Return Result to caller with ?, use if let/match, or unwrap_or_default(). Never use unwrap() on lock() - it causes panic cascades.",
+    rationale = "unwrap() and expect() turn every Err or None into a panic at the call site, so an expected runtime condition (missing file, bad input, closed connection) becomes a crash instead of an error the caller can handle. The failure also loses its context: the panic message names the unwrap, not the operation that failed or why. Propagating with ? keeps the program running and the error explainable.",
     target_items = [Function],
+    subsumed_by = ["E1016"],
     config_entry_name = "e1002_direct_unwrap_expect",
     config = E1002Config {
         enabled: bool = true,
@@ -43,6 +52,10 @@ define_checker! {
         categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
         /// Allow unwrap in test code
         allow_in_tests: bool = true,
+        /// Skip calls lexically inside `fn main`
+        exempt_main: bool = true,
+        /// Skip calls lexically inside functions annotated `#[test]` (or `#[tokio::test]` etc.)
+        exempt_test_fns: bool = true,
     },
     check_item(self, item, file_path) {
         // Skip test code if configured
@@ -54,6 +67,7 @@ define_checker! {
             violations: Vec::new(),
             file_path,
             checker: self,
+            exempt: false,
         };
         visitor.visit_item(item);
         Ok(visitor.violations)
@@ -64,10 +78,44 @@ struct UnwrapVisitor<'a> {
     violations: Vec<Violation>,
     file_path: &'a str,
     checker: &'a E1002DirectUnwrapExpect,
+    /// Inside `main` or a test function (per config), where calls are not reported
+    exempt: bool,
+}
+
+impl<'a> UnwrapVisitor<'a> {
+    /// Visit a function body, exempting it if it is `main` or a test function
+    fn in_fn(&mut self, attrs: &[syn::Attribute], is_main: bool, visit: impl FnOnce(&mut Self)) {
+        let config = &self.checker.config;
+        let exempt = (config.exempt_main && is_main) || (config.exempt_test_fns && is_test_fn(attrs));
+        let outer = std::mem::replace(&mut self.exempt, exempt);
+        visit(self);
+        self.exempt = outer;
+    }
+}
+
+/// `#[test]`, `#[tokio::test]`, `#[async_std::test]`, ...
+fn is_test_fn(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().segments.last().is_some_and(|s| s.ident == "test"))
 }
 
 impl<'a> Visit<'a> for UnwrapVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        let is_main = node.sig.ident == "main";
+        self.in_fn(&node.attrs, is_main, |v| syn::visit::visit_item_fn(v, node));
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.in_fn(&node.attrs, false, |v| syn::visit::visit_impl_item_fn(v, node));
+    }
+
     fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if self.exempt {
+            syn::visit::visit_expr_method_call(self, node);
+            return;
+        }
+
         let method_name = node.method.to_string();
 
         if method_name == "unwrap" {
@@ -246,4 +294,69 @@ mod tests {
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_main_and_test_fns_exempt_but_helpers_checked() {
+        let code = r#"
+            fn main() {
+                let config = load().unwrap();
+                let port = std::env::var("PORT").expect("PORT must be set");
+            }
+
+            fn load() -> Config {
+                std::fs::read_to_string("app.toml").unwrap().parse().unwrap()
+            }
+
+            #[test]
+            fn parses() {
+                assert_eq!(load().name.unwrap(), "app");
+            }
+
+            #[tokio::test]
+            async fn connects() {
+                connect().await.expect("connect");
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1002DirectUnwrapExpect::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "src/main.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.line == 8));
+    }
+
+    #[test]
+    fn test_exemptions_can_be_disabled() {
+        let code = r#"
+            fn main() {
+                let config = load().unwrap();
+            }
+
+            #[test]
+            fn parses() {
+                load().unwrap();
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1002DirectUnwrapExpect {
+            config: E1002Config {
+                exempt_main: false,
+                exempt_test_fns: false,
+                ..E1002Config::default()
+            },
+        };
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "src/main.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 2);
+    }
 }
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1003",
     name = "Direct use of unsafe code",
     suggestions = "Avoid unsafe code in production code",
+    rationale = "Inside unsafe the compiler stops checking the invariants that make Rust memory safe, so a single mistake can cause undefined behavior far from the block that caused it: corrupted data, crashes that depend on optimization level, or exploitable bugs. Every unsafe block widens the code that has to be audited by hand. Most production code can use a safe abstraction instead, and the rest should be small, isolated and documented.",
     target_items = [Function],
     config_entry_name = "e1003_unsafe_code",
     /// Configuration for E1003: Unsafe code checker
@@ -55,6 +56,7 @@ impl<'a> UnsafeVisitor<'a> {
             start.line,
             start.column.saturating_add(1),
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 }
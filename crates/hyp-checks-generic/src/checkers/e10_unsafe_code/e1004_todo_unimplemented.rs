@@ -16,6 +16,7 @@ define_checker! {
     code = "E1004",
     name = "todo!/unimplemented! macro in code",
     suggestions = "Replace with actual implementation or return Result/Option for incomplete features",
+    rationale = "todo!() and unimplemented!() compile, type-check and then panic when reached, so an unfinished code path ships silently and fails in production on the first input that takes it. Nothing at compile time or in the signature tells callers that the function does not work. Return an error (or do not expose the function) until it is implemented.",
     target_items = [Function],
     config_entry_name = "e1004_todo_unimplemented",
     config = E1004Config {
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1005",
     name = "Raw pointer dereference",
     suggestions = "Use references instead of raw pointers, or use safe abstractions like Box, Rc, Arc",
+    rationale = "Dereferencing a raw pointer is only sound if it is non-null, aligned, points to a live and initialized value, and does not alias a mutable reference, none of which the compiler checks. Violating any of these is undefined behavior that may appear to work until a compiler upgrade or a different allocation pattern breaks it. References, Box, Rc and Arc encode these guarantees in the type system.",
     target_items = [Function, Impl],
     config_entry_name = "e1005_raw_pointer_deref",
     config = E1005Config {
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1006",
     name = "Unsafe transmute",
     suggestions = "Use safe alternatives like From/Into traits, TryFrom/TryInto, or type-specific conversion methods like to_bits()/from_bits()",
+    rationale = "transmute reinterprets the bits of one type as another with no checks beyond equal size. Layout of non-repr(C) types is unspecified, so the result can change between compiler versions, and producing an invalid value (a bool that is 2, a dangling reference, a wrong enum discriminant) is instant undefined behavior. Dedicated conversions such as from_bits, from_ne_bytes or TryFrom express the same intent safely.",
     target_items = [Function, Impl],
     config_entry_name = "e1006_unsafe_transmute",
     config = E1006Config {
@@ -14,6 +14,7 @@ define_checker! {
     code = "E1007",
     name = "Potential null pointer dereference",
     suggestions = "Check for null before dereferencing raw pointers, or use Option<NonNull<T>>",
+    rationale = "Dereferencing a null pointer is undefined behavior, not a guaranteed segfault: the optimizer may assume it never happens and remove the checks around it. Pointers from FFI or from null_mut() need an explicit null check before use. NonNull and Option<NonNull<T>> make nullability part of the type.",
     target_items = [Function],
     config_entry_name = "e1007_null_pointer_deref",
     /// Configuration for E1007: Null pointer dereference checker
@@ -56,6 +57,7 @@ impl<'a> NullDerefVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 }
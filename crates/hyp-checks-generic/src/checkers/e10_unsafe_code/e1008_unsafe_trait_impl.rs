@@ -13,6 +13,7 @@ define_checker! {
     code = "E1008",
     name = "Unsafe trait implementation",
     suggestions = "Ensure the type truly satisfies the unsafe trait's safety requirements. Document why this is safe.",
+    rationale = "An unsafe trait has invariants the compiler cannot verify, and implementing it is a promise that the type upholds them. Code elsewhere, including the standard library, relies on that promise for memory safety, so a wrong unsafe impl causes undefined behavior in code that is itself entirely safe. Each impl needs a written argument for why the invariants hold.",
     target_items = [Impl],
     config_entry_name = "e1008_unsafe_trait_impl",
     /// Configuration for E1008: Unsafe trait implementation checker
@@ -56,6 +57,7 @@ impl<'a> UnsafeTraitVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 }
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1009",
     name = "UnsafeCell misuse",
     suggestions = "Use safe wrappers like Cell<T>, RefCell<T>, Mutex<T>, or RwLock<T> instead of UnsafeCell directly",
+    rationale = "UnsafeCell is the primitive underneath every interior-mutability type, and using it directly means re-implementing their guarantees by hand: no two &mut to the same value, no mutation while a & is alive, and synchronization if the value is shared between threads. Getting any of this wrong is a data race or aliasing violation, both undefined behavior. Cell, RefCell, Mutex and RwLock already provide checked versions.",
     target_items = [Struct, Function, Impl],
     config_entry_name = "e1009_unsafe_cell_misuse",
     /// Configuration for E1009: UnsafeCell misuse checker
@@ -14,6 +14,7 @@ define_checker! {
     code = "E1010",
     name = "Mutable static without synchronization",
     suggestions = "Use thread-safe alternatives like Mutex<T>, RwLock<T>, AtomicXxx, or lazy_static!/once_cell",
+    rationale = "A static mut can be read and written from any thread with no synchronization, so every access is a potential data race, which is undefined behavior. Even single-threaded, taking references to it can create aliasing &mut. Recent editions deny references to static mut for this reason; atomics, Mutex, OnceLock or LazyLock give shared global state that is safe.",
     target_items = [Static, Function, Impl],
     config_entry_name = "e1010_mutable_static",
     /// Configuration for E1010: Mutable static checker
@@ -56,6 +57,7 @@ impl<'a> MutableStaticVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1011",
     name = "Uninitialized memory access",
     suggestions = "Use MaybeUninit::zeroed() or proper initialization. Never read from MaybeUninit before calling assume_init().",
+    rationale = "Reading uninitialized memory is undefined behavior even for integers: the compiler may treat the value as anything, including different values at different uses. mem::uninitialized and assume_init on a value that was never written are the usual sources. MaybeUninit only helps if every byte is written before assume_init is called.",
     target_items = [Function, Impl],
     config_entry_name = "e1011_uninitialized_memory",
     /// Configuration for E1011: Uninitialized memory checker
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1012",
     name = "Unsafe auto trait implementation",
     suggestions = "Let the compiler automatically implement Send/Sync/Unpin. Use safe wrappers like Arc, Mutex instead of manual unsafe impl.",
+    rationale = "Send and Sync are implemented automatically when every field is thread-safe; an unsafe impl overrides the compiler's conclusion that the type is not. If the type contains Rc, raw pointers, Cell or similar, sharing it across threads becomes a data race that no other code can detect. Wrapping the non-thread-safe parts in Arc and Mutex usually makes the manual impl unnecessary.",
     target_items = [Impl],
     config_entry_name = "e1012_unsafe_auto_trait",
     /// Configuration for E1012: Unsafe auto trait implementation checker
@@ -14,6 +14,7 @@ define_checker! {
     code = "E1013",
     name = "Union with unsafe field access",
     suggestions = "Consider using enums with explicit variants, or ensure all union access is carefully validated",
+    rationale = "Reading a union field reinterprets whatever bytes were last written, and reading a field other than the one that was written is undefined behavior for most types. Nothing in the type records which field is active, so every access depends on external bookkeeping being right. An enum stores the active variant and checks it on every match.",
     target_items = [Union, Function, Impl],
     config_entry_name = "e1013_union_field_access",
     /// Configuration for E1013: Union field access checker
@@ -64,6 +65,7 @@ impl<'a> UnionVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 }
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1014",
     name = "Raw pointer arithmetic without bounds checking",
     suggestions = "Use slice iterators or checked pointer operations. Validate bounds before pointer arithmetic.",
+    rationale = "Pointer arithmetic (add, offset, sub) is undefined behavior as soon as the result leaves the bounds of the original allocation, even if the pointer is never dereferenced. Out-of-bounds reads and writes built this way are a classic source of memory corruption and security bugs. Slices and their iterators carry the length and check it.",
     target_items = [Function],
     config_entry_name = "e1014_raw_pointer_arithmetic",
     /// Configuration for E1014: Raw pointer arithmetic checker
@@ -56,6 +57,7 @@ impl<'a> PointerArithmeticVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 }
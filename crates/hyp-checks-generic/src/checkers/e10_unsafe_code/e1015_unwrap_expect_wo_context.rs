@@ -13,6 +13,7 @@ define_checker! {
     code = "E1015",
     name = "Unwrap/expect without context",
     suggestions = "Use pattern matching, if let, or the ? operator instead. If unwrap is necessary, use expect() with a descriptive message.",
+    rationale = "A bare unwrap() panics with a generic message such as called Option::unwrap() on a None value, which says nothing about what was missing or why it was expected to be there. When it fires in production, the only clue is a line number. Handling the case, or at least expect() with a message stating the invariant, makes the failure diagnosable.",
     target_items = [Function],
     config_entry_name = "e1015_unwrap_expect",
     /// Configuration for E1015: Unwrap/expect checker
@@ -57,6 +58,7 @@ impl<'a> UnwrapExpectVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 }
@@ -38,9 +38,20 @@
 //! use parking_lot::Mutex;
 //! let guard = mutex.lock(); // No Result, no poisoning!
 //! ```
+//!
+//! ## parking_lot awareness
+//!
+//! `parking_lot` locks never poison, so their guards need no unwrap. The
+//! receiver's type is looked up (best effort) from parameter and `let`
+//! annotations, `Mutex::new(..)` initializers and `use parking_lot::..`
+//! imports anywhere in the file. Only types listed in `recognized_mutex_types`
+//! are reported; parking_lot locks are reported only when `flag_parking_lot`
+//! is set. Receivers of unknown type (e.g. struct
+//! fields) are still reported.
 
 use crate::{checker::Checker, define_checker, violation::Violation};
 
+use std::collections::{HashMap, HashSet};
 use syn::visit::Visit;
 
 define_checker! {
@@ -49,31 +60,217 @@ define_checker! {
     code = "E1016",
     name = "Mutex raw lock().unwrap() (causes panic cascades)",
     suggestions = "Use lock().unwrap_or_else(|e| e.into_inner()) to recover, or propagate error with ?, or use parking_lot::Mutex",
+    rationale = "lock() returns Err only when another thread panicked while holding the lock. unwrap() on that result turns one panic into a panic in every thread that touches the mutex afterwards, so a single failure cascades through the whole program. Recovering the guard with into_inner(), or handling PoisonError deliberately, keeps the failure contained.",
     target_items = [Function],
     config_entry_name = "e1016_mutex_unwrap",
     config = E1016Config {
         enabled: bool = true,
         severity: crate::config::SeverityLevel = crate::config::SeverityLevel::High,
         categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Lock types whose lock()/read()/write() return a poisoning Result, matched against
+        /// the annotated type path as written (after peeling `&`, `Arc`, `Rc` and `Box`)
+        recognized_mutex_types: Vec<String> = ["Mutex", "RwLock", "sync::Mutex", "sync::RwLock", "std::sync::Mutex", "std::sync::RwLock"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        /// Also report parking_lot locks (which never poison), for teams that ban them
+        flag_parking_lot: bool = false,
     },
-    check_item(self, item, file_path) {
+    check_item(self, _item, _file_path) {
+        // `use parking_lot::..` imports usually sit at file level, see check_file
+        Ok(Vec::new())
+    }
+    check_file(self, file, file_path) {
+        let mut imports = ParkingLotImports::default();
+        imports.visit_file(file);
+
         let mut visitor = MutexUnwrapVisitor {
             violations: Vec::new(),
             file_path,
             checker: self,
+            parking_lot_names: imports.names,
+            lock_types: HashMap::new(),
         };
-        visitor.visit_item(item);
+        visitor.visit_file(file);
         Ok(visitor.violations)
     }
 }
 
+/// What is known about the type of a lock receiver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LockKind {
+    /// One of `recognized_mutex_types` (std): lock() returns a poisoning Result
+    Poisoning,
+    /// parking_lot lock: lock() returns the guard directly
+    ParkingLot,
+    /// Some other annotated type (e.g. `File`, `tokio::sync::Mutex`)
+    Other,
+}
+
+/// Names brought into scope with `use parking_lot::...` anywhere in the file
+#[derive(Default)]
+struct ParkingLotImports {
+    names: HashSet<String>,
+}
+
+impl<'a> Visit<'a> for ParkingLotImports {
+    fn visit_item_use(&mut self, node: &'a syn::ItemUse) {
+        if let syn::UseTree::Path(path) = &node.tree {
+            if path.ident == "parking_lot" {
+                collect_use_names(&path.tree, &mut self.names);
+            }
+        }
+    }
+}
+
+fn collect_use_names(tree: &syn::UseTree, names: &mut HashSet<String>) {
+    match tree {
+        syn::UseTree::Path(path) => collect_use_names(&path.tree, names),
+        syn::UseTree::Name(name) => {
+            names.insert(name.ident.to_string());
+        }
+        syn::UseTree::Rename(rename) => {
+            names.insert(rename.rename.to_string());
+        }
+        syn::UseTree::Group(group) => group.items.iter().for_each(|item| collect_use_names(item, names)),
+        syn::UseTree::Glob(_) => {
+            names.extend(["Mutex", "RwLock", "ReentrantMutex", "FairMutex"].map(String::from));
+        }
+    }
+}
+
 struct MutexUnwrapVisitor<'a> {
     violations: Vec<Violation>,
     file_path: &'a str,
     checker: &'a E1016MutexUnwrap,
+    /// Lock type names imported from parking_lot
+    parking_lot_names: HashSet<String>,
+    /// Known lock kinds of parameters and locals in the current function
+    lock_types: HashMap<String, LockKind>,
+}
+
+impl<'a> MutexUnwrapVisitor<'a> {
+    fn classify(&self, path: &syn::Path) -> LockKind {
+        let written = path_string(path);
+        let is_parking_lot = path.segments.first().is_some_and(|s| s.ident == "parking_lot")
+            || (path.segments.len() == 1 && self.parking_lot_names.contains(&written));
+        if is_parking_lot {
+            LockKind::ParkingLot
+        } else if self.checker.config.recognized_mutex_types.contains(&written) {
+            LockKind::Poisoning
+        } else {
+            LockKind::Other
+        }
+    }
+
+    /// Kind of an annotated type, looking through `&`, `Arc`, `Rc` and `Box`
+    fn type_kind(&self, ty: &syn::Type) -> Option<LockKind> {
+        match ty {
+            syn::Type::Reference(reference) => self.type_kind(&reference.elem),
+            syn::Type::Paren(paren) => self.type_kind(&paren.elem),
+            syn::Type::Path(type_path) => {
+                let last = type_path.path.segments.last()?;
+                if matches!(last.ident.to_string().as_str(), "Arc" | "Rc" | "Box") {
+                    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+                        return None;
+                    };
+                    return args.args.iter().find_map(|arg| match arg {
+                        syn::GenericArgument::Type(inner) => self.type_kind(inner),
+                        _ => None,
+                    });
+                }
+                Some(self.classify(&type_path.path))
+            }
+            _ => None,
+        }
+    }
+
+    /// Kind of a `Mutex::new(..)` initializer, looking through `Arc::new`/`Rc::new`/`Box::new`
+    fn init_kind(&self, expr: &syn::Expr) -> Option<LockKind> {
+        let syn::Expr::Call(call) = expr else {
+            return None;
+        };
+        let syn::Expr::Path(func) = &*call.func else {
+            return None;
+        };
+        let mut type_path = func.path.clone();
+        if type_path.segments.pop().is_none_or(|ctor| ctor.value().ident != "new") {
+            return None;
+        }
+        type_path.segments.pop_punct();
+        match type_path.segments.last()?.ident.to_string().as_str() {
+            "Arc" | "Rc" | "Box" => self.init_kind(call.args.first()?),
+            _ => Some(self.classify(&type_path)),
+        }
+    }
+
+    fn record(&mut self, pat: &syn::Pat, kind: Option<LockKind>) {
+        if let (syn::Pat::Ident(ident), Some(kind)) = (pat, kind) {
+            self.lock_types.insert(ident.ident.to_string(), kind);
+        }
+    }
+
+    fn in_fn(&mut self, sig: &'a syn::Signature, visit: impl FnOnce(&mut Self)) {
+        let outer = std::mem::take(&mut self.lock_types);
+        for input in &sig.inputs {
+            if let syn::FnArg::Typed(pat_type) = input {
+                let kind = self.type_kind(&pat_type.ty);
+                self.record(&pat_type.pat, kind);
+            }
+        }
+        visit(self);
+        self.lock_types = outer;
+    }
+
+    /// Whether a lock call on `receiver` should be reported
+    fn is_reported_receiver(&self, receiver: &syn::Expr) -> bool {
+        let kind = match receiver {
+            syn::Expr::Path(path) => path
+                .path
+                .get_ident()
+                .and_then(|ident| self.lock_types.get(&ident.to_string()).copied()),
+            _ => None,
+        };
+        match kind {
+            // Unknown receivers (fields, call results) keep being reported
+            None | Some(LockKind::Poisoning) => true,
+            Some(LockKind::ParkingLot) => self.checker.config.flag_parking_lot,
+            Some(LockKind::Other) => false,
+        }
+    }
+}
+
+fn path_string(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
 }
 
 impl<'a> Visit<'a> for MutexUnwrapVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.in_fn(&node.sig, |v| syn::visit::visit_item_fn(v, node));
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.in_fn(&node.sig, |v| syn::visit::visit_impl_item_fn(v, node));
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        syn::visit::visit_local(self, node);
+        match &node.pat {
+            syn::Pat::Type(pat_type) => {
+                let kind = self.type_kind(&pat_type.ty);
+                self.record(&pat_type.pat, kind);
+            }
+            pat => {
+                let kind = node.init.as_ref().and_then(|init| self.init_kind(&init.expr));
+                self.record(pat, kind);
+            }
+        }
+    }
+
     fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
         let method_name = node.method.to_string();
 
@@ -82,8 +279,11 @@ impl<'a> Visit<'a> for MutexUnwrapVisitor<'a> {
             if let syn::Expr::MethodCall(inner) = &*node.receiver {
                 let inner_method = inner.method.to_string();
 
-                // Check if it's a lock/read/write call on a mutex/rwlock
-                if matches!(inner_method.as_str(), "lock" | "try_lock" | "read" | "write" | "try_read" | "try_write") {
+                // Check if it's a lock/read/write call on a mutex/rwlock (lock methods take no arguments)
+                if matches!(inner_method.as_str(), "lock" | "try_lock" | "read" | "write" | "try_read" | "try_write")
+                    && inner.args.is_empty()
+                    && self.is_reported_receiver(&inner.receiver)
+                {
                     let lock_type = match inner_method.as_str() {
                         "lock" | "try_lock" => "Mutex",
                         "read" | "try_read" | "write" | "try_write" => "RwLock",
@@ -138,10 +338,7 @@ mod tests {
         let syntax = syn::parse_file(code).unwrap();
         let checker = E1016MutexUnwrap::default();
 
-        let mut violations = Vec::new();
-        for item in &syntax.items {
-            violations.extend(checker.check_item(item, "test.rs").unwrap());
-        }
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
 
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("panic cascades"));
@@ -161,10 +358,7 @@ mod tests {
         let syntax = syn::parse_file(code).unwrap();
         let checker = E1016MutexUnwrap::default();
 
-        let mut violations = Vec::new();
-        for item in &syntax.items {
-            violations.extend(checker.check_item(item, "test.rs").unwrap());
-        }
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
 
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("RwLock"));
@@ -183,10 +377,7 @@ mod tests {
         let syntax = syn::parse_file(code).unwrap();
         let checker = E1016MutexUnwrap::default();
 
-        let mut violations = Vec::new();
-        for item in &syntax.items {
-            violations.extend(checker.check_item(item, "test.rs").unwrap());
-        }
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
 
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("expect()"));
@@ -205,10 +396,7 @@ mod tests {
         let syntax = syn::parse_file(code).unwrap();
         let checker = E1016MutexUnwrap::default();
 
-        let mut violations = Vec::new();
-        for item in &syntax.items {
-            violations.extend(checker.check_item(item, "test.rs").unwrap());
-        }
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
 
         assert_eq!(violations.len(), 0);
     }
@@ -227,10 +415,7 @@ mod tests {
         let syntax = syn::parse_file(code).unwrap();
         let checker = E1016MutexUnwrap::default();
 
-        let mut violations = Vec::new();
-        for item in &syntax.items {
-            violations.extend(checker.check_item(item, "test.rs").unwrap());
-        }
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
 
         assert_eq!(violations.len(), 0);
     }
@@ -250,11 +435,79 @@ mod tests {
         let syntax = syn::parse_file(code).unwrap();
         let checker = E1016MutexUnwrap::default();
 
-        let mut violations = Vec::new();
-        for item in &syntax.items {
-            violations.extend(checker.check_item(item, "test.rs").unwrap());
-        }
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
 
         assert_eq!(violations.len(), 3);
     }
+
+    #[test]
+    fn test_parking_lot_and_non_lock_receivers_not_flagged() {
+        let code = r#"
+            use parking_lot::Mutex;
+
+            fn example(pl: &parking_lot::Mutex<Option<u8>>, file: &mut File, shared: Arc<parking_lot::RwLock<Option<u8>>>) {
+                let local: Mutex<Option<u8>> = Mutex::new(None);
+                let built = Arc::new(Mutex::new(Some(1)));
+                let a = pl.try_lock().unwrap();
+                let b = local.lock().unwrap();
+                let c = built.try_lock().expect("free");
+                let d = shared.read().unwrap();
+                file.write().unwrap();
+                reader.read(&mut buf).unwrap();
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1016MutexUnwrap::default();
+
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_std_and_unknown_receivers_flagged_next_to_parking_lot() {
+        let code = r#"
+            impl Cache {
+                fn refresh(&self, pl: &parking_lot::Mutex<Option<u8>>) {
+                    let std_lock = std::sync::Mutex::new(0);
+                    let a = std_lock.lock().unwrap();
+                    let b = self.entries.write().unwrap();
+                    let c = pl.try_lock().unwrap();
+                }
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1016MutexUnwrap::default();
+
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 5);
+        assert_eq!(violations[1].line, 6);
+
+        let strict = E1016MutexUnwrap {
+            config: E1016Config {
+                flag_parking_lot: true,
+                ..E1016Config::default()
+            },
+        };
+        let violations = strict.check_file(&syntax, "test.rs").unwrap();
+        assert_eq!(violations.len(), 3);
+    }
+
+    #[test]
+    fn test_file_level_parking_lot_import() {
+        let code = r#"
+            use parking_lot::Mutex;
+
+            fn f(m: &Mutex<Option<i32>>) -> i32 {
+                m.lock().unwrap()
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let violations = E1016MutexUnwrap::default().check_file(&syntax, "test.rs").unwrap();
+        assert!(violations.is_empty());
+    }
 }
@@ -15,6 +15,7 @@ define_checker! {
     code = "E1017",
     name = "Prohibit std::mem::transmute",
     suggestions = "Use safe alternatives: f32::from_bits(), to_bits(), TryFrom::try_from(), or proper conversions",
+    rationale = "std::mem::transmute bypasses the type system entirely, and its safety depends on layout details that are mostly unspecified for Rust types. Some teams forbid it outright so that every bit-level conversion goes through a named, checked API. Safe conversions exist for the common cases: from_bits and to_bits, from_ne_bytes, TryFrom and pointer casts.",
     target_items = [Function, Impl],
     config_entry_name = "e1017_prohibit_transmute",
     /// Configuration for E1017: Prohibit transmute checker
@@ -87,6 +87,7 @@ mod tests {
                 severity: crate::config::SeverityLevel::Low,
                 categories: vec![crate::config::CheckerCategory::Complexity],
                 max_lines: 250,
+                ..E1106Config::default()
             }))
             .unwrap();
 
@@ -142,6 +143,7 @@ mod tests {
                 severity: crate::config::SeverityLevel::Low,
                 categories: vec![crate::config::CheckerCategory::Complexity],
                 max_lines: 5,
+                ..E1106Config::default()
             }))
             .unwrap();
 
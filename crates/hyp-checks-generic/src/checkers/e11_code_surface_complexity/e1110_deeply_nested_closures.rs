@@ -3,6 +3,12 @@
 //! Detects closures nested beyond a configurable depth threshold.
 //! Deeply nested closures make code hard to read and maintain.
 //!
+//! Nesting is followed through method-call arguments (`.map(|x| x.map(|y| ..))`)
+//! and async blocks. A closure returned directly by its enclosing closure
+//! (`|a| move |b| move |c| ..`) forms a curried chain; it counts as another
+//! level unless `count_returned_closures` is disabled. One violation is reported
+//! per outermost closure, at its span, with the deepest level measured inside it.
+//!
 //! Example:
 //! ```text
 //! // Bad: 5 levels of nesting
@@ -20,7 +26,7 @@
 //! ```
 
 use crate::{checker::Checker, define_checker, violation::Violation};
-use syn::visit::Visit;
+use syn::{spanned::Spanned, visit::Visit};
 
 define_checker! {
     /// Checker for E1110: Deeply nested callbacks/closures
@@ -34,8 +40,11 @@ define_checker! {
         enabled: bool = true,
         severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
         categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
-        /// Maximum allowed closure nesting depth
-        max_depth: usize = 4,
+        /// Maximum allowed closure nesting depth (formerly `max_depth`)
+        #[serde(alias = "max_depth")]
+        max_closure_depth: usize = 4,
+        /// Count a closure returned directly by its enclosing closure as another level
+        count_returned_closures: bool = true,
     },
     check_item(self, item, file_path) {
         let mut visitor = ClosureNestingVisitor {
@@ -44,6 +53,7 @@ define_checker! {
             checker: self,
             current_depth: 0,
             max_depth_found: 0,
+            returned_closure: None,
         };
         visitor.visit_item(item);
         Ok(visitor.violations)
@@ -55,31 +65,43 @@ struct ClosureNestingVisitor<'a> {
     file_path: &'a str,
     checker: &'a E1110DeeplyNestedClosures,
     current_depth: usize,
+    /// Deepest level reached inside the outermost closure being visited
     max_depth_found: usize,
+    /// Closure returned directly by the closure being visited
+    returned_closure: Option<&'a syn::ExprClosure>,
 }
 
-impl<'a> Visit<'a> for ClosureNestingVisitor<'a> {
-    fn visit_expr_closure(&mut self, node: &'a syn::ExprClosure) {
-        use syn::spanned::Spanned;
+impl<'a> ClosureNestingVisitor<'a> {
+    /// Visit a closure-like node one level deeper (when `counted`); the outermost
+    /// node reports the deepest level found beneath it
+    fn nest(&mut self, kind: &str, span: proc_macro2::Span, counted: bool, visit: impl FnOnce(&mut Self)) {
+        let outermost = self.current_depth == 0;
+        if counted {
+            self.current_depth += 1;
+        }
+        self.max_depth_found = self.max_depth_found.max(self.current_depth);
 
-        self.current_depth += 1;
+        visit(self);
 
-        if self.current_depth > self.max_depth_found {
-            self.max_depth_found = self.current_depth;
+        if counted {
+            self.current_depth -= 1;
+        }
+        if !outermost {
+            return;
         }
 
-        // Report when we exceed the threshold
-        if self.current_depth > self.checker.config.max_depth {
-            let start = node.span().start();
+        let max_depth = self.checker.config.max_closure_depth;
+        let depth = std::mem::take(&mut self.max_depth_found);
+        if depth > max_depth {
+            let start = span.start();
             self.violations.push(
                 Violation::new(
                     self.checker.code(),
                     self.checker.name(),
                     self.checker.severity().into(),
                     format!(
-                        "Closure nested {} levels deep (max {}). Deeply nested closures are hard to read and maintain.",
-                        self.current_depth,
-                        self.checker.config.max_depth
+                        "{} contains closures nested {} levels deep (max {}). Deeply nested closures are hard to read and maintain.",
+                        kind, depth, max_depth
                     ),
                     self.file_path,
                     start.line,
@@ -88,46 +110,38 @@ impl<'a> Visit<'a> for ClosureNestingVisitor<'a> {
                 .with_suggestion(self.checker.suggestions()),
             );
         }
+    }
+}
 
-        // Continue visiting nested closures
-        syn::visit::visit_expr_closure(self, node);
-
-        self.current_depth -= 1;
+impl<'a> Visit<'a> for ClosureNestingVisitor<'a> {
+    fn visit_expr_closure(&mut self, node: &'a syn::ExprClosure) {
+        let is_returned = self.returned_closure.is_some_and(|returned| std::ptr::eq(returned, node));
+        let counted = !is_returned || self.checker.config.count_returned_closures;
+        let outer = std::mem::replace(&mut self.returned_closure, returned_closure(&node.body));
+        self.nest("Closure", node.span(), counted, |visitor| {
+            syn::visit::visit_expr_closure(visitor, node)
+        });
+        self.returned_closure = outer;
     }
 
     // Also track async blocks as they behave like closures
     fn visit_expr_async(&mut self, node: &'a syn::ExprAsync) {
-        use syn::spanned::Spanned;
-
-        self.current_depth += 1;
-
-        if self.current_depth > self.max_depth_found {
-            self.max_depth_found = self.current_depth;
-        }
-
-        if self.current_depth > self.checker.config.max_depth {
-            let start = node.span().start();
-            self.violations.push(
-                Violation::new(
-                    self.checker.code(),
-                    self.checker.name(),
-                    self.checker.severity().into(),
-                    format!(
-                        "Async block nested {} levels deep (max {}). Consider extracting into a named async function.",
-                        self.current_depth,
-                        self.checker.config.max_depth
-                    ),
-                    self.file_path,
-                    start.line,
-                    start.column + 1,
-                )
-                .with_suggestion(self.checker.suggestions()),
-            );
-        }
-
-        syn::visit::visit_expr_async(self, node);
+        self.nest("Async block", node.span(), true, |visitor| {
+            syn::visit::visit_expr_async(visitor, node)
+        });
+    }
+}
 
-        self.current_depth -= 1;
+/// The closure a closure body evaluates to (`|a| move |b| ..` or `|a| { ..; move |b| .. }`)
+fn returned_closure(body: &syn::Expr) -> Option<&syn::ExprClosure> {
+    match body {
+        syn::Expr::Closure(closure) => Some(closure),
+        syn::Expr::Block(block) => match block.block.stmts.last() {
+            Some(syn::Stmt::Expr(expr, None)) => returned_closure(expr),
+            _ => None,
+        },
+        syn::Expr::Paren(paren) => returned_closure(&paren.expr),
+        _ => None,
     }
 }
 
@@ -241,7 +255,7 @@ mod tests {
 
         let syntax = syn::parse_file(code).unwrap();
         let mut checker = E1110DeeplyNestedClosures::default();
-        checker.config.max_depth = 1; // Set low threshold
+        checker.config.max_closure_depth = 1; // Set low threshold
 
         let mut violations = Vec::new();
         for item in &syntax.items {
@@ -251,4 +265,59 @@ mod tests {
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("2 levels"));
     }
+
+    #[test]
+    fn test_reports_outermost_closure_once() {
+        let code = r#"
+            fn nested() {
+                let _ = rows.iter().map(|row| {
+                    row.iter().map(|cell| cell.iter().map(|x| x.map(|y| y.map(|z| z + 1))))
+                });
+                let _ = rows.iter().map(|row| row.len());
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1110DeeplyNestedClosures::default();
+        let violations = checker.check_item(&syntax.items[0], "test.rs").unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("nested 5 levels deep (max 4)"));
+        assert_eq!((violations[0].line, violations[0].column), (3, 41));
+    }
+
+    #[test]
+    fn test_returned_closure_chain() {
+        let code = r#"
+            fn curried() -> impl Fn(i32) -> Box<dyn Fn(i32) -> i32> {
+                let add = |a| move |b| move |c| move |d| move |e| a + b + c + d + e;
+                let _ = add;
+                |a| {
+                    let offset = a * 2;
+                    Box::new(move |b| offset + b)
+                }
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let violations = E1110DeeplyNestedClosures::default()
+            .check_item(&syntax.items[0], "test.rs")
+            .unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("5 levels"));
+
+        let mut checker = E1110DeeplyNestedClosures::default();
+        checker.config.count_returned_closures = false;
+        assert!(checker.check_item(&syntax.items[0], "test.rs").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_old_max_depth_key_still_loads() {
+        let config = crate::config::AnalyzerConfig::from_toml(
+            "[checkers.e1110_deeply_nested_closures]\nmax_depth = 2\n",
+        )
+        .unwrap();
+        let e1110_config: E1110Config = config.get_checker_config("e1110_deeply_nested_closures").unwrap();
+        assert_eq!(e1110_config.max_closure_depth, 2);
+    }
 }
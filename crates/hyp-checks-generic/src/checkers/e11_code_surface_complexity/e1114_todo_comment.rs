@@ -0,0 +1,363 @@
+//! E1114: TODO/FIXME comment density
+//!
+//! Reports tech-debt markers (`TODO`, `FIXME`, `XXX`, `HACK`) in line, block
+//! and doc comments. `syn` drops ordinary comments, so this checker scans the
+//! raw source text via `check_source` instead of the AST.
+
+use crate::{define_checker, violation::Violation};
+
+define_checker! {
+    /// Checker for E1114: TODO/FIXME comment density
+    E1114TodoComment,
+    code = "E1114",
+    name = "Tech-debt marker in comment",
+    suggestions = "Resolve the marker, or track the work in an issue and reference it instead of leaving TODO/FIXME in the code",
+    target_items = [Module],
+    config_entry_name = "e1114_todo_comment",
+    /// Configuration for E1114: TODO/FIXME comment checker
+    config = E1114Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Marker keywords to look for (matched as whole, case-sensitive words)
+        markers: Vec<String> = vec![
+            "TODO".to_string(),
+            "FIXME".to_string(),
+            "XXX".to_string(),
+            "HACK".to_string(),
+        ],
+        /// Number of markers allowed per file before they are reported
+        max_markers_per_file: usize = 0,
+        /// Skip markers that reference a tracker entry, e.g. `TODO(#123)` or `FIXME(alice)`
+        allow_referenced: bool = true,
+    },
+    check_item(self, _item, _file_path) {
+        // Comments are not part of the AST; see check_source
+        Ok(Vec::new())
+    }
+    check_source(self, source, file_path) {
+        let found: Vec<(String, usize, usize)> = extract_comments(source)
+            .iter()
+            .flat_map(|comment| find_markers(comment, &self.config.markers, self.config.allow_referenced))
+            .collect();
+
+        if found.len() <= self.config.max_markers_per_file {
+            return Ok(Vec::new());
+        }
+
+        let total = found.len();
+        Ok(found
+            .into_iter()
+            .map(|(marker, line, column)| {
+                Violation::new(
+                    self.code(),
+                    self.name(),
+                    self.severity().into(),
+                    format!(
+                        "'{}' marker in comment ({} markers in this file, max {}).",
+                        marker, total, self.config.max_markers_per_file
+                    ),
+                    file_path,
+                    line,
+                    column,
+                )
+                .with_suggestion(self.suggestions())
+            })
+            .collect())
+    }
+}
+
+/// A comment in source text with the 1-based position of its first character
+struct Comment {
+    line: usize,
+    column: usize,
+    text: String,
+}
+
+/// Find whole-word `markers` in a comment, returning (marker, line, column) for each.
+/// With `allow_referenced`, markers directly followed by `(` (e.g. `TODO(#123)`) are skipped.
+fn find_markers(comment: &Comment, markers: &[String], allow_referenced: bool) -> Vec<(String, usize, usize)> {
+    let chars: Vec<char> = comment.text.chars().collect();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut found = Vec::new();
+    let (mut line, mut column) = (comment.line, comment.column);
+
+    for (i, &c) in chars.iter().enumerate() {
+        let at_word_start = i == 0 || !is_word(chars[i - 1]);
+        if at_word_start {
+            for marker in markers {
+                let len = marker.chars().count();
+                let matches = chars.get(i..i + len).is_some_and(|s| s.iter().copied().eq(marker.chars()));
+                let next = chars.get(i + len).copied();
+                let at_word_end = next.is_none_or(|n| !is_word(n));
+                let referenced = allow_referenced && next == Some('(');
+                if matches && at_word_end && !referenced {
+                    found.push((marker.clone(), line, column));
+                }
+            }
+        }
+
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    found
+}
+
+/// Character cursor tracking 1-based line/column positions
+struct Cursor {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Cursor {
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+}
+
+/// Extract line, block and doc comments from Rust source, skipping string and char literals
+fn extract_comments(source: &str) -> Vec<Comment> {
+    let mut cursor = Cursor {
+        chars: source.chars().collect(),
+        pos: 0,
+        line: 1,
+        column: 1,
+    };
+    let mut comments = Vec::new();
+
+    while let Some(c) = cursor.peek(0) {
+        match (c, cursor.peek(1)) {
+            ('/', Some('/')) => {
+                let (line, column) = (cursor.line, cursor.column);
+                let mut text = String::new();
+                while let Some(next) = cursor.peek(0).filter(|&n| n != '\n') {
+                    text.push(next);
+                    cursor.bump();
+                }
+                comments.push(Comment { line, column, text });
+            }
+            ('/', Some('*')) => {
+                let (line, column) = (cursor.line, cursor.column);
+                let mut text = String::new();
+                let mut depth = 0usize;
+                while let Some(next) = cursor.peek(0) {
+                    let delimiter = match (next, cursor.peek(1)) {
+                        ('/', Some('*')) => Some(true),
+                        ('*', Some('/')) => Some(false),
+                        _ => None,
+                    };
+                    let Some(opens) = delimiter else {
+                        text.push(next);
+                        cursor.bump();
+                        continue;
+                    };
+                    for _ in 0..2 {
+                        text.extend(cursor.bump());
+                    }
+                    if opens {
+                        depth += 1;
+                    } else {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                }
+                comments.push(Comment { line, column, text });
+            }
+            ('"', _) => skip_string(&mut cursor),
+            ('r', Some('"' | '#')) if !follows_identifier(&cursor) => skip_raw_string(&mut cursor),
+            ('\'', _) => skip_char_or_lifetime(&mut cursor),
+            _ => {
+                cursor.bump();
+            }
+        }
+    }
+
+    comments
+}
+
+/// Whether the `r` at the cursor continues an identifier (`for`, `bar`) rather than
+/// starting a raw string (`r"`, `br"`)
+fn follows_identifier(cursor: &Cursor) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let before = |n: usize| cursor.pos.checked_sub(n).map(|i| cursor.chars[i]);
+    match before(1) {
+        Some('b') => before(2).is_some_and(is_ident),
+        Some(c) => is_ident(c),
+        None => false,
+    }
+}
+
+/// Skip a `"..."` string literal, honoring backslash escapes
+fn skip_string(cursor: &mut Cursor) {
+    cursor.bump();
+    while let Some(c) = cursor.bump() {
+        match c {
+            '\\' => {
+                cursor.bump();
+            }
+            '"' => return,
+            _ => {}
+        }
+    }
+}
+
+/// Skip a raw string literal `r"..."` / `r#"..."#`; a lone `r#ident` is left alone
+fn skip_raw_string(cursor: &mut Cursor) {
+    let mut hashes = 0;
+    while cursor.peek(1 + hashes) == Some('#') {
+        hashes += 1;
+    }
+    if cursor.peek(1 + hashes) != Some('"') {
+        // Raw identifier like r#type
+        cursor.bump();
+        return;
+    }
+    for _ in 0..hashes + 2 {
+        cursor.bump();
+    }
+    while let Some(c) = cursor.bump() {
+        if c == '"' && (0..hashes).all(|i| cursor.peek(i) == Some('#')) {
+            for _ in 0..hashes {
+                cursor.bump();
+            }
+            return;
+        }
+    }
+}
+
+/// Skip a char literal (`'a'`, `'\n'`); for lifetimes only the quote is consumed
+fn skip_char_or_lifetime(cursor: &mut Cursor) {
+    match (cursor.peek(1), cursor.peek(2)) {
+        (Some('\\'), _) => {
+            cursor.bump();
+            cursor.bump();
+            while let Some(c) = cursor.bump() {
+                if c == '\'' {
+                    return;
+                }
+            }
+        }
+        (Some(_), Some('\'')) => {
+            cursor.bump();
+            cursor.bump();
+            cursor.bump();
+        }
+        _ => {
+            cursor.bump();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::Checker;
+
+    fn check_source(code: &str, config: E1114Config) -> Vec<Violation> {
+        let checker = E1114TodoComment { config };
+        checker.check_source(code, "test.rs").unwrap()
+    }
+
+    #[test]
+    fn test_detects_markers_in_comments() {
+        let code = r#"
+// TODO: handle errors
+fn f() {
+    /// FIXME this is wrong
+    let x = 1; /* HACK */
+}
+"#;
+        let violations = check_source(code, E1114Config::default());
+        assert_eq!(violations.len(), 3);
+        assert_eq!(violations[0].code, "E1114");
+        assert!(violations[0].message.contains("'TODO'"));
+        assert_eq!((violations[0].line, violations[0].column), (2, 4));
+        assert!(violations[1].message.contains("'FIXME'"));
+        assert_eq!(violations[1].line, 4);
+        assert!(violations[2].message.contains("'HACK'"));
+        assert_eq!((violations[2].line, violations[2].column), (5, 19));
+    }
+
+    #[test]
+    fn test_ignores_strings_and_partial_words() {
+        let code = r##"
+fn f() -> &'static str {
+    let c = '"';
+    let _raw = r#"// TODO in raw string"#;
+    // TODOS and XXXL are not markers, nor is todo
+    "// FIXME inside a string"
+}
+"##;
+        assert!(check_source(code, E1114Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_multiline_block_comment_position() {
+        let code = "/*\n * first line\n * XXX: second\n */\nfn f() {}";
+        let violations = check_source(code, E1114Config::default());
+        assert_eq!(violations.len(), 1);
+        assert_eq!((violations[0].line, violations[0].column), (3, 4));
+    }
+
+    #[test]
+    fn test_threshold_and_custom_markers() {
+        let code = "// TODO one\n// TODO two\n// NOTE three\n// TODO(#42) tracked\n";
+
+        let config = E1114Config {
+            max_markers_per_file: 2,
+            ..E1114Config::default()
+        };
+        assert!(check_source(code, config).is_empty());
+
+        let config = E1114Config {
+            markers: vec!["NOTE".to_string()],
+            ..E1114Config::default()
+        };
+        let violations = check_source(code, config);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'NOTE'"));
+    }
+
+    #[test]
+    fn test_referenced_markers() {
+        let code = "// TODO(#42): tracked\n// FIXME(alice) later\n";
+        assert!(check_source(code, E1114Config::default()).is_empty());
+
+        let config = E1114Config {
+            allow_referenced: false,
+            ..E1114Config::default()
+        };
+        assert_eq!(check_source(code, config).len(), 2);
+    }
+
+    #[test]
+    fn test_check_item_reports_nothing() {
+        let syntax = syn::parse_file("// TODO\nfn f() {}").unwrap();
+        let checker = E1114TodoComment::default();
+        assert!(checker.check_item(&syntax.items[0], "test.rs").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,403 @@
+//! E1115: Large value returned by value
+//!
+//! Detects functions whose return type is estimated to be large, so every call
+//! copies a big block of memory (and may overflow small thread stacks).
+//! Complements E1701, which covers large *parameters*.
+//!
+//! There is no type layout information at the syntax level, so the size is a
+//! heuristic estimate from the type's shape:
+//! - primitives use their real size, `String`/`Vec`/maps their header size,
+//!   and references, `Box`, `Rc`, `Arc` are pointer-sized
+//! - arrays multiply the element size by a literal length or a `const`
+//!   defined in the same file
+//! - tuples sum their elements; nesting deeper than `max_tuple_depth` is
+//!   reported on its own
+//! - structs and enums defined in the same file sum their fields (enums take
+//!   their largest variant); types from other files and generic parameters
+//!   count as pointer-sized
+//!
+//! The estimate ignores padding, niches and types from other crates, so it is
+//! only a hint. Types that are intentionally returned by value can be listed
+//! in `allowed_types`.
+//!
+//! Example:
+//! ```text
+//! // Bad: copies 16 KiB on every call
+//! fn render() -> [u32; 4096] { ... }
+//!
+//! // Good: one pointer is moved
+//! fn render() -> Box<[u32; 4096]> { ... }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use std::collections::HashMap;
+use syn::spanned::Spanned;
+
+/// Size assumed for types the estimate cannot see into
+const POINTER_SIZE: usize = 8;
+
+/// How deep local type definitions are expanded (guards against recursive types)
+const MAX_EXPANSION_DEPTH: usize = 8;
+
+define_checker! {
+    /// Checker for E1115: Large value returned by value
+    E1115LargeReturnValue,
+    code = "E1115",
+    name = "Large value returned by value",
+    suggestions = "Return Box<T> (or write into a caller-provided &mut T) so only a pointer is moved; use a named struct instead of deeply nested tuples",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1115_large_return_value",
+    /// Configuration for E1115: Large return value checker
+    config = E1115Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Estimated size in bytes above which a return type is reported
+        max_return_bytes: usize = 256,
+        /// Maximum nesting of tuples inside a returned tuple
+        max_tuple_depth: usize = 2,
+        /// Type names (last path segment, e.g. "Frame") that may be returned by value
+        allowed_types: Vec<String> = Vec::new(),
+    },
+    check_item(self, _item, _file_path) {
+        // Sizes of local types need the whole file, see check_file
+        Ok(Vec::new())
+    }
+    check_file(self, file, file_path) {
+        let mut checker = ReturnSizeChecker {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            types: HashMap::new(),
+            consts: HashMap::new(),
+            self_type: None,
+        };
+        checker.collect_local_items(&file.items);
+        checker.check_items(&file.items);
+        Ok(checker.violations)
+    }
+}
+
+/// Field lists of a struct (one entry) or enum (one entry per variant)
+type LocalType<'a> = Vec<&'a syn::Fields>;
+
+struct ReturnSizeChecker<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1115LargeReturnValue,
+    /// Structs and enums defined in the file, by name
+    types: HashMap<String, (LocalType<'a>, bool)>,
+    /// Integer constants defined in the file, for array lengths
+    consts: HashMap<String, usize>,
+    /// Name of the type `Self` refers to inside an impl block
+    self_type: Option<String>,
+}
+
+impl<'a> ReturnSizeChecker<'a> {
+    fn check_items(&mut self, items: &'a [syn::Item]) {
+        for item in items {
+            match item {
+                syn::Item::Fn(func) => self.check_signature(&func.sig),
+                syn::Item::Impl(item_impl) => {
+                    // Trait impl methods follow the trait's signature, which is reported at the trait
+                    if item_impl.trait_.is_some() {
+                        continue;
+                    }
+                    self.self_type = type_name(&item_impl.self_ty);
+                    for impl_item in &item_impl.items {
+                        if let syn::ImplItem::Fn(method) = impl_item {
+                            self.check_signature(&method.sig);
+                        }
+                    }
+                    self.self_type = None;
+                }
+                syn::Item::Trait(item_trait) => {
+                    for trait_item in &item_trait.items {
+                        if let syn::TraitItem::Fn(method) = trait_item {
+                            self.check_signature(&method.sig);
+                        }
+                    }
+                }
+                syn::Item::Mod(module) => {
+                    if let Some((_, items)) = &module.content {
+                        self.check_items(items);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn check_signature(&mut self, sig: &syn::Signature) {
+        let syn::ReturnType::Type(_, ty) = &sig.output else {
+            return;
+        };
+        if type_name(ty).is_some_and(|name| self.checker.config.allowed_types.contains(&name)) {
+            return;
+        }
+
+        let depth = tuple_depth(ty);
+        let size = self.type_size(ty, 0);
+        let message = if depth > self.checker.config.max_tuple_depth + 1 {
+            format!(
+                "Function '{}' returns tuples nested {} levels deep (max: {}). Use a named struct instead.",
+                sig.ident,
+                depth - 1,
+                self.checker.config.max_tuple_depth
+            )
+        } else if size > self.checker.config.max_return_bytes {
+            format!(
+                "Function '{}' returns about {} bytes by value (threshold: {}). Consider returning Box<_>.",
+                sig.ident, size, self.checker.config.max_return_bytes
+            )
+        } else {
+            return;
+        };
+
+        let start = ty.span().start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                message,
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+
+    /// Record every struct, enum and integer const in `items` (including inline modules)
+    fn collect_local_items(&mut self, items: &'a [syn::Item]) {
+        for item in items {
+            match item {
+                syn::Item::Struct(item_struct) => {
+                    self.types.insert(item_struct.ident.to_string(), (vec![&item_struct.fields], false));
+                }
+                syn::Item::Enum(item_enum) => {
+                    let variants = item_enum.variants.iter().map(|v| &v.fields).collect();
+                    self.types.insert(item_enum.ident.to_string(), (variants, true));
+                }
+                syn::Item::Const(item_const) => {
+                    if let Some(value) = int_literal(&item_const.expr) {
+                        self.consts.insert(item_const.ident.to_string(), value);
+                    }
+                }
+                syn::Item::Mod(module) => {
+                    if let Some((_, items)) = &module.content {
+                        self.collect_local_items(items);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Estimated size of `ty` in bytes
+    fn type_size(&self, ty: &syn::Type, depth: usize) -> usize {
+        match ty {
+            syn::Type::Reference(reference) => match &*reference.elem {
+                // Fat pointers carry a length or vtable
+                syn::Type::Slice(_) | syn::Type::TraitObject(_) => 2 * POINTER_SIZE,
+                syn::Type::Path(path) if path.path.is_ident("str") => 2 * POINTER_SIZE,
+                _ => POINTER_SIZE,
+            },
+            syn::Type::Array(array) => {
+                let elem = self.type_size(&array.elem, depth);
+                let len = match &array.len {
+                    syn::Expr::Path(path) => path.path.get_ident().and_then(|name| self.consts.get(&name.to_string()).copied()),
+                    len => int_literal(len),
+                };
+                len.map_or(elem, |len| elem.saturating_mul(len))
+            }
+            syn::Type::Tuple(tuple) => tuple.elems.iter().map(|elem| self.type_size(elem, depth)).sum(),
+            syn::Type::Paren(paren) => self.type_size(&paren.elem, depth),
+            syn::Type::Group(group) => self.type_size(&group.elem, depth),
+            syn::Type::Never(_) => 0,
+            syn::Type::Path(path) => self.path_size(&path.path, depth),
+            _ => POINTER_SIZE,
+        }
+    }
+
+    fn path_size(&self, path: &syn::Path, depth: usize) -> usize {
+        let Some(last) = path.segments.last() else {
+            return POINTER_SIZE;
+        };
+        let generic = |index: usize| match &last.arguments {
+            syn::PathArguments::AngleBracketed(args) => args
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .nth(index),
+            _ => None,
+        };
+
+        let name = last.ident.to_string();
+        match name.as_str() {
+            "u8" | "i8" | "bool" => 1,
+            "u16" | "i16" => 2,
+            "u32" | "i32" | "f32" | "char" => 4,
+            "u64" | "i64" | "f64" | "usize" | "isize" => 8,
+            "u128" | "i128" => 16,
+            "String" | "Vec" | "VecDeque" | "PathBuf" | "OsString" | "BTreeMap" | "BTreeSet" => 3 * POINTER_SIZE,
+            "HashMap" | "HashSet" => 6 * POINTER_SIZE,
+            // Assume the niche optimization applies
+            "Option" => generic(0).map_or(POINTER_SIZE, |inner| self.type_size(inner, depth)),
+            "Result" => {
+                let ok = generic(0).map_or(POINTER_SIZE, |ty| self.type_size(ty, depth));
+                let err = generic(1).map_or(POINTER_SIZE, |ty| self.type_size(ty, depth));
+                ok.max(err) + POINTER_SIZE
+            }
+            "Self" => match &self.self_type {
+                Some(self_type) => self.local_type_size(self_type, depth),
+                None => POINTER_SIZE,
+            },
+            _ => self.local_type_size(&name, depth),
+        }
+    }
+
+    /// Size of a struct/enum defined in this file, pointer-sized if unknown
+    fn local_type_size(&self, name: &str, depth: usize) -> usize {
+        let Some((variants, is_enum)) = self.types.get(name) else {
+            return POINTER_SIZE;
+        };
+        if depth >= MAX_EXPANSION_DEPTH {
+            return POINTER_SIZE;
+        }
+        let largest = variants
+            .iter()
+            .map(|fields| fields.iter().map(|field| self.type_size(&field.ty, depth + 1)).sum::<usize>())
+            .max()
+            .unwrap_or(0);
+        if *is_enum {
+            largest + POINTER_SIZE
+        } else {
+            largest
+        }
+    }
+}
+
+/// Value of an integer literal expression
+fn int_literal(expr: &syn::Expr) -> Option<usize> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) => int.base10_parse().ok(),
+        _ => None,
+    }
+}
+
+/// Nesting depth of tuples in `ty` (`(A, B)` is 1, `((A, B), C)` is 2)
+fn tuple_depth(ty: &syn::Type) -> usize {
+    match ty {
+        syn::Type::Tuple(tuple) => 1 + tuple.elems.iter().map(tuple_depth).max().unwrap_or(0),
+        syn::Type::Paren(paren) => tuple_depth(&paren.elem),
+        syn::Type::Group(group) => tuple_depth(&group.elem),
+        _ => 0,
+    }
+}
+
+/// Last path segment of a path type (`crate::Frame` -> "Frame")
+fn type_name(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code_with(checker: &E1115LargeReturnValue, code: &str) -> Vec<Violation> {
+        let file = syn::parse_file(code).unwrap();
+        checker.check_file(&file, "test.rs").unwrap()
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_code_with(&E1115LargeReturnValue::default(), code)
+    }
+
+    #[test]
+    fn test_detects_large_array_and_local_struct() {
+        let code = r#"
+            struct Frame {
+                pixels: [u32; 64],
+                width: usize,
+                height: usize,
+            }
+
+            fn render() -> [u8; 1024] {
+                [0; 1024]
+            }
+
+            impl Frame {
+                fn blank() -> Self {
+                    todo!()
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1115");
+        assert!(violations[0].message.contains("'render' returns about 1024 bytes"));
+        assert_eq!(violations[0].line, 8);
+        assert!(violations[1].message.contains("'blank' returns about 272 bytes"));
+    }
+
+    #[test]
+    fn test_detects_deeply_nested_tuples() {
+        let code = r#"
+            fn pairs() -> (u8, (u8, (u8, (u8, u8)))) {
+                todo!()
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("nested 3 levels deep"));
+    }
+
+    #[test]
+    fn test_small_boxed_and_unknown_types_not_flagged() {
+        let code = r#"
+            struct Point { x: f64, y: f64 }
+
+            fn origin() -> Point { todo!() }
+            fn render() -> Box<[u8; 1024]> { todo!() }
+            fn lines() -> Result<Vec<String>, std::io::Error> { todo!() }
+            fn external() -> serde_json::Value { todo!() }
+            fn pair() -> ((u8, u8), u8) { todo!() }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_threshold_and_allowed_types() {
+        let config = E1115Config {
+            max_return_bytes: 16,
+            allowed_types: vec!["Matrix".to_string()],
+            ..E1115Config::default()
+        };
+        let checker = E1115LargeReturnValue { config };
+        let code = r#"
+            const CELLS: usize = 16;
+            struct Matrix { cells: [f64; CELLS] }
+            fn cells() -> [f64; CELLS] { todo!() }
+            enum Shape { Circle(f64), Rect { w: f64, h: f64, angle: f64 } }
+
+            fn identity() -> Matrix { todo!() }
+            fn shape() -> Option<Shape> { todo!() }
+        "#;
+        let violations = check_code_with(&checker, code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("'cells' returns about 128 bytes"));
+        assert!(violations[1].message.contains("'shape' returns about 32 bytes"));
+    }
+}
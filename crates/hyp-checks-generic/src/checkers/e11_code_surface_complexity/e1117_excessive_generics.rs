@@ -0,0 +1,173 @@
+//! E1117: Public function with too many generic parameters
+//!
+//! Counts the generic type parameters of public functions and public impl
+//! methods. Every parameter is something a caller may have to name in a
+//! turbofish or puzzle over in an error message. Lifetimes and const generics
+//! are not counted unless `count_lifetimes` / `count_const_params` are set.
+//! E1201 looks at how complex generic bounds are; this is a plain count that
+//! teams can tune.
+//!
+//! Example:
+//! ```text
+//! // Bad: five type parameters on a public entry point
+//! pub fn merge<K, V, S, F, E>(a: Map<K, V, S>, b: Map<K, V, S>, f: F) -> Result<Map<K, V, S>, E>
+//!
+//! // Good: group related parameters behind a trait or a concrete type
+//! pub fn merge<M: MergeableMap, F>(a: M, b: M, f: F) -> Result<M, M::Error>
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1117: Public function with too many generic parameters
+    E1117ExcessiveGenerics,
+    code = "E1117",
+    name = "Public function with too many generic parameters",
+    suggestions = "Group related type parameters behind a trait with associated types, use `impl Trait` arguments, or use concrete types where callers never vary them",
+    target_items = [Function, Impl],
+    config_entry_name = "e1117_excessive_generics",
+    /// Configuration for E1117: Excessive generics checker
+    config = E1117Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Maximum number of generic parameters on a public function
+        max_type_params: usize = 4,
+        /// Also count lifetime parameters
+        count_lifetimes: bool = false,
+        /// Also count const generic parameters
+        count_const_params: bool = false,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = GenericsVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct GenericsVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1117ExcessiveGenerics,
+}
+
+impl<'a> GenericsVisitor<'a> {
+    fn check_signature(&mut self, vis: &syn::Visibility, sig: &syn::Signature) {
+        if !matches!(vis, syn::Visibility::Public(_)) {
+            return;
+        }
+
+        let config = &self.checker.config;
+        let count = sig
+            .generics
+            .params
+            .iter()
+            .filter(|param| match param {
+                syn::GenericParam::Type(_) => true,
+                syn::GenericParam::Lifetime(_) => config.count_lifetimes,
+                syn::GenericParam::Const(_) => config.count_const_params,
+            })
+            .count();
+
+        if count > config.max_type_params {
+            let start = sig.ident.span().start();
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    self.checker.severity().into(),
+                    format!(
+                        "Public function '{}' has {} generic parameters (max {}).",
+                        sig.ident, count, config.max_type_params
+                    ),
+                    self.file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_suggestion(self.checker.suggestions()),
+            );
+        }
+    }
+}
+
+impl<'a> Visit<'a> for GenericsVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.check_signature(&node.vis, &node.sig);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.check_signature(&node.vis, &node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code_with(checker: &E1117ExcessiveGenerics, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_code_with(&E1117ExcessiveGenerics::default(), code)
+    }
+
+    #[test]
+    fn test_detects_too_many_type_params() {
+        let code = r#"
+            pub fn merge<K, V, S, F, E>(a: K, b: V, s: S, f: F) -> Result<(), E> { todo!() }
+
+            impl Store {
+                pub fn query<'a, A, B, C, D, R, const N: usize>(&'a self) -> R { todo!() }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1117");
+        assert!(violations[0].message.contains("'merge' has 5 generic parameters (max 4)"));
+        assert_eq!(violations[0].line, 2);
+        assert!(violations[1].message.contains("'query' has 5"));
+    }
+
+    #[test]
+    fn test_private_and_small_generics_pass() {
+        let code = r#"
+            fn helper<A, B, C, D, E>() {}
+            pub fn small<'a, 'b, K, V, const N: usize>(k: &'a K, v: &'b V) {}
+            impl Store {
+                fn internal<A, B, C, D, E>(&self) {}
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_counting_lifetimes_and_consts() {
+        let checker = E1117ExcessiveGenerics {
+            config: E1117Config {
+                count_lifetimes: true,
+                count_const_params: true,
+                ..E1117Config::default()
+            },
+        };
+        let violations = check_code_with(&checker, "pub fn small<'a, 'b, K, V, const N: usize>() {}");
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("has 5 generic parameters"));
+    }
+}
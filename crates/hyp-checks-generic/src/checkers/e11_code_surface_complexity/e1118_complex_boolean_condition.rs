@@ -0,0 +1,190 @@
+//! E1118: Complex boolean condition
+//!
+//! Counts the logical operators (`&&`, `||`, `!`) in each `if` and `while`
+//! condition and reports conditions with more than `max_bool_ops`. Mixed
+//! `&&`/`||` chains with negations force the reader to work out precedence
+//! and De Morgan in their head; a named boolean or predicate function says
+//! what the condition means.
+//!
+//! Closures and blocks inside the condition are not counted, they are
+//! separate expressions.
+//!
+//! Example:
+//! ```text
+//! // Bad: five operators, and the intent is nowhere
+//! if user.active && !user.banned || user.admin && !maintenance || force { .. }
+//!
+//! // Good: name the parts
+//! let may_log_in = (user.active && !user.banned) || user.admin;
+//! if (may_log_in && !maintenance) || force { .. }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1118: Complex boolean condition
+    E1118ComplexBooleanCondition,
+    code = "E1118",
+    name = "Complex boolean condition",
+    suggestions = "Extract parts of the condition into well-named boolean variables or a predicate function",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1118_complex_boolean_condition",
+    /// Configuration for E1118: Complex boolean condition checker
+    config = E1118Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Maximum number of `&&`, `||` and `!` operators in one condition
+        max_bool_ops: usize = 3,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = ConditionVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct ConditionVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1118ComplexBooleanCondition,
+}
+
+impl ConditionVisitor<'_> {
+    fn check_condition(&mut self, keyword: &str, cond: &syn::Expr) {
+        let mut counter = BoolOpCounter::default();
+        counter.visit_expr(cond);
+        let max = self.checker.config.max_bool_ops;
+        if counter.count <= max {
+            return;
+        }
+
+        let start = cond.span().start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "'{}' condition has {} logical operators (max {}).",
+                    keyword, counter.count, max
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for ConditionVisitor<'a> {
+    fn visit_expr_if(&mut self, node: &'a syn::ExprIf) {
+        self.check_condition("if", &node.cond);
+        syn::visit::visit_expr_if(self, node);
+    }
+
+    fn visit_expr_while(&mut self, node: &'a syn::ExprWhile) {
+        self.check_condition("while", &node.cond);
+        syn::visit::visit_expr_while(self, node);
+    }
+}
+
+/// Counts `&&`, `||` and `!` in one expression, without entering closures or blocks
+#[derive(Default)]
+struct BoolOpCounter {
+    count: usize,
+}
+
+impl<'ast> Visit<'ast> for BoolOpCounter {
+    fn visit_expr_binary(&mut self, node: &'ast syn::ExprBinary) {
+        if matches!(node.op, syn::BinOp::And(_) | syn::BinOp::Or(_)) {
+            self.count += 1;
+        }
+        syn::visit::visit_expr_binary(self, node);
+    }
+
+    fn visit_expr_unary(&mut self, node: &'ast syn::ExprUnary) {
+        if matches!(node.op, syn::UnOp::Not(_)) {
+            self.count += 1;
+        }
+        syn::visit::visit_expr_unary(self, node);
+    }
+
+    fn visit_expr_closure(&mut self, _node: &'ast syn::ExprClosure) {}
+
+    fn visit_block(&mut self, _node: &'ast syn::Block) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1118ComplexBooleanCondition::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_four_operator_condition() {
+        let code = r#"
+            fn allow(a: bool, b: bool, c: bool, d: bool) -> bool {
+                if a && b || c && !d {
+                    return true;
+                }
+                false
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1118");
+        assert_eq!((violations[0].line, violations[0].column), (3, 20));
+        assert!(violations[0].message.contains("'if' condition has 4 logical operators (max 3)"));
+    }
+
+    #[test]
+    fn test_while_and_nested_conditions() {
+        let code = r#"
+            impl Worker {
+                fn run(&mut self) {
+                    while !self.done && (self.busy || !self.paused) && self.ok {
+                        if self.a && self.b { self.step(); }
+                    }
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'while' condition has 5"));
+    }
+
+    #[test]
+    fn test_simple_conditions_pass() {
+        let code = r#"
+            fn allow(a: bool, b: bool, items: &[u8]) -> bool {
+                if a && !b {
+                    return true;
+                }
+                if items.iter().any(|x| *x > 1 && *x < 5 || *x == 9 && !a) && b {
+                    return true;
+                }
+                false
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
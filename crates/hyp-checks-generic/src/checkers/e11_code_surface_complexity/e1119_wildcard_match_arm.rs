@@ -0,0 +1,323 @@
+//! E1119: Catch-all `_ =>` arm hides new enum variants
+//!
+//! Detects `match` expressions over enum variants whose last arm is a bare
+//! `_` with a trivial body. Such an arm absorbs every variant added to the
+//! enum later, so the compiler no longer points at the places that need to
+//! handle them.
+//!
+//! The matched type cannot be resolved, so a match counts as an enum match
+//! when its other arms use variant paths (`Kind::A`, `Shape::Circle { .. }`)
+//! of an enum declared in the same file. Enums from other files and crates
+//! are skipped, and so are `#[non_exhaustive]` enums, which require the `_`
+//! arm outside their own crate.
+//!
+//! An empty body (`{}` or `()`) and `unreachable!()` are always reported.
+//! Other trivial bodies (a literal, `None`, `Default::default()`) are only
+//! reported when `flag_trivial_wildcard` is set.
+//!
+//! Example:
+//! ```text
+//! // Bad: a new `Event::Resize` variant is silently ignored
+//! match event {
+//!     Event::Click(pos) => click(pos),
+//!     Event::Key(key) => press(key),
+//!     _ => {}
+//! }
+//!
+//! // Good: the compiler reports every match that misses a new variant
+//! match event {
+//!     Event::Click(pos) => click(pos),
+//!     Event::Key(key) => press(key),
+//!     Event::Scroll(_) | Event::Focus => {}
+//! }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use std::collections::HashSet;
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1119: Catch-all `_ =>` arm hides new enum variants
+    E1119WildcardMatchArm,
+    code = "E1119",
+    name = "Catch-all match arm hides new enum variants",
+    suggestions = "List the remaining variants explicitly (`A | B => {}`) so adding a variant is a compile error at every match that must handle it",
+    target_items = [Function, Impl],
+    config_entry_name = "e1119_wildcard_match_arm",
+    /// Configuration for E1119: Wildcard match arm checker
+    config = E1119Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Also report wildcard arms returning a trivial value (literal, `None`,
+        /// `Default::default()`); when unset only empty bodies and `unreachable!()` are reported
+        flag_trivial_wildcard: bool = false,
+    },
+    check_item(self, _item, _file_path) {
+        // Only enums declared in the file are considered, see check_file
+        Ok(Vec::new())
+    }
+    check_file(self, file, file_path) {
+        let mut enums = LocalEnums::default();
+        enums.visit_file(file);
+        let mut visitor = WildcardArmVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            local_enums: enums.names,
+            self_type: None,
+        };
+        visitor.visit_file(file);
+        Ok(visitor.violations)
+    }
+}
+
+/// Names of the enums declared in a file without `#[non_exhaustive]`
+#[derive(Default)]
+struct LocalEnums {
+    names: HashSet<String>,
+}
+
+impl<'a> Visit<'a> for LocalEnums {
+    fn visit_item_enum(&mut self, node: &'a syn::ItemEnum) {
+        if !node.attrs.iter().any(|attr| attr.path().is_ident("non_exhaustive")) {
+            self.names.insert(node.ident.to_string());
+        }
+    }
+}
+
+struct WildcardArmVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1119WildcardMatchArm,
+    local_enums: HashSet<String>,
+    /// Name of the type `Self` refers to inside an impl block
+    self_type: Option<String>,
+}
+
+impl<'a> Visit<'a> for WildcardArmVisitor<'a> {
+    fn visit_item_impl(&mut self, node: &'a syn::ItemImpl) {
+        let outer = self.self_type.take();
+        if let syn::Type::Path(type_path) = &*node.self_ty {
+            self.self_type = type_path.path.segments.last().map(|segment| segment.ident.to_string());
+        }
+        syn::visit::visit_item_impl(self, node);
+        self.self_type = outer;
+    }
+
+    fn visit_expr_match(&mut self, node: &'a syn::ExprMatch) {
+        if let Some((last, others)) = node.arms.split_last() {
+            let is_bare_wildcard = matches!(last.pat, syn::Pat::Wild(_)) && last.guard.is_none();
+            let enum_name = others
+                .iter()
+                .filter_map(|arm| variant_enum_name(&arm.pat))
+                .map(|name| match (name.as_str(), &self.self_type) {
+                    ("Self", Some(self_type)) => self_type.clone(),
+                    _ => name,
+                })
+                .find(|name| self.local_enums.contains(name));
+            if let (true, Some(enum_name)) = (is_bare_wildcard, enum_name) {
+                if let Some(body) = self.reported_body(&last.body) {
+                    let start = last.pat.span().start();
+                    self.violations.push(
+                        Violation::new(
+                            self.checker.code(),
+                            self.checker.name(),
+                            self.checker.severity().into(),
+                            format!(
+                                "Match on {} variants ends with `_ => {}`: variants added to {} later are silently absorbed.",
+                                enum_name, body, enum_name
+                            ),
+                            self.file_path,
+                            start.line,
+                            start.column + 1,
+                        )
+                        .with_suggestion(self.checker.suggestions()),
+                    );
+                }
+            }
+        }
+        syn::visit::visit_expr_match(self, node);
+    }
+}
+
+impl<'a> WildcardArmVisitor<'a> {
+    /// Short rendering of the wildcard arm body if it is one that should be reported
+    fn reported_body(&self, body: &syn::Expr) -> Option<&'static str> {
+        match body {
+            syn::Expr::Block(block) if block.block.stmts.is_empty() => Some("{}"),
+            syn::Expr::Tuple(tuple) if tuple.elems.is_empty() => Some("()"),
+            syn::Expr::Macro(mac) if mac.mac.path.is_ident("unreachable") => Some("unreachable!()"),
+            _ if self.checker.config.flag_trivial_wildcard && is_trivial_value(body) => Some("<default value>"),
+            _ => None,
+        }
+    }
+}
+
+/// A literal, `None`, `Default::default()` / `T::default()`, or a block holding only one of these
+fn is_trivial_value(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(_) => true,
+        syn::Expr::Path(path) => path.path.is_ident("None"),
+        syn::Expr::Call(call) => {
+            call.args.is_empty()
+                && matches!(&*call.func, syn::Expr::Path(path)
+                    if path.path.segments.last().is_some_and(|s| s.ident == "default"))
+        }
+        syn::Expr::Block(block) => match block.block.stmts.as_slice() {
+            [syn::Stmt::Expr(inner, None)] => is_trivial_value(inner),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// The enum name of a `Enum::Variant` pattern (also inside `|` alternatives)
+fn variant_enum_name(pat: &syn::Pat) -> Option<String> {
+    let path = match pat {
+        syn::Pat::Path(path) => &path.path,
+        syn::Pat::TupleStruct(tuple) => &tuple.path,
+        syn::Pat::Struct(strukt) => &strukt.path,
+        syn::Pat::Or(or) => return or.cases.iter().find_map(variant_enum_name),
+        syn::Pat::Reference(reference) => return variant_enum_name(&reference.pat),
+        syn::Pat::Ident(ident) => return ident.subpat.as_ref().and_then(|(_, sub)| variant_enum_name(sub)),
+        _ => return None,
+    };
+    let count = path.segments.len();
+    if count < 2 {
+        return None;
+    }
+    let enum_segment = &path.segments[count - 2].ident;
+    if enum_segment == "Option" || enum_segment == "Result" || enum_segment == "Ordering" {
+        return None;
+    }
+    Some(enum_segment.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code_with(checker: &E1119WildcardMatchArm, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        checker.check_file(&syntax, "test.rs").unwrap()
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_code_with(&E1119WildcardMatchArm::default(), code)
+    }
+
+    fn flag_trivial() -> E1119WildcardMatchArm {
+        E1119WildcardMatchArm {
+            config: E1119Config {
+                flag_trivial_wildcard: true,
+                ..E1119Config::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_detects_empty_wildcard_on_local_enum() {
+        let code = r#"
+            enum Event { Click(u32), Key { code: u8 }, Focus, Blur }
+
+            fn handle(event: Event) {
+                match event {
+                    Event::Click(pos) => click(pos),
+                    Event::Key { code, .. } => press(code),
+                    _ => {}
+                }
+            }
+
+            impl Event {
+                fn log_focus(&self) {
+                    match self {
+                        Self::Focus => log(),
+                        _ => (),
+                    }
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1119");
+        assert_eq!((violations[0].line, violations[0].column), (8, 21));
+        assert!(violations[0].message.contains("Match on Event variants ends with `_ => {}`"));
+        assert!(violations[1].message.contains("`_ => ()`"));
+    }
+
+    #[test]
+    fn test_trivial_values_reported_only_when_configured() {
+        let code = r#"
+            enum Event { Focus, Blur, Click }
+
+            fn f(e: Event) -> bool {
+                match e {
+                    Event::Focus | Event::Blur => true,
+                    _ => false,
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+
+        let violations = check_code_with(&flag_trivial(), code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("`_ => <default value>`"));
+    }
+
+    #[test]
+    fn test_foreign_and_non_exhaustive_enums_pass() {
+        let code = r#"
+            #[non_exhaustive]
+            pub enum Kind { A, B }
+
+            fn f(k: Kind, expr: &syn::Expr, e: Event) {
+                match k { Kind::A => {}, _ => {} }
+                match expr { syn::Expr::Lit(_) => {}, _ => {} }
+                match e { Event::Click(_) => {}, _ => unreachable!() }
+            }
+        "#;
+        assert!(check_code_with(&flag_trivial(), code).is_empty());
+    }
+
+    #[test]
+    fn test_non_enum_and_meaningful_wildcards_pass() {
+        let code = r#"
+            enum Event { Click(u8), Key(u8) }
+
+            fn f(n: u8, s: &str, o: Option<u8>, e: Event) -> u8 {
+                match n { 0 => 1, _ => 0 }
+                match s { "a" => 1, _ => 0 }
+                match o { Some(x) => x, _ => 0 }
+                match e {
+                    Event::Click(_) => 1,
+                    _ => fallback(e),
+                }
+                match e {
+                    Event::Click(_) => 1,
+                    _ if strict => 0,
+                    other => fallback(other),
+                }
+            }
+        "#;
+        assert!(check_code_with(&flag_trivial(), code).is_empty());
+    }
+
+    #[test]
+    fn test_unreachable_wildcard() {
+        let code = r#"
+            enum Event { A, B }
+
+            fn f(e: Event) -> u8 {
+                match e { Event::A => 1, _ => unreachable!() }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("`_ => unreachable!()`"));
+    }
+}
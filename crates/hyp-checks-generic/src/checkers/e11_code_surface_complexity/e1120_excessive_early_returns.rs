@@ -0,0 +1,231 @@
+//! E1120: Excessive early returns
+//!
+//! Counts the `return` expressions in each function body and reports
+//! functions with more than `max_returns`. A few guard clauses at the top of
+//! a function are fine; returns scattered through loops and match arms mean
+//! every exit has to be found before the function's result can be reasoned
+//! about, and cleanup or logging added at the end is silently skipped by some
+//! paths. With `count_question_marks` the `?` operator is counted as well.
+//!
+//! Returns inside closures and `async` blocks leave only the closure or
+//! block, and nested functions are checked on their own, so none of them
+//! count toward the enclosing function.
+//!
+//! Example:
+//! ```text
+//! // Bad: six exits spread over the body
+//! fn classify(x: i32) -> &'static str {
+//!     if x < 0 { return "negative"; }
+//!     if x == 0 { return "zero"; }
+//!     for d in [2, 3, 5] { if x % d == 0 { return "composite"; } }
+//!     ..
+//! }
+//!
+//! // Good: compute the answer in one expression
+//! fn classify(x: i32) -> &'static str {
+//!     match x { ..0 => "negative", 0 => "zero", _ if is_prime(x) => "prime", _ => "composite" }
+//! }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1120: Excessive early returns
+    E1120ExcessiveEarlyReturns,
+    code = "E1120",
+    name = "Excessive early returns",
+    suggestions = "Restructure the function so it exits in fewer places: use match/if expressions, extract helpers, or group guard clauses at the top",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1120_excessive_early_returns",
+    /// Configuration for E1120: Excessive early returns checker
+    config = E1120Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Maximum number of `return` expressions in one function
+        max_returns: usize = 5,
+        /// Also count `?` operators as early returns
+        count_question_marks: bool = false,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = FunctionVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct FunctionVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1120ExcessiveEarlyReturns,
+}
+
+impl FunctionVisitor<'_> {
+    fn check_function(&mut self, ident: &syn::Ident, body: &syn::Block) {
+        let mut counter = ReturnCounter {
+            count: 0,
+            count_question_marks: self.checker.config.count_question_marks,
+        };
+        counter.visit_block(body);
+        let max = self.checker.config.max_returns;
+        if counter.count <= max {
+            return;
+        }
+
+        let what = if counter.count_question_marks { "returns and `?` operators" } else { "return statements" };
+        let start = ident.span().start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!("Function '{}' has {} {} (max {}).", ident, counter.count, what, max),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for FunctionVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.check_function(&node.sig.ident, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.check_function(&node.sig.ident, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'a syn::TraitItemFn) {
+        if let Some(body) = &node.default {
+            self.check_function(&node.sig.ident, body);
+        }
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+}
+
+/// Counts the exits of one function body, without entering closures, async blocks or nested items
+struct ReturnCounter {
+    count: usize,
+    count_question_marks: bool,
+}
+
+impl<'ast> Visit<'ast> for ReturnCounter {
+    fn visit_expr_return(&mut self, node: &'ast syn::ExprReturn) {
+        self.count += 1;
+        syn::visit::visit_expr_return(self, node);
+    }
+
+    fn visit_expr_try(&mut self, node: &'ast syn::ExprTry) {
+        if self.count_question_marks {
+            self.count += 1;
+        }
+        syn::visit::visit_expr_try(self, node);
+    }
+
+    fn visit_expr_closure(&mut self, _node: &'ast syn::ExprClosure) {}
+
+    fn visit_expr_async(&mut self, _node: &'ast syn::ExprAsync) {}
+
+    fn visit_item(&mut self, _node: &'ast syn::Item) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_with(checker: &E1120ExcessiveEarlyReturns, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_with(&E1120ExcessiveEarlyReturns::default(), code)
+    }
+
+    #[test]
+    fn test_detects_six_returns() {
+        let code = r#"
+            fn classify(x: i32) -> &'static str {
+                if x < 0 { return "negative"; }
+                if x == 0 { return "zero"; }
+                for d in [2, 3, 5] {
+                    if x % d == 0 { return "composite"; }
+                }
+                match x {
+                    1 => return "one",
+                    7 => return "seven",
+                    _ => {}
+                }
+                return "prime";
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1120");
+        assert_eq!((violations[0].line, violations[0].column), (2, 16));
+        assert!(violations[0].message.contains("Function 'classify' has 6 return statements (max 5)"));
+    }
+
+    #[test]
+    fn test_closure_and_nested_fn_returns_not_counted() {
+        let code = r#"
+            impl Parser {
+                fn parse(&self, items: &[i32]) -> Option<i32> {
+                    if items.is_empty() { return None; }
+                    let pick = |x: i32| {
+                        if x < 0 { return 0; }
+                        if x > 9 { return 9; }
+                        if x == 5 { return 4; }
+                        return x;
+                    };
+                    fn helper(x: i32) -> i32 {
+                        if x < 0 { return 0; }
+                        return x;
+                    }
+                    let fut = async { return 1; };
+                    if items.len() > 3 { return Some(pick(items[3])); }
+                    Some(helper(items[0]))
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_question_marks_counted_when_configured() {
+        let code = r#"
+            fn load(path: &str) -> Result<u32, Error> {
+                let text = read(path)?;
+                let line = first_line(&text)?;
+                if line.is_empty() { return Ok(0); }
+                Ok(line.parse()?)
+            }
+        "#;
+        let mut checker = E1120ExcessiveEarlyReturns::default();
+        checker.config.max_returns = 2;
+        assert!(check_with(&checker, code).is_empty());
+
+        checker.config.count_question_marks = true;
+        let violations = check_with(&checker, code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("has 4 returns and `?` operators (max 2)"));
+    }
+}
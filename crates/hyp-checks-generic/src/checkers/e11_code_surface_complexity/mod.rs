@@ -12,6 +12,12 @@ pub mod e1109_excessive_chaining;
 pub mod e1110_deeply_nested_closures;
 pub mod e1111_excessive_tuple_complexity;
 pub mod e1112_magic_numbers;
+pub mod e1114_todo_comment;
+pub mod e1115_large_return_value;
+pub mod e1117_excessive_generics;
+pub mod e1118_complex_boolean_condition;
+pub mod e1119_wildcard_match_arm;
+pub mod e1120_excessive_early_returns;
 pub mod registry;
 
 pub use e1101_high_cyclomatic_complexity::{E1101Config, E1101HighCyclomaticComplexity};
@@ -26,3 +32,9 @@ pub use e1109_excessive_chaining::{E1109Config, E1109ExcessiveChaining};
 pub use e1110_deeply_nested_closures::{E1110Config, E1110DeeplyNestedClosures};
 pub use e1111_excessive_tuple_complexity::{E1111Config, E1111ExcessiveTupleComplexity};
 pub use e1112_magic_numbers::{E1112Config, E1112MagicNumbers};
+pub use e1114_todo_comment::{E1114Config, E1114TodoComment};
+pub use e1115_large_return_value::{E1115Config, E1115LargeReturnValue};
+pub use e1117_excessive_generics::{E1117Config, E1117ExcessiveGenerics};
+pub use e1119_wildcard_match_arm::{E1119Config, E1119WildcardMatchArm};
+pub use e1118_complex_boolean_condition::{E1118Config, E1118ComplexBooleanCondition};
+pub use e1120_excessive_early_returns::{E1120Config, E1120ExcessiveEarlyReturns};
@@ -9,6 +9,12 @@ use crate::{
         E1107Config, E1107DeeplyNestedConditionals, E1108Config, E1108DeeplyNestedMatch,
         E1109Config, E1109ExcessiveChaining, E1110Config, E1110DeeplyNestedClosures,
         E1111Config, E1111ExcessiveTupleComplexity, E1112Config, E1112MagicNumbers,
+        E1114Config, E1114TodoComment,
+        E1115Config, E1115LargeReturnValue,
+        E1117Config, E1117ExcessiveGenerics,
+        E1119Config, E1119WildcardMatchArm,
+        E1118Config, E1118ComplexBooleanCondition,
+        E1120Config, E1120ExcessiveEarlyReturns,
     },
     register_checker,
     registry::CheckerRegistration,
@@ -29,5 +35,11 @@ pub fn e11_registrations() -> Vec<CheckerRegistration> {
         register_checker!(E1110DeeplyNestedClosures, E1110Config),
         register_checker!(E1111ExcessiveTupleComplexity, E1111Config),
         register_checker!(E1112MagicNumbers, E1112Config),
+        register_checker!(E1114TodoComment, E1114Config),
+        register_checker!(E1115LargeReturnValue, E1115Config),
+        register_checker!(E1117ExcessiveGenerics, E1117Config),
+        register_checker!(E1119WildcardMatchArm, E1119Config),
+        register_checker!(E1118ComplexBooleanCondition, E1118Config),
+        register_checker!(E1120ExcessiveEarlyReturns, E1120Config),
     ]
 }
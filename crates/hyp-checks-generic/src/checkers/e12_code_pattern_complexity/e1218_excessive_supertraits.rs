@@ -0,0 +1,160 @@
+//! E1218: Trait with too many supertraits
+//!
+//! Detects "god traits" whose supertrait list (`trait Foo: A + B + C + D`)
+//! exceeds a configurable limit. Every supertrait is an obligation each
+//! implementor must satisfy, so wide hierarchies couple unrelated concerns and
+//! make the trait hard to implement or mock. Lifetime bounds (`'a`,
+//! `'static`) are not counted; `where Self: X` bounds are, since they are
+//! supertraits written in another place.
+//!
+//! Example:
+//! ```text
+//! // Bad: five obligations for every implementor
+//! trait Repository: Clone + Debug + Serialize + Send + Sync {}
+//!
+//! // Good: require only what the trait itself uses
+//! trait Repository: Send + Sync {}
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1218: Trait with too many supertraits
+    E1218ExcessiveSupertraits,
+    code = "E1218",
+    name = "Trait with too many supertraits",
+    suggestions = "Split the trait into smaller focused traits, or move bounds to the functions that need them instead of requiring them of every implementor",
+    target_items = [Trait],
+    config_entry_name = "e1218_excessive_supertraits",
+    /// Configuration for E1218: Excessive supertraits checker
+    config = E1218Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Maximum number of supertraits (lifetime bounds excluded)
+        max_supertraits: usize = 3,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = SupertraitVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct SupertraitVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1218ExcessiveSupertraits,
+}
+
+impl<'a> Visit<'a> for SupertraitVisitor<'a> {
+    fn visit_item_trait(&mut self, node: &'a syn::ItemTrait) {
+        let where_self_bounds = node
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|clause| &clause.predicates)
+            .filter_map(|predicate| match predicate {
+                syn::WherePredicate::Type(bound) if is_self(&bound.bounded_ty) => Some(&bound.bounds),
+                _ => None,
+            });
+        let count = std::iter::once(&node.supertraits)
+            .chain(where_self_bounds)
+            .flatten()
+            .filter(|bound| matches!(bound, syn::TypeParamBound::Trait(_)))
+            .count();
+
+        if count > self.checker.config.max_supertraits {
+            let start = node.ident.span().start();
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    self.checker.severity().into(),
+                    format!(
+                        "Trait '{}' has {} supertraits (max {}). Every implementor must satisfy all of them.",
+                        node.ident, count, self.checker.config.max_supertraits
+                    ),
+                    self.file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_suggestion(self.checker.suggestions()),
+            );
+        }
+
+        syn::visit::visit_item_trait(self, node);
+    }
+}
+
+fn is_self(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(path) if path.path.is_ident("Self"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code_with(checker: &E1218ExcessiveSupertraits, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_code_with(&E1218ExcessiveSupertraits::default(), code)
+    }
+
+    #[test]
+    fn test_detects_too_many_supertraits() {
+        let code = r#"
+            trait Repository: Clone + std::fmt::Debug + Serialize + Send + Sync {}
+
+            trait Handler: Clone + Send
+            where
+                Self: Sync + 'static + Debug,
+            {
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1218");
+        assert!(violations[0].message.contains("'Repository' has 5 supertraits (max 3)"));
+        assert_eq!(violations[0].line, 2);
+        assert!(violations[1].message.contains("'Handler' has 4 supertraits"));
+    }
+
+    #[test]
+    fn test_lifetimes_and_small_lists_not_flagged() {
+        let code = r#"
+            trait Plugin<'a>: Send + Sync + 'a + 'static {}
+            trait Codec: Encode + Decode + Clone {}
+            trait Marker {}
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_custom_max_supertraits() {
+        let checker = E1218ExcessiveSupertraits {
+            config: E1218Config {
+                max_supertraits: 1,
+                ..E1218Config::default()
+            },
+        };
+        let violations = check_code_with(&checker, "trait Codec: Encode + Decode {}");
+        assert_eq!(violations.len(), 1);
+    }
+}
@@ -0,0 +1,326 @@
+//! E1219: Box<dyn Trait> constructed inside a loop
+//!
+//! Detects `Box::new(..)` coerced to `Box<dyn Trait>` inside a loop body.
+//! Every iteration then pays a heap allocation, and every later call through
+//! the box an indirect vtable call the optimizer cannot inline. When the set
+//! of concrete types is known, an enum or a generic avoids both; when the
+//! value does not change between iterations, it can be boxed once before the
+//! loop.
+//!
+//! Call sites in other functions are not visible, so only coercions written
+//! in the loop itself are reported:
+//! - `Box::new(x) as Box<dyn Trait>`
+//! - `let b: Box<dyn Trait> = Box::new(x);` (also through `if`/`match` arms)
+//! - `items.push(Box::new(x))` where `items` is a local annotated
+//!   `Vec<Box<dyn Trait>>`
+//!
+//! Closures and nested functions are not treated as part of the loop.
+//!
+//! Example:
+//! ```text
+//! // Bad: one allocation per row, dynamic dispatch for every call
+//! for row in rows {
+//!     let shape: Box<dyn Shape> = Box::new(Circle::new(row.radius));
+//!     total += shape.area();
+//! }
+//!
+//! // Good: a concrete type (or an enum of the possible shapes)
+//! for row in rows {
+//!     total += Circle::new(row.radius).area();
+//! }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use quote::ToTokens;
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1219: Box<dyn Trait> constructed inside a loop
+    E1219DynBoxInLoop,
+    code = "E1219",
+    name = "Box<dyn Trait> constructed inside a loop",
+    suggestions = "Use an enum over the concrete types or a generic parameter, or box the value once outside the loop",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1219_dyn_box_in_loop",
+    /// Configuration for E1219: Box<dyn Trait> in loop checker
+    config = E1219Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = DynBoxVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            loop_depth: 0,
+            dyn_vecs: Vec::new(),
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct DynBoxVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1219DynBoxInLoop,
+    loop_depth: usize,
+    /// Locals annotated `Vec<Box<dyn Trait>>`, with the trait object type
+    dyn_vecs: Vec<(String, String)>,
+}
+
+impl DynBoxVisitor<'_> {
+    fn report(&mut self, call: &syn::ExprCall, dyn_type: &str) {
+        let start = call.span().start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "Box::new inside a loop allocates a Box<{}> on every iteration, and each call through it is dynamically dispatched.",
+                    dyn_type
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_span(start, call.span().end())
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+
+    fn report_all(&mut self, value: &syn::Expr, dyn_type: &str) {
+        for call in box_new_values(value) {
+            self.report(call, dyn_type);
+        }
+    }
+
+    fn in_loop(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.loop_depth += 1;
+        visit(self);
+        self.loop_depth -= 1;
+    }
+
+    fn outside_loop(&mut self, visit: impl FnOnce(&mut Self)) {
+        let depth = std::mem::take(&mut self.loop_depth);
+        visit(self);
+        self.loop_depth = depth;
+    }
+}
+
+impl<'a> Visit<'a> for DynBoxVisitor<'a> {
+    fn visit_expr_for_loop(&mut self, node: &'a syn::ExprForLoop) {
+        self.visit_expr(&node.expr);
+        self.in_loop(|v| v.visit_block(&node.body));
+    }
+
+    fn visit_expr_while(&mut self, node: &'a syn::ExprWhile) {
+        self.in_loop(|v| syn::visit::visit_expr_while(v, node));
+    }
+
+    fn visit_expr_loop(&mut self, node: &'a syn::ExprLoop) {
+        self.in_loop(|v| syn::visit::visit_expr_loop(v, node));
+    }
+
+    fn visit_expr_closure(&mut self, node: &'a syn::ExprClosure) {
+        self.outside_loop(|v| syn::visit::visit_expr_closure(v, node));
+    }
+
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        let vecs = self.dyn_vecs.len();
+        self.outside_loop(|v| syn::visit::visit_item_fn(v, node));
+        self.dyn_vecs.truncate(vecs);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        let vecs = self.dyn_vecs.len();
+        syn::visit::visit_impl_item_fn(self, node);
+        self.dyn_vecs.truncate(vecs);
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        if let syn::Pat::Type(pat_type) = &node.pat {
+            if let Some(dyn_type) = boxed_dyn_type(&pat_type.ty) {
+                if self.loop_depth > 0 {
+                    if let Some(init) = &node.init {
+                        self.report_all(&init.expr, &dyn_type);
+                    }
+                }
+            } else if let (Some(dyn_type), syn::Pat::Ident(name)) = (vec_of_boxed_dyn(&pat_type.ty), &*pat_type.pat) {
+                self.dyn_vecs.push((name.ident.to_string(), dyn_type));
+            }
+        }
+        syn::visit::visit_local(self, node);
+    }
+
+    fn visit_expr_cast(&mut self, node: &'a syn::ExprCast) {
+        if self.loop_depth > 0 {
+            if let Some(dyn_type) = boxed_dyn_type(&node.ty) {
+                self.report_all(&node.expr, &dyn_type);
+            }
+        }
+        syn::visit::visit_expr_cast(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if self.loop_depth > 0 && node.method == "push" && node.args.len() == 1 {
+            let vec_type = match &*node.receiver {
+                syn::Expr::Path(path) => path.path.get_ident().and_then(|ident| {
+                    self.dyn_vecs
+                        .iter()
+                        .rev()
+                        .find(|(name, _)| ident == name)
+                        .map(|(_, dyn_type)| dyn_type.clone())
+                }),
+                _ => None,
+            };
+            if let (Some(dyn_type), Some(arg)) = (vec_type, node.args.first()) {
+                self.report_all(arg, &dyn_type);
+            }
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// `Box::new(..)` calls that `value` evaluates to, looking through parentheses,
+/// blocks and the arms of `if` / `match`
+fn box_new_values(value: &syn::Expr) -> Vec<&syn::ExprCall> {
+    match value {
+        syn::Expr::Call(call) if is_box_new(call) => vec![call],
+        syn::Expr::Paren(paren) => box_new_values(&paren.expr),
+        syn::Expr::Block(block) => block_value(&block.block),
+        syn::Expr::If(expr_if) => {
+            let mut calls = block_value(&expr_if.then_branch);
+            if let Some((_, else_branch)) = &expr_if.else_branch {
+                calls.extend(box_new_values(else_branch));
+            }
+            calls
+        }
+        syn::Expr::Match(expr_match) => expr_match.arms.iter().flat_map(|arm| box_new_values(&arm.body)).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn block_value(block: &syn::Block) -> Vec<&syn::ExprCall> {
+    match block.stmts.last() {
+        Some(syn::Stmt::Expr(expr, None)) => box_new_values(expr),
+        _ => Vec::new(),
+    }
+}
+
+fn is_box_new(call: &syn::ExprCall) -> bool {
+    let syn::Expr::Path(path) = &*call.func else {
+        return false;
+    };
+    let segments: Vec<_> = path.path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+    segments.ends_with(&["Box".to_string(), "new".to_string()])
+}
+
+/// `dyn Trait` written as text if `ty` is `Box<dyn Trait>`
+fn boxed_dyn_type(ty: &syn::Type) -> Option<String> {
+    match single_type_argument(ty, "Box")? {
+        syn::Type::TraitObject(object) => Some(object.to_token_stream().to_string()),
+        _ => None,
+    }
+}
+
+/// `dyn Trait` written as text if `ty` is `Vec<Box<dyn Trait>>`
+fn vec_of_boxed_dyn(ty: &syn::Type) -> Option<String> {
+    boxed_dyn_type(single_type_argument(ty, "Vec")?)
+}
+
+/// `T` of `Name<T>` (matched on the last path segment)
+fn single_type_argument<'t>(ty: &'t syn::Type, name: &str) -> Option<&'t syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1219DynBoxInLoop::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_boxing_in_loop() {
+        let code = r#"
+            fn total_area(radii: &[f64]) -> f64 {
+                let mut total = 0.0;
+                for r in radii {
+                    let shape: Box<dyn Shape> = Box::new(Circle::new(*r));
+                    total += shape.area();
+                }
+                total
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1219");
+        assert_eq!((violations[0].line, violations[0].column), (5, 49));
+        assert!(violations[0].message.contains("allocates a Box<dyn Shape> on every iteration"));
+    }
+
+    #[test]
+    fn test_detects_cast_branches_and_vec_push() {
+        let code = r#"
+            impl Scene {
+                fn build(&mut self, rows: &[Row]) {
+                    let mut shapes: Vec<Box<dyn Shape + Send>> = Vec::new();
+                    let mut i = 0;
+                    while i < rows.len() {
+                        let s = if rows[i].round { Box::new(Circle) as Box<dyn Shape> } else { Box::new(Square) as Box<dyn Shape> };
+                        shapes.push(Box::new(Square));
+                        let any: Box<dyn Shape> = match rows[i].kind { 0 => Box::new(Circle), _ => Box::new(Square) };
+                        i += 1;
+                    }
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 5);
+        assert!(violations[2].message.contains("Box<dyn Shape + Send>"));
+    }
+
+    #[test]
+    fn test_single_construction_passes() {
+        let code = r#"
+            fn render(rows: &[Row]) -> Box<dyn Shape> {
+                let shape: Box<dyn Shape> = Box::new(Circle::new(1.0));
+                let mut sizes = Vec::new();
+                for row in rows {
+                    sizes.push(Box::new(row.size));
+                    let f = || Box::new(Circle) as Box<dyn Shape>;
+                    let concrete: Box<Circle> = Box::new(Circle);
+                }
+                shape
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+//! E1247: Recursive field without indirection
+//!
+//! Companion to E1210. Detects structs and enums that contain a field of their
+//! own type stored inline (`struct Node { next: Node }`), possibly wrapped in
+//! containers that keep the value inline such as `Option` or tuples. Such types
+//! are infinitely sized and will not compile; this checker explains why and
+//! points to `Box`/`Rc` indirection instead of the raw compiler error.
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1247: Recursive field without indirection
+    E1247DirectRecursiveField,
+    code = "E1247",
+    name = "Recursive field without indirection",
+    suggestions = "Store the recursive field behind Box<T>, Rc<T>/Arc<T>, a reference, or a Vec<T>, e.g. `next: Option<Box<Node>>`",
+    target_items = [Struct, Enum],
+    config_entry_name = "e1247_direct_recursive_field",
+    /// Configuration for E1247: Recursive field without indirection checker
+    config = E1247Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::High,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = DirectRecursionVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+/// Generic wrappers that store their parameter inline (no heap indirection)
+const INLINE_WRAPPERS: &[&str] = &[
+    "Option",
+    "Cell",
+    "RefCell",
+    "UnsafeCell",
+    "Mutex",
+    "RwLock",
+    "ManuallyDrop",
+    "MaybeUninit",
+    "Wrapping",
+];
+
+struct DirectRecursionVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1247DirectRecursiveField,
+}
+
+impl<'a> DirectRecursionVisitor<'a> {
+    fn check_fields(&mut self, kind: &str, type_name: &syn::Ident, fields: &syn::Fields) {
+        for (index, field) in fields.iter().enumerate() {
+            if contains_inline_self(&field.ty, type_name) {
+                let field_name = field
+                    .ident
+                    .as_ref()
+                    .map(|i| i.to_string())
+                    .unwrap_or_else(|| index.to_string());
+                let start = type_name.span().start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "{} '{}' stores itself inline in field '{}', which makes it infinitely sized. Use Box<{}> for indirection.",
+                            kind, type_name, field_name, type_name
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for DirectRecursionVisitor<'a> {
+    fn visit_item_struct(&mut self, node: &'a syn::ItemStruct) {
+        self.check_fields("Struct", &node.ident, &node.fields);
+        syn::visit::visit_item_struct(self, node);
+    }
+
+    fn visit_item_enum(&mut self, node: &'a syn::ItemEnum) {
+        for variant in &node.variants {
+            self.check_fields("Enum", &node.ident, &variant.fields);
+        }
+        syn::visit::visit_item_enum(self, node);
+    }
+}
+
+/// Check whether `ty` contains `type_name` (or `Self`) without heap/reference indirection
+fn contains_inline_self(ty: &syn::Type, type_name: &syn::Ident) -> bool {
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => {
+            let Some(last) = type_path.path.segments.last() else {
+                return false;
+            };
+            if type_path.path.segments.len() == 1 && (last.ident == *type_name || last.ident == "Self") {
+                return true;
+            }
+            if !INLINE_WRAPPERS.contains(&last.ident.to_string().as_str()) {
+                return false;
+            }
+            match &last.arguments {
+                syn::PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| {
+                    matches!(arg, syn::GenericArgument::Type(inner) if contains_inline_self(inner, type_name))
+                }),
+                _ => false,
+            }
+        }
+        syn::Type::Tuple(tuple) => tuple.elems.iter().any(|t| contains_inline_self(t, type_name)),
+        syn::Type::Array(array) => contains_inline_self(&array.elem, type_name),
+        syn::Type::Paren(paren) => contains_inline_self(&paren.elem, type_name),
+        syn::Type::Group(group) => contains_inline_self(&group.elem, type_name),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1247DirectRecursiveField::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_direct_struct_field() {
+        let violations = check_code("struct Node { value: i32, next: Node }");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1247");
+        assert!(violations[0].message.contains("'next'"));
+    }
+
+    #[test]
+    fn test_detects_option_wrapped_field() {
+        let violations = check_code("struct Node { next: Option<Node> }");
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_detects_enum_variant() {
+        let violations = check_code("enum List { Cons(i32, List), Nil }");
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("Enum 'List'"));
+    }
+
+    #[test]
+    fn test_detects_nested_in_function() {
+        let code = r#"
+            fn build() {
+                struct Inner { a: (u8, Inner) }
+            }
+        "#;
+        assert_eq!(check_code(code).len(), 1);
+    }
+
+    #[test]
+    fn test_boxed_field_not_flagged() {
+        let code = r#"
+            struct Node { value: i32, next: Option<Box<Node>> }
+            enum List { Cons(i32, Box<List>), Nil }
+            struct Tree { children: Vec<Tree> }
+            struct Parent<'a> { parent: Option<&'a Parent<'a>> }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_qualified_same_name_not_flagged() {
+        let code = "struct Node { inner: other::Node }";
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,160 @@
+//! E1249: Struct made only of `PhantomData` fields
+//!
+//! Companion to E1208 and E1215. Detects structs that carry no runtime data at
+//! all - every field is `PhantomData<_>` - and stack more than a configurable
+//! number of such markers. A single `PhantomData<T>` marker is a common idiom;
+//! several of them in an otherwise empty struct usually means type-level state
+//! machines or unit encodings that a plain enum or runtime value would express
+//! more simply.
+//!
+//! Example:
+//! ```text
+//! struct Request<Method, Auth, Body> {
+//!     _method: PhantomData<Method>,
+//!     _auth: PhantomData<Auth>,
+//!     _body: PhantomData<Body>,
+//! }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1249: Struct made only of PhantomData fields
+    E1249PhantomOnlyStruct,
+    code = "E1249",
+    name = "Struct made only of PhantomData fields",
+    suggestions = "Consider a plain enum, a runtime field, or a single marker type parameter instead of several zero-sized PhantomData markers",
+    target_items = [Struct],
+    config_entry_name = "e1249_phantom_only_struct",
+    /// Configuration for E1249: PhantomData-only struct checker
+    config = E1249Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Maximum number of PhantomData fields allowed in a struct without real fields
+        max_phantom_only_fields: usize = 1,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = PhantomOnlyVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct PhantomOnlyVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1249PhantomOnlyStruct,
+}
+
+impl<'a> Visit<'a> for PhantomOnlyVisitor<'a> {
+    fn visit_item_struct(&mut self, node: &'a syn::ItemStruct) {
+        let phantom_fields = node.fields.iter().filter(|f| is_phantom_data(&f.ty)).count();
+        let real_fields = node.fields.len() - phantom_fields;
+
+        if real_fields == 0 && phantom_fields > self.checker.config.max_phantom_only_fields {
+            let start = node.ident.span().start();
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    self.checker.severity().into(),
+                    format!(
+                        "Struct '{}' has no data, only {} PhantomData markers (max {}). Type-level encodings like this are hard to follow; a plain enum or runtime value is often simpler.",
+                        node.ident, phantom_fields, self.checker.config.max_phantom_only_fields
+                    ),
+                    self.file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_suggestion(self.checker.suggestions()),
+            );
+        }
+
+        syn::visit::visit_item_struct(self, node);
+    }
+}
+
+/// Check whether a type is `PhantomData<_>` (bare or path-qualified)
+fn is_phantom_data(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1249PhantomOnlyStruct::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_multiple_phantom_only_fields() {
+        let code = r#"
+            struct Request<M, A, B> {
+                _method: PhantomData<M>,
+                _auth: std::marker::PhantomData<A>,
+                _body: PhantomData<B>,
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1249");
+        assert!(violations[0].message.contains("only 3 PhantomData markers"));
+    }
+
+    #[test]
+    fn test_detects_tuple_struct() {
+        let code = "struct Units<A, B>(PhantomData<A>, PhantomData<B>);";
+        assert_eq!(check_code(code).len(), 1);
+    }
+
+    #[test]
+    fn test_single_marker_not_flagged() {
+        let code = "struct Meters<T> { _unit: PhantomData<T> }";
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_real_fields_not_flagged() {
+        let code = r#"
+            struct Id<A, B> {
+                value: u64,
+                _a: PhantomData<A>,
+                _b: PhantomData<B>,
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_respects_configured_limit() {
+        let code = "struct Units<A, B>(PhantomData<A>, PhantomData<B>);";
+        let syntax = syn::parse_file(code).unwrap();
+        let mut checker = E1249PhantomOnlyStruct::default();
+        checker.config.max_phantom_only_fields = 2;
+        assert!(checker.check_item(&syntax.items[0], "test.rs").unwrap().is_empty());
+    }
+}
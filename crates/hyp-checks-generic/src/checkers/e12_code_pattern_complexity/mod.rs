@@ -17,6 +17,10 @@ pub mod e1214_macro_impl;
 pub mod e1215_type_level_programming;
 pub mod e1216_associated_type_chains;
 pub mod e1217_abba_deadlock;
+pub mod e1218_excessive_supertraits;
+pub mod e1219_dyn_box_in_loop;
+pub mod e1247_direct_recursive_field;
+pub mod e1249_phantom_only_struct;
 pub mod registry;
 
 pub use e1201_complex_generics::{E1201ComplexGenerics, E1201Config};
@@ -36,3 +40,7 @@ pub use e1214_macro_impl::{E1214Config, E1214MacroImpl};
 pub use e1215_type_level_programming::{E1215Config, E1215TypeLevelProgramming};
 pub use e1216_associated_type_chains::{E1216AssociatedTypeChains, E1216Config};
 pub use e1217_abba_deadlock::{E1217AbbaDeadlock, E1217Config};
+pub use e1218_excessive_supertraits::{E1218Config, E1218ExcessiveSupertraits};
+pub use e1247_direct_recursive_field::{E1247Config, E1247DirectRecursiveField};
+pub use e1249_phantom_only_struct::{E1249Config, E1249PhantomOnlyStruct};
+pub use e1219_dyn_box_in_loop::{E1219Config, E1219DynBoxInLoop};
@@ -11,6 +11,10 @@ use crate::{
         E1212Config, E1212GatComplexity, E1213Config, E1213ConstGenericComplexity, E1214Config,
         E1214MacroImpl, E1215Config, E1215TypeLevelProgramming, E1216AssociatedTypeChains,
         E1216Config, E1217AbbaDeadlock, E1217Config,
+        E1247Config, E1247DirectRecursiveField,
+        E1249Config, E1249PhantomOnlyStruct,
+        E1218Config, E1218ExcessiveSupertraits,
+        E1219Config, E1219DynBoxInLoop,
     },
     register_checker,
     registry::CheckerRegistration,
@@ -36,5 +40,9 @@ pub fn e12_registrations() -> Vec<CheckerRegistration> {
         register_checker!(E1215TypeLevelProgramming, E1215Config),
         register_checker!(E1216AssociatedTypeChains, E1216Config),
         register_checker!(E1217AbbaDeadlock, E1217Config),
+        register_checker!(E1247DirectRecursiveField, E1247Config),
+        register_checker!(E1249PhantomOnlyStruct, E1249Config),
+        register_checker!(E1218ExcessiveSupertraits, E1218Config),
+        register_checker!(E1219DynBoxInLoop, E1219Config),
     ]
 }
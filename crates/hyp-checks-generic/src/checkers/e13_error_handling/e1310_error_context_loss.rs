@@ -2,18 +2,25 @@
 //!
 //! Detects when map_err uses `|_|` to discard the original error, losing
 //! valuable debugging information about what actually went wrong.
+//!
+//! A closure that names its parameter but never mentions it in the body
+//! (`.map_err(|e| MyError::Generic)`) discards the error just the same and is
+//! reported too. Function paths such as `.map_err(MyError::from)` keep the
+//! source and pass.
 
 use crate::{checker::Checker, define_checker, violation::Violation};
 
-use syn::visit::Visit;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{spanned::Spanned, visit::Visit};
 
 define_checker! {
     /// Checker for E1310: Error context loss
     E1310ErrorContextLoss,
     code = "E1310",
     name = "Error context loss",
-    suggestions = "Preserve the original error by wrapping it, not discarding with |_|. Use anyhow/thiserror for error chains",
-    target_items = [Function],
+    suggestions = "Preserve the original error by wrapping it (e.g. MyError::from(e) or a #[from] variant), not discarding with |_|. Use anyhow/thiserror for error chains",
+    target_items = [Function, Impl, Trait],
     config_entry_name = "e1310_error_context_loss",
     config = E1310Config {
         enabled: bool = true,
@@ -41,22 +48,22 @@ impl<'a> Visit<'a> for ContextLossVisitor<'a> {
     fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
         let method_name = node.method.to_string();
 
-        // Check for map_err with discarding closure |_| ...
+        // Check for map_err with a closure that discards its error
         if method_name == "map_err" {
-            if let Some(arg) = node.args.first() {
-                if is_discarding_closure(arg) {
-                    use syn::spanned::Spanned;
-                    let start = node.span().start();
+            if let Some(syn::Expr::Closure(closure)) = node.args.first() {
+                if let Some(message) = discard_message(closure) {
+                    let start = node.method.span().start();
                     self.violations.push(
                         Violation::new(
                             self.checker.code(),
                             self.checker.name(),
                             self.checker.severity().into(),
-                            "map_err with |_| discards original error context. Preserve error information for debugging.",
+                            message,
                             self.file_path,
                             start.line,
                             start.column + 1,
                         )
+                        .with_span(start, node.span().end())
                         .with_suggestion(self.checker.suggestions()),
                     );
                 }
@@ -67,16 +74,38 @@ impl<'a> Visit<'a> for ContextLossVisitor<'a> {
     }
 }
 
-fn is_discarding_closure(expr: &syn::Expr) -> bool {
-    if let syn::Expr::Closure(closure) = expr {
-        // Check if the closure parameter is _ (wildcard)
-        if closure.inputs.len() == 1 {
-            if let syn::Pat::Wild(_) = &closure.inputs[0] {
-                return true;
-            }
+/// Why a single-parameter `map_err` closure loses the error, if it does
+fn discard_message(closure: &syn::ExprClosure) -> Option<String> {
+    if closure.inputs.len() != 1 {
+        return None;
+    }
+    let mut pat = &closure.inputs[0];
+    if let syn::Pat::Type(typed) = pat {
+        pat = &typed.pat;
+    }
+    match pat {
+        syn::Pat::Wild(_) => {
+            Some("map_err with |_| discards original error context. Preserve error information for debugging.".to_string())
         }
+        syn::Pat::Ident(ident) if !mentions(closure.body.to_token_stream(), &ident.ident.to_string()) => Some(format!(
+            "map_err closure never uses its error '{}', discarding original error context. Preserve error information for debugging.",
+            ident.ident
+        )),
+        _ => None,
     }
-    false
+}
+
+/// Whether `name` appears in `tokens`, as an identifier or inlined in a format string (`"{e}"`)
+fn mentions(tokens: TokenStream, name: &str) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => mentions(group.stream(), name),
+        TokenTree::Literal(literal) => {
+            let text = literal.to_string();
+            text.contains(&format!("{{{}}}", name)) || text.contains(&format!("{{{}:", name))
+        }
+        TokenTree::Punct(_) => false,
+    })
 }
 
 #[cfg(test)]
@@ -146,6 +175,41 @@ mod tests {
         assert_eq!(violations.len(), 0);
     }
 
+    #[test]
+    fn test_detects_unused_named_param_in_method() {
+        let code = r#"
+            impl Loader {
+                fn load(&self) -> Result<String, LoadError> {
+                    let text = std::fs::read_to_string(&self.path).map_err(|e| LoadError::Generic)?;
+                    let port = text.trim().parse::<u16>().map_err(|err: ParseIntError| LoadError::Port(err))?;
+                    let name = std::env::var("NAME").map_err(|e| LoadError::Env(format!("{e}")))?;
+                    Ok(format!("{}:{}", name, port))
+                }
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1310ErrorContextLoss::default();
+        let violations = checker.check_item(&syntax.items[0], "test.rs").unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!((violations[0].line, violations[0].column), (4, 68));
+        assert!(violations[0].message.contains("never uses its error 'e'"));
+    }
+
+    #[test]
+    fn test_map_err_with_function_path_passes() {
+        let code = r#"
+            fn example() -> Result<String, AppError> {
+                std::fs::read_to_string("config.txt").map_err(AppError::from)
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1310ErrorContextLoss::default();
+        assert!(checker.check_item(&syntax.items[0], "test.rs").unwrap().is_empty());
+    }
+
     #[test]
     fn test_map_err_with_named_param_passes() {
         let code = r#"
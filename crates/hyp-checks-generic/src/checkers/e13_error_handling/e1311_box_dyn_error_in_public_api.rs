@@ -0,0 +1,213 @@
+//! E1311: Box<dyn Error> returned from a public API
+//!
+//! Detects public functions returning `Result<T, Box<dyn Error>>` (with or
+//! without `+ Send + Sync`). The boxed trait object erases the error type, so
+//! library callers can only print the error instead of matching on it.
+//! `main` is never reported.
+//!
+//! Example:
+//! ```text
+//! // Bad: callers cannot tell an I/O failure from a parse failure
+//! pub fn load(path: &Path) -> Result<Config, Box<dyn Error>>;
+//!
+//! // Good: a concrete error type
+//! pub fn load(path: &Path) -> Result<Config, ConfigError>;
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1311: Box<dyn Error> returned from a public API
+    E1311BoxDynErrorInPublicApi,
+    code = "E1311",
+    name = "Box<dyn Error> returned from public API",
+    suggestions = "Define a concrete error enum (e.g. with `thiserror`) so callers can match on the failure; keep `Box<dyn Error>`/`anyhow` for binaries",
+    target_items = [Function, Impl],
+    config_entry_name = "e1311_box_dyn_error_in_public_api",
+    /// Configuration for E1311: Box<dyn Error> in public API checker
+    config = E1311Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Only report `pub` functions and methods; set to false to report private ones too
+        public_only: bool = true,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = PublicApiVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct PublicApiVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1311BoxDynErrorInPublicApi,
+}
+
+impl<'a> PublicApiVisitor<'a> {
+    fn check_signature(&mut self, vis: &syn::Visibility, sig: &syn::Signature) {
+        if sig.ident == "main" {
+            return;
+        }
+        if self.checker.config.public_only && !matches!(vis, syn::Visibility::Public(_)) {
+            return;
+        }
+        let syn::ReturnType::Type(_, ty) = &sig.output else {
+            return;
+        };
+        if !returns_boxed_dyn_error(ty) {
+            return;
+        }
+
+        let start = sig.ident.span().start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "Function '{}' returns Result<_, Box<dyn Error>>; callers cannot match on the error type.",
+                    sig.ident
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for PublicApiVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.check_signature(&node.vis, &node.sig);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'a syn::ItemImpl) {
+        // Trait impl methods follow the trait's signature, which is reported at the trait
+        if node.trait_.is_none() {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.check_signature(&node.vis, &node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+}
+
+/// Whether `ty` is `Result<_, Box<dyn Error ..>>`
+fn returns_boxed_dyn_error(ty: &syn::Type) -> bool {
+    let Some(args) = last_segment_args(ty, "Result") else {
+        return false;
+    };
+    let Some(syn::GenericArgument::Type(error)) = args.args.iter().nth(1) else {
+        return false;
+    };
+    let Some(box_args) = last_segment_args(error, "Box") else {
+        return false;
+    };
+    matches!(box_args.args.first(), Some(syn::GenericArgument::Type(syn::Type::TraitObject(object))) if is_dyn_error(object))
+}
+
+/// Generic arguments of a path type whose last segment is `name`
+fn last_segment_args<'t>(ty: &'t syn::Type, name: &str) -> Option<&'t syn::AngleBracketedGenericArguments> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let last = type_path.path.segments.last()?;
+    match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) if last.ident == name => Some(args),
+        _ => None,
+    }
+}
+
+/// Whether a trait object has an `Error` bound (`dyn Error`, `dyn std::error::Error + Send + Sync`)
+fn is_dyn_error(object: &syn::TypeTraitObject) -> bool {
+    object.bounds.iter().any(|bound| {
+        matches!(bound, syn::TypeParamBound::Trait(trait_bound)
+            if trait_bound.path.segments.last().is_some_and(|s| s.ident == "Error"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code_with(checker: &E1311BoxDynErrorInPublicApi, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_code_with(&E1311BoxDynErrorInPublicApi::default(), code)
+    }
+
+    #[test]
+    fn test_detects_public_fn_and_method() {
+        let code = r#"
+            pub fn load(path: &str) -> Result<Config, Box<dyn std::error::Error>> {
+                todo!()
+            }
+
+            impl Client {
+                pub fn fetch(&self) -> std::result::Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+                    todo!()
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1311");
+        assert!(violations[0].message.contains("'load'"));
+        assert_eq!(violations[0].line, 2);
+        assert!(violations[1].message.contains("'fetch'"));
+    }
+
+    #[test]
+    fn test_private_concrete_and_main_not_flagged() {
+        let code = r#"
+            fn helper() -> Result<(), Box<dyn Error>> { Ok(()) }
+            pub(crate) fn internal() -> Result<(), Box<dyn Error>> { Ok(()) }
+            pub fn typed() -> Result<(), ConfigError> { Ok(()) }
+            pub fn boxed_value() -> Result<Box<dyn Display>, ConfigError> { todo!() }
+            pub fn main() -> Result<(), Box<dyn Error>> { Ok(()) }
+
+            impl Display for Config {
+                fn fmt(&self, f: &mut Formatter) -> Result<(), Box<dyn Error>> { todo!() }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_public_only_disabled_reports_private_fns() {
+        let config = E1311Config {
+            public_only: false,
+            ..E1311Config::default()
+        };
+        let checker = E1311BoxDynErrorInPublicApi { config };
+        let code = r#"
+            fn helper() -> Result<(), Box<dyn Error>> { Ok(()) }
+            fn main() -> Result<(), Box<dyn Error>> { Ok(()) }
+        "#;
+        let violations = check_code_with(&checker, code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'helper'"));
+    }
+}
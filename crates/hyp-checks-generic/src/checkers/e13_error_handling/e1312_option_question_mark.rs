@@ -0,0 +1,221 @@
+//! E1312: `?` on an Option inside a Result-returning function
+//!
+//! Detects `expr?` in a function returning `Result<..>` where `expr` is
+//! syntactically an Option: a call to a method that returns one (`.get(..)`,
+//! `.first()`, `.find(..)`, ...), `Some(..)` or `None`. `Option` has no
+//! conversion into an error type, so this does not compile; the usual fix is a
+//! missing `.ok_or(..)` / `.ok_or_else(..)` that says which value was absent.
+//!
+//! The receiver type is not known, so a method from the list that returns a
+//! `Result` on some custom type is a false positive; `option_methods` can be
+//! tuned per project. `?` inside closures and async blocks is not checked.
+//!
+//! Example:
+//! ```text
+//! // Bad: `get` returns Option<&String>, the function returns Result
+//! fn port(config: &HashMap<String, String>) -> Result<u16, ConfigError> {
+//!     let raw = config.get("port")?;
+//!     ...
+//! }
+//!
+//! // Good: the missing key becomes a meaningful error
+//! let raw = config.get("port").ok_or_else(|| ConfigError::Missing("port"))?;
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1312: `?` on an Option inside a Result-returning function
+    E1312OptionQuestionMark,
+    code = "E1312",
+    name = "`?` on an Option in a Result-returning function",
+    suggestions = "Convert the Option first: `.ok_or_else(|| MyError::Missing(..))?` or `.ok_or(err)?`, naming what was absent",
+    target_items = [Function, Impl],
+    config_entry_name = "e1312_option_question_mark",
+    /// Configuration for E1312: Option `?` checker
+    config = E1312Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Method names treated as returning an Option
+        option_methods: Vec<String> = [
+            "get", "get_mut", "first", "last", "find", "find_map", "position", "next", "pop",
+            "checked_add", "checked_sub", "checked_mul", "checked_div", "strip_prefix", "strip_suffix",
+        ]
+        .iter()
+        .map(|name| name.to_string())
+        .collect(),
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = OptionTryVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            returns_result: false,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct OptionTryVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1312OptionQuestionMark,
+    /// Whether `?` in the current scope returns from a function declared to return `Result`
+    returns_result: bool,
+}
+
+impl<'a> OptionTryVisitor<'a> {
+    /// Visit a function body knowing whether it returns `Result`
+    fn visit_fn(&mut self, sig: &'a syn::Signature, visit: impl FnOnce(&mut Self)) {
+        let outer = std::mem::replace(&mut self.returns_result, returns_result(&sig.output));
+        visit(self);
+        self.returns_result = outer;
+    }
+
+    /// Short description of an Option-producing expression, if `expr` is one
+    fn option_source(&self, expr: &syn::Expr) -> Option<String> {
+        match expr {
+            syn::Expr::MethodCall(call) => {
+                let method = call.method.to_string();
+                self.checker
+                    .config
+                    .option_methods
+                    .contains(&method)
+                    .then(|| format!(".{}(..)", method))
+            }
+            syn::Expr::Call(call) => match &*call.func {
+                syn::Expr::Path(path) if path.path.is_ident("Some") => Some(String::from("Some(..)")),
+                _ => None,
+            },
+            syn::Expr::Path(path) if path.path.is_ident("None") => Some(String::from("None")),
+            syn::Expr::Paren(paren) => self.option_source(&paren.expr),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for OptionTryVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.visit_fn(&node.sig, |visitor| syn::visit::visit_item_fn(visitor, node));
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.visit_fn(&node.sig, |visitor| syn::visit::visit_impl_item_fn(visitor, node));
+    }
+
+    fn visit_expr_closure(&mut self, node: &'a syn::ExprClosure) {
+        // `?` inside a closure returns from the closure, whose type is not declared
+        let outer = std::mem::replace(&mut self.returns_result, false);
+        syn::visit::visit_expr_closure(self, node);
+        self.returns_result = outer;
+    }
+
+    fn visit_expr_async(&mut self, node: &'a syn::ExprAsync) {
+        let outer = std::mem::replace(&mut self.returns_result, false);
+        syn::visit::visit_expr_async(self, node);
+        self.returns_result = outer;
+    }
+
+    fn visit_expr_try(&mut self, node: &'a syn::ExprTry) {
+        if self.returns_result {
+            if let Some(source) = self.option_source(&node.expr) {
+                let start = node.question_token.span.start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "`?` applied to {}, which produces an Option, in a function returning Result; an Option does not convert into the error type.",
+                            source
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+
+        syn::visit::visit_expr_try(self, node);
+    }
+}
+
+/// Whether the declared return type is `Result<..>` (including `io::Result<T>` and similar aliases)
+fn returns_result(output: &syn::ReturnType) -> bool {
+    match output {
+        syn::ReturnType::Type(_, ty) => match &**ty {
+            syn::Type::Path(path) => path.path.segments.last().is_some_and(|s| s.ident == "Result"),
+            _ => false,
+        },
+        syn::ReturnType::Default => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1312OptionQuestionMark::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_option_question_mark_in_result_fn() {
+        let code = r#"
+            fn port(config: &HashMap<String, String>, args: &[String]) -> Result<u16, ConfigError> {
+                let raw = config.get("port")?;
+                let first = args.first()?;
+                let n = Some(5)?;
+                raw.parse().map_err(ConfigError::from)
+            }
+
+            impl Store {
+                fn newest(&self) -> io::Result<Entry> {
+                    let entry = self.entries.iter().find(|e| e.live)?;
+                    Ok(entry.clone())
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 4);
+        assert_eq!(violations[0].code, "E1312");
+        assert_eq!((violations[0].line, violations[0].column), (3, 45));
+        assert!(violations[0].message.contains("`?` applied to .get(..)"));
+        assert!(violations[2].message.contains("Some(..)"));
+        assert!(violations[3].message.contains(".find(..)"));
+    }
+
+    #[test]
+    fn test_converted_options_and_option_fns_pass() {
+        let code = r#"
+            fn port(config: &HashMap<String, String>) -> Result<u16, ConfigError> {
+                let raw = config.get("port").ok_or(ConfigError::Missing)?;
+                let text = std::fs::read_to_string(path)?;
+                let parsed = args.iter().map(|a| a.get(0..1)).collect::<Option<Vec<_>>>();
+                let first = (|| Some(*args.first()?))();
+                raw.parse().map_err(ConfigError::from)
+            }
+
+            fn lookup(map: &HashMap<u32, u32>, key: u32) -> Option<u32> {
+                let value = map.get(&key)?;
+                Some(*value)
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,133 @@
+//! E1319: `expect` with an empty message
+//!
+//! Detects `.expect("")` calls whose message is empty or only whitespace. The
+//! panic then reads `: called Option::unwrap() on a None value` with nothing
+//! to say why the value was expected, which is strictly worse than `unwrap()`
+//! because it looks as if a message had been written. Unlike E1015, which
+//! judges message length heuristically, this pattern is always a mistake.
+//!
+//! Example:
+//! ```text
+//! // Bad: the panic message is blank
+//! let config = CONFIG.get().expect("");
+//!
+//! // Good: state the invariant that guarantees the value
+//! let config = CONFIG.get().expect("config must be loaded before the server starts");
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1319: expect with an empty message
+    E1319EmptyExpectMessage,
+    code = "E1319",
+    name = "expect() with an empty message",
+    suggestions = "Write a message describing the invariant that guarantees the value is present, e.g. .expect(\"config must be loaded before use\")",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1319_empty_expect_message",
+    /// Configuration for E1319: Empty expect message checker
+    config = E1319Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::High,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = EmptyExpectVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct EmptyExpectVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1319EmptyExpectMessage,
+}
+
+impl<'a> Visit<'a> for EmptyExpectVisitor<'a> {
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if node.method == "expect" && node.args.len() == 1 {
+            if let Some(syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(message), .. })) = node.args.first() {
+                if message.value().trim().is_empty() {
+                    let start = node.method.span().start();
+                    self.violations.push(
+                        Violation::new(
+                            self.checker.code(),
+                            self.checker.name(),
+                            self.checker.severity().into(),
+                            "expect() is called with an empty message, so the panic says nothing about what was expected.",
+                            self.file_path,
+                            start.line,
+                            start.column + 1,
+                        )
+                        .with_span(start, node.span().end())
+                        .with_suggestion(self.checker.suggestions()),
+                    );
+                }
+            }
+        }
+
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1319EmptyExpectMessage::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_empty_message() {
+        let code = r#"
+            fn load() {
+                let config = CONFIG.get().expect("");
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1319");
+        assert_eq!(violations[0].severity, crate::violation::Severity::High);
+        assert_eq!((violations[0].line, violations[0].column), (3, 43));
+    }
+
+    #[test]
+    fn test_detects_whitespace_message() {
+        let code = r#"
+            impl Server {
+                fn port(&self) -> u16 {
+                    self.port.expect("   ")
+                }
+            }
+        "#;
+        assert_eq!(check_code(code).len(), 1);
+    }
+
+    #[test]
+    fn test_descriptive_message_passes() {
+        let code = r#"
+            fn load() {
+                let config = CONFIG.get().expect("config must be loaded");
+                let port = env.expect(MESSAGE);
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,284 @@
+//! E1320: Error type without a `std::error::Error` implementation
+//!
+//! Detects structs and enums used as the error type of a `Result` whose file
+//! never implements `std::error::Error` for them. Without that impl the type
+//! cannot be boxed into `Box<dyn Error>`, converted by `anyhow`/`eyre`, used
+//! as the `source()` of another error, or printed with the usual reporting
+//! tools, so every caller has to special-case it.
+//!
+//! The file is searched for `Result<_, E>` in function signatures and type
+//! aliases (e.g. `type Result<T> = std::result::Result<T, Error>`). Each `E`
+//! defined in the same file must have an `impl .. Error for E` block or a
+//! derive from `error_derives` (`thiserror::Error`, `snafu::Snafu`). Types
+//! defined elsewhere are not checked.
+//!
+//! Limitation: only one file is analyzed at a time, so an `Error` impl
+//! written in another file is not seen. List such types in `allowed_types`.
+//!
+//! Example:
+//! ```text
+//! // Bad: cannot be used with `?` in a function returning Box<dyn Error>
+//! pub enum ParseError { Empty, BadDigit(char) }
+//! pub fn parse(s: &str) -> Result<u32, ParseError> { .. }
+//!
+//! // Good
+//! #[derive(Debug, thiserror::Error)]
+//! pub enum ParseError { #[error("empty input")] Empty, #[error("bad digit {0}")] BadDigit(char) }
+//! ```
+
+use crate::{define_checker, violation::Violation};
+
+use std::collections::{BTreeMap, HashSet};
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1320: Error type without a std::error::Error implementation
+    E1320ErrorTypeMissingErrorImpl,
+    code = "E1320",
+    name = "Error type without std::error::Error implementation",
+    suggestions = "Implement std::error::Error (and Display) for the type, e.g. with #[derive(Debug, thiserror::Error)]",
+    target_items = [Struct, Enum],
+    config_entry_name = "e1320_error_type_missing_error_impl",
+    /// Configuration for E1320: Error type missing Error impl checker
+    config = E1320Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Derive macros that implement `Error` (matched on the last path segment)
+        error_derives: Vec<String> = vec!["Error".to_string(), "Snafu".to_string()],
+        /// Error types not to report, e.g. ones whose `Error` impl lives in another file
+        allowed_types: Vec<String> = Vec::new(),
+    },
+    check_item(self, _item, _file_path) {
+        // Uses and impls of a type can be anywhere in the file, see check_file
+        Ok(Vec::new())
+    }
+    check_file(self, file, file_path) {
+        let mut collector = ErrorTypeCollector {
+            error_derives: &self.config.error_derives,
+            ..ErrorTypeCollector::default()
+        };
+        collector.visit_file(file);
+
+        let mut violations = Vec::new();
+        for (name, ident) in &collector.defined {
+            let Some(used_in) = collector.used_as_error.get(name) else {
+                continue;
+            };
+            if collector.implements_error.contains(name) || self.config.allowed_types.contains(name) {
+                continue;
+            }
+
+            let start = ident.span().start();
+            violations.push(
+                Violation::new(
+                    self.code(),
+                    self.name(),
+                    self.severity().into(),
+                    format!(
+                        "'{}' is the error type of {} but does not implement std::error::Error.",
+                        name, used_in
+                    ),
+                    file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_suggestion(self.suggestions()),
+            );
+        }
+
+        Ok(violations)
+    }
+}
+
+#[derive(Default)]
+struct ErrorTypeCollector<'a> {
+    error_derives: &'a [String],
+    /// Structs and enums defined in the file, by name
+    defined: BTreeMap<String, syn::Ident>,
+    /// Error types of `Result`s, with the first place each one was seen
+    used_as_error: BTreeMap<String, String>,
+    /// Types with an `impl Error for T` block or an error derive
+    implements_error: HashSet<String>,
+}
+
+impl ErrorTypeCollector<'_> {
+    fn define(&mut self, ident: &syn::Ident, attrs: &[syn::Attribute]) {
+        let name = ident.to_string();
+        if has_derive(attrs, self.error_derives) {
+            self.implements_error.insert(name.clone());
+        }
+        self.defined.insert(name, ident.clone());
+    }
+
+    fn record_use(&mut self, ty: &syn::Type, place: impl FnOnce() -> String) {
+        if let Some(error) = result_error_type(ty) {
+            self.used_as_error.entry(error).or_insert_with(place);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for ErrorTypeCollector<'_> {
+    fn visit_item_struct(&mut self, node: &'ast syn::ItemStruct) {
+        self.define(&node.ident, &node.attrs);
+        syn::visit::visit_item_struct(self, node);
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast syn::ItemEnum) {
+        self.define(&node.ident, &node.attrs);
+        syn::visit::visit_item_enum(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
+        let is_error_impl = node
+            .trait_
+            .as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .is_some_and(|segment| segment.ident == "Error");
+        if is_error_impl {
+            if let Some(name) = type_name(&node.self_ty) {
+                self.implements_error.insert(name);
+            }
+        }
+        syn::visit::visit_item_impl(self, node);
+    }
+
+    fn visit_signature(&mut self, node: &'ast syn::Signature) {
+        if let syn::ReturnType::Type(_, ty) = &node.output {
+            self.record_use(ty, || format!("'{}'", node.ident));
+        }
+        syn::visit::visit_signature(self, node);
+    }
+
+    fn visit_item_type(&mut self, node: &'ast syn::ItemType) {
+        self.record_use(&node.ty, || format!("type alias '{}'", node.ident));
+        syn::visit::visit_item_type(self, node);
+    }
+}
+
+/// Whether `#[derive(..)]` lists one of `derives` (matched on the last path segment)
+fn has_derive(attrs: &[syn::Attribute], derives: &[String]) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("derive")).any(|attr| {
+        attr.parse_args_with(syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)
+            .is_ok_and(|paths| {
+                paths
+                    .iter()
+                    .filter_map(|path| path.segments.last())
+                    .any(|segment| derives.iter().any(|derive| segment.ident == derive))
+            })
+    })
+}
+
+/// Name of `E` in `Result<T, E>` (any path ending in `Result` with two type arguments)
+fn result_error_type(ty: &syn::Type) -> Option<String> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    let (_, error) = (types.next()?, types.next()?);
+    type_name(error)
+}
+
+/// Last path segment of a plain path type (`Error`, `crate::ParseError<'a>`)
+fn type_name(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => {
+            type_path.path.segments.last().map(|segment| segment.ident.to_string())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::Checker;
+
+    fn check_with(checker: &E1320ErrorTypeMissingErrorImpl, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        checker.check_file(&syntax, "test.rs").unwrap()
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_with(&E1320ErrorTypeMissingErrorImpl::default(), code)
+    }
+
+    #[test]
+    fn test_detects_error_enum_without_impl() {
+        let code = r#"
+            #[derive(Debug)]
+            pub enum ParseError { Empty, BadDigit(char) }
+
+            pub fn parse(s: &str) -> Result<u32, ParseError> {
+                Err(ParseError::Empty)
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1320");
+        assert_eq!((violations[0].line, violations[0].column), (3, 22));
+        assert!(violations[0].message.contains("'ParseError' is the error type of 'parse'"));
+    }
+
+    #[test]
+    fn test_detects_type_alias_and_method_uses() {
+        let code = r#"
+            pub struct ConfigError { key: String }
+            pub type Result<T> = std::result::Result<T, ConfigError>;
+
+            struct StoreError;
+            impl Store {
+                fn load(&self) -> std::result::Result<(), StoreError> { Ok(()) }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("'ConfigError' is the error type of type alias 'Result'"));
+        assert!(violations[1].message.contains("'StoreError' is the error type of 'load'"));
+    }
+
+    #[test]
+    fn test_error_impls_and_derives_pass() {
+        let code = r#"
+            #[derive(Debug)]
+            pub struct IoFailure;
+            impl std::fmt::Display for IoFailure {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "io") }
+            }
+            impl std::error::Error for IoFailure {}
+
+            #[derive(Debug, thiserror::Error)]
+            pub enum AppError { #[error("io")] Io }
+
+            pub struct NotAnError;
+
+            pub fn read() -> Result<u8, IoFailure> { Err(IoFailure) }
+            pub fn run() -> Result<(), AppError> { Ok(()) }
+            pub fn other() -> Result<NotAnError, std::io::Error> { Ok(NotAnError) }
+            pub fn text() -> Result<(), String> { Ok(()) }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_allowed_types() {
+        let code = "pub struct RemoteError;\npub fn call() -> Result<(), RemoteError> { Err(RemoteError) }\n";
+        let mut checker = E1320ErrorTypeMissingErrorImpl::default();
+        assert_eq!(check_with(&checker, code).len(), 1);
+        checker.config.allowed_types = vec!["RemoteError".to_string()];
+        assert!(check_with(&checker, code).is_empty());
+    }
+}
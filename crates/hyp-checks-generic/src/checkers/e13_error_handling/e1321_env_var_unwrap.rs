@@ -0,0 +1,166 @@
+//! E1321: Environment variable read with unwrap()/expect()
+//!
+//! Detects `std::env::var(...)` / `env::var_os(...)` immediately followed by
+//! `.unwrap()` or `.expect()`. Configuration read this way crashes the process
+//! with an unhelpful panic as soon as a variable is missing or not valid UTF-8.
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1321: Environment variable read with unwrap()/expect()
+    E1321EnvVarUnwrap,
+    code = "E1321",
+    name = "Environment variable read with unwrap()",
+    suggestions = "Provide a default with unwrap_or/unwrap_or_else, or return an error that names the missing variable",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1321_env_var_unwrap",
+    /// Configuration for E1321: Environment variable unwrap checker
+    config = E1321Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = EnvVarUnwrapVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct EnvVarUnwrapVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1321EnvVarUnwrap,
+}
+
+impl<'a> Visit<'a> for EnvVarUnwrapVisitor<'a> {
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        let method = node.method.to_string();
+        if matches!(method.as_str(), "unwrap" | "expect") {
+            if let Some(var_name) = env_var_call(&node.receiver) {
+                let start = node.span().start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "Environment variable {} is read with {}(); the program panics if it is unset or not valid UTF-8.",
+                            var_name, method
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// If `expr` is `env::var(..)` or `env::var_os(..)`, return a display name for the variable
+fn env_var_call(expr: &syn::Expr) -> Option<String> {
+    let syn::Expr::Call(call) = expr else {
+        return None;
+    };
+    let syn::Expr::Path(func) = &*call.func else {
+        return None;
+    };
+
+    let segments: Vec<String> = func.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    let is_env_var = match segments.as_slice() {
+        [.., env, var] => env == "env" && (var == "var" || var == "var_os"),
+        _ => false,
+    };
+    if !is_env_var {
+        return None;
+    }
+
+    match call.args.first() {
+        Some(syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(name), .. })) => Some(format!("'{}'", name.value())),
+        _ => Some("(dynamic name)".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1321EnvVarUnwrap::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_env_var_unwrap() {
+        let code = r#"
+            fn port() -> String {
+                std::env::var("PORT").unwrap()
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1321");
+        assert!(violations[0].message.contains("'PORT'"));
+    }
+
+    #[test]
+    fn test_detects_var_os_expect() {
+        let code = r#"
+            fn home() -> std::ffi::OsString {
+                env::var_os("HOME").expect("HOME must be set")
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("expect()"));
+    }
+
+    #[test]
+    fn test_default_not_flagged() {
+        let code = r#"
+            fn port() -> String {
+                std::env::var("PORT").unwrap_or_else(|_| "8080".to_string())
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_question_mark_not_flagged() {
+        let code = r#"
+            fn port() -> Result<String, std::env::VarError> {
+                let port = env::var("PORT")?;
+                Ok(port)
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_other_var_function_not_flagged() {
+        let code = r#"
+            fn value(store: Store) -> i32 {
+                config::var("PORT").unwrap()
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+//! E1322: Returning a lock guard from a function
+//!
+//! Detects functions whose return type contains a `MutexGuard`,
+//! `RwLockReadGuard` or `RwLockWriteGuard`. Returning the guard hands the
+//! critical section to every caller, who decides how long the lock is held;
+//! a caller that takes a second lock (or calls back into the same type) while
+//! holding it can deadlock.
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1322: Returning a lock guard from a function
+    E1322ReturnedGuard,
+    code = "E1322",
+    name = "Lock guard returned from function",
+    suggestions = "Expose a closure-based API instead, e.g. `fn with_lock<R>(&self, f: impl FnOnce(&mut T) -> R) -> R`, so the lock is released before returning",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1322_returned_guard",
+    /// Configuration for E1322: Returned lock guard checker
+    config = E1322Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Function names allowed to return guards (lock wrapper types)
+        allowed_functions: Vec<String> = vec![
+            "lock".to_string(),
+            "try_lock".to_string(),
+            "read".to_string(),
+            "write".to_string(),
+        ],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = ReturnedGuardVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+/// Guard types that keep a lock held while alive
+const GUARD_TYPES: &[&str] = &[
+    "MutexGuard",
+    "RwLockReadGuard",
+    "RwLockWriteGuard",
+    "MappedMutexGuard",
+    "MappedRwLockReadGuard",
+    "MappedRwLockWriteGuard",
+];
+
+struct ReturnedGuardVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1322ReturnedGuard,
+}
+
+impl<'a> Visit<'a> for ReturnedGuardVisitor<'a> {
+    fn visit_signature(&mut self, node: &'a syn::Signature) {
+        if let syn::ReturnType::Type(_, ty) = &node.output {
+            let fn_name = node.ident.to_string();
+            if !self.checker.config.allowed_functions.contains(&fn_name) {
+                if let Some(guard) = find_guard_type(ty) {
+                    let start = node.ident.span().start();
+                    self.violations.push(
+                        Violation::new(
+                            self.checker.code(),
+                            self.checker.name(),
+                            self.checker.severity().into(),
+                            format!(
+                                "Function '{}' returns a {}, leaving the lock held for as long as the caller keeps it. Prefer a closure-based with_lock API.",
+                                fn_name, guard
+                            ),
+                            self.file_path,
+                            start.line,
+                            start.column + 1,
+                        )
+                        .with_suggestion(self.checker.suggestions()),
+                    );
+                }
+            }
+        }
+
+        syn::visit::visit_signature(self, node);
+    }
+}
+
+/// Find a lock guard type anywhere in `ty`, including inside `Result`/`Option`/tuples
+fn find_guard_type(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(type_path) => {
+            let last = type_path.path.segments.last()?;
+            let name = last.ident.to_string();
+            if GUARD_TYPES.contains(&name.as_str()) {
+                return Some(name);
+            }
+            match &last.arguments {
+                syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                    syn::GenericArgument::Type(inner) => find_guard_type(inner),
+                    _ => None,
+                }),
+                _ => None,
+            }
+        }
+        syn::Type::Tuple(tuple) => tuple.elems.iter().find_map(find_guard_type),
+        syn::Type::Paren(paren) => find_guard_type(&paren.elem),
+        syn::Type::Group(group) => find_guard_type(&group.elem),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1322ReturnedGuard::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_mutex_guard_method() {
+        let code = r#"
+            impl Cache {
+                fn entries(&self) -> MutexGuard<'_, Vec<String>> {
+                    self.entries.lock().unwrap()
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1322");
+        assert!(violations[0].message.contains("MutexGuard"));
+        assert!(violations[0].message.contains("with_lock"));
+    }
+
+    #[test]
+    fn test_detects_guard_inside_result() {
+        let code = r#"
+            fn config(state: &State) -> Result<std::sync::RwLockReadGuard<'_, Config>, Error> {
+                state.config.read().map_err(|_| Error)
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("RwLockReadGuard"));
+    }
+
+    #[test]
+    fn test_value_returned_under_lock_not_flagged() {
+        let code = r#"
+            impl Cache {
+                fn len(&self) -> usize {
+                    let guard = self.entries.lock().unwrap();
+                    guard.len()
+                }
+
+                fn with_entries<R>(&self, f: impl FnOnce(&mut Vec<String>) -> R) -> R {
+                    let mut guard = self.entries.lock().unwrap();
+                    f(&mut guard)
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_lock_wrapper_method_allowed() {
+        let code = r#"
+            impl<T> Lock<T> {
+                fn lock(&self) -> MutexGuard<'_, T> {
+                    self.inner.lock().unwrap_or_else(|e| e.into_inner())
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,232 @@
+//! E1323: main() returning () with panic-prone error handling
+//!
+//! Detects `fn main()` without a `Result` return type whose body handles errors
+//! with several `unwrap()`/`expect()` calls (or `?`, which does not compile in a
+//! unit `main`). Returning `Result<(), Box<dyn Error>>` from `main` lets every
+//! one of those sites use `?` and still report the error on exit.
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1323: main() returning () with panic-prone error handling
+    E1323QuestionMarkInUnitMain,
+    code = "E1323",
+    name = "main() returning () with unwrap/expect calls",
+    suggestions = "Declare `fn main() -> Result<(), Box<dyn std::error::Error>>` and replace unwrap()/expect() with `?`",
+    target_items = [Function],
+    config_entry_name = "e1323_question_mark_in_unit_main",
+    /// Configuration for E1323: unit main checker
+    config = E1323Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Number of unwrap()/expect()/? sites tolerated in a unit main before it is reported
+        max_panicking_calls: usize = 1,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = UnitMainVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct UnitMainVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1323QuestionMarkInUnitMain,
+}
+
+impl<'a> Visit<'a> for UnitMainVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        if node.sig.ident == "main" && returns_unit(&node.sig.output) {
+            let mut counter = PanickingCallCounter::default();
+            counter.visit_block(&node.block);
+            let total = counter.unwraps + counter.question_marks;
+
+            if total > self.checker.config.max_panicking_calls {
+                let mut parts = Vec::new();
+                if counter.unwraps > 0 {
+                    parts.push(format!("{} unwrap()/expect()", counter.unwraps));
+                }
+                if counter.question_marks > 0 {
+                    parts.push(format!("{} `?` (not allowed in a unit main)", counter.question_marks));
+                }
+                let start = node.sig.ident.span().start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "fn main() returns () but handles errors with {} (max {}); each failure panics instead of returning an error.",
+                            parts.join(" and "),
+                            self.checker.config.max_panicking_calls
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+
+        syn::visit::visit_item_fn(self, node);
+    }
+}
+
+/// Whether the return type is omitted or written as `()`
+fn returns_unit(output: &syn::ReturnType) -> bool {
+    match output {
+        syn::ReturnType::Default => true,
+        syn::ReturnType::Type(_, ty) => matches!(&**ty, syn::Type::Tuple(tuple) if tuple.elems.is_empty()),
+    }
+}
+
+/// Counts unwrap()/expect() calls and `?` operators that belong to the enclosing function
+#[derive(Default)]
+struct PanickingCallCounter {
+    unwraps: usize,
+    question_marks: usize,
+}
+
+impl<'ast> Visit<'ast> for PanickingCallCounter {
+    fn visit_expr_method_call(&mut self, node: &'ast syn::ExprMethodCall) {
+        if node.method == "unwrap" || node.method == "expect" {
+            self.unwraps += 1;
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_try(&mut self, node: &'ast syn::ExprTry) {
+        self.question_marks += 1;
+        syn::visit::visit_expr_try(self, node);
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast syn::ExprClosure) {
+        // `?` inside a closure returns from the closure, not from main
+        let question_marks = self.question_marks;
+        syn::visit::visit_expr_closure(self, node);
+        self.question_marks = question_marks;
+    }
+
+    fn visit_expr_async(&mut self, node: &'ast syn::ExprAsync) {
+        let question_marks = self.question_marks;
+        syn::visit::visit_expr_async(self, node);
+        self.question_marks = question_marks;
+    }
+
+    fn visit_item(&mut self, _node: &'ast syn::Item) {
+        // Nested items are separate functions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1323QuestionMarkInUnitMain::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "main.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_unit_main_with_unwraps() {
+        let code = r#"
+            fn main() {
+                let config = std::fs::read_to_string("app.toml").unwrap();
+                let port: u16 = config.trim().parse().expect("port");
+                serve(port);
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1323");
+        assert!(violations[0].message.contains("2 unwrap()/expect()"));
+        assert_eq!(violations[0].line, 2);
+    }
+
+    #[test]
+    fn test_counts_question_marks_and_explicit_unit() {
+        let code = r#"
+            async fn main() -> () {
+                let data = load().await?;
+                save(data).unwrap();
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("1 unwrap()/expect() and 1 `?`"));
+    }
+
+    #[test]
+    fn test_result_main_not_flagged() {
+        let code = r#"
+            fn main() -> Result<(), Box<dyn std::error::Error>> {
+                let config = std::fs::read_to_string("app.toml")?;
+                let port: u16 = config.trim().parse()?;
+                serve(port).unwrap();
+                Ok(())
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_single_unwrap_and_other_functions_not_flagged() {
+        let code = r#"
+            fn main() {
+                run().unwrap();
+            }
+
+            fn helper() {
+                a().unwrap();
+                b().unwrap();
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_closures_and_nested_items() {
+        let code = r#"
+            fn main() {
+                let parse = |s: &str| -> Result<u32, Error> { Ok(s.parse::<u32>()?) };
+                fn nested() { a().unwrap(); b().unwrap(); }
+                run(parse).unwrap();
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_configurable_threshold() {
+        let code = r#"
+            fn main() {
+                run().unwrap();
+            }
+        "#;
+        let checker = E1323QuestionMarkInUnitMain {
+            config: E1323Config {
+                max_panicking_calls: 0,
+                ..E1323Config::default()
+            },
+        };
+        let syntax = syn::parse_file(code).unwrap();
+        assert_eq!(checker.check_item(&syntax.items[0], "main.rs").unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,201 @@
+//! E1324: Type-erased variant in a typed error enum
+//!
+//! Detects error enums (named `*Error` or deriving `Error`) with a variant that
+//! wraps `Box<dyn Error>`, `anyhow::Error` or another type-erased error. The
+//! catch-all variant lets any failure through without a type, so callers can
+//! no longer match on what went wrong and the enum stops documenting the
+//! failure modes of the API.
+
+use crate::{define_checker, violation::Violation};
+
+define_checker! {
+    /// Checker for E1324: Type-erased variant in a typed error enum
+    E1324BoxDynInErrorEnum,
+    code = "E1324",
+    name = "Type-erased variant in typed error enum",
+    suggestions = "Replace the catch-all variant with specific variants, e.g. `Io(#[from] std::io::Error)`, one per underlying error type",
+    target_items = [Enum],
+    config_entry_name = "e1324_box_dyn_in_error_enum",
+    /// Configuration for E1324: Type-erased error variant checker
+    config = E1324Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Type paths treated as type-erased errors in addition to `dyn Error`
+        erased_types: Vec<String> = vec![
+            "anyhow::Error".to_string(),
+            "eyre::Report".to_string(),
+        ],
+    },
+    check_item(self, item, file_path) {
+        let mut violations = Vec::new();
+
+        let syn::Item::Enum(item_enum) = item else {
+            return Ok(violations);
+        };
+        let is_error_enum = item_enum.ident.to_string().ends_with("Error") || derives_error(&item_enum.attrs);
+        if !is_error_enum {
+            return Ok(violations);
+        }
+
+        for variant in &item_enum.variants {
+            let Some(erased) = variant.fields.iter().find_map(|field| self.erased_error(&field.ty)) else {
+                continue;
+            };
+            let start = variant.ident.span().start();
+            violations.push(
+                Violation::new(
+                    self.code(),
+                    self.name(),
+                    self.severity().into(),
+                    format!(
+                        "Variant '{}::{}' wraps {}, which erases the error type inside an otherwise typed error enum.",
+                        item_enum.ident, variant.ident, erased
+                    ),
+                    file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_suggestion(self.suggestions()),
+            );
+        }
+
+        Ok(violations)
+    }
+}
+
+impl E1324BoxDynInErrorEnum {
+    /// Find a `dyn Error` trait object or a configured erased type anywhere inside `ty`
+    fn erased_error(&self, ty: &syn::Type) -> Option<String> {
+        match ty {
+            syn::Type::TraitObject(object) if is_dyn_error(object) => Some("dyn Error".to_string()),
+            syn::Type::Paren(paren) => self.erased_error(&paren.elem),
+            syn::Type::Reference(reference) => self.erased_error(&reference.elem),
+            syn::Type::Path(type_path) => {
+                let path = type_path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|s| s.ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join("::");
+                if self.config.erased_types.contains(&path) {
+                    return Some(path);
+                }
+                let last = type_path.path.segments.last()?;
+                let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+                    return None;
+                };
+                let inner = args.args.iter().find_map(|arg| match arg {
+                    syn::GenericArgument::Type(inner) => self.erased_error(inner),
+                    _ => None,
+                })?;
+                Some(format!("{}<{}>", last.ident, inner))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Whether a trait object has an `Error` bound (`dyn Error`, `dyn std::error::Error + Send + Sync`)
+fn is_dyn_error(object: &syn::TypeTraitObject) -> bool {
+    object.bounds.iter().any(|bound| {
+        matches!(bound, syn::TypeParamBound::Trait(trait_bound)
+            if trait_bound.path.segments.last().is_some_and(|s| s.ident == "Error"))
+    })
+}
+
+/// Whether the item derives `Error` (e.g. `thiserror::Error`)
+fn derives_error(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("derive")).any(|attr| {
+        let mut found = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.segments.last().is_some_and(|s| s.ident == "Error") {
+                found = true;
+            }
+            Ok(())
+        });
+        found
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::Checker;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1324BoxDynInErrorEnum::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_box_dyn_error_variant() {
+        let code = r#"
+            pub enum ConfigError {
+                Missing(String),
+                Io(std::io::Error),
+                Other(Box<dyn std::error::Error + Send + Sync>),
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1324");
+        assert!(violations[0].message.contains("'ConfigError::Other' wraps Box<dyn Error>"));
+        assert_eq!(violations[0].line, 5);
+    }
+
+    #[test]
+    fn test_detects_anyhow_in_thiserror_enum() {
+        let code = r#"
+            #[derive(Debug, thiserror::Error)]
+            pub enum Failure {
+                #[error("parse failed")]
+                Parse(#[from] std::num::ParseIntError),
+                #[error(transparent)]
+                Unexpected { source: anyhow::Error },
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'Failure::Unexpected' wraps anyhow::Error"));
+    }
+
+    #[test]
+    fn test_nested_erased_type() {
+        let code = r#"
+            enum TaskError {
+                Join(Option<Arc<dyn Error>>),
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("Option<Arc<dyn Error>>"));
+    }
+
+    #[test]
+    fn test_typed_variants_and_non_error_enums_not_flagged() {
+        let code = r#"
+            #[derive(Debug, thiserror::Error)]
+            pub enum LoadError {
+                #[error("io")]
+                Io(#[from] std::io::Error),
+                #[error("json")]
+                Json(#[from] serde_json::Error),
+            }
+
+            pub enum Handler {
+                Callback(Box<dyn Error>),
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
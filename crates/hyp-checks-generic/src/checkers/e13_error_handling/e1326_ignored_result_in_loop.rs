@@ -0,0 +1,282 @@
+//! E1326: Result ignored inside a loop
+//!
+//! Detects `let _ = fallible();` or a bare `fallible();` statement inside a
+//! `for`/`while`/`loop` body (or a `for_each` closure). E1303 covers `let _ =`
+//! in general; inside a loop the same pattern drops one error per iteration,
+//! so a batch write or a channel send can fail for every element without a
+//! trace.
+//!
+//! Example:
+//! ```text
+//! // Bad: a closed channel or a full disk is skipped silently, item by item
+//! for item in items {
+//!     let _ = tx.send(item);
+//! }
+//!
+//! // Good: stop on the first error, or collect failures and report them
+//! for item in items {
+//!     tx.send(item)?;
+//! }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1326: Result ignored inside a loop
+    E1326IgnoredResultInLoop,
+    code = "E1326",
+    name = "Result ignored inside a loop",
+    suggestions = "Propagate the error with `?`, handle it with `match`/`if let Err`, or collect the failures and report them after the loop",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1326_ignored_result_in_loop",
+    /// Configuration for E1326: Result ignored in loop checker
+    config = E1326Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::High,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Method or function names treated as returning `Result`; a path such as
+        /// `fs::copy` only matches function calls whose path ends with it
+        fallible_calls: Vec<String> = [
+            "send", "try_send", "send_to", "recv", "write", "write_all", "write_fmt", "flush",
+            "sync_all", "sync_data", "set_len", "connect", "shutdown", "remove_file", "remove_dir",
+            "remove_dir_all", "create_dir", "create_dir_all", "fs::copy", "io::copy", "rename",
+            "hard_link", "set_permissions",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect(),
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = LoopResultVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            loop_depth: 0,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct LoopResultVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1326IgnoredResultInLoop,
+    /// Number of loop bodies enclosing the current node
+    loop_depth: usize,
+}
+
+impl<'a> LoopResultVisitor<'a> {
+    fn in_loop_body(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.loop_depth += 1;
+        visit(self);
+        self.loop_depth -= 1;
+    }
+
+    /// The name of the fallible call `expr` ends in, if any (`.await` is looked through)
+    fn fallible_call(&self, expr: &syn::Expr) -> Option<String> {
+        let segments: Vec<String> = match expr {
+            syn::Expr::MethodCall(call) => vec![call.method.to_string()],
+            syn::Expr::Call(call) => match &*call.func {
+                syn::Expr::Path(path) => path.path.segments.iter().map(|s| s.ident.to_string()).collect(),
+                _ => return None,
+            },
+            syn::Expr::Await(await_expr) => return self.fallible_call(&await_expr.base),
+            syn::Expr::Paren(paren) => return self.fallible_call(&paren.expr),
+            _ => return None,
+        };
+        let path = segments.join("::");
+        let matches = |entry: &String| path == *entry || path.ends_with(&format!("::{}", entry));
+        let name = segments.last()?.clone();
+        self.checker.config.fallible_calls.iter().any(matches).then_some(name)
+    }
+
+    fn report(&mut self, call: &str, how: &str, span: proc_macro2::Span) {
+        let start = span.start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "Result of '{}()' is {} inside a loop; a failure is silently skipped on every iteration.",
+                    call, how
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for LoopResultVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        // A nested function is not part of the enclosing loop body
+        let depth = std::mem::take(&mut self.loop_depth);
+        syn::visit::visit_item_fn(self, node);
+        self.loop_depth = depth;
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'a syn::ExprForLoop) {
+        self.visit_expr(&node.expr);
+        self.in_loop_body(|v| v.visit_block(&node.body));
+    }
+
+    fn visit_expr_while(&mut self, node: &'a syn::ExprWhile) {
+        self.visit_expr(&node.cond);
+        self.in_loop_body(|v| v.visit_block(&node.body));
+    }
+
+    fn visit_expr_loop(&mut self, node: &'a syn::ExprLoop) {
+        self.in_loop_body(|v| v.visit_block(&node.body));
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        // `iter.for_each(|x| ...)` runs its closure once per element, like a loop body
+        if node.method == "for_each" {
+            self.visit_expr(&node.receiver);
+            self.in_loop_body(|v| {
+                for arg in &node.args {
+                    v.visit_expr(arg);
+                }
+            });
+            return;
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        if self.loop_depth > 0 && matches!(node.pat, syn::Pat::Wild(_)) {
+            if let Some(call) = node.init.as_ref().and_then(|init| self.fallible_call(&init.expr)) {
+                self.report(&call, "discarded with `let _ =`", node.span());
+            }
+        }
+        syn::visit::visit_local(self, node);
+    }
+
+    fn visit_stmt(&mut self, node: &'a syn::Stmt) {
+        if self.loop_depth > 0 {
+            if let syn::Stmt::Expr(expr, Some(_)) = node {
+                if let Some(call) = self.fallible_call(expr) {
+                    self.report(&call, "dropped", expr.span());
+                }
+            }
+        }
+        syn::visit::visit_stmt(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1326IgnoredResultInLoop::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_let_underscore_send_in_for_loop() {
+        let code = r#"
+            fn forward(tx: Sender<u32>, items: Vec<u32>) {
+                for item in items {
+                    let _ = tx.send(item);
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1326");
+        assert!(violations[0].message.contains("'send()' is discarded with `let _ =`"));
+        assert_eq!(violations[0].line, 4);
+    }
+
+    #[test]
+    fn test_detects_dropped_calls_in_while_loop_and_for_each() {
+        let code = r#"
+            impl Sink {
+                async fn drain(&mut self) {
+                    while let Some(line) = self.queue.pop() {
+                        self.out.write_all(line.as_bytes()).await;
+                    }
+                }
+
+                fn cleanup(paths: &[PathBuf]) {
+                    paths.iter().for_each(|p| {
+                        let _ = std::fs::remove_file(p);
+                    });
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("'write_all()' is dropped"));
+        assert!(violations[1].message.contains("'remove_file()'"));
+    }
+
+    #[test]
+    fn test_handled_results_and_code_outside_loops_not_flagged() {
+        let code = r#"
+            fn write_all(out: &mut File, lines: &[String]) -> io::Result<Vec<io::Error>> {
+                let _ = std::fs::remove_file("stale.lock");
+                let mut failures = Vec::new();
+                for line in lines {
+                    if let Err(e) = out.write_all(line.as_bytes()) {
+                        failures.push(e);
+                    }
+                    out.flush()?;
+                    let _ = line.len();
+                }
+                Ok(failures)
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_nested_fn_inside_loop_not_flagged() {
+        let code = r#"
+            fn outer(items: &[u32]) {
+                for _ in items {
+                    fn helper(tx: &Sender<u32>) -> Result<(), SendError<u32>> {
+                        tx.send(1)
+                    }
+                }
+                fn other(tx: &Sender<u32>) {
+                    let _ = tx.send(2);
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_copy_matched_by_path() {
+        let code = r#"
+            fn backup(files: &[PathBuf], buf: &mut [u8]) {
+                for f in files {
+                    let _ = std::fs::copy(f, "backup");
+                    let _ = io::copy(&mut reader, &mut writer);
+                    unsafe { std::ptr::copy(src, dst, 1); }
+                    let _ = buf.copy_from_slice(&[0]);
+                    let _ = cursor.copy(1);
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!((violations[0].line, violations[1].line), (4, 5));
+    }
+}
@@ -10,6 +10,15 @@ pub mod e1307_string_error_type;
 pub mod e1308_not_using_question_mark;
 pub mod e1309_panic_in_drop;
 pub mod e1310_error_context_loss;
+pub mod e1311_box_dyn_error_in_public_api;
+pub mod e1312_option_question_mark;
+pub mod e1319_empty_expect_message;
+pub mod e1320_error_type_missing_error_impl;
+pub mod e1321_env_var_unwrap;
+pub mod e1322_returned_guard;
+pub mod e1323_question_mark_in_unit_main;
+pub mod e1324_box_dyn_in_error_enum;
+pub mod e1326_ignored_result_in_loop;
 pub mod registry;
 
 pub use e1301_unhandled_result::{E1301Config, E1301UnhandledResult};
@@ -22,3 +31,12 @@ pub use e1307_string_error_type::{E1307Config, E1307StringErrorType};
 pub use e1308_not_using_question_mark::{E1308Config, E1308NotUsingQuestionMark};
 pub use e1309_panic_in_drop::{E1309Config, E1309PanicInDrop};
 pub use e1310_error_context_loss::{E1310Config, E1310ErrorContextLoss};
+pub use e1311_box_dyn_error_in_public_api::{E1311Config, E1311BoxDynErrorInPublicApi};
+pub use e1312_option_question_mark::{E1312Config, E1312OptionQuestionMark};
+pub use e1321_env_var_unwrap::{E1321Config, E1321EnvVarUnwrap};
+pub use e1322_returned_guard::{E1322Config, E1322ReturnedGuard};
+pub use e1323_question_mark_in_unit_main::{E1323Config, E1323QuestionMarkInUnitMain};
+pub use e1324_box_dyn_in_error_enum::{E1324Config, E1324BoxDynInErrorEnum};
+pub use e1326_ignored_result_in_loop::{E1326Config, E1326IgnoredResultInLoop};
+pub use e1319_empty_expect_message::{E1319Config, E1319EmptyExpectMessage};
+pub use e1320_error_type_missing_error_impl::{E1320Config, E1320ErrorTypeMissingErrorImpl};
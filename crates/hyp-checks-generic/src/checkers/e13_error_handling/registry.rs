@@ -8,6 +8,15 @@ use crate::{
         E1305NonExhaustiveMatch, E1306Config, E1306SwallowedErrors, E1307Config,
         E1307StringErrorType, E1308Config, E1308NotUsingQuestionMark, E1309Config,
         E1309PanicInDrop, E1310Config, E1310ErrorContextLoss,
+        E1321Config, E1321EnvVarUnwrap,
+        E1322Config, E1322ReturnedGuard,
+        E1323Config, E1323QuestionMarkInUnitMain,
+        E1324Config, E1324BoxDynInErrorEnum,
+        E1326Config, E1326IgnoredResultInLoop,
+        E1311Config, E1311BoxDynErrorInPublicApi,
+        E1312Config, E1312OptionQuestionMark,
+        E1319Config, E1319EmptyExpectMessage,
+        E1320Config, E1320ErrorTypeMissingErrorImpl,
     },
     register_checker,
     registry::CheckerRegistration,
@@ -26,5 +35,14 @@ pub fn e13_registrations() -> Vec<CheckerRegistration> {
         register_checker!(E1308NotUsingQuestionMark, E1308Config),
         register_checker!(E1309PanicInDrop, E1309Config),
         register_checker!(E1310ErrorContextLoss, E1310Config),
+        register_checker!(E1321EnvVarUnwrap, E1321Config),
+        register_checker!(E1322ReturnedGuard, E1322Config),
+        register_checker!(E1323QuestionMarkInUnitMain, E1323Config),
+        register_checker!(E1324BoxDynInErrorEnum, E1324Config),
+        register_checker!(E1326IgnoredResultInLoop, E1326Config),
+        register_checker!(E1311BoxDynErrorInPublicApi, E1311Config),
+        register_checker!(E1312OptionQuestionMark, E1312Config),
+        register_checker!(E1319EmptyExpectMessage, E1319Config),
+        register_checker!(E1320ErrorTypeMissingErrorImpl, E1320Config),
     ]
 }
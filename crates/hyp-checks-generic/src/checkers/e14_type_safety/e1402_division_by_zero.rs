@@ -31,6 +31,7 @@ define_checker! {
             file_path,
             checker: self,
             protected_divisors: Vec::new(),
+            bindings: Vec::new(),
         };
         visitor.visit_item(item);
         Ok(visitor.violations)
@@ -44,6 +45,9 @@ struct DivisionVisitor<'a> {
     checker: &'a E1402DivisionByZero,
     /// Track if we're inside a protected context (e.g., inside else block after checking for zero)
     protected_divisors: Vec<String>,
+    /// Parameters and locals in scope, and whether each was declared with a `NonZero*`
+    /// type (e.g. `divisor: NonZeroU32`); the last entry for a name is the live one
+    bindings: Vec<(String, bool)>,
 }
 
 impl<'a> DivisionVisitor<'a> {
@@ -58,6 +62,7 @@ impl<'a> DivisionVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 
@@ -69,6 +74,50 @@ impl<'a> DivisionVisitor<'a> {
             divisor_str.contains(&p_normalized)
         })
     }
+
+    /// Check if a divisor is `x.get()` (or `x` itself) where `x` has a `NonZero*` type
+    fn is_non_zero_binding(&self, divisor_expr: &syn::Expr) -> bool {
+        let binding = match divisor_expr {
+            syn::Expr::MethodCall(call) if call.method == "get" && call.args.is_empty() => &*call.receiver,
+            syn::Expr::Paren(paren) => return self.is_non_zero_binding(&paren.expr),
+            syn::Expr::Group(group) => return self.is_non_zero_binding(&group.expr),
+            other => other,
+        };
+        match binding {
+            syn::Expr::Path(path) => path.path.get_ident().is_some_and(|ident| {
+                self.bindings
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| ident == name)
+                    .is_some_and(|(_, non_zero)| *non_zero)
+            }),
+            _ => false,
+        }
+    }
+
+    /// Record the identifier bound by `pat` (`name` or `name: ty`), which shadows earlier bindings
+    fn track_binding(&mut self, pat: &syn::Pat) {
+        match pat {
+            syn::Pat::Type(pat_type) => self.track_typed_binding(pat_type),
+            syn::Pat::Ident(pat_ident) => self.bindings.push((pat_ident.ident.to_string(), false)),
+            _ => {}
+        }
+    }
+
+    /// Record `name` of `name: ty`, noting whether `ty` is a `NonZero*` type
+    fn track_typed_binding(&mut self, pat_type: &syn::PatType) {
+        if let syn::Pat::Ident(pat_ident) = &*pat_type.pat {
+            self.bindings
+                .push((pat_ident.ident.to_string(), is_non_zero_type(&pat_type.ty)));
+        }
+    }
+
+    /// Visit a function or closure with its own parameter scope
+    fn scoped(&mut self, visit: impl FnOnce(&mut Self)) {
+        let original_len = self.bindings.len();
+        visit(self);
+        self.bindings.truncate(original_len);
+    }
 }
 
 impl<'a> Visit<'a> for DivisionVisitor<'a> {
@@ -76,7 +125,10 @@ impl<'a> Visit<'a> for DivisionVisitor<'a> {
         if matches!(node.op, BinOp::Div(_) | BinOp::Rem(_)) {
             // Check if the divisor is a non-zero literal (safe case)
             // or if it's protected by context
-            if !is_non_zero_literal(&node.right) && !self.is_protected(&node.right) {
+            if !is_non_zero_literal(&node.right)
+                && !self.is_protected(&node.right)
+                && !self.is_non_zero_binding(&node.right)
+            {
                 self.violations.push(self.create_violation(node.span()));
             }
         }
@@ -85,6 +137,41 @@ impl<'a> Visit<'a> for DivisionVisitor<'a> {
         syn::visit::visit_expr_binary(self, node);
     }
 
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.scoped(|visitor| syn::visit::visit_item_fn(visitor, node));
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.scoped(|visitor| syn::visit::visit_impl_item_fn(visitor, node));
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'a syn::TraitItemFn) {
+        self.scoped(|visitor| syn::visit::visit_trait_item_fn(visitor, node));
+    }
+
+    fn visit_expr_closure(&mut self, node: &'a syn::ExprClosure) {
+        self.scoped(|visitor| {
+            for input in &node.inputs {
+                visitor.track_binding(input);
+            }
+            syn::visit::visit_expr_closure(visitor, node);
+        });
+    }
+
+    fn visit_fn_arg(&mut self, node: &'a syn::FnArg) {
+        if let syn::FnArg::Typed(pat_type) = node {
+            self.track_typed_binding(pat_type);
+        }
+        syn::visit::visit_fn_arg(self, node);
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        // Visit the initializer first: in `let d = x / d;` the divisor is the previous `d`
+        syn::visit::visit_local(self, node);
+        // let divisor: NonZeroU32 = ...; a plain `let divisor = ...` shadows it
+        self.track_binding(&node.pat);
+    }
+
     fn visit_expr_if(&mut self, node: &'a syn::ExprIf) {
         // Check if the condition protects against zero/empty
         let (protected_in_else, protected_in_then) = extract_protected_divisors(&node.cond);
@@ -136,6 +223,23 @@ fn is_non_zero_literal(expr: &syn::Expr) -> bool {
     }
 }
 
+/// Check if a type is one of the `NonZero*` integer types (or the generic `NonZero<T>`)
+fn is_non_zero_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path.path.segments.last().is_some_and(|segment| {
+            let name = segment.ident.to_string();
+            name == "NonZero"
+                || name
+                    .strip_prefix("NonZero")
+                    .is_some_and(|rest| matches!(rest.chars().next(), Some('U' | 'I')))
+        }),
+        syn::Type::Reference(reference) => is_non_zero_type(&reference.elem),
+        syn::Type::Paren(paren) => is_non_zero_type(&paren.elem),
+        syn::Type::Group(group) => is_non_zero_type(&group.elem),
+        _ => false,
+    }
+}
+
 /// Convert expression to a string for pattern matching
 fn expr_to_string(expr: &syn::Expr) -> String {
     quote::quote!(#expr).to_string()
@@ -371,4 +475,112 @@ mod tests {
         // Should flag - division in then branch is NOT protected
         assert_eq!(violations.len(), 1, "Division in then branch after is_empty() should be flagged");
     }
+
+    #[test]
+    fn test_allows_non_zero_parameter_divisor() {
+        let code = r#"
+            use std::num::NonZeroU32;
+
+            fn example(x: u32, divisor: NonZeroU32) -> u32 {
+                x / divisor.get() + x % divisor.get()
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1402DivisionByZero::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 0, "NonZeroU32 parameter divisor should not be flagged");
+    }
+
+    #[test]
+    fn test_allows_non_zero_local_divisor() {
+        let code = r#"
+            fn example(x: usize, n: usize) -> usize {
+                let chunks: std::num::NonZeroUsize = std::num::NonZeroUsize::new(n).unwrap_or(std::num::NonZeroUsize::MIN);
+                x / chunks.get()
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1402DivisionByZero::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 0, "NonZeroUsize local divisor should not be flagged");
+    }
+
+    #[test]
+    fn test_non_zero_parameter_does_not_leak_into_next_method() {
+        let code = r#"
+            use std::num::NonZeroU32;
+
+            impl S {
+                fn a(&self, x: u32, d: NonZeroU32) -> u32 {
+                    x / d.get()
+                }
+
+                fn b(&self, x: u32, d: u32) -> u32 {
+                    x / d
+                }
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1402DivisionByZero::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 1, "Only the plain u32 divisor in b() should be flagged");
+        assert_eq!(violations[0].line, 10);
+    }
+
+    #[test]
+    fn test_detects_non_zero_name_rebound_to_plain_value() {
+        let code = r#"
+            fn example(x: u32, d: std::num::NonZeroU32, raw: u32) -> u32 {
+                let d = raw;
+                x / d
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1402DivisionByZero::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 1, "A plain let rebinding the name should be flagged");
+    }
+
+    #[test]
+    fn test_detects_get_on_plain_type() {
+        let code = r#"
+            fn example(x: u32, divisor: Cell<u32>, other: NonZeroU32) -> u32 {
+                x / divisor.get()
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1402DivisionByZero::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 1, ".get() on a non-NonZero binding should still be flagged");
+    }
 }
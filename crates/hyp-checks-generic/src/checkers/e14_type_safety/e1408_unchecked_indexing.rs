@@ -56,6 +56,7 @@ impl<'a> IndexingVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 
@@ -164,6 +165,79 @@ impl<'a> Visit<'a> for IndexingVisitor<'a> {
             }
         }
     }
+
+    fn visit_expr_for_loop(&mut self, node: &'a syn::ExprForLoop) {
+        // The range and pattern are evaluated outside the loop body's protection
+        self.visit_pat(&node.pat);
+        self.visit_expr(&node.expr);
+
+        // for i in 0..arr.len() { arr[i] } - the loop variable is bounded by the length
+        let original_len = self.protected_accesses.len();
+        if let Some(protection) = extract_loop_bound_protection(&node.pat, &node.expr) {
+            self.protected_accesses.push(protection);
+        }
+        self.visit_block(&node.body);
+        self.protected_accesses.truncate(original_len);
+    }
+}
+
+/// Extract (array, loop variable) protection from a `for` loop header
+/// Examples:
+/// - for i in 0..arr.len() -> arr[i] is safe in the body
+/// - for i in 0..=arr.len() - 1 -> arr[i] is safe in the body
+fn extract_loop_bound_protection(pat: &syn::Pat, iter: &syn::Expr) -> Option<(String, String)> {
+    let syn::Pat::Ident(pat_ident) = pat else {
+        return None;
+    };
+    // for mut i in .. { i += 1; arr[i] } - the body may move the index out of bounds
+    if pat_ident.mutability.is_some() || pat_ident.by_ref.is_some() {
+        return None;
+    }
+    let syn::Expr::Range(range) = strip_parens(iter) else {
+        return None;
+    };
+    let end = strip_parens(range.end.as_deref()?);
+
+    let len_receiver = match range.limits {
+        // 0..arr.len()
+        syn::RangeLimits::HalfOpen(_) => len_call_receiver(end)?,
+        // 0..=arr.len() - 1
+        syn::RangeLimits::Closed(_) => match end {
+            syn::Expr::Binary(bin) if matches!(bin.op, BinOp::Sub(_)) && is_int_literal(&bin.right, 1) => {
+                len_call_receiver(strip_parens(&bin.left))?
+            }
+            _ => return None,
+        },
+    };
+
+    Some((expr_to_string(len_receiver), pat_ident.ident.to_string()))
+}
+
+/// If `expr` is `<receiver>.len()`, return the receiver
+fn len_call_receiver(expr: &syn::Expr) -> Option<&syn::Expr> {
+    match expr {
+        syn::Expr::MethodCall(call) if call.method == "len" && call.args.is_empty() => Some(&call.receiver),
+        _ => None,
+    }
+}
+
+/// Check if an expression is the integer literal `value`
+fn is_int_literal(expr: &syn::Expr, value: usize) -> bool {
+    match strip_parens(expr) {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int_lit), .. }) => {
+            int_lit.base10_parse::<usize>().ok() == Some(value)
+        }
+        _ => false,
+    }
+}
+
+/// Remove surrounding parentheses/groups from an expression
+fn strip_parens(expr: &syn::Expr) -> &syn::Expr {
+    match expr {
+        syn::Expr::Paren(paren) => strip_parens(&paren.expr),
+        syn::Expr::Group(group) => strip_parens(&group.expr),
+        _ => expr,
+    }
 }
 
 /// Check if an index expression is a compile-time constant
@@ -610,4 +684,119 @@ mod tests {
         // Should not flag - compound condition with key_lc.len() >= 2 protects key_lc[1..]
         assert_eq!(violations.len(), 0, "Compound condition with proper length check should not be flagged");
     }
+
+    #[test]
+    fn test_protected_by_for_loop_over_len() {
+        let code = r#"
+            fn example(arr: &[i32]) -> i32 {
+                let mut sum = 0;
+                for i in 0..arr.len() {
+                    sum += arr[i];
+                }
+                sum
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1408UncheckedIndexing::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 0, "arr[i] bounded by for i in 0..arr.len() should not be flagged");
+    }
+
+    #[test]
+    fn test_protected_by_inclusive_for_loop() {
+        let code = r#"
+            fn example(arr: &[i32]) -> i32 {
+                let mut sum = 0;
+                for i in 0..=arr.len() - 1 {
+                    sum += arr[i];
+                }
+                sum
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1408UncheckedIndexing::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 0, "arr[i] bounded by for i in 0..=arr.len() - 1 should not be flagged");
+    }
+
+    #[test]
+    fn test_for_loop_bound_on_different_array() {
+        let code = r#"
+            fn example(arr: &[i32], other: &[i32]) -> i32 {
+                let mut sum = 0;
+                for i in 0..other.len() {
+                    sum += arr[i];
+                }
+                sum
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1408UncheckedIndexing::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 1, "arr[i] bounded by a different array's length should be flagged");
+    }
+
+    #[test]
+    fn test_for_loop_protection_ends_after_loop() {
+        let code = r#"
+            fn example(arr: &[i32], i: usize) -> i32 {
+                for i in 0..arr.len() {
+                    let _ = arr[i];
+                }
+                arr[i]
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1408UncheckedIndexing::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 1, "Protection must not leak past the loop body");
+    }
+
+    #[test]
+    fn test_mutable_loop_variable_is_not_protected() {
+        let code = r#"
+            fn example(arr: &[i32]) -> i32 {
+                let mut sum = 0;
+                for mut i in 0..arr.len() {
+                    i += 1;
+                    sum += arr[i];
+                }
+                sum
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1408UncheckedIndexing::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 1, "A mut loop variable may be changed before indexing");
+    }
 }
@@ -62,6 +62,7 @@ impl<'a> UnionProhibitVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 
@@ -63,6 +63,7 @@ impl<'a> SixtyFourOverflowVisitor<'a> {
             start.line,
             start.column + 1,
         )
+        .with_span(span.start(), span.end())
         .with_suggestion(self.checker.suggestions())
     }
 }
@@ -0,0 +1,159 @@
+//! E1414: Sign-changing `as` cast
+//!
+//! Detects `as` casts between integer types of the same width but different
+//! signedness (`i32 as u32`, `u64 as i64`, `usize as isize`). Such casts keep
+//! the bits and change their meaning: `-1i32 as u32` is `4294967295`.
+//!
+//! The source type is only known syntactically, so it is taken from integer
+//! literal suffixes, nested casts, and function parameters or `let` bindings
+//! with a primitive integer type annotation. Casts from other expressions are
+//! not reported.
+//!
+//! Example:
+//! ```text
+//! // Bad: a negative offset silently becomes a huge index
+//! fn index(offset: i64) -> u64 { offset as u64 }
+//!
+//! // Good: the sign problem is handled explicitly
+//! fn index(offset: i64) -> Option<u64> { u64::try_from(offset).ok() }
+//! ```
+
+use super::int_types::{integer_type, is_sign_change, IntScope};
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1414: Sign-changing `as` cast
+    E1414SignChangingCast,
+    code = "E1414",
+    name = "Sign-changing as cast",
+    suggestions = "Use try_into()/TryFrom to reject out-of-range values, or check the range (e.g. `x >= 0`) before converting",
+    target_items = [Function, Impl],
+    config_entry_name = "e1414_sign_changing_cast",
+    /// Configuration for E1414: Sign-changing cast checker
+    config = E1414Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = SignCastVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            ints: IntScope::default(),
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct SignCastVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1414SignChangingCast,
+    /// Integer type of parameters and annotated `let` bindings in the current fn
+    ints: IntScope,
+}
+
+impl<'a> Visit<'a> for SignCastVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        let outer = self.ints.enter_fn(&node.sig);
+        syn::visit::visit_item_fn(self, node);
+        self.ints.exit_fn(outer);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        let outer = self.ints.enter_fn(&node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+        self.ints.exit_fn(outer);
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        syn::visit::visit_local(self, node);
+        self.ints.bind_local(node);
+    }
+
+    fn visit_expr_cast(&mut self, node: &'a syn::ExprCast) {
+        let from = self.ints.expr_type(&node.expr);
+        let to = integer_type(&node.ty);
+        if let (Some(from), Some(to)) = (from, to) {
+            if is_sign_change(from, to) {
+                let span = node.span();
+                let start = span.start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "'{} as {}' keeps the bits but changes the sign interpretation; out-of-range values wrap silently.",
+                            from, to
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_span(span.start(), span.end())
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+
+        syn::visit::visit_expr_cast(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1414SignChangingCast::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_same_width_sign_change() {
+        let code = r#"
+            fn example(x: i32, len: usize) -> u32 {
+                let offset: u64 = 7;
+                let _ = offset as i64;
+                let _ = len as isize;
+                let _ = -1i8 as u8;
+                x as u32
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 4);
+        assert_eq!(violations[0].code, "E1414");
+        assert!(violations[0].message.contains("'u64 as i64'"));
+        assert!(violations[3].message.contains("'i32 as u32'"));
+        assert_eq!(violations[3].line, 7);
+    }
+
+    #[test]
+    fn test_widening_and_unknown_sources_pass() {
+        let code = r#"
+            impl Buffer {
+                fn example(&self, x: i32, y: u16) -> i64 {
+                    let _ = y as i32;
+                    let _ = self.len() as isize;
+                    let _ = 5 as u32;
+                    let _ = x as f64;
+                    x as i64
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,239 @@
+//! E1416: Shift that may overflow the type width
+//!
+//! Detects `<<`, `>>`, `<<=` and `>>=` whose shift amount is not a constant.
+//! Shifting by the bit width or more panics in debug builds and masks the
+//! amount in release builds, so `1u32 << 32` is `1`, not `0`.
+//!
+//! Shift amounts that are integer literals are only reported when the shifted
+//! value has a known type and the literal reaches its width. The type is taken
+//! from literal suffixes, casts, and function parameters or `let` bindings
+//! with a primitive integer type annotation. Constants (`SCREAMING_CASE`
+//! paths), amounts bounded by `& lit` or `% lit`, and sums or products of
+//! such bounded amounts are not reported.
+//!
+//! Example:
+//! ```text
+//! // Bad: `bits == 32` panics in debug and returns `value` in release
+//! fn mask(value: u32, bits: u32) -> u32 { value << bits }
+//!
+//! // Good: the out-of-range case is handled explicitly
+//! fn mask(value: u32, bits: u32) -> u32 { value.checked_shl(bits).unwrap_or(0) }
+//! ```
+
+use super::int_types::{type_width, IntScope};
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use quote::ToTokens;
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1416: Shift that may overflow the type width
+    E1416UncheckedShift,
+    code = "E1416",
+    name = "Shift that may overflow the type width",
+    suggestions = "Use checked_shl()/checked_shr() and handle None, or wrapping_shl()/wrapping_shr() when masking the amount is intended",
+    target_items = [Function, Impl],
+    config_entry_name = "e1416_unchecked_shift",
+    /// Configuration for E1416: Unchecked shift checker
+    config = E1416Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = ShiftVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            ints: IntScope::default(),
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct ShiftVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1416UncheckedShift,
+    /// Integer type of parameters and annotated `let` bindings in the current fn
+    ints: IntScope,
+}
+
+impl<'a> ShiftVisitor<'a> {
+    /// Message for a shift that should be reported, `None` if the amount is safe or unknown
+    fn problem(&self, node: &syn::ExprBinary, op: &str) -> Option<String> {
+        match literal_value(&node.right) {
+            Some(amount) => {
+                let ty = self.ints.expr_type(&node.left)?;
+                let width = type_width(ty)?;
+                (amount >= u128::from(width)).then(|| {
+                    format!(
+                        "Shifting a {} by {} bits ({} {}) reaches its {}-bit width: this panics in debug builds and masks the amount in release.",
+                        ty, amount, op, amount, width
+                    )
+                })
+            }
+            None if is_bounded_amount(&node.right) => None,
+            None => Some(format!(
+                "Shift amount '{}' in '{}' is not a constant; an amount at or above the bit width panics in debug builds and is masked in release.",
+                node.right.to_token_stream(),
+                op
+            )),
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ShiftVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        let outer = self.ints.enter_fn(&node.sig);
+        syn::visit::visit_item_fn(self, node);
+        self.ints.exit_fn(outer);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        let outer = self.ints.enter_fn(&node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+        self.ints.exit_fn(outer);
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        syn::visit::visit_local(self, node);
+        self.ints.bind_local(node);
+    }
+
+    fn visit_expr_binary(&mut self, node: &'a syn::ExprBinary) {
+        let op = match node.op {
+            syn::BinOp::Shl(_) => Some("<<"),
+            syn::BinOp::Shr(_) => Some(">>"),
+            syn::BinOp::ShlAssign(_) => Some("<<="),
+            syn::BinOp::ShrAssign(_) => Some(">>="),
+            _ => None,
+        };
+        if let Some(message) = op.and_then(|op| self.problem(node, op)) {
+            let span = node.span();
+            let start = span.start();
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    self.checker.severity().into(),
+                    message,
+                    self.file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_span(span.start(), span.end())
+                .with_suggestion(self.checker.suggestions()),
+            );
+        }
+
+        syn::visit::visit_expr_binary(self, node);
+    }
+}
+
+/// Value of an integer literal, looking through parentheses and casts
+fn literal_value(expr: &syn::Expr) -> Option<u128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit.base10_parse().ok(),
+        syn::Expr::Paren(paren) => literal_value(&paren.expr),
+        syn::Expr::Group(group) => literal_value(&group.expr),
+        syn::Expr::Cast(cast) => literal_value(&cast.expr),
+        _ => None,
+    }
+}
+
+/// Constants (`SHIFT`, `Self::BITS`), amounts masked with `& lit` / `% lit`,
+/// and sums or products of such amounts
+fn is_bounded_amount(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Path(path) => path.path.segments.last().is_some_and(|segment| {
+            let name = segment.ident.to_string();
+            name.chars().any(|c| c.is_ascii_uppercase()) && !name.chars().any(|c| c.is_ascii_lowercase())
+        }),
+        syn::Expr::Binary(binary) => {
+            let bounded = |expr: &syn::Expr| literal_value(expr).is_some() || is_bounded_amount(expr);
+            match binary.op {
+                syn::BinOp::BitAnd(_) | syn::BinOp::Rem(_) => bounded(&binary.right),
+                syn::BinOp::Add(_) | syn::BinOp::Mul(_) => bounded(&binary.left) && bounded(&binary.right),
+                _ => false,
+            }
+        }
+        syn::Expr::Paren(paren) => is_bounded_amount(&paren.expr),
+        syn::Expr::Group(group) => is_bounded_amount(&group.expr),
+        syn::Expr::Cast(cast) => is_bounded_amount(&cast.expr),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1416UncheckedShift::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_variable_shift_amount() {
+        let code = r#"
+            fn example(value: u32, bits: u32, mut acc: u64) -> u32 {
+                acc <<= bits;
+                let _ = acc >> (bits * 8);
+                let _ = acc >> self.offset;
+                value << bits
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 4);
+        assert_eq!(violations[0].code, "E1416");
+        assert!(violations[0].message.contains("'bits' in '<<='"));
+        assert!(violations[2].message.contains("'self . offset' in '>>'"));
+        assert_eq!((violations[3].line, violations[3].column), (6, 17));
+    }
+
+    #[test]
+    fn test_detects_literal_at_or_beyond_width() {
+        let code = r#"
+            fn example(x: u32, y: i8) -> u32 {
+                let _ = y >> 8;
+                let _ = 1u64 << 63;
+                x << 40
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("Shifting a i8 by 8 bits"));
+        assert!(violations[1].message.contains("Shifting a u32 by 40 bits (<< 40) reaches its 32-bit width"));
+    }
+
+    #[test]
+    fn test_constant_and_bounded_shifts_pass() {
+        let code = r#"
+            const SHIFT: u32 = 4;
+
+            impl Hasher {
+                fn example(&self, x: u32, n: u32, len: usize) -> u32 {
+                    let _ = x << 2;
+                    let _ = x >> SHIFT;
+                    let _ = x << (n & 31);
+                    let _ = x >> (n % 32);
+                    let _ = x >> ((n % 4) * 8);
+                    let _ = len << 40;
+                    let _ = x.checked_shl(n).unwrap_or(0);
+                    x << Self::BITS
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+//! E1417: Float ordering comparison without NaN handling
+//!
+//! Detects `<`, `>`, `<=` and `>=` between two float operands. Every ordering
+//! comparison involving NaN is false, so `if a < b { .. } else { .. }` takes the
+//! `else` branch for NaN, and min/max or sorting logic built on these operators
+//! silently misbehaves. E1410 covers `==`/`!=` on floats.
+//!
+//! Operands count as floats when they are float literals (`1.5`, `2f64`), casts
+//! to `f32`/`f64`, or function parameters and `let` bindings annotated with
+//! `f32`/`f64`. Functions that call `is_nan()` or `is_finite()` are assumed to
+//! handle NaN and are skipped. Most float ordering is harmless, so the checker
+//! is low severity; `include_literal_operands` can restrict it to comparisons
+//! between two float variables.
+//!
+//! Example:
+//! ```text
+//! // Bad: NaN is never larger, so a NaN sample is silently dropped
+//! fn max(a: f64, b: f64) -> f64 { if a > b { a } else { b } }
+//!
+//! // Good: total ordering with a defined place for NaN
+//! fn max(a: f64, b: f64) -> f64 { if a.total_cmp(&b).is_gt() { a } else { b } }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use std::collections::HashSet;
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1417: Float ordering comparison without NaN handling
+    E1417FloatOrdering,
+    code = "E1417",
+    name = "Float ordering comparison without NaN handling",
+    suggestions = "Use f64::total_cmp()/partial_cmp() for a defined ordering, or check is_nan() before comparing when NaN is possible",
+    target_items = [Function, Impl],
+    config_entry_name = "e1417_float_ordering",
+    /// Configuration for E1417: Float ordering checker
+    config = E1417Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Also report comparisons where one side is a float literal (`x < 0.0`);
+        /// when unset only comparisons between two float variables are reported
+        include_literal_operands: bool = true,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = FloatOrderingVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            floats: HashSet::new(),
+            handles_nan: false,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct FloatOrderingVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1417FloatOrdering,
+    /// Float-typed parameters and annotated `let` bindings in the current fn
+    floats: HashSet<String>,
+    /// Whether the current fn checks for NaN itself
+    handles_nan: bool,
+}
+
+/// How an operand is known to be a float
+#[derive(PartialEq)]
+enum FloatOperand {
+    Literal,
+    Variable,
+}
+
+impl<'a> FloatOrderingVisitor<'a> {
+    /// Visit a function body with its float parameters bound
+    fn visit_fn(&mut self, sig: &'a syn::Signature, block: &'a syn::Block, visit: impl FnOnce(&mut Self)) {
+        let outer_floats = std::mem::take(&mut self.floats);
+        let mut nan_checks = NanCheckFinder::default();
+        nan_checks.visit_block(block);
+        let outer_handles_nan = std::mem::replace(&mut self.handles_nan, nan_checks.found);
+        for input in &sig.inputs {
+            if let syn::FnArg::Typed(pat_type) = input {
+                self.bind(&pat_type.pat, &pat_type.ty);
+            }
+        }
+        visit(self);
+        self.floats = outer_floats;
+        self.handles_nan = outer_handles_nan;
+    }
+
+    fn bind(&mut self, pat: &syn::Pat, ty: &syn::Type) {
+        if let (syn::Pat::Ident(ident), true) = (pat, is_float_type(ty)) {
+            self.floats.insert(ident.ident.to_string());
+        }
+    }
+
+    /// Whether an expression is apparently a float, and how that is known
+    fn float_operand(&self, expr: &syn::Expr) -> Option<FloatOperand> {
+        match expr {
+            syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Float(_), .. }) => Some(FloatOperand::Literal),
+            syn::Expr::Cast(cast) => is_float_type(&cast.ty).then_some(FloatOperand::Variable),
+            syn::Expr::Paren(paren) => self.float_operand(&paren.expr),
+            syn::Expr::Group(group) => self.float_operand(&group.expr),
+            syn::Expr::Unary(unary) => self.float_operand(&unary.expr),
+            syn::Expr::Path(path) => {
+                let ident = path.path.get_ident()?;
+                self.floats.contains(&ident.to_string()).then_some(FloatOperand::Variable)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for FloatOrderingVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.visit_fn(&node.sig, &node.block, |visitor| syn::visit::visit_item_fn(visitor, node));
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.visit_fn(&node.sig, &node.block, |visitor| syn::visit::visit_impl_item_fn(visitor, node));
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        syn::visit::visit_local(self, node);
+        if let syn::Pat::Type(pat_type) = &node.pat {
+            self.bind(&pat_type.pat, &pat_type.ty);
+        }
+    }
+
+    fn visit_expr_binary(&mut self, node: &'a syn::ExprBinary) {
+        let is_ordering = matches!(
+            node.op,
+            syn::BinOp::Lt(_) | syn::BinOp::Gt(_) | syn::BinOp::Le(_) | syn::BinOp::Ge(_)
+        );
+        if is_ordering && !self.handles_nan {
+            let operands = (self.float_operand(&node.left), self.float_operand(&node.right));
+            let reported = match operands {
+                (Some(FloatOperand::Variable), Some(FloatOperand::Variable)) => true,
+                (Some(_), Some(_)) => {
+                    self.checker.config.include_literal_operands
+                        && operands != (Some(FloatOperand::Literal), Some(FloatOperand::Literal))
+                }
+                _ => false,
+            };
+            if reported {
+                let span = node.op.span();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        "Float ordering comparison is false whenever either side is NaN, so the NaN case silently takes the other branch.",
+                        self.file_path,
+                        span.start().line,
+                        span.start().column + 1,
+                    )
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+
+        syn::visit::visit_expr_binary(self, node);
+    }
+}
+
+/// Finds `is_nan()` / `is_finite()` calls in a function body
+#[derive(Default)]
+struct NanCheckFinder {
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for NanCheckFinder {
+    fn visit_expr_method_call(&mut self, node: &'ast syn::ExprMethodCall) {
+        if node.method == "is_nan" || node.method == "is_finite" {
+            self.found = true;
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// `f32` or `f64`, looking through references
+fn is_float_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path.path.get_ident().is_some_and(|ident| ident == "f32" || ident == "f64"),
+        syn::Type::Reference(reference) => is_float_type(&reference.elem),
+        syn::Type::Paren(paren) => is_float_type(&paren.elem),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code_with(checker: &E1417FloatOrdering, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_code_with(&E1417FloatOrdering::default(), code)
+    }
+
+    #[test]
+    fn test_detects_float_ordering() {
+        let code = r#"
+            fn clamp(value: f64, max: &f32, n: u32) -> bool {
+                let limit: f64 = 10.0;
+                let _ = value > limit;
+                let _ = *max <= 1.5;
+                value >= n as f64
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 3);
+        assert_eq!(violations[0].code, "E1417");
+        assert_eq!((violations[0].line, violations[0].column), (4, 31));
+        assert_eq!(violations[2].line, 6);
+    }
+
+    #[test]
+    fn test_integer_and_unknown_comparisons_pass() {
+        let code = r#"
+            impl Stats {
+                fn example(&self, a: i32, b: u64, x: f64) -> bool {
+                    let _ = a < 10;
+                    let _ = b >= 3;
+                    let _ = 1.0 < 2.0;
+                    let _ = self.mean > x;
+                    a > 0
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_nan_checked_functions_pass() {
+        let code = r#"
+            fn max(a: f64, b: f64) -> f64 {
+                if a.is_nan() || b.is_nan() { return f64::NAN; }
+                if a > b { a } else { b }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_variables_only_mode() {
+        let checker = E1417FloatOrdering {
+            config: E1417Config {
+                include_literal_operands: false,
+                ..E1417Config::default()
+            },
+        };
+        let code = r#"
+            fn f(x: f32, y: f32) -> bool {
+                let _ = x < 0.0;
+                x < y
+            }
+        "#;
+        let violations = check_code_with(&checker, code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 4);
+    }
+}
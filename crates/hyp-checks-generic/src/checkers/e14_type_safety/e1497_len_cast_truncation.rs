@@ -0,0 +1,178 @@
+//! E1497: `.len()` cast to a narrower integer type
+//!
+//! Detects `.len()` results cast with `as` to a type narrower than `usize`
+//! (e.g. `v.len() as i32`). Collection lengths are `usize`, so on large
+//! collections such casts silently truncate or wrap to negative values.
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1497: `.len()` cast truncation
+    E1497LenCastTruncation,
+    code = "E1497",
+    name = "Collection length cast to narrower integer",
+    suggestions = "Use u32::try_from(v.len()) (or i32::try_from) and handle the error, or keep the length as usize",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1497_len_cast_truncation",
+    /// Configuration for E1497: `.len()` cast truncation checker
+    config = E1497Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = LenCastVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+/// Integer types that cannot hold every `usize` value on 64-bit targets
+const NARROW_TARGETS: &[&str] = &["u8", "u16", "u32", "i8", "i16", "i32", "isize"];
+
+struct LenCastVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1497LenCastTruncation,
+}
+
+impl<'a> LenCastVisitor<'a> {
+    fn create_violation(&self, span: proc_macro2::Span, target: &str) -> Violation {
+        let start = span.start();
+        Violation::new(
+            self.checker.code(),
+            self.checker.name(),
+            self.checker.severity().into(),
+            format!(
+                "`.len()` returns usize; casting it to {} silently truncates on large collections.",
+                target
+            ),
+            self.file_path,
+            start.line,
+            start.column + 1,
+        )
+        .with_span(span.start(), span.end())
+        .with_suggestion(self.checker.suggestions())
+    }
+}
+
+impl<'a> Visit<'a> for LenCastVisitor<'a> {
+    fn visit_expr_cast(&mut self, node: &'a syn::ExprCast) {
+        if is_len_call(&node.expr) {
+            if let Some(target) = narrow_target(&node.ty) {
+                self.violations.push(self.create_violation(node.span(), target));
+            }
+        }
+
+        syn::visit::visit_expr_cast(self, node);
+    }
+}
+
+/// Check whether an expression is a zero-argument `.len()` method call
+fn is_len_call(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::MethodCall(call) => call.method == "len" && call.args.is_empty(),
+        syn::Expr::Paren(paren) => is_len_call(&paren.expr),
+        syn::Expr::Group(group) => is_len_call(&group.expr),
+        _ => false,
+    }
+}
+
+/// Return the target type name if it is narrower than `usize`
+fn narrow_target(ty: &syn::Type) -> Option<&'static str> {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(ident) = type_path.path.get_ident() {
+            let name = ident.to_string();
+            return NARROW_TARGETS.iter().copied().find(|t| *t == name);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1497LenCastTruncation::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_len_as_i32() {
+        let code = r#"
+            fn count(v: &[u8]) -> i32 {
+                v.len() as i32
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1497");
+        assert!(violations[0].message.contains("i32"));
+    }
+
+    #[test]
+    fn test_detects_len_as_u16_and_u8() {
+        let code = r#"
+            fn header(v: &Vec<u8>, s: &str) -> (u16, u8) {
+                (v.len() as u16, s.len() as u8)
+            }
+        "#;
+        assert_eq!(check_code(code).len(), 2);
+    }
+
+    #[test]
+    fn test_detects_in_impl_method() {
+        let code = r#"
+            struct Buf { data: Vec<u8> }
+            impl Buf {
+                fn size(&self) -> u32 { self.data.len() as u32 }
+            }
+        "#;
+        assert_eq!(check_code(code).len(), 1);
+    }
+
+    #[test]
+    fn test_widening_cast_not_flagged() {
+        let code = r#"
+            fn count(v: &[u8]) -> u64 {
+                v.len() as u64
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_try_from_not_flagged() {
+        let code = r#"
+            fn count(v: &[u8]) -> Option<u32> {
+                u32::try_from(v.len()).ok()
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_non_len_cast_not_flagged() {
+        let code = r#"
+            fn narrow(x: u64) -> u32 {
+                x as u32
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
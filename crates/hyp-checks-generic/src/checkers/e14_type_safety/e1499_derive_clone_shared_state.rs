@@ -0,0 +1,232 @@
+//! E1499: `#[derive(Clone)]` on a type with shared mutable state
+//!
+//! Detects structs and enums deriving `Clone` that hold an `Arc<Mutex<_>>`,
+//! `Rc<RefCell<_>>` or similar shared interior-mutability field. The derived
+//! `Clone` only bumps the reference count, so every clone observes and mutates
+//! the same state - a surprise for callers expecting an independent copy.
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1499: derived Clone sharing mutable state
+    E1499DeriveCloneSharedState,
+    code = "E1499",
+    name = "Derived Clone shares mutable state",
+    suggestions = "Implement Clone manually to deep-copy the inner value, or document that clones share state (e.g. name the type a Handle)",
+    target_items = [Struct, Enum],
+    config_entry_name = "e1499_derive_clone_shared_state",
+    /// Configuration for E1499: derived Clone sharing mutable state checker
+    config = E1499Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = SharedStateCloneVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+/// Reference-counted pointers whose clone shares the pointee
+const SHARED_POINTERS: &[&str] = &["Arc", "Rc"];
+
+/// Interior-mutability types that make the shared pointee mutable
+const INTERIOR_MUTABLE: &[&str] = &["Mutex", "RwLock", "RefCell", "Cell", "OnceCell"];
+
+struct SharedStateCloneVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1499DeriveCloneSharedState,
+}
+
+impl<'a> SharedStateCloneVisitor<'a> {
+    fn check_fields<'f>(
+        &mut self,
+        type_name: &syn::Ident,
+        attrs: &[syn::Attribute],
+        fields: impl IntoIterator<Item = &'f syn::Field>,
+    ) {
+        if !derives_clone(attrs) {
+            return;
+        }
+
+        for (index, field) in fields.into_iter().enumerate() {
+            let Some(shared) = shared_mutable_type(&field.ty) else {
+                continue;
+            };
+            let field_name = field
+                .ident
+                .as_ref()
+                .map(|i| i.to_string())
+                .unwrap_or_else(|| index.to_string());
+            let start = type_name.span().start();
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    self.checker.severity().into(),
+                    format!(
+                        "'{}' derives Clone but field '{}' is {}: clones share the same state instead of getting an independent copy.",
+                        type_name, field_name, shared
+                    ),
+                    self.file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_suggestion(self.checker.suggestions()),
+            );
+        }
+    }
+}
+
+impl<'a> Visit<'a> for SharedStateCloneVisitor<'a> {
+    fn visit_item_struct(&mut self, node: &'a syn::ItemStruct) {
+        self.check_fields(&node.ident, &node.attrs, &node.fields);
+        syn::visit::visit_item_struct(self, node);
+    }
+
+    fn visit_item_enum(&mut self, node: &'a syn::ItemEnum) {
+        self.check_fields(
+            &node.ident,
+            &node.attrs,
+            node.variants.iter().flat_map(|v| v.fields.iter()),
+        );
+        syn::visit::visit_item_enum(self, node);
+    }
+}
+
+/// Check whether the attributes contain `#[derive(..., Clone, ...)]`
+fn derives_clone(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("derive")).any(|attr| {
+        let mut found = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.segments.last().is_some_and(|s| s.ident == "Clone") {
+                found = true;
+            }
+            Ok(())
+        });
+        found
+    })
+}
+
+/// If `ty` is `Arc<Mutex<_>>`, `Rc<RefCell<_>>` or similar, return a short description like `Arc<Mutex<_>>`
+fn shared_mutable_type(ty: &syn::Type) -> Option<String> {
+    let (pointer, inner) = single_generic(ty)?;
+    if !SHARED_POINTERS.contains(&pointer.as_str()) {
+        return None;
+    }
+    let inner_name = type_name(inner)?;
+    if INTERIOR_MUTABLE.contains(&inner_name.as_str()) || inner_name.starts_with("Atomic") {
+        let inner_desc = if inner_name.starts_with("Atomic") {
+            inner_name
+        } else {
+            format!("{}<_>", inner_name)
+        };
+        return Some(format!("{}<{}>", pointer, inner_desc));
+    }
+    None
+}
+
+/// Return the last path segment name and its first generic type argument
+fn single_generic(ty: &syn::Type) -> Option<(String, &syn::Type)> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let last = type_path.path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(inner) => Some((last.ident.to_string(), inner)),
+        _ => None,
+    })
+}
+
+/// Return the last path segment name of a type
+fn type_name(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(type_path) => type_path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1499DeriveCloneSharedState::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_arc_mutex_field() {
+        let code = r#"
+            #[derive(Debug, Clone)]
+            struct Counter {
+                hits: std::sync::Arc<std::sync::Mutex<u64>>,
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1499");
+        assert!(violations[0].message.contains("Arc<Mutex<_>>"));
+        assert!(violations[0].message.contains("share the same state"));
+    }
+
+    #[test]
+    fn test_detects_rc_refcell_in_enum() {
+        let code = r#"
+            #[derive(Clone)]
+            enum Node {
+                Leaf(Rc<RefCell<Vec<u8>>>),
+                Empty,
+            }
+        "#;
+        assert_eq!(check_code(code).len(), 1);
+    }
+
+    #[test]
+    fn test_detects_arc_atomic() {
+        let code = r#"
+            #[derive(Clone)]
+            struct Stats { count: Arc<AtomicUsize> }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("Arc<AtomicUsize>"));
+    }
+
+    #[test]
+    fn test_no_derive_clone_not_flagged() {
+        let code = r#"
+            #[derive(Debug)]
+            struct Counter { hits: Arc<Mutex<u64>> }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_immutable_shared_field_not_flagged() {
+        let code = r#"
+            #[derive(Clone)]
+            struct Config { name: Arc<str>, values: Rc<Vec<u8>>, lock: Mutex<u8> }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+//! Syntactic integer type tracking shared by the E14 checkers.
+//!
+//! Without type inference, the integer type of an expression is only known
+//! from literal suffixes, casts, and function parameters or `let` bindings
+//! with a primitive integer type annotation. [`IntScope`] records those
+//! bindings while a visitor walks a function body.
+
+use std::collections::HashMap;
+
+/// Primitive integer types: (name, width in bits, signed); pointer-sized types use width 0
+const INTEGER_TYPES: &[(&str, u32, bool)] = &[
+    ("i8", 8, true),
+    ("u8", 8, false),
+    ("i16", 16, true),
+    ("u16", 16, false),
+    ("i32", 32, true),
+    ("u32", 32, false),
+    ("i64", 64, true),
+    ("u64", 64, false),
+    ("i128", 128, true),
+    ("u128", 128, false),
+    ("isize", 0, true),
+    ("usize", 0, false),
+];
+
+fn lookup(name: &str) -> Option<&'static (&'static str, u32, bool)> {
+    INTEGER_TYPES.iter().find(|(ty, _, _)| *ty == name)
+}
+
+fn intern(name: &str) -> Option<&'static str> {
+    lookup(name).map(|(ty, _, _)| *ty)
+}
+
+/// Width of a fixed-size integer type; pointer-sized types have no fixed width
+pub(crate) fn type_width(name: &str) -> Option<u32> {
+    lookup(name).map(|(_, width, _)| *width).filter(|width| *width > 0)
+}
+
+/// Same width (pointer-sized counts as its own width) but different signedness
+pub(crate) fn is_sign_change(from: &str, to: &str) -> bool {
+    matches!(
+        (lookup(from), lookup(to)),
+        (Some((_, from_width, from_signed)), Some((_, to_width, to_signed))) if from_width == to_width && from_signed != to_signed
+    )
+}
+
+/// Primitive integer type named by `ty`, looking through references
+pub(crate) fn integer_type(ty: &syn::Type) -> Option<&'static str> {
+    match ty {
+        syn::Type::Path(path) => intern(&path.path.get_ident()?.to_string()),
+        syn::Type::Reference(reference) => integer_type(&reference.elem),
+        syn::Type::Paren(paren) => integer_type(&paren.elem),
+        _ => None,
+    }
+}
+
+/// Integer type of parameters and annotated `let` bindings in the current fn
+#[derive(Default)]
+pub(crate) struct IntScope {
+    bindings: HashMap<String, &'static str>,
+}
+
+impl IntScope {
+    /// Start a function body with its integer parameters bound; returns the
+    /// outer scope to hand back to [`IntScope::exit_fn`]
+    pub(crate) fn enter_fn(&mut self, sig: &syn::Signature) -> IntScope {
+        let outer = std::mem::take(self);
+        for input in &sig.inputs {
+            if let syn::FnArg::Typed(pat_type) = input {
+                self.bind(&pat_type.pat, &pat_type.ty);
+            }
+        }
+        outer
+    }
+
+    /// Leave a function body, restoring the scope it was entered from
+    pub(crate) fn exit_fn(&mut self, outer: IntScope) {
+        *self = outer;
+    }
+
+    /// Record `let name: <int> = ..`
+    pub(crate) fn bind_local(&mut self, local: &syn::Local) {
+        if let syn::Pat::Type(pat_type) = &local.pat {
+            self.bind(&pat_type.pat, &pat_type.ty);
+        }
+    }
+
+    fn bind(&mut self, pat: &syn::Pat, ty: &syn::Type) {
+        if let (syn::Pat::Ident(ident), Some(int)) = (pat, integer_type(ty)) {
+            self.bindings.insert(ident.ident.to_string(), int);
+        }
+    }
+
+    /// Integer type an expression apparently has, if it can be told from the syntax
+    pub(crate) fn expr_type(&self, expr: &syn::Expr) -> Option<&'static str> {
+        match expr {
+            syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => intern(lit.suffix()),
+            syn::Expr::Cast(cast) => integer_type(&cast.ty),
+            syn::Expr::Paren(paren) => self.expr_type(&paren.expr),
+            syn::Expr::Group(group) => self.expr_type(&group.expr),
+            syn::Expr::Unary(unary) => self.expr_type(&unary.expr),
+            syn::Expr::Path(path) => {
+                let ident = path.path.get_ident()?;
+                self.bindings.get(&ident.to_string()).copied()
+            }
+            _ => None,
+        }
+    }
+}
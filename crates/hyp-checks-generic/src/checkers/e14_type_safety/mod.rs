@@ -12,6 +12,12 @@ pub mod e1409_partial_initialization;
 pub mod e1410_float_equality;
 pub mod e1412_prohibit_unions;
 pub mod e1413_64bit_integer_overflow;
+pub mod e1414_sign_changing_cast;
+pub mod e1416_unchecked_shift;
+pub mod e1417_float_ordering;
+pub mod e1497_len_cast_truncation;
+pub mod e1499_derive_clone_shared_state;
+pub(crate) mod int_types;
 pub mod registry;
 
 pub use e1401_integer_overflow::{E1401Config, E1401IntegerOverflow};
@@ -26,3 +32,8 @@ pub use e1409_partial_initialization::{E1409Config, E1409PartialInitialization};
 pub use e1410_float_equality::{E1410Config, E1410FloatEquality};
 pub use e1412_prohibit_unions::{E1412Config, E1412ProhibitUnions};
 pub use e1413_64bit_integer_overflow::{E1413Config, E1413SixtyFourIntegerOverflow};
+pub use e1414_sign_changing_cast::{E1414Config, E1414SignChangingCast};
+pub use e1416_unchecked_shift::{E1416Config, E1416UncheckedShift};
+pub use e1417_float_ordering::{E1417Config, E1417FloatOrdering};
+pub use e1497_len_cast_truncation::{E1497Config, E1497LenCastTruncation};
+pub use e1499_derive_clone_shared_state::{E1499Config, E1499DeriveCloneSharedState};
@@ -9,6 +9,11 @@ use crate::{
         E1407Config, E1407LossyFloatConversion, E1408Config, E1408UncheckedIndexing,
         E1409Config, E1409PartialInitialization, E1410Config, E1410FloatEquality,
         E1412Config, E1412ProhibitUnions, E1413Config, E1413SixtyFourIntegerOverflow,
+        E1497Config, E1497LenCastTruncation,
+        E1499Config, E1499DeriveCloneSharedState,
+        E1414Config, E1414SignChangingCast,
+        E1416Config, E1416UncheckedShift,
+        E1417Config, E1417FloatOrdering,
     },
     register_checker,
     registry::CheckerRegistration,
@@ -29,5 +34,10 @@ pub fn e14_registrations() -> Vec<CheckerRegistration> {
         register_checker!(E1410FloatEquality, E1410Config),
         register_checker!(E1412ProhibitUnions, E1412Config),
         register_checker!(E1413SixtyFourIntegerOverflow, E1413Config),
+        register_checker!(E1497LenCastTruncation, E1497Config),
+        register_checker!(E1499DeriveCloneSharedState, E1499Config),
+        register_checker!(E1414SignChangingCast, E1414Config),
+        register_checker!(E1416UncheckedShift, E1416Config),
+        register_checker!(E1417FloatOrdering, E1417Config),
     ]
 }
@@ -24,6 +24,7 @@ define_checker! {
     code = "E1502",
     name = "Lock held across await",
     suggestions = "Drop the lock guard before await points, or use async-aware locks like tokio::sync::Mutex.",
+    rationale = "A std::sync::Mutex guard held across .await keeps the lock while the task is suspended, so every other task that needs the lock blocks its worker thread, and if one of them runs on the same thread the program deadlocks. The guard is also not Send, which makes the future not Send and fails to compile with tokio::spawn, usually with a confusing error. Drop the guard before awaiting, or use tokio::sync::Mutex when the lock must span an await.",
     target_items = [Function, Impl],
     config_entry_name = "e1502_lock_across_await",
     config = E1502Config {
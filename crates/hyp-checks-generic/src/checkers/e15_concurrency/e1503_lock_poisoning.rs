@@ -13,6 +13,7 @@ define_checker! {
     code = "E1503",
     name = "Lock poisoning not handled",
     suggestions = "Use lock().unwrap_or_else(|e| e.into_inner()) to recover from poisoned locks, or handle explicitly",
+    rationale = "A Mutex or RwLock is poisoned when a thread panics while holding it, to signal that the protected data may be half-updated. Calling unwrap() on lock() turns that signal into a new panic, which spreads the original failure to every thread that uses the lock. Decide explicitly: recover the data with into_inner() if it is still consistent, or report the error.",
     target_items = [Function],
     config_entry_name = "e1503_lock_poisoning",
     config = E1503Config {
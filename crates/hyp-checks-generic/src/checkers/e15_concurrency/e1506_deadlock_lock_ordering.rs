@@ -13,6 +13,7 @@ define_checker! {
     code = "E1506",
     name = "Potential deadlock from lock ordering",
     suggestions = "Always acquire locks in a consistent order across all code paths to prevent deadlocks",
+    rationale = "When one code path takes lock A then B and another takes B then A, two threads can each hold one lock and wait forever for the other. The deadlock depends on timing, so it rarely shows up in tests and then hangs production under load. A single global acquisition order, or merging the data under one lock, rules it out.",
     target_items = [Function],
     config_entry_name = "e1506_deadlock_lock_ordering",
     config = E1506Config {
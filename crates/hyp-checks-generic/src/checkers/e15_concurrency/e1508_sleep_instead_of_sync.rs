@@ -13,6 +13,7 @@ define_checker! {
     code = "E1508",
     name = "Sleep instead of synchronization",
     suggestions = "Use proper synchronization primitives (Mutex, RwLock, channels, condvars) instead of sleep",
+    rationale = "Sleeping to wait for another thread assumes it will be done within a fixed time. Under load or on a slow machine the assumption breaks and the code races; on a fast machine it wastes the whole delay. Channels, condition variables, barriers and join handles wait exactly as long as needed and establish the happens-before ordering that a sleep does not.",
     target_items = [Function],
     config_entry_name = "e1508_sleep_instead_of_sync",
     config = E1508Config {
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1509",
     name = "Channel lifetime issue",
     suggestions = "Create channels outside loops, drop senders/receivers when no longer needed",
+    rationale = "Channels created inside loops, or senders kept alive longer than needed, lead to receivers that never see the end of the stream: recv() blocks forever because a sender clone is still alive somewhere. Creating a channel per iteration also allocates and drops queues repeatedly. Create channels once and drop senders as soon as producers are done.",
     target_items = [Function],
     config_entry_name = "e1509_channel_lifetime",
     config = E1509Config {
@@ -13,6 +13,7 @@ define_checker! {
     code = "E1510",
     name = "Mutex instead of RwLock",
     suggestions = "Consider using RwLock if data is read more often than written",
+    rationale = "A Mutex serializes every access, including reads that could safely run in parallel. For data that is read far more often than written, readers queue behind each other for no reason and throughput drops as threads are added. An RwLock lets readers proceed together and only excludes them during writes.",
     target_items = [Function, Struct],
     config_entry_name = "e1510_mutex_instead_of_rwlock",
     config = E1510Config {
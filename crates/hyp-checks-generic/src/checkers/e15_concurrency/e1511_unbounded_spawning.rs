@@ -28,6 +28,7 @@ define_checker! {
     code = "E1511",
     name = "Unbounded task/thread spawning in loop",
     suggestions = "Use bounded concurrency with Semaphore, thread pools, or stream::buffer_unordered()",
+    rationale = "Spawning a thread or task per item in a loop has no upper bound: a large input or a burst of requests creates thousands of threads (exhausting memory and OS limits) or tasks that all compete for the same resources. The system degrades for everyone instead of applying backpressure. A semaphore, worker pool or buffer_unordered caps how much runs at once.",
     target_items = [Function],
     config_entry_name = "e1511_unbounded_spawning",
     config = E1511Config {
@@ -28,6 +28,7 @@ define_checker! {
     code = "E1512",
     name = "std::thread::spawn is prohibited in async codebases",
     suggestions = "Use tokio::task::spawn_blocking() instead. This ensures blocking work is scheduled through tokio's thread pool, preserving runtime context and observability.",
+    rationale = "In an async application std::thread::spawn creates an OS thread outside the runtime: it does not inherit the runtime context (tokio handles, tracing spans, task-local data), is not bounded by the blocking pool, and is invisible to runtime metrics. tokio::task::spawn_blocking runs the same blocking work on the runtime's managed pool.",
     target_items = [Function],
     config_entry_name = "e1512_prohibit_std_thread_spawn",
     config = E1512Config {
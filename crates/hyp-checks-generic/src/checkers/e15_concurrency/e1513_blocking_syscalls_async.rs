@@ -4,6 +4,10 @@
 //! preventing other tasks from making progress. This causes severe
 //! performance degradation and potential deadlocks in microservices.
 //!
+//! Only calls lexically inside an `async fn` or `async` block are reported.
+//! Calls that are `.await`ed are async by definition, and closures passed to
+//! `spawn_blocking`/`block_in_place` run off the executor, so neither is flagged.
+//!
 //! Example:
 //! ```text
 //! // Bad: Blocks the executor thread
@@ -17,8 +21,9 @@
 //! }
 //! ```
 
-use crate::{checker::Checker, define_checker, violation::Violation};
+use crate::{checker::Checker, define_checker, violation::Violation, AnalyzerError};
 
+use regex::Regex;
 use syn::{spanned::Spanned, visit::Visit};
 
 define_checker! {
@@ -27,18 +32,38 @@ define_checker! {
     code = "E1513",
     name = "Blocking syscall in async code",
     suggestions = "Use async alternatives: tokio::fs::* for file I/O, tokio::net::* for networking, tokio::time::sleep for sleeping.",
-    target_items = [Function],
+    rationale = "Async executors run many tasks on a few threads, and a task only yields at .await. A blocking call such as std::fs::read, std::thread::sleep or a blocking socket read holds the worker thread for its whole duration, stalling every other task scheduled on it. Latency spikes and apparent hangs follow under load. Use the async equivalents, or move the call to spawn_blocking.",
+    target_items = [Function, Impl, Trait],
     config_entry_name = "e1513_blocking_syscalls_async",
     config = E1513Config {
         enabled: bool = true,
         severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
         categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Regexes matched against the call path as written (`std::fs::read`, `thread::sleep`);
+        /// method calls on a call are written as `stdin().read_line`
+        blocking_paths: Vec<String> = vec![
+            r"^(std::)?fs::(read|read_to_string|read_dir|write|metadata|remove_file|remove_dir|remove_dir_all|create_dir|create_dir_all|copy|rename)$".to_string(),
+            r"^(std::)?(fs::)?File::(open|create)$".to_string(),
+            r"^(std::)?(net::)?(TcpStream::connect|TcpListener::bind|UdpSocket::bind)$".to_string(),
+            r"^(std::)?thread::sleep$".to_string(),
+            r"^(std::)?(io::)?stdin\(\)\.read_line$".to_string(),
+        ],
     },
     check_item(self, item, file_path) {
         let mut visitor = BlockingSyscallVisitor {
             violations: Vec::new(),
             file_path,
             checker: self,
+            patterns: self
+                .config
+                .blocking_paths
+                .iter()
+                .map(|p| {
+                    Regex::new(p)
+                        .map_err(|e| AnalyzerError::Config(format!("Invalid blocking path pattern '{}': {}", p, e)))
+                })
+                .collect::<Result<_, _>>()?,
+            async_depth: 0,
         };
         visitor.visit_item(item);
         Ok(visitor.violations)
@@ -49,187 +74,161 @@ struct BlockingSyscallVisitor<'a> {
     violations: Vec<Violation>,
     file_path: &'a str,
     checker: &'a E1513BlockingSyscallsAsync,
+    /// Compiled `blocking_paths` (invalid patterns are skipped)
+    patterns: Vec<Regex>,
+    /// Number of enclosing async fns/blocks; reset by sync fns and blocking-pool closures
+    async_depth: usize,
 }
 
-/// Blocking call patterns to detect
-struct BlockingPattern {
-    /// Path segments to match (e.g., ["std", "fs", "read"])
-    segments: &'static [&'static str],
-    /// Description of what's blocked
-    description: &'static str,
-    /// Suggested async alternative
-    suggestion: &'static str,
-}
-
-const BLOCKING_PATTERNS: &[BlockingPattern] = &[
-    // std::fs operations
-    BlockingPattern {
-        segments: &["std", "fs", "read"],
-        description: "std::fs::read blocks the executor",
-        suggestion: "Use tokio::fs::read().await instead",
-    },
-    BlockingPattern {
-        segments: &["std", "fs", "write"],
-        description: "std::fs::write blocks the executor",
-        suggestion: "Use tokio::fs::write().await instead",
-    },
-    BlockingPattern {
-        segments: &["std", "fs", "read_to_string"],
-        description: "std::fs::read_to_string blocks the executor",
-        suggestion: "Use tokio::fs::read_to_string().await instead",
-    },
-    BlockingPattern {
-        segments: &["std", "fs", "metadata"],
-        description: "std::fs::metadata blocks the executor",
-        suggestion: "Use tokio::fs::metadata().await instead",
-    },
-    BlockingPattern {
-        segments: &["std", "fs", "remove_file"],
-        description: "std::fs::remove_file blocks the executor",
-        suggestion: "Use tokio::fs::remove_file().await instead",
-    },
-    BlockingPattern {
-        segments: &["std", "fs", "create_dir"],
-        description: "std::fs::create_dir blocks the executor",
-        suggestion: "Use tokio::fs::create_dir().await instead",
-    },
-    BlockingPattern {
-        segments: &["std", "fs", "create_dir_all"],
-        description: "std::fs::create_dir_all blocks the executor",
-        suggestion: "Use tokio::fs::create_dir_all().await instead",
-    },
-    BlockingPattern {
-        segments: &["std", "fs", "copy"],
-        description: "std::fs::copy blocks the executor",
-        suggestion: "Use tokio::fs::copy().await instead",
-    },
-    BlockingPattern {
-        segments: &["std", "fs", "rename"],
-        description: "std::fs::rename blocks the executor",
-        suggestion: "Use tokio::fs::rename().await instead",
-    },
-    // std::fs::File operations
-    BlockingPattern {
-        segments: &["fs", "File", "open"],
-        description: "std::fs::File::open blocks the executor",
-        suggestion: "Use tokio::fs::File::open().await instead",
-    },
-    BlockingPattern {
-        segments: &["fs", "File", "create"],
-        description: "std::fs::File::create blocks the executor",
-        suggestion: "Use tokio::fs::File::create().await instead",
-    },
-    // std::net operations
-    BlockingPattern {
-        segments: &["net", "TcpStream", "connect"],
-        description: "std::net::TcpStream::connect blocks the executor",
-        suggestion: "Use tokio::net::TcpStream::connect().await instead",
-    },
-    BlockingPattern {
-        segments: &["TcpStream", "connect"],
-        description: "std::net::TcpStream::connect blocks the executor",
-        suggestion: "Use tokio::net::TcpStream::connect().await instead",
-    },
-    BlockingPattern {
-        segments: &["net", "TcpListener", "bind"],
-        description: "std::net::TcpListener::bind blocks the executor",
-        suggestion: "Use tokio::net::TcpListener::bind().await instead",
-    },
-    BlockingPattern {
-        segments: &["TcpListener", "bind"],
-        description: "std::net::TcpListener::bind blocks the executor",
-        suggestion: "Use tokio::net::TcpListener::bind().await instead",
-    },
-    BlockingPattern {
-        segments: &["net", "UdpSocket", "bind"],
-        description: "std::net::UdpSocket::bind blocks the executor",
-        suggestion: "Use tokio::net::UdpSocket::bind().await instead",
-    },
-    BlockingPattern {
-        segments: &["UdpSocket", "bind"],
-        description: "std::net::UdpSocket::bind blocks the executor",
-        suggestion: "Use tokio::net::UdpSocket::bind().await instead",
-    },
-    // std::thread::sleep
-    BlockingPattern {
-        segments: &["thread", "sleep"],
-        description: "std::thread::sleep blocks the executor",
-        suggestion: "Use tokio::time::sleep().await instead",
-    },
-];
-
 impl<'a> BlockingSyscallVisitor<'a> {
-    /// Check if expression matches a blocking pattern
-    fn check_blocking_call(&self, expr: &syn::Expr) -> Option<&'static BlockingPattern> {
-        let segments = Self::extract_path_segments(expr)?;
+    /// Visit with the given async nesting, restoring the previous depth afterwards
+    fn with_async_depth(&mut self, depth: usize, visit: impl FnOnce(&mut Self)) {
+        let saved = std::mem::replace(&mut self.async_depth, depth);
+        visit(self);
+        self.async_depth = saved;
+    }
 
-        for pattern in BLOCKING_PATTERNS {
-            if Self::matches_pattern(&segments, pattern.segments) {
-                return Some(pattern);
-            }
-        }
-        None
+    fn in_fn(&mut self, sig: &syn::Signature, visit: impl FnOnce(&mut Self)) {
+        let depth = if sig.asyncness.is_some() { 1 } else { 0 };
+        self.with_async_depth(depth, visit);
     }
 
-    /// Extract path segments from a call expression
-    fn extract_path_segments(expr: &syn::Expr) -> Option<Vec<String>> {
+    /// The called path as written, e.g. `std::fs::read` or `stdin().read_line`
+    fn call_path(expr: &syn::Expr) -> Option<String> {
         match expr {
-            syn::Expr::Call(call) => {
-                if let syn::Expr::Path(path) = &*call.func {
-                    Some(
-                        path.path
-                            .segments
-                            .iter()
-                            .map(|s| s.ident.to_string())
-                            .collect(),
-                    )
-                } else {
-                    None
-                }
-            }
-            syn::Expr::MethodCall(method) => {
-                // For method calls like `file.read()`, we check the method name
-                // but this is less reliable since we don't have type info
-                Some(vec![method.method.to_string()])
-            }
+            syn::Expr::Call(call) => Self::fn_path(call),
+            syn::Expr::MethodCall(method) => Self::method_path(method),
+            _ => None,
+        }
+    }
+
+    fn fn_path(call: &syn::ExprCall) -> Option<String> {
+        match &*call.func {
+            syn::Expr::Path(path) => Some(path_string(&path.path)),
             _ => None,
         }
     }
 
-    /// Check if segments match a pattern (suffix match for flexibility)
-    fn matches_pattern(segments: &[String], pattern: &[&str]) -> bool {
-        if segments.len() < pattern.len() {
-            return false;
+    fn method_path(method: &syn::ExprMethodCall) -> Option<String> {
+        let receiver = Self::call_path(&method.receiver)?;
+        Some(format!("{}().{}", receiver, method.method))
+    }
+
+    /// Visit a call's function and arguments; closures given to the blocking
+    /// thread pool (`spawn_blocking`, `block_in_place`) are not async context
+    fn visit_call_args(&mut self, call: &'a syn::ExprCall) {
+        let runs_on_blocking_pool = matches!(&*call.func, syn::Expr::Path(path)
+            if path.path.segments.last().is_some_and(|s| s.ident == "spawn_blocking" || s.ident == "block_in_place"));
+        let depth = if runs_on_blocking_pool { 0 } else { self.async_depth };
+        self.visit_expr(&call.func);
+        self.with_async_depth(depth, |v| {
+            for arg in &call.args {
+                v.visit_expr(arg);
+            }
+        });
+    }
+
+    fn check_call(&mut self, path: Option<String>, span: proc_macro2::Span) {
+        if self.async_depth == 0 {
+            return;
+        }
+        let Some(path) = path else {
+            return;
+        };
+        if !self.patterns.iter().any(|re| re.is_match(&path)) {
+            return;
         }
 
-        // Check if segments end with the pattern
-        let start = segments.len() - pattern.len();
-        segments[start..]
-            .iter()
-            .zip(pattern.iter())
-            .all(|(seg, pat)| seg == *pat)
+        let start = span.start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "{} blocks the executor thread inside async code. Use {} instead.",
+                    path,
+                    async_equivalent(&path)
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+/// Path segments joined with `::`
+fn path_string(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// The tokio counterpart of a blocking call, for the violation message
+fn async_equivalent(path: &str) -> String {
+    let segments: Vec<&str> = path.split("::").collect();
+    let last = segments.last().copied().unwrap_or_default();
+    let owner = segments.len().checked_sub(2).and_then(|i| segments.get(i)).copied();
+
+    if last == "sleep" {
+        "tokio::time::sleep(..).await".to_string()
+    } else if last.ends_with(".read_line") {
+        "tokio::io::BufReader::new(tokio::io::stdin()).read_line(..).await".to_string()
+    } else if let Some(owner @ ("TcpStream" | "TcpListener" | "UdpSocket")) = owner {
+        format!("tokio::net::{}::{}(..).await", owner, last)
+    } else if owner == Some("File") {
+        format!("tokio::fs::File::{}(..).await", last)
+    } else if owner == Some("fs") {
+        format!("tokio::fs::{}(..).await", last)
+    } else {
+        "an async alternative, or move the call into tokio::task::spawn_blocking".to_string()
     }
 }
 
 impl<'a> Visit<'a> for BlockingSyscallVisitor<'a> {
-    fn visit_expr(&mut self, node: &'a syn::Expr) {
-        if let Some(pattern) = self.check_blocking_call(node) {
-            let span = node.span();
-            self.violations.push(
-                Violation::new(
-                    self.checker.code(),
-                    self.checker.name(),
-                    self.checker.severity().into(),
-                    pattern.description,
-                    self.file_path,
-                    span.start().line,
-                    span.start().column + 1,
-                )
-                .with_suggestion(pattern.suggestion),
-            );
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.in_fn(&node.sig, |v| syn::visit::visit_item_fn(v, node));
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.in_fn(&node.sig, |v| syn::visit::visit_impl_item_fn(v, node));
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'a syn::TraitItemFn) {
+        self.in_fn(&node.sig, |v| syn::visit::visit_trait_item_fn(v, node));
+    }
+
+    fn visit_expr_async(&mut self, node: &'a syn::ExprAsync) {
+        let depth = self.async_depth + 1;
+        self.with_async_depth(depth, |v| syn::visit::visit_expr_async(v, node));
+    }
+
+    fn visit_expr_await(&mut self, node: &'a syn::ExprAwait) {
+        // An awaited call is async; only look inside its receiver and arguments
+        match &*node.base {
+            syn::Expr::Call(call) => self.visit_call_args(call),
+            syn::Expr::MethodCall(method) => {
+                self.visit_expr(&method.receiver);
+                for arg in &method.args {
+                    self.visit_expr(arg);
+                }
+            }
+            base => self.visit_expr(base),
         }
+    }
 
-        syn::visit::visit_expr(self, node);
+    fn visit_expr_call(&mut self, node: &'a syn::ExprCall) {
+        self.check_call(Self::fn_path(node), node.span());
+        self.visit_call_args(node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        self.check_call(Self::method_path(node), node.span());
+        syn::visit::visit_expr_method_call(self, node);
     }
 }
 
@@ -332,4 +331,99 @@ mod tests {
         let violations = check_code(code);
         assert_eq!(violations.len(), 3);
     }
+
+    #[test]
+    fn test_sync_fn_not_flagged() {
+        let code = r#"
+            fn load() -> Vec<u8> {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                std::fs::read("file.txt").unwrap()
+            }
+
+            impl Store {
+                fn flush(&self) {
+                    std::fs::write("store.db", &self.data).unwrap();
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_detects_async_block_and_method() {
+        let code = r#"
+            fn spawn_loader() {
+                tokio::spawn(async move {
+                    let config = std::fs::read_to_string("config.json").unwrap();
+                });
+            }
+
+            impl Server {
+                async fn accept(&self) {
+                    let listener = std::net::TcpListener::bind("0.0.0.0:80").unwrap();
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 4);
+        assert!(violations[0].message.contains("tokio::fs::read_to_string"));
+        assert!(violations[1].message.contains("tokio::net::TcpListener::bind"));
+    }
+
+    #[test]
+    fn test_detects_stdin_read_line() {
+        let code = r#"
+            async fn prompt() -> String {
+                let mut line = String::new();
+                std::io::stdin().read_line(&mut line).unwrap();
+                line
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.starts_with("std::io::stdin().read_line"));
+        assert!(violations[0].message.contains("tokio::io::BufReader"));
+    }
+
+    #[test]
+    fn test_blocking_pool_and_awaited_calls_not_flagged() {
+        let code = r#"
+            async fn load() -> Vec<u8> {
+                let stream = tokio::net::TcpStream::connect("127.0.0.1:80").await.unwrap();
+                tokio::task::spawn_blocking(|| std::fs::read("file.txt").unwrap())
+                    .await
+                    .unwrap()
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_custom_blocking_paths() {
+        let config = E1513Config {
+            blocking_paths: vec![r"^reqwest::blocking::get$".to_string()],
+            ..E1513Config::default()
+        };
+        let checker = E1513BlockingSyscallsAsync { config };
+        let code = r#"
+            async fn fetch() {
+                let body = reqwest::blocking::get("http://example.com").unwrap();
+                std::thread::sleep(std::time::Duration::from_secs(1));
+            }
+        "#;
+        let file = syn::parse_file(code).unwrap();
+        let violations = checker.check_item(&file.items[0], "test.rs").unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("spawn_blocking"));
+    }
+
+    #[test]
+    fn test_invalid_blocking_path_is_an_error() {
+        let mut checker = E1513BlockingSyscallsAsync::default();
+        checker.config.blocking_paths.push("std::fs::(read".to_string());
+        let file = syn::parse_file("async fn f() {}").unwrap();
+        let err = checker.check_item(&file.items[0], "test.rs").unwrap_err();
+        assert!(err.to_string().contains("Invalid blocking path pattern 'std::fs::(read'"));
+    }
 }
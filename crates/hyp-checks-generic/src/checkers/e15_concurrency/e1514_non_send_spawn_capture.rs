@@ -0,0 +1,451 @@
+//! E1514: !Send value captured by a spawned task
+//!
+//! Detects `spawn(async move { .. })` / `spawn(move || ..)` calls whose task
+//! body captures a local of a known `!Send` type (`Rc`, `RefCell`, raw
+//! pointers, `MutexGuard`, ...). `tokio::spawn` and `std::thread::spawn`
+//! require `Send`, so these fail to compile with a long trait-bound error;
+//! this checker names the offending variable instead.
+//!
+//! Example:
+//! ```text
+//! // Bad: Rc is !Send
+//! let state = Rc::new(State::default());
+//! tokio::spawn(async move { state.run().await });
+//!
+//! // Good: Arc is Send + Sync
+//! let state = Arc::new(State::default());
+//! tokio::spawn(async move { state.run().await });
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1514: !Send value captured by a spawned task
+    E1514NonSendSpawnCapture,
+    code = "E1514",
+    name = "!Send value captured by spawned task",
+    suggestions = "Use Send alternatives (Arc instead of Rc, Mutex/RwLock instead of RefCell), drop guards before spawning, or use spawn_local on a LocalSet",
+    rationale = "tokio::spawn and std::thread::spawn require the task to be Send because it may run on another thread. Capturing an Rc, RefCell, raw pointer or lock guard makes it !Send, and the compiler reports a long trait-bound error at the spawn that rarely names the offending variable. Naming the captured value early points straight at what to change.",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1514_non_send_spawn_capture",
+    /// Configuration for E1514: !Send spawn capture checker
+    config = E1514Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Type names treated as !Send; `*const` and `*mut` stand for raw pointers
+        non_send_types: Vec<String> = vec![
+            "Rc".to_string(),
+            "RefCell".to_string(),
+            "MutexGuard".to_string(),
+            "RwLockReadGuard".to_string(),
+            "RwLockWriteGuard".to_string(),
+            "*const".to_string(),
+            "*mut".to_string(),
+        ],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = SpawnCaptureVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            locals: Vec::new(),
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct SpawnCaptureVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1514NonSendSpawnCapture,
+    /// Bindings in scope with the !Send type they hold, if any (later entries shadow earlier ones)
+    locals: Vec<(String, Option<String>)>,
+}
+
+impl<'a> SpawnCaptureVisitor<'a> {
+    fn non_send_local(&self, name: &str) -> Option<&str> {
+        self.locals
+            .iter()
+            .rev()
+            .find(|(local, _)| local == name)
+            .and_then(|(_, ty)| ty.as_deref())
+    }
+
+    fn is_non_send_name(&self, name: &str) -> bool {
+        self.checker.config.non_send_types.iter().any(|ty| ty == name)
+    }
+
+    /// Find a configured !Send type anywhere inside `ty` (containers of !Send values are !Send too)
+    fn non_send_in_type(&self, ty: &syn::Type) -> Option<String> {
+        match ty {
+            syn::Type::Ptr(ptr) => {
+                let kind = if ptr.mutability.is_some() { "*mut" } else { "*const" };
+                self.is_non_send_name(kind).then(|| kind.to_string())
+            }
+            syn::Type::Reference(reference) => self.non_send_in_type(&reference.elem),
+            syn::Type::Paren(paren) => self.non_send_in_type(&paren.elem),
+            syn::Type::Array(array) => self.non_send_in_type(&array.elem),
+            syn::Type::Slice(slice) => self.non_send_in_type(&slice.elem),
+            syn::Type::Tuple(tuple) => tuple.elems.iter().find_map(|elem| self.non_send_in_type(elem)),
+            syn::Type::Path(type_path) => type_path.path.segments.iter().find_map(|segment| {
+                let name = segment.ident.to_string();
+                if self.is_non_send_name(&name) {
+                    return Some(name);
+                }
+                let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                    return None;
+                };
+                args.args.iter().find_map(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => self.non_send_in_type(ty),
+                    _ => None,
+                })
+            }),
+            _ => None,
+        }
+    }
+
+    /// Infer a !Send type from an initializer such as `Rc::new(..)`, `m.lock().unwrap()` or `x.clone()`
+    fn non_send_in_expr(&self, expr: &syn::Expr) -> Option<String> {
+        match expr {
+            syn::Expr::Call(call) => {
+                let syn::Expr::Path(path) = &*call.func else {
+                    return None;
+                };
+                let segments: Vec<_> = path.path.segments.iter().collect();
+                let owner = segments.len().checked_sub(2).map(|i| segments[i].ident.to_string())?;
+                self.is_non_send_name(&owner).then_some(owner)
+            }
+            syn::Expr::MethodCall(call) => match call.method.to_string().as_str() {
+                "lock" => self.is_non_send_name("MutexGuard").then(|| "MutexGuard".to_string()),
+                "clone" | "unwrap" | "expect" | "borrow" | "borrow_mut" => self.non_send_in_expr(&call.receiver),
+                _ => None,
+            },
+            syn::Expr::Cast(cast) => self.non_send_in_type(&cast.ty),
+            syn::Expr::Reference(reference) => self.non_send_in_expr(&reference.expr),
+            syn::Expr::Paren(paren) => self.non_send_in_expr(&paren.expr),
+            syn::Expr::Path(path) => path
+                .path
+                .get_ident()
+                .and_then(|ident| self.non_send_local(&ident.to_string()))
+                .map(str::to_string),
+            _ => None,
+        }
+    }
+
+    fn bind_pat(&mut self, pat: &syn::Pat, ty: Option<String>) {
+        match pat {
+            syn::Pat::Ident(pat_ident) => self.locals.push((pat_ident.ident.to_string(), ty)),
+            syn::Pat::Type(pat_type) => {
+                let ty = self.non_send_in_type(&pat_type.ty).or(ty);
+                self.bind_pat(&pat_type.pat, ty);
+            }
+            other => {
+                // Destructuring: bind every name without a known type so it shadows outer locals
+                let mut collector = CaptureCollector::default();
+                collector.visit_pat(other);
+                self.locals.extend(collector.bound.into_iter().map(|name| (name, None)));
+            }
+        }
+    }
+
+    fn check_spawn(&mut self, task: &syn::Expr) {
+        let task = match task {
+            syn::Expr::Paren(paren) => &*paren.expr,
+            other => other,
+        };
+        if !matches!(task, syn::Expr::Async(_) | syn::Expr::Closure(_)) {
+            return;
+        }
+
+        let mut collector = CaptureCollector::default();
+        collector.visit_expr(task);
+
+        let mut reported: Vec<String> = Vec::new();
+        for (name, span) in collector.used {
+            if collector.bound.contains(&name) || reported.contains(&name) {
+                continue;
+            }
+            let Some(ty) = self.non_send_local(&name).map(str::to_string) else {
+                continue;
+            };
+            let start = span.start();
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    self.checker.severity().into(),
+                    format!(
+                        "'{}' ({}) is captured by a spawned task, but {} is not Send, so the task cannot move to another thread.",
+                        name, ty, ty
+                    ),
+                    self.file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_suggestion(self.checker.suggestions()),
+            );
+            reported.push(name);
+        }
+    }
+}
+
+impl<'a> Visit<'a> for SpawnCaptureVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        let original_len = self.locals.len();
+        syn::visit::visit_item_fn(self, node);
+        self.locals.truncate(original_len);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        let original_len = self.locals.len();
+        syn::visit::visit_impl_item_fn(self, node);
+        self.locals.truncate(original_len);
+    }
+
+    fn visit_block(&mut self, node: &'a syn::Block) {
+        let original_len = self.locals.len();
+        syn::visit::visit_block(self, node);
+        self.locals.truncate(original_len);
+    }
+
+    fn visit_fn_arg(&mut self, node: &'a syn::FnArg) {
+        if let syn::FnArg::Typed(pat_type) = node {
+            let ty = self.non_send_in_type(&pat_type.ty);
+            self.bind_pat(&pat_type.pat, ty);
+        }
+        syn::visit::visit_fn_arg(self, node);
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        // Visit the initializer first so it is checked against the outer bindings
+        syn::visit::visit_local(self, node);
+        let ty = node.init.as_ref().and_then(|init| self.non_send_in_expr(&init.expr));
+        self.bind_pat(&node.pat, ty);
+    }
+
+    fn visit_expr_call(&mut self, node: &'a syn::ExprCall) {
+        if let syn::Expr::Path(path) = &*node.func {
+            if path.path.segments.last().is_some_and(|s| s.ident == "spawn") {
+                if let Some(task) = node.args.first() {
+                    self.check_spawn(task);
+                }
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        // handle.spawn(..), join_set.spawn(..), scope.spawn(..)
+        if node.method == "spawn" {
+            if let Some(task) = node.args.first() {
+                self.check_spawn(task);
+            }
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// Collects identifiers used inside a task body and the names bound within it
+#[derive(Default)]
+struct CaptureCollector {
+    used: Vec<(String, proc_macro2::Span)>,
+    bound: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for CaptureCollector {
+    fn visit_expr_path(&mut self, node: &'ast syn::ExprPath) {
+        if let Some(ident) = node.path.get_ident() {
+            self.used.push((ident.to_string(), node.span()));
+        }
+        syn::visit::visit_expr_path(self, node);
+    }
+
+    fn visit_pat_ident(&mut self, node: &'ast syn::PatIdent) {
+        self.bound.push(node.ident.to_string());
+        syn::visit::visit_pat_ident(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        // println!("{}", state) / format!("{state}"): macro arguments are plain tokens
+        self.collect_tokens(node.tokens.clone());
+    }
+}
+
+impl CaptureCollector {
+    fn collect_tokens(&mut self, tokens: proc_macro2::TokenStream) {
+        for token in tokens {
+            match token {
+                proc_macro2::TokenTree::Ident(ident) => self.used.push((ident.to_string(), ident.span())),
+                proc_macro2::TokenTree::Group(group) => self.collect_tokens(group.stream()),
+                proc_macro2::TokenTree::Literal(literal) => {
+                    let text = literal.to_string();
+                    for name in inline_format_args(&text) {
+                        self.used.push((name, literal.span()));
+                    }
+                }
+                proc_macro2::TokenTree::Punct(_) => {}
+            }
+        }
+    }
+}
+
+/// Names inlined in a format string literal (`"{state}"`, `"{state:?}"`)
+fn inline_format_args(literal: &str) -> Vec<String> {
+    if !literal.starts_with('"') {
+        return Vec::new();
+    }
+    literal
+        .replace("{{", "")
+        .split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split(['}', ':']).next())
+        .filter(|name| name.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+        .filter(|name| name.chars().all(|c| c.is_alphanumeric() || c == '_'))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1514NonSendSpawnCapture::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_rc_in_async_move() {
+        let code = r#"
+            async fn run() {
+                let state = Rc::new(State::default());
+                tokio::spawn(async move {
+                    state.tick().await;
+                    state.tick().await;
+                });
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1514");
+        assert!(violations[0].message.contains("'state' (Rc)"));
+    }
+
+    #[test]
+    fn test_detects_annotated_params_and_guards() {
+        let code = r#"
+            fn run(cache: &RefCell<Vec<u8>>, lock: &Mutex<u32>, raw: *mut u8) {
+                let guard = lock.lock().unwrap();
+                let shared: Vec<Rc<str>> = Vec::new();
+                std::thread::spawn(move || {
+                    cache.borrow_mut().push(*guard as u8);
+                    let _ = (raw, shared);
+                });
+            }
+        "#;
+        let violations = check_code(code);
+        let messages: Vec<_> = violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(violations.len(), 4, "{:?}", messages);
+        assert!(messages.iter().any(|m| m.contains("'cache' (RefCell)")));
+        assert!(messages.iter().any(|m| m.contains("'guard' (MutexGuard)")));
+        assert!(messages.iter().any(|m| m.contains("'raw' (*mut)")));
+        assert!(messages.iter().any(|m| m.contains("'shared' (Rc)")));
+    }
+
+    #[test]
+    fn test_send_types_and_shadowing_not_flagged() {
+        let code = r#"
+            async fn run(input: Rc<String>) {
+                let state = Arc::new(State::default());
+                let input = input.to_string();
+                tokio::spawn(async move {
+                    state.tick().await;
+                    println!("{}", input);
+                });
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_spawn_local_and_bound_names_not_flagged() {
+        let code = r#"
+            async fn run() {
+                let state = Rc::new(1);
+                tokio::task::spawn_local(async move { state.tick().await });
+                tokio::spawn(async move {
+                    let state = 5;
+                    state + 1
+                });
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_method_spawn_and_clone() {
+        let code = r#"
+            impl Server {
+                fn start(&self, set: &mut JoinSet<()>) {
+                    let counter = Rc::new(Cell::new(0));
+                    let counter2 = counter.clone();
+                    set.spawn(async move { counter2.set(1) });
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'counter2'"));
+    }
+
+    #[test]
+    fn test_custom_type_list() {
+        let code = r#"
+            fn run() {
+                let state = Rc::new(1);
+                let conn = Connection::new();
+                std::thread::spawn(move || { let _ = (state, conn); });
+            }
+        "#;
+        let checker = E1514NonSendSpawnCapture {
+            config: E1514Config {
+                non_send_types: vec!["Connection".to_string()],
+                ..E1514Config::default()
+            },
+        };
+        let syntax = syn::parse_file(code).unwrap();
+        let violations = checker.check_item(&syntax.items[0], "test.rs").unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'conn' (Connection)"));
+    }
+
+    #[test]
+    fn test_detects_capture_used_only_in_macros() {
+        let code = r#"
+            async fn run() {
+                let rc = Rc::new(1);
+                tokio::spawn(async move { println!("{}", rc); });
+                let cell = RefCell::new(0);
+                tokio::spawn(async move { println!("{cell:?}"); });
+                let shared = Arc::new(1);
+                tokio::spawn(async move { println!("{shared} {{rc}}"); });
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("'rc' (Rc)"));
+        assert!(violations[1].message.contains("'cell' (RefCell)"));
+    }
+}
@@ -0,0 +1,237 @@
+//! E1515: `RefCell` or `Cell` shared through `Arc`
+//!
+//! Detects the types `Arc<RefCell<_>>` / `Arc<Cell<_>>` (in signatures,
+//! fields, `let` annotations, aliases, ..) and the constructor
+//! `Arc::new(RefCell::new(..))` / `Arc::new(Cell::new(..))`. `RefCell` and
+//! `Cell` are not `Sync`, so the `Arc` is neither `Send` nor `Sync`: the
+//! first attempt to hand it to another thread fails with a long trait-bound
+//! error, far from where the type was chosen. On a single thread the atomic
+//! reference count buys nothing over `Rc`.
+//!
+//! A `let` whose annotation is reported does not report its constructor
+//! again.
+//!
+//! Example:
+//! ```text
+//! // Bad: cannot cross threads, and the atomic count is wasted on one
+//! let cache: Arc<RefCell<Vec<u8>>> = Arc::new(RefCell::new(Vec::new()));
+//!
+//! // Good: shared between threads
+//! let cache: Arc<Mutex<Vec<u8>>> = Arc::new(Mutex::new(Vec::new()));
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1515: RefCell or Cell shared through Arc
+    E1515ArcRefCell,
+    code = "E1515",
+    name = "RefCell or Cell shared through Arc",
+    suggestions = "Use Arc<Mutex<T>> or Arc<RwLock<T>> (or atomics instead of Cell) to share between threads, or Rc<RefCell<T>> if the value never leaves its thread",
+    rationale = "Arc exists to share a value between threads, but RefCell and Cell are not Sync, so Arc<RefCell<T>> is neither Send nor Sync and cannot actually be shared. It compiles until the first spawn, then fails with a trait-bound error far from where the type was chosen. On a single thread the atomic reference count is pure overhead over Rc.",
+    target_items = [Function, Struct, Enum, Impl, Trait, Type, Static, Const],
+    config_entry_name = "e1515_arc_ref_cell",
+    /// Configuration for E1515: RefCell or Cell shared through Arc checker
+    config = E1515Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = ArcRefCellVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            reported_constructor: None,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct ArcRefCellVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1515ArcRefCell,
+    /// Constructor of a `let` whose annotation was already reported
+    reported_constructor: Option<&'a syn::ExprCall>,
+}
+
+impl ArcRefCellVisitor<'_> {
+    fn report(&mut self, node: &dyn Spanned, cell: &str, form: &str) {
+        let (start, end) = (node.span().start(), node.span().end());
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "{} is neither Send nor Sync because {} is not Sync; it cannot be shared between threads.",
+                    form, cell
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_span(start, end)
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for ArcRefCellVisitor<'a> {
+    fn visit_type_path(&mut self, node: &'a syn::TypePath) {
+        if let Some(cell) = arc_of_cell_type(node) {
+            self.report(node, cell, &format!("Arc<{}<_>>", cell));
+        }
+        syn::visit::visit_type_path(self, node);
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        let before = self.violations.len();
+        self.visit_pat(&node.pat);
+        if let Some(init) = &node.init {
+            if let syn::Expr::Call(call) = &*init.expr {
+                if self.violations.len() > before {
+                    self.reported_constructor = Some(call);
+                }
+            }
+            self.visit_expr(&init.expr);
+            if let Some((_, diverge)) = &init.diverge {
+                self.visit_expr(diverge);
+            }
+        }
+    }
+
+    fn visit_expr_call(&mut self, node: &'a syn::ExprCall) {
+        let already_reported = self.reported_constructor.is_some_and(|call| std::ptr::eq(call, node));
+        if !already_reported {
+            if let Some(cell) = arc_of_cell_constructor(node) {
+                self.report(node, cell, &format!("Arc::new({}::new(..))", cell));
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+}
+
+/// Last segment identifiers of a path
+fn last_segments(path: &syn::Path, n: usize) -> Vec<String> {
+    let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    segments[segments.len().saturating_sub(n)..].to_vec()
+}
+
+/// `"RefCell"` / `"Cell"` if the type is `Arc<RefCell<_>>` / `Arc<Cell<_>>`
+fn arc_of_cell_type(node: &syn::TypePath) -> Option<&'static str> {
+    let arc = node.path.segments.last().filter(|segment| segment.ident == "Arc")?;
+    let syn::PathArguments::AngleBracketed(args) = &arc.arguments else {
+        return None;
+    };
+    let Some(syn::GenericArgument::Type(syn::Type::Path(inner))) = args.args.first() else {
+        return None;
+    };
+    cell_name(&inner.path.segments.last()?.ident.to_string())
+}
+
+/// `"RefCell"` / `"Cell"` if the call is `Arc::new(RefCell::new(..))` / `Arc::new(Cell::new(..))`
+fn arc_of_cell_constructor(node: &syn::ExprCall) -> Option<&'static str> {
+    let syn::Expr::Path(func) = &*node.func else {
+        return None;
+    };
+    if last_segments(&func.path, 2) != ["Arc", "new"] || node.args.len() != 1 {
+        return None;
+    }
+    let Some(syn::Expr::Call(inner)) = node.args.first() else {
+        return None;
+    };
+    let syn::Expr::Path(inner_func) = &*inner.func else {
+        return None;
+    };
+    match last_segments(&inner_func.path, 2).as_slice() {
+        [cell, new] if new == "new" => cell_name(cell),
+        _ => None,
+    }
+}
+
+fn cell_name(name: &str) -> Option<&'static str> {
+    match name {
+        "RefCell" => Some("RefCell"),
+        "Cell" => Some("Cell"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1515ArcRefCell::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_annotation_form() {
+        let code = r#"
+            struct Shared {
+                cache: std::sync::Arc<RefCell<Vec<u8>>>,
+            }
+
+            fn start(counter: Arc<Cell<u32>>) {}
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1515");
+        assert_eq!((violations[0].line, violations[0].column), (3, 24));
+        assert!(violations[0].message.starts_with("Arc<RefCell<_>> is neither Send nor Sync"));
+        assert!(violations[1].message.starts_with("Arc<Cell<_>> is neither Send nor Sync because Cell"));
+    }
+
+    #[test]
+    fn test_detects_constructor_form() {
+        let code = r#"
+            impl Worker {
+                fn new() -> Self {
+                    let state = Arc::new(std::cell::RefCell::new(State::default()));
+                    Worker { state }
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!((violations[0].line, violations[0].column), (4, 33));
+        assert!(violations[0].message.starts_with("Arc::new(RefCell::new(..))"));
+    }
+
+    #[test]
+    fn test_annotated_let_reported_once() {
+        let code = r#"
+            fn build() {
+                let cache: Arc<RefCell<Vec<u8>>> = Arc::new(RefCell::new(Vec::new()));
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.starts_with("Arc<RefCell<_>>"));
+    }
+
+    #[test]
+    fn test_thread_safe_and_single_thread_sharing_pass() {
+        let code = r#"
+            fn build() -> (Arc<Mutex<u32>>, Rc<RefCell<u32>>, Arc<AtomicU32>) {
+                let local = Rc::new(RefCell::new(0));
+                (Arc::new(Mutex::new(0)), local, Arc::new(AtomicU32::new(0)))
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
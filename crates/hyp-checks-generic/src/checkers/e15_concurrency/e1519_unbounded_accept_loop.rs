@@ -0,0 +1,233 @@
+//! E1519: Unbounded spawning in an accept/receive loop
+//!
+//! Detects task or thread spawns inside `loop { .. }` and `while let .. { .. }`
+//! bodies that contain no bounding primitive. These loops typically accept
+//! connections or drain a channel, so the number of live tasks follows the
+//! incoming rate with nothing to push back. E1511 reports spawns in any loop;
+//! this checker is the narrower, stronger signal for server-style loops and is
+//! silenced once a semaphore, `JoinSet` or bounded channel appears in the loop.
+//!
+//! Example:
+//! ```text
+//! // Bad: one task per connection, no limit
+//! loop {
+//!     let (socket, _) = listener.accept().await?;
+//!     tokio::spawn(handle(socket));
+//! }
+//!
+//! // Good: a permit is needed before each spawn
+//! loop {
+//!     let permit = limit.clone().acquire_owned().await?;
+//!     let (socket, _) = listener.accept().await?;
+//!     tokio::spawn(async move { handle(socket).await; drop(permit) });
+//! }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1519: Unbounded spawning in an accept/receive loop
+    E1519UnboundedAcceptLoop,
+    code = "E1519",
+    name = "Unbounded spawning in accept/receive loop",
+    suggestions = "Acquire a Semaphore permit before spawning, track tasks in a JoinSet and cap its size, or hand work to a bounded channel",
+    rationale = "A loop that accepts connections or receives messages and spawns a task for each one has no limit on concurrent work. A traffic spike or a slow downstream dependency lets tasks pile up until memory runs out, and every request slows down together. Bounding the number of in-flight tasks applies backpressure to clients instead.",
+    target_items = [Function, Impl],
+    config_entry_name = "e1519_unbounded_accept_loop",
+    /// Configuration for E1519: Unbounded accept loop checker
+    config = E1519Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::High,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Identifiers (types, functions or methods) whose presence in the loop bounds concurrency
+        bounding_primitives: Vec<String> = vec![
+            "Semaphore".to_string(),
+            "acquire".to_string(),
+            "acquire_owned".to_string(),
+            "try_acquire".to_string(),
+            "JoinSet".to_string(),
+            "join_next".to_string(),
+            "buffer_unordered".to_string(),
+            "for_each_concurrent".to_string(),
+            "sync_channel".to_string(),
+            "bounded".to_string(),
+        ],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = AcceptLoopVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            loops: Vec::new(),
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct AcceptLoopVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1519UnboundedAcceptLoop,
+    /// For each enclosing `loop`/`while let`: whether it contains a bounding primitive
+    loops: Vec<bool>,
+}
+
+impl<'a> AcceptLoopVisitor<'a> {
+    fn visit_loop(&mut self, node: &'a syn::Expr, visit: impl FnOnce(&mut Self)) {
+        let mut finder = BoundFinder {
+            primitives: &self.checker.config.bounding_primitives,
+            found: false,
+        };
+        finder.visit_expr(node);
+        self.loops.push(finder.found);
+        visit(self);
+        self.loops.pop();
+    }
+
+    fn report(&mut self, span: proc_macro2::Span, what: &str) {
+        let start = span.start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "'{}' inside a loop with no concurrency bound: one task per accepted connection or received message, with no limit.",
+                    what
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+
+    /// Whether the innermost `loop`/`while let` around the current node is unbounded
+    fn in_unbounded_loop(&self) -> bool {
+        self.loops.last() == Some(&false)
+    }
+}
+
+impl<'a> Visit<'a> for AcceptLoopVisitor<'a> {
+    fn visit_expr(&mut self, node: &'a syn::Expr) {
+        match node {
+            syn::Expr::Loop(_) => self.visit_loop(node, |visitor| syn::visit::visit_expr(visitor, node)),
+            syn::Expr::While(while_loop) if matches!(*while_loop.cond, syn::Expr::Let(_)) => {
+                self.visit_loop(node, |visitor| syn::visit::visit_expr(visitor, node))
+            }
+            _ => syn::visit::visit_expr(self, node),
+        }
+    }
+
+    fn visit_expr_call(&mut self, node: &'a syn::ExprCall) {
+        if self.in_unbounded_loop() {
+            if let syn::Expr::Path(path) = &*node.func {
+                if let Some(last) = path.path.segments.last().filter(|s| is_spawn(&s.ident)) {
+                    self.report(node.span(), &last.ident.to_string());
+                }
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if self.in_unbounded_loop() && is_spawn(&node.method) {
+            self.report(node.span(), &node.method.to_string());
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+fn is_spawn(ident: &syn::Ident) -> bool {
+    ident == "spawn" || ident == "spawn_blocking" || ident == "spawn_local"
+}
+
+/// Looks for any configured bounding identifier in paths and method names
+struct BoundFinder<'a> {
+    primitives: &'a [String],
+    found: bool,
+}
+
+impl<'a> Visit<'a> for BoundFinder<'a> {
+    fn visit_ident(&mut self, node: &'a proc_macro2::Ident) {
+        if self.primitives.iter().any(|primitive| node == primitive) {
+            self.found = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1519UnboundedAcceptLoop::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_accept_and_receive_loops() {
+        let code = r#"
+            async fn serve(listener: TcpListener) {
+                loop {
+                    let (socket, _) = listener.accept().await.unwrap();
+                    tokio::spawn(handle(socket));
+                }
+            }
+
+            fn drain(rx: Receiver<Job>, pool: &Runtime) {
+                while let Ok(job) = rx.recv() {
+                    pool.spawn(run(job));
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1519");
+        assert!(violations[0].message.contains("'spawn'"));
+        assert_eq!((violations[0].line, violations[0].column), (5, 21));
+        assert_eq!(violations[1].line, 11);
+    }
+
+    #[test]
+    fn test_bounded_loops_and_for_loops_pass() {
+        let code = r#"
+            async fn serve(listener: TcpListener, limit: Arc<Semaphore>) {
+                loop {
+                    let permit = limit.clone().acquire_owned().await.unwrap();
+                    let (socket, _) = listener.accept().await.unwrap();
+                    tokio::spawn(async move { handle(socket).await; drop(permit); });
+                }
+            }
+
+            async fn drain(mut rx: Receiver<Job>) {
+                let mut tasks = JoinSet::new();
+                while let Some(job) = rx.recv().await {
+                    while tasks.len() >= 8 {
+                        tasks.join_next().await;
+                    }
+                    tasks.spawn(run(job));
+                }
+            }
+
+            fn batch(items: Vec<Job>) {
+                for item in items {
+                    std::thread::spawn(move || run(item));
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,284 @@
+//! E1520: Unbounded channel created
+//!
+//! Detects calls to unbounded channel constructors such as
+//! `std::sync::mpsc::channel()` and `tokio::sync::mpsc::unbounded_channel()`.
+//! An unbounded channel never pushes back on the sender: when the receiver
+//! falls behind, messages pile up in memory until the process runs out.
+//!
+//! Call paths are resolved through the file's `use` declarations, so
+//! `use std::sync::mpsc; mpsc::channel()` and `use std::sync::mpsc::channel;
+//! channel()` are both recognized. Files where an unbounded channel is
+//! intentional can be allowlisted with `allowed_files` regexes.
+//!
+//! Example:
+//! ```text
+//! // Bad: the queue grows without limit if the consumer is slow
+//! let (tx, rx) = std::sync::mpsc::channel();
+//!
+//! // Good: senders block once 64 messages are queued
+//! let (tx, rx) = std::sync::mpsc::sync_channel(64);
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation, AnalyzerError};
+
+use regex::Regex;
+use std::collections::HashMap;
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1520: Unbounded channel created
+    E1520UnboundedChannel,
+    code = "E1520",
+    name = "Unbounded channel created",
+    suggestions = "Use a bounded channel so senders wait when the receiver falls behind: std::sync::mpsc::sync_channel(n) or tokio::sync::mpsc::channel(n)",
+    rationale = "An unbounded channel never makes the sender wait, so when the consumer falls behind the queue grows without limit. The first symptom is usually memory growth and latency under load, long after the code was written. A bounded channel makes producers slow down to the consumer's pace.",
+    target_items = [Function, Impl],
+    config_entry_name = "e1520_unbounded_channel",
+    /// Configuration for E1520: Unbounded channel checker
+    config = E1520Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Full paths of unbounded channel constructors. A call written with a shorter
+        /// path (`mpsc::channel`) matches when it keeps at least the last two segments
+        unbounded_paths: Vec<String> = vec![
+            "std::sync::mpsc::channel".to_string(),
+            "tokio::sync::mpsc::unbounded_channel".to_string(),
+        ],
+        /// Regexes matched against the file path; matching files are not checked
+        allowed_files: Vec<String> = Vec::new(),
+    },
+    check_item(self, _item, _file_path) {
+        // Call paths are resolved through the file's `use` declarations, see check_file
+        Ok(Vec::new())
+    }
+    check_file(self, file, file_path) {
+        let normalized_path = file_path.replace('\\', "/");
+        let allowed = self
+            .config
+            .allowed_files
+            .iter()
+            .map(|p| Regex::new(p).map_err(|e| AnalyzerError::Config(format!("Invalid file pattern '{}': {}", p, e))))
+            .collect::<Result<Vec<_>, _>>()?
+            .iter()
+            .any(|re| re.is_match(&normalized_path));
+        if allowed {
+            return Ok(Vec::new());
+        }
+
+        let mut imports = ImportCollector { imports: HashMap::new() };
+        imports.visit_file(file);
+
+        let mut visitor = ChannelVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            imports: imports.imports,
+        };
+        visitor.visit_file(file);
+        Ok(visitor.violations)
+    }
+}
+
+/// Maps names brought into scope by `use` to the full path they stand for
+struct ImportCollector {
+    imports: HashMap<String, String>,
+}
+
+impl ImportCollector {
+    fn collect(&mut self, tree: &syn::UseTree, prefix: &str) {
+        let join = |name: &str| {
+            if prefix.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}::{}", prefix, name)
+            }
+        };
+        match tree {
+            syn::UseTree::Path(path) => self.collect(&path.tree, &join(&path.ident.to_string())),
+            syn::UseTree::Name(name) if name.ident == "self" => {
+                if let Some(last) = prefix.rsplit("::").next() {
+                    self.imports.insert(last.to_string(), prefix.to_string());
+                }
+            }
+            syn::UseTree::Name(name) => {
+                self.imports.insert(name.ident.to_string(), join(&name.ident.to_string()));
+            }
+            syn::UseTree::Rename(rename) => {
+                let target = if rename.ident == "self" {
+                    prefix.to_string()
+                } else {
+                    join(&rename.ident.to_string())
+                };
+                self.imports.insert(rename.rename.to_string(), target);
+            }
+            syn::UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.collect(tree, prefix);
+                }
+            }
+            syn::UseTree::Glob(_) => {}
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ImportCollector {
+    fn visit_item_use(&mut self, node: &'a syn::ItemUse) {
+        self.collect(&node.tree, "");
+    }
+}
+
+struct ChannelVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1520UnboundedChannel,
+    imports: HashMap<String, String>,
+}
+
+impl<'a> ChannelVisitor<'a> {
+    /// The configured unbounded constructor a call path refers to, if any
+    fn unbounded_constructor(&self, path: &syn::Path) -> Option<&'a str> {
+        let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+        let (first, rest) = segments.split_first()?;
+        let resolved = match self.imports.get(first) {
+            Some(full) => std::iter::once(full.clone()).chain(rest.iter().cloned()).collect::<Vec<_>>().join("::"),
+            None => segments.join("::"),
+        };
+        let resolved_len = resolved.split("::").count();
+
+        self.checker
+            .config
+            .unbounded_paths
+            .iter()
+            .find(|candidate| {
+                **candidate == resolved
+                    || (resolved_len >= 2 && candidate.ends_with(&format!("::{}", resolved)))
+            })
+            .map(String::as_str)
+    }
+}
+
+impl<'a> Visit<'a> for ChannelVisitor<'a> {
+    fn visit_expr_call(&mut self, node: &'a syn::ExprCall) {
+        if let syn::Expr::Path(path) = &*node.func {
+            if let Some(constructor) = self.unbounded_constructor(&path.path) {
+                let start = node.span().start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "Unbounded {} channel created with {}(): messages queue up without limit when the receiver falls behind. Use {} instead.",
+                            channel_kind(constructor),
+                            constructor,
+                            bounded_alternative(constructor)
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_span(start, node.span().end())
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+}
+
+/// The library a constructor path belongs to (`std`, `tokio`, ...)
+fn channel_kind(constructor: &str) -> &str {
+    constructor.split("::").next().unwrap_or(constructor)
+}
+
+/// The bounded counterpart of an unbounded constructor, for the violation message
+fn bounded_alternative(constructor: &str) -> String {
+    match constructor.rsplit_once("::") {
+        Some((module, "unbounded_channel")) => format!("{}::channel(n)", module),
+        Some((module, "channel")) if constructor.starts_with("std::") => format!("{}::sync_channel(n)", module),
+        _ => "a bounded channel".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::Checker;
+
+    fn check_code_with(checker: &E1520UnboundedChannel, code: &str, path: &str) -> Vec<Violation> {
+        let file = syn::parse_file(code).unwrap();
+        checker.check_file(&file, path).unwrap()
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_code_with(&E1520UnboundedChannel::default(), code, "src/lib.rs")
+    }
+
+    #[test]
+    fn test_detects_std_channels() {
+        let code = r#"
+            use std::sync::mpsc;
+            use std::sync::mpsc::channel as make_channel;
+
+            fn a() {
+                let (tx, rx) = std::sync::mpsc::channel::<u32>();
+                let (tx, rx) = mpsc::channel();
+                let (tx, rx) = make_channel();
+                let (tx, rx) = mpsc::sync_channel(8);
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 3);
+        assert_eq!(violations[0].code, "E1520");
+        assert_eq!((violations[0].line, violations[0].column), (6, 32));
+        assert!(violations[0].message.contains("Unbounded std channel created with std::sync::mpsc::channel()"));
+        assert!(violations[0].message.contains("std::sync::mpsc::sync_channel(n)"));
+        assert_eq!(violations[2].line, 8);
+    }
+
+    #[test]
+    fn test_detects_tokio_unbounded_channel() {
+        let code = r#"
+            use tokio::sync::mpsc::{self, unbounded_channel};
+
+            async fn a() {
+                let (tx, rx) = unbounded_channel::<String>();
+                let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+                let (tx, rx) = mpsc::channel::<String>(16);
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("Unbounded tokio channel"));
+        assert!(violations[0].message.contains("tokio::sync::mpsc::channel(n)"));
+    }
+
+    #[test]
+    fn test_unresolved_bare_name_and_allowed_files_pass() {
+        // A bare `channel` that was not imported could be anything
+        assert!(check_code("fn a() { let c = channel(); }").is_empty());
+
+        let checker = E1520UnboundedChannel {
+            config: E1520Config {
+                allowed_files: vec!["^src/bin/".to_string()],
+                ..E1520Config::default()
+            },
+        };
+        let code = "fn a() { let (tx, rx) = std::sync::mpsc::channel::<u8>(); }";
+        assert!(check_code_with(&checker, code, "src/bin/tool.rs").is_empty());
+        assert_eq!(check_code_with(&checker, code, "src/lib.rs").len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_allowed_file_is_an_error() {
+        let mut checker = E1520UnboundedChannel::default();
+        checker.config.allowed_files.push("src/[bin".to_string());
+        let file = syn::parse_file("fn f() {}").unwrap();
+        let err = checker.check_file(&file, "src/lib.rs").unwrap_err();
+        assert!(err.to_string().contains("Invalid file pattern 'src/[bin'"));
+    }
+}
@@ -0,0 +1,432 @@
+//! E1521: Arc cloned on every loop iteration without being moved
+//!
+//! Detects `let handle = Arc::clone(&shared);` / `let handle = shared.clone();`
+//! inside a loop body, where `shared` is known to be an `Arc` (from a type
+//! annotation or an `Arc::new(..)` initializer), and the clone is never moved
+//! out of the iteration. Each clone is an atomic increment and each drop an
+//! atomic decrement; when the handle is only used by reference the original
+//! `Arc` can be borrowed instead, or cloned once before the loop.
+//!
+//! A clone is considered moved (and not reported) when the binding is captured
+//! by a `move` closure or `async move` block (e.g. a spawned task), or passed by
+//! value to a call, method call or struct literal in the same loop body.
+//! `shared.clone().method()` inside a loop is always reported.
+//!
+//! Example:
+//! ```text
+//! // Bad: one atomic inc/dec pair per item, the clone only lends access
+//! for item in items { let cache = Arc::clone(&cache); cache.insert(item); }
+//!
+//! // Good: borrow the Arc; clone only when a task takes ownership
+//! for item in items { cache.insert(item); }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1521: Arc cloned on every loop iteration without being moved
+    E1521ArcCloneInLoop,
+    code = "E1521",
+    name = "Arc cloned in loop without being moved",
+    suggestions = "Borrow the Arc inside the loop (`&shared` or `shared.method()`), or clone it once before the loop; clone per iteration only when the handle is moved into a task",
+    rationale = "Each Arc::clone is an atomic increment and each drop an atomic decrement; under contention those atomics bounce a cache line between cores. Cloning on every loop iteration when the clone is only borrowed pays that cost for nothing. Clone per iteration only when the handle is moved into something that outlives the iteration.",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1521_arc_clone_in_loop",
+    /// Configuration for E1521: Arc clone in loop checker
+    config = E1521Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = ArcCloneVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            arc_locals: Vec::new(),
+            loop_depth: 0,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct ArcCloneVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1521ArcCloneInLoop,
+    /// Bindings in scope and whether they hold an `Arc` (later entries shadow earlier ones)
+    arc_locals: Vec<(String, bool)>,
+    loop_depth: usize,
+}
+
+impl<'a> ArcCloneVisitor<'a> {
+    fn is_arc_local(&self, name: &str) -> bool {
+        self.arc_locals
+            .iter()
+            .rev()
+            .find(|(local, _)| local == name)
+            .is_some_and(|(_, is_arc)| *is_arc)
+    }
+
+    /// The `Arc` local cloned by `Arc::clone(&x)` or `x.clone()`, if any
+    fn arc_clone_source(&self, expr: &syn::Expr) -> Option<String> {
+        let source = match expr {
+            syn::Expr::Call(call) if call.args.len() == 1 && is_arc_path(&call.func, "clone") => {
+                match call.args.first() {
+                    Some(syn::Expr::Reference(reference)) => &*reference.expr,
+                    _ => return None,
+                }
+            }
+            syn::Expr::MethodCall(call) if call.method == "clone" && call.args.is_empty() => &*call.receiver,
+            syn::Expr::Paren(paren) => return self.arc_clone_source(&paren.expr),
+            _ => return None,
+        };
+        let syn::Expr::Path(path) = source else {
+            return None;
+        };
+        let name = path.path.get_ident()?.to_string();
+        self.is_arc_local(&name).then_some(name)
+    }
+
+    fn bind_pat(&mut self, pat: &syn::Pat, is_arc: bool) {
+        match pat {
+            syn::Pat::Ident(pat_ident) => self.arc_locals.push((pat_ident.ident.to_string(), is_arc)),
+            syn::Pat::Type(pat_type) => self.bind_pat(&pat_type.pat, is_arc || is_arc_type(&pat_type.ty)),
+            syn::Pat::Reference(reference) => self.bind_pat(&reference.pat, is_arc),
+            other => {
+                // Destructuring: bind every name as non-Arc so it shadows outer locals
+                let mut collector = BindingCollector::default();
+                collector.visit_pat(other);
+                self.arc_locals.extend(collector.bound.into_iter().map(|name| (name, false)));
+            }
+        }
+    }
+
+    /// Report `let handle = <Arc clone>;` statements of a loop body whose binding never leaves the iteration
+    fn check_loop_body(&mut self, body: &syn::Block) {
+        let original_len = self.arc_locals.len();
+        for stmt in &body.stmts {
+            let syn::Stmt::Local(local) = stmt else {
+                continue;
+            };
+            if let (Some(init), syn::Pat::Ident(binding)) = (&local.init, &local.pat) {
+                if let Some(source) = self.arc_clone_source(&init.expr) {
+                    let name = binding.ident.to_string();
+                    let mut moves = MoveFinder { name: &name, moved: false };
+                    moves.visit_block(body);
+                    if !moves.moved {
+                        self.report(
+                            init.expr.span(),
+                            format!(
+                                "'{}' clones the Arc '{}' on every iteration but is never moved out of the loop body; each clone is an atomic increment and decrement.",
+                                name, source
+                            ),
+                        );
+                    }
+                }
+            }
+            // Track shadowing so later statements see the right bindings
+            self.bind_local(local);
+        }
+        self.arc_locals.truncate(original_len);
+    }
+
+    fn bind_local(&mut self, local: &syn::Local) {
+        let is_arc = local.init.as_ref().is_some_and(|init| {
+            matches!(&*init.expr, syn::Expr::Call(call) if is_arc_path(&call.func, "new"))
+                || self.arc_clone_source(&init.expr).is_some()
+        });
+        self.bind_pat(&local.pat, is_arc);
+    }
+
+    fn report(&mut self, span: proc_macro2::Span, message: String) {
+        let start = span.start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                message,
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+
+    fn visit_loop_body(&mut self, body: &syn::Block, visit: impl FnOnce(&mut Self)) {
+        self.loop_depth += 1;
+        self.check_loop_body(body);
+        visit(self);
+        self.loop_depth -= 1;
+    }
+}
+
+impl<'a> Visit<'a> for ArcCloneVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        let original_len = self.arc_locals.len();
+        syn::visit::visit_item_fn(self, node);
+        self.arc_locals.truncate(original_len);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        let original_len = self.arc_locals.len();
+        syn::visit::visit_impl_item_fn(self, node);
+        self.arc_locals.truncate(original_len);
+    }
+
+    fn visit_block(&mut self, node: &'a syn::Block) {
+        let original_len = self.arc_locals.len();
+        syn::visit::visit_block(self, node);
+        self.arc_locals.truncate(original_len);
+    }
+
+    fn visit_fn_arg(&mut self, node: &'a syn::FnArg) {
+        if let syn::FnArg::Typed(pat_type) = node {
+            self.bind_pat(&pat_type.pat, is_arc_type(&pat_type.ty));
+        }
+        syn::visit::visit_fn_arg(self, node);
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        syn::visit::visit_local(self, node);
+        self.bind_local(node);
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'a syn::ExprForLoop) {
+        self.visit_loop_body(&node.body, |visitor| syn::visit::visit_expr_for_loop(visitor, node));
+    }
+
+    fn visit_expr_while(&mut self, node: &'a syn::ExprWhile) {
+        self.visit_loop_body(&node.body, |visitor| syn::visit::visit_expr_while(visitor, node));
+    }
+
+    fn visit_expr_loop(&mut self, node: &'a syn::ExprLoop) {
+        self.visit_loop_body(&node.body, |visitor| syn::visit::visit_expr_loop(visitor, node));
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        // shared.clone().method(): the clone only lives for one call
+        if self.loop_depth > 0 {
+            if let Some(source) = self.arc_clone_source(&node.receiver) {
+                self.report(
+                    node.receiver.span(),
+                    format!(
+                        "The Arc '{}' is cloned on every iteration only to call '.{}()' on the clone; call it on '{}' directly.",
+                        source, node.method, source
+                    ),
+                );
+            }
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_closure(&mut self, node: &'a syn::ExprClosure) {
+        // A closure body runs on its own schedule, not once per iteration of the enclosing loop
+        let loop_depth = std::mem::replace(&mut self.loop_depth, 0);
+        syn::visit::visit_expr_closure(self, node);
+        self.loop_depth = loop_depth;
+    }
+}
+
+/// Whether `func` is the path `Arc::<method>` (optionally qualified, e.g. `std::sync::Arc::clone`)
+fn is_arc_path(func: &syn::Expr, method: &str) -> bool {
+    let syn::Expr::Path(path) = func else {
+        return false;
+    };
+    let segments: Vec<_> = path.path.segments.iter().collect();
+    matches!(segments.as_slice(), [.., owner, last] if owner.ident == "Arc" && last.ident == method)
+}
+
+/// Whether a type is `Arc<..>` or a reference to one
+fn is_arc_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path.path.segments.last().is_some_and(|segment| segment.ident == "Arc"),
+        syn::Type::Reference(reference) => is_arc_type(&reference.elem),
+        syn::Type::Paren(paren) => is_arc_type(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Whether an expression is exactly the path `name`
+fn is_name(expr: &syn::Expr, name: &str) -> bool {
+    matches!(expr, syn::Expr::Path(path) if path.path.is_ident(name))
+}
+
+/// Finds whether a binding is moved out of a loop body
+struct MoveFinder<'n> {
+    name: &'n str,
+    moved: bool,
+}
+
+impl MoveFinder<'_> {
+    fn mentions(&self, node: impl FnOnce(&mut NameFinder<'_>)) -> bool {
+        let mut finder = NameFinder { name: self.name, found: false };
+        node(&mut finder);
+        finder.found
+    }
+}
+
+impl<'ast> Visit<'ast> for MoveFinder<'_> {
+    fn visit_expr_closure(&mut self, node: &'ast syn::ExprClosure) {
+        if node.capture.is_some() && self.mentions(|finder| finder.visit_expr(&node.body)) {
+            self.moved = true;
+        }
+        syn::visit::visit_expr_closure(self, node);
+    }
+
+    fn visit_expr_async(&mut self, node: &'ast syn::ExprAsync) {
+        if node.capture.is_some() && self.mentions(|finder| finder.visit_block(&node.block)) {
+            self.moved = true;
+        }
+        syn::visit::visit_expr_async(self, node);
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast syn::ExprCall) {
+        if node.args.iter().any(|arg| is_name(arg, self.name)) {
+            self.moved = true;
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast syn::ExprMethodCall) {
+        if node.args.iter().any(|arg| is_name(arg, self.name)) {
+            self.moved = true;
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_struct(&mut self, node: &'ast syn::ExprStruct) {
+        if node.fields.iter().any(|field| is_name(&field.expr, self.name)) {
+            self.moved = true;
+        }
+        syn::visit::visit_expr_struct(self, node);
+    }
+}
+
+/// Finds any use of a name
+struct NameFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for NameFinder<'_> {
+    fn visit_expr_path(&mut self, node: &'ast syn::ExprPath) {
+        if node.path.is_ident(self.name) {
+            self.found = true;
+        }
+        syn::visit::visit_expr_path(self, node);
+    }
+}
+
+/// Collects the names bound by a pattern
+#[derive(Default)]
+struct BindingCollector {
+    bound: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for BindingCollector {
+    fn visit_pat_ident(&mut self, node: &'ast syn::PatIdent) {
+        self.bound.push(node.ident.to_string());
+        syn::visit::visit_pat_ident(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1521ArcCloneInLoop::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_clone_used_by_reference() {
+        let code = r#"
+            fn fill(cache: Arc<Cache>, items: Vec<Item>) {
+                let stats = Arc::new(Stats::default());
+                for item in items {
+                    let cache = Arc::clone(&cache);
+                    cache.insert(item);
+                    let stats = stats.clone();
+                    stats.record();
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1521");
+        assert_eq!(violations[0].severity, crate::violation::Severity::Low);
+        assert_eq!((violations[0].line, violations[0].column), (5, 33));
+        assert!(violations[0].message.contains("'cache' clones the Arc 'cache'"));
+        assert!(violations[1].message.contains("'stats' clones the Arc 'stats'"));
+    }
+
+    #[test]
+    fn test_detects_clone_as_receiver() {
+        let code = r#"
+            impl Pool {
+                fn run(&self, shared: &Arc<Mutex<u32>>) {
+                    loop {
+                        *shared.clone().lock().unwrap() += 1;
+                    }
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("only to call '.lock()'"));
+    }
+
+    #[test]
+    fn test_moved_clones_pass() {
+        let code = r#"
+            async fn serve(state: Arc<State>, listener: Listener) {
+                let mut handles = Vec::new();
+                loop {
+                    let state = Arc::clone(&state);
+                    tokio::spawn(async move { state.handle().await });
+                    let worker_state = state.clone();
+                    std::thread::spawn(move || worker_state.work());
+                    let pushed = state.clone();
+                    handles.push(pushed);
+                    let owned = state.clone();
+                    let _ = Worker { state: owned };
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_non_arc_and_outside_loop_pass() {
+        let code = r#"
+            fn run(names: Vec<String>, shared: Rc<State>) {
+                let state = Arc::new(State::default());
+                let once = state.clone();
+                for name in &names {
+                    let copy = name.clone();
+                    let rc = shared.clone();
+                    rc.touch(&copy);
+                    let state = 5;
+                    let n = state.clone();
+                }
+                once.touch();
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
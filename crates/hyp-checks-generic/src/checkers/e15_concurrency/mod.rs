@@ -9,6 +9,11 @@ pub mod e1510_mutex_instead_of_rwlock;
 pub mod e1511_unbounded_spawning;
 pub mod e1512_prohibit_std_thread_spawn;
 pub mod e1513_blocking_syscalls_async;
+pub mod e1514_non_send_spawn_capture;
+pub mod e1515_arc_ref_cell;
+pub mod e1519_unbounded_accept_loop;
+pub mod e1520_unbounded_channel;
+pub mod e1521_arc_clone_in_loop;
 pub mod registry;
 
 pub use e1502_lock_across_await::{E1502Config, E1502LockAcrossAwait};
@@ -20,3 +25,8 @@ pub use e1510_mutex_instead_of_rwlock::{E1510Config, E1510MutexInsteadOfRwLock};
 pub use e1511_unbounded_spawning::{E1511Config, E1511UnboundedSpawning};
 pub use e1512_prohibit_std_thread_spawn::{E1512Config, E1512ProhibitStdThreadSpawn};
 pub use e1513_blocking_syscalls_async::{E1513BlockingSyscallsAsync, E1513Config};
+pub use e1514_non_send_spawn_capture::{E1514Config, E1514NonSendSpawnCapture};
+pub use e1519_unbounded_accept_loop::{E1519Config, E1519UnboundedAcceptLoop};
+pub use e1520_unbounded_channel::{E1520Config, E1520UnboundedChannel};
+pub use e1521_arc_clone_in_loop::{E1521Config, E1521ArcCloneInLoop};
+pub use e1515_arc_ref_cell::{E1515Config, E1515ArcRefCell};
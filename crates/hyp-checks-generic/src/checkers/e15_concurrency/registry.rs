@@ -7,6 +7,11 @@ use crate::{
         E1506DeadlockLockOrdering, E1508Config, E1508SleepInsteadOfSync, E1509ChannelLifetime,
         E1509Config, E1510Config, E1510MutexInsteadOfRwLock, E1511Config, E1511UnboundedSpawning,
         E1512Config, E1512ProhibitStdThreadSpawn, E1513BlockingSyscallsAsync, E1513Config,
+        E1514Config, E1514NonSendSpawnCapture,
+        E1519Config, E1519UnboundedAcceptLoop,
+        E1520Config, E1520UnboundedChannel,
+        E1521Config, E1521ArcCloneInLoop,
+        E1515Config, E1515ArcRefCell,
     },
     register_checker,
     registry::CheckerRegistration,
@@ -24,5 +29,10 @@ pub fn e15_registrations() -> Vec<CheckerRegistration> {
         register_checker!(E1511UnboundedSpawning, E1511Config),
         register_checker!(E1512ProhibitStdThreadSpawn, E1512Config),
         register_checker!(E1513BlockingSyscallsAsync, E1513Config),
+        register_checker!(E1514NonSendSpawnCapture, E1514Config),
+        register_checker!(E1519UnboundedAcceptLoop, E1519Config),
+        register_checker!(E1520UnboundedChannel, E1520Config),
+        register_checker!(E1521ArcCloneInLoop, E1521Config),
+        register_checker!(E1515ArcRefCell, E1515Config),
     ]
 }
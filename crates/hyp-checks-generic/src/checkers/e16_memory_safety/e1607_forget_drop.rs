@@ -1,26 +1,58 @@
 //! E1607: Forgetting to drop
 //!
-//! Detects use of mem::forget which prevents destructors from running,
-//! potentially leaking resources.
+//! Detects `std::mem::forget` / `core::mem::forget` calls, which prevent
+//! destructors from running and leak whatever the value owns (memory, file
+//! handles, locks). Outside FFI, where ownership is deliberately handed to
+//! foreign code, this is almost always a bug.
+//!
+//! `ManuallyDrop::new(..)` is the same leak when nothing releases the value
+//! again, so it is reported when the enclosing function has no
+//! `ManuallyDrop::drop`, `ManuallyDrop::take` or `ManuallyDrop::into_inner`
+//! call. Files matching `allowed_paths` (e.g. FFI modules) are not checked.
+//!
+//! Example:
+//! ```text
+//! // Bad: the Vec's buffer is never freed
+//! std::mem::forget(data);
+//!
+//! // Good
+//! drop(data);
+//! ```
 
-use crate::{checker::Checker, define_checker, violation::Violation};
+use crate::{checker::Checker, define_checker, violation::Violation, AnalyzerError};
 
-use syn::visit::Visit;
+use regex::Regex;
+use syn::{spanned::Spanned, visit::Visit};
 
 define_checker! {
     /// Checker for E1607: Forgetting to drop
     E1607ForgetDrop,
     code = "E1607",
     name = "Using mem::forget to leak resources",
-    suggestions = "Let values drop normally, or use ManuallyDrop for explicit control over destruction",
-    target_items = [Function],
+    suggestions = "Call drop() instead; if leaking is intentional (e.g. ownership passed to FFI), say why in a comment and add the file to allowed_paths",
+    target_items = [Function, Impl, Trait],
     config_entry_name = "e1607_forget_drop",
     config = E1607Config {
         enabled: bool = true,
         severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
         categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Regex patterns for file paths where leaking is allowed, e.g. FFI modules
+        allowed_paths: Vec<String> = Vec::new(),
     },
     check_item(self, item, file_path) {
+        let normalized_path = file_path.replace('\\', "/");
+        let allowed = self
+            .config
+            .allowed_paths
+            .iter()
+            .map(|p| Regex::new(p).map_err(|e| AnalyzerError::Config(format!("Invalid path pattern '{}': {}", p, e))))
+            .collect::<Result<Vec<_>, _>>()?
+            .iter()
+            .any(|re| re.is_match(&normalized_path));
+        if allowed {
+            return Ok(Vec::new());
+        }
+
         let mut visitor = ForgetVisitor {
             violations: Vec::new(),
             file_path,
@@ -37,44 +69,90 @@ struct ForgetVisitor<'a> {
     checker: &'a E1607ForgetDrop,
 }
 
+impl ForgetVisitor<'_> {
+    fn check_body(&mut self, body: &syn::Block) {
+        let mut calls = LeakCalls::default();
+        calls.visit_block(body);
+
+        for call in calls.forgets {
+            self.report(call, "mem::forget prevents the destructor from running, leaking whatever the value owns.");
+        }
+        if !calls.released {
+            for call in calls.manually_drops {
+                self.report(
+                    call,
+                    "ManuallyDrop::new without ManuallyDrop::drop, take or into_inner in this function leaks the value.",
+                );
+            }
+        }
+    }
+
+    fn report(&mut self, call: &syn::ExprCall, message: &str) {
+        let start = call.span().start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                message,
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_span(start, call.span().end())
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
 impl<'a> Visit<'a> for ForgetVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.check_body(&node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.check_body(&node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'a syn::TraitItemFn) {
+        if let Some(body) = &node.default {
+            self.check_body(body);
+        }
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+}
+
+/// Leaking and releasing calls in one function body; nested items are checked on their own
+#[derive(Default)]
+struct LeakCalls<'a> {
+    forgets: Vec<&'a syn::ExprCall>,
+    manually_drops: Vec<&'a syn::ExprCall>,
+    released: bool,
+}
+
+impl<'a> Visit<'a> for LeakCalls<'a> {
     fn visit_expr_call(&mut self, node: &'a syn::ExprCall) {
         if let syn::Expr::Path(path) = &*node.func {
-            let path_str = path
-                .path
-                .segments
-                .iter()
-                .map(|s| s.ident.to_string())
-                .collect::<Vec<_>>()
-                .join("::");
-
-            // Check for mem::forget or std::mem::forget
-            if path_str.ends_with("forget") && (path_str.contains("mem") || path_str == "forget") {
-                use syn::spanned::Spanned;
-                let start = node.span().start();
-                self.violations.push(
-                    Violation::new(
-                        self.checker.code(),
-                        self.checker.name(),
-                        self.checker.severity().into(),
-                        "Using mem::forget prevents destructor from running, potentially leaking resources.",
-                        self.file_path,
-                        start.line,
-                        start.column + 1,
-                    )
-                    .with_suggestion(self.checker.suggestions()),
-                );
+            let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            match segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+                // `forget(x)` after `use std::mem::forget`
+                ["forget"] | [.., "mem", "forget"] => self.forgets.push(node),
+                [.., "ManuallyDrop", "new"] => self.manually_drops.push(node),
+                [.., "ManuallyDrop", "drop" | "take" | "into_inner"] => self.released = true,
+                _ => {}
             }
         }
-
         syn::visit::visit_expr_call(self, node);
     }
+
+    fn visit_item(&mut self, _node: &'a syn::Item) {}
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::checker::Checker;
 
     #[test]
     fn test_detects_mem_forget() {
@@ -116,6 +194,49 @@ mod tests {
         assert_eq!(violations.len(), 0);
     }
 
+    #[test]
+    fn test_detects_core_forget_and_unreleased_manually_drop() {
+        let code = r#"
+            impl Handle {
+                fn leak(self) {
+                    let raw = ManuallyDrop::new(self.buffer);
+                    core::mem::forget(self.file);
+                }
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1607ForgetDrop::default();
+        let violations = checker.check_item(&syntax.items[0], "test.rs").unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!((violations[0].line, violations[0].column), (5, 21));
+        assert!(violations[0].message.starts_with("mem::forget prevents the destructor"));
+        assert!(violations[1].message.starts_with("ManuallyDrop::new without"));
+    }
+
+    #[test]
+    fn test_released_manually_drop_and_allowed_paths_pass() {
+        let code = r#"
+            fn example() -> Vec<u8> {
+                let mut data = ManuallyDrop::new(vec![1, 2, 3]);
+                unsafe { ManuallyDrop::take(&mut data) }
+            }
+
+            fn hand_to_c(data: Vec<u8>) {
+                std::mem::forget(data);
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let mut checker = E1607ForgetDrop::default();
+        assert!(checker.check_item(&syntax.items[0], "src/ffi/mod.rs").unwrap().is_empty());
+        assert_eq!(checker.check_item(&syntax.items[1], "src/ffi/mod.rs").unwrap().len(), 1);
+
+        checker.config.allowed_paths = vec!["src/ffi/".to_string()];
+        assert!(checker.check_item(&syntax.items[1], "src/ffi/mod.rs").unwrap().is_empty());
+    }
+
     #[test]
     fn test_explicit_drop_passes() {
         let code = r#"
@@ -135,4 +256,13 @@ mod tests {
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_invalid_allowed_path_is_an_error() {
+        let mut checker = E1607ForgetDrop::default();
+        checker.config.allowed_paths.push("ffi/(".to_string());
+        let syntax = syn::parse_file("fn f() {}").unwrap();
+        let err = checker.check_item(&syntax.items[0], "test.rs").unwrap_err();
+        assert!(err.to_string().contains("Invalid path pattern 'ffi/('"));
+    }
 }
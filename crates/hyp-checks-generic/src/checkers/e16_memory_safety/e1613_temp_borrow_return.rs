@@ -0,0 +1,276 @@
+//! E1613: Reference to a temporary returned from a function
+//!
+//! Detects functions declared to return a reference (`-> &str`, `-> &[T]`)
+//! whose returned value borrows a temporary created in the function itself:
+//! `&self.id.to_string()`, `&format!(..)`, `self.name.clone().as_str()`. The
+//! temporary is dropped when the function returns, so the compiler rejects the
+//! code (E0515) with a lifetime error that is hard to read for beginners.
+//!
+//! Only the tail expression (through `if`/`match` branches and blocks) and
+//! explicit `return` statements are inspected, and only borrows of calls that
+//! are known to produce a new owned value (`owning_methods`, `format!`).
+//!
+//! Example:
+//! ```text
+//! // Bad: the String from to_string() dies at the end of the function
+//! fn name(&self) -> &str { &self.name.to_string() }
+//!
+//! // Good: borrow the field itself, or return the owned String
+//! fn name(&self) -> &str { &self.name }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1613: Reference to a temporary returned from a function
+    E1613TempBorrowReturn,
+    code = "E1613",
+    name = "Reference to a temporary returned from a function",
+    suggestions = "Return a reference to data that outlives the call (e.g. `&self.name`), or change the return type to the owned value (`String`, `Vec<T>`)",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1613_temp_borrow_return",
+    /// Configuration for E1613: Temporary borrow return checker
+    config = E1613Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+        /// Method names whose result is a new owned value
+        owning_methods: Vec<String> = ["to_string", "to_owned", "clone", "to_vec", "to_uppercase", "to_lowercase"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = TempBorrowVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+/// Borrowing methods that return a reference into their receiver
+const BORROWING_METHODS: &[&str] = &["as_str", "as_slice", "as_bytes", "as_path"];
+
+struct TempBorrowVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1613TempBorrowReturn,
+}
+
+impl TempBorrowVisitor<'_> {
+    fn check_fn(&mut self, sig: &syn::Signature, block: &syn::Block) {
+        if !matches!(&sig.output, syn::ReturnType::Type(_, ty) if matches!(**ty, syn::Type::Reference(_))) {
+            return;
+        }
+
+        let mut returned = Vec::new();
+        tail_exprs(block, &mut returned);
+        let mut returns = ReturnCollector::default();
+        returns.visit_block(block);
+        returned.extend(returns.exprs);
+
+        for expr in returned {
+            if let Some(source) = self.temporary_borrow(expr) {
+                let span = expr.span();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "Function '{}' returns a reference to the temporary created by {}; the temporary is dropped when the function returns.",
+                            sig.ident, source
+                        ),
+                        self.file_path,
+                        span.start().line,
+                        span.start().column + 1,
+                    )
+                    .with_span(span.start(), span.end())
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+    }
+
+    /// The owned temporary a returned expression borrows from, if any
+    fn temporary_borrow(&self, expr: &syn::Expr) -> Option<String> {
+        match expr {
+            syn::Expr::Reference(reference) => match &*reference.expr {
+                syn::Expr::Index(index) => self.owning_source(&index.expr),
+                inner => self.owning_source(inner),
+            },
+            syn::Expr::MethodCall(call)
+                if call.args.is_empty() && BORROWING_METHODS.contains(&call.method.to_string().as_str()) =>
+            {
+                self.owning_source(&call.receiver)
+            }
+            syn::Expr::Paren(paren) => self.temporary_borrow(&paren.expr),
+            _ => None,
+        }
+    }
+
+    /// Description of an expression that creates a new owned value
+    fn owning_source(&self, expr: &syn::Expr) -> Option<String> {
+        match expr {
+            syn::Expr::MethodCall(call) => {
+                let method = call.method.to_string();
+                self.checker
+                    .config
+                    .owning_methods
+                    .contains(&method)
+                    .then(|| format!("'.{}()'", method))
+            }
+            syn::Expr::Macro(mac) if mac.mac.path.is_ident("format") => Some(String::from("'format!'")),
+            syn::Expr::Paren(paren) => self.owning_source(&paren.expr),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for TempBorrowVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.check_fn(&node.sig, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.check_fn(&node.sig, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'a syn::TraitItemFn) {
+        if let Some(block) = &node.default {
+            self.check_fn(&node.sig, block);
+        }
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+}
+
+/// Expressions a block evaluates to, looking into `if`/`match` branches and nested blocks
+fn tail_exprs<'a>(block: &'a syn::Block, out: &mut Vec<&'a syn::Expr>) {
+    if let Some(syn::Stmt::Expr(expr, None)) = block.stmts.last() {
+        branch_exprs(expr, out);
+    }
+}
+
+fn branch_exprs<'a>(expr: &'a syn::Expr, out: &mut Vec<&'a syn::Expr>) {
+    match expr {
+        syn::Expr::If(expr_if) => {
+            tail_exprs(&expr_if.then_branch, out);
+            if let Some((_, else_branch)) = &expr_if.else_branch {
+                branch_exprs(else_branch, out);
+            }
+        }
+        syn::Expr::Match(expr_match) => {
+            for arm in &expr_match.arms {
+                branch_exprs(&arm.body, out);
+            }
+        }
+        syn::Expr::Block(expr_block) if expr_block.label.is_none() => tail_exprs(&expr_block.block, out),
+        _ => out.push(expr),
+    }
+}
+
+/// Collects `return <expr>` values of one function body
+#[derive(Default)]
+struct ReturnCollector<'a> {
+    exprs: Vec<&'a syn::Expr>,
+}
+
+impl<'a> Visit<'a> for ReturnCollector<'a> {
+    fn visit_expr_return(&mut self, node: &'a syn::ExprReturn) {
+        if let Some(expr) = &node.expr {
+            branch_exprs(expr, &mut self.exprs);
+        }
+        syn::visit::visit_expr_return(self, node);
+    }
+
+    fn visit_expr_closure(&mut self, _node: &'a syn::ExprClosure) {
+        // `return` inside a closure leaves the closure, not the function
+    }
+
+    fn visit_item(&mut self, _node: &'a syn::Item) {
+        // Nested items are checked on their own
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1613TempBorrowReturn::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_borrowed_temporaries() {
+        let code = r#"
+            impl User {
+                fn name(&self) -> &str {
+                    &self.name.to_string()
+                }
+
+                fn label(&self, short: bool) -> &str {
+                    if short {
+                        return self.id.to_string().as_str();
+                    }
+                    match self.kind {
+                        Kind::Admin => &format!("admin {}", self.name),
+                        _ => &self.name,
+                    }
+                }
+
+                fn tags(&self) -> &[String] {
+                    &self.tags.clone()[..]
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 4);
+        assert_eq!(violations[0].code, "E1613");
+        assert_eq!((violations[0].line, violations[0].column), (4, 21));
+        assert!(violations[0].message.contains("Function 'name' returns a reference to the temporary created by '.to_string()'"));
+        assert!(violations[1].message.contains("'format!'"));
+        assert!(violations[2].message.contains("'.to_string()'"));
+        assert!(violations[3].message.contains("'.clone()'"));
+    }
+
+    #[test]
+    fn test_field_borrows_and_owned_returns_pass() {
+        let code = r#"
+            impl User {
+                fn name(&self) -> &str {
+                    &self.name
+                }
+
+                fn display(&self) -> String {
+                    self.name.to_string()
+                }
+
+                fn first(&self) -> &str {
+                    let upper = |s: &str| -> String { return s.to_uppercase(); };
+                    self.names[0].as_str()
+                }
+            }
+
+            fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+                if a.len() > b.len() { a } else { b }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
         E1605RcCycle, E1606Config, E1606UnnecessaryClone, E1607Config, E1607ForgetDrop,
         E1609Config, E1609InvalidSlice, E1610Config, E1610UnalignedDeref, E1611Config,
         E1611ConsumingSelf, E1612Config, E1612ProhibitCustomAllocators,
+        E1613Config, E1613TempBorrowReturn,
     },
     register_checker,
     registry::CheckerRegistration,
@@ -24,5 +25,6 @@ pub fn e16_registrations() -> Vec<CheckerRegistration> {
         register_checker!(E1610UnalignedDeref, E1610Config),
         register_checker!(E1611ConsumingSelf, E1611Config),
         register_checker!(E1612ProhibitCustomAllocators, E1612Config),
+        register_checker!(E1613TempBorrowReturn, E1613Config),
     ]
 }
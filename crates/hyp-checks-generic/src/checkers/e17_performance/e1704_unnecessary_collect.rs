@@ -1,7 +1,8 @@
 //! E1704: Unnecessary collect()
 //!
-//! Detects .collect() calls that are immediately iterated over again,
-//! which could be avoided by using iterator adaptors directly.
+//! Detects .collect() calls that are immediately iterated over again
+//! (`.collect().iter()`, `.into_iter()` or `.iter_mut()` with no binding in
+//! between), which could be avoided by using iterator adaptors directly.
 
 use crate::{checker::Checker, define_checker, violation::Violation};
 
@@ -41,18 +42,18 @@ impl<'a> Visit<'a> for CollectVisitor<'a> {
     fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
         let method_name = node.method.to_string();
 
-        // Check for .collect().iter() or .collect().into_iter()
-        if method_name == "iter" || method_name == "into_iter" {
+        // Check for .collect().iter(), .collect().into_iter() or .collect().iter_mut()
+        if method_name == "iter" || method_name == "into_iter" || method_name == "iter_mut" {
             if let syn::Expr::MethodCall(inner) = &*node.receiver {
                 if inner.method == "collect" {
-                    use syn::spanned::Spanned;
-                    let start = node.span().start();
+                    // Point at the collect() itself, which is what should be removed
+                    let start = inner.method.span().start();
                     self.violations.push(
                         Violation::new(
                             self.checker.code(),
                             self.checker.name(),
                             self.checker.severity().into(),
-                            "Collecting and immediately iterating is wasteful. Use iterator adaptors directly.",
+                            format!("Collecting and immediately calling .{}() is wasteful. Remove the collect() to keep the iterator lazy.", method_name),
                             self.file_path,
                             start.line,
                             start.column + 1,
@@ -149,4 +150,52 @@ mod tests {
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_reports_collect_span_in_chain() {
+        let code = r#"
+            fn example(names: &[String]) -> Vec<usize> {
+                names
+                    .iter()
+                    .map(|n| n.trim())
+                    .collect::<Vec<_>>()
+                    .iter_mut()
+                    .map(|n| n.len())
+                    .collect()
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1704UnnecessaryCollect::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 6);
+        assert!(violations[0].message.contains(".iter_mut()"));
+    }
+
+    #[test]
+    fn test_bound_and_reused_collect_passes() {
+        let code = r#"
+            fn example(values: &[i32]) -> (i32, usize) {
+                let evens: Vec<i32> = values.iter().copied().filter(|x| x % 2 == 0).collect();
+                let sum = evens.iter().sum();
+                (sum, evens.len())
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1704UnnecessaryCollect::default();
+
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+
+        assert_eq!(violations.len(), 0);
+    }
 }
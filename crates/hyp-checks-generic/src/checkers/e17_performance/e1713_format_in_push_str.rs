@@ -0,0 +1,178 @@
+//! E1713: format! appended to a String
+//!
+//! Detects `s.push_str(&format!(..))` and `s += &format!(..)`. The `format!`
+//! allocates a temporary `String` only to copy it into `s` and drop it;
+//! `write!(s, ..)` formats straight into the existing buffer. Unlike E1703
+//! this is reported whether or not the code is inside a loop.
+//!
+//! Example:
+//! ```text
+//! // Bad: a throwaway String per call
+//! out.push_str(&format!("{}={}\n", key, value));
+//!
+//! // Good: formats directly into `out` (needs `use std::fmt::Write`)
+//! let _ = writeln!(out, "{}={}", key, value);
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use quote::ToTokens;
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1713: format! appended to a String
+    E1713FormatInPushStr,
+    code = "E1713",
+    name = "format! appended to a String",
+    suggestions = "Use write!(s, ...) / writeln!(s, ...) with std::fmt::Write to format directly into the existing String",
+    target_items = [Function, Impl],
+    config_entry_name = "e1713_format_in_push_str",
+    /// Configuration for E1713: format! in push_str checker
+    config = E1713Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Operations],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = FormatAppendVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct FormatAppendVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1713FormatInPushStr,
+}
+
+impl<'a> FormatAppendVisitor<'a> {
+    fn report(&mut self, span: proc_macro2::Span, target: &syn::Expr, how: &str) {
+        let start = span.start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "format!() result appended with {} allocates a temporary String; use write!({}, ...) instead.",
+                    how,
+                    write_target(target).to_token_stream()
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for FormatAppendVisitor<'a> {
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if node.method == "push_str" && node.args.len() == 1 && is_format(&node.args[0]) {
+            self.report(node.span(), &node.receiver, "push_str");
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_binary(&mut self, node: &'a syn::ExprBinary) {
+        if matches!(node.op, syn::BinOp::AddAssign(_)) && is_format(&node.right) {
+            self.report(node.span(), &node.left, "+=");
+        }
+        syn::visit::visit_expr_binary(self, node);
+    }
+}
+
+/// `format!(..)`, optionally borrowed (`&format!(..)`) or viewed (`format!(..).as_str()`)
+/// `*s += &format!(..)` writes through the reference: `write!(s, ..)`
+fn write_target(expr: &syn::Expr) -> &syn::Expr {
+    match expr {
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Deref(_), expr, .. }) => write_target(expr),
+        syn::Expr::Paren(paren) => write_target(&paren.expr),
+        _ => expr,
+    }
+}
+
+fn is_format(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Macro(mac) => mac.mac.path.segments.last().is_some_and(|s| s.ident == "format"),
+        syn::Expr::Reference(reference) => is_format(&reference.expr),
+        syn::Expr::Paren(paren) => is_format(&paren.expr),
+        syn::Expr::MethodCall(call) if call.method == "as_str" && call.args.is_empty() => {
+            is_format(&call.receiver)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1713FormatInPushStr::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_format_appended() {
+        let code = r#"
+            fn render(key: &str, value: u32) -> String {
+                let mut out = String::new();
+                out.push_str(&format!("{}={}", key, value));
+                out += &format!("; {}", key);
+                self.buf.push_str(format!("!").as_str());
+                out
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 3);
+        assert_eq!(violations[0].code, "E1713");
+        assert_eq!(violations[0].line, 4);
+        assert!(violations[0].message.contains("push_str"));
+        assert!(violations[1].message.contains("+="));
+        assert!(violations[2].message.contains("write!(self . buf"));
+    }
+
+    #[test]
+    fn test_write_and_plain_push_str_pass() {
+        let code = r#"
+            fn render(key: &str, value: u32) -> String {
+                use std::fmt::Write;
+                let mut out = String::new();
+                let _ = write!(out, "{}={}", key, value);
+                out.push_str(key);
+                out += "; ";
+                let line = format!("{}", value);
+                out.push_str(&line);
+                out
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_deref_target_suggests_reference() {
+        let code = r#"
+            fn append(s: &mut String, key: &str) {
+                *s += &format!("{}", key);
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("use write!(s, ...) instead"));
+    }
+}
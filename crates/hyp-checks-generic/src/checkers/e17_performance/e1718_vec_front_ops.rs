@@ -0,0 +1,194 @@
+//! E1718: Removing or inserting at the front of a Vec
+//!
+//! Detects `.remove(0)` and `.insert(0, ..)` calls. Both shift every remaining
+//! element, so they are O(n), and code that repeatedly takes from or pushes to
+//! the front is usually a queue that should be a `VecDeque`, whose
+//! `pop_front()`/`push_front()` are O(1). Inside a loop the total cost becomes
+//! quadratic, so those calls are reported with `loop_severity`.
+//!
+//! Only a literal `0` index is reported. The receiver type is not known, so a
+//! `HashMap` keyed by integers whose key `0` is removed is a false positive.
+//!
+//! Example:
+//! ```text
+//! // Bad: each remove(0) moves all remaining jobs
+//! while !jobs.is_empty() { let job = jobs.remove(0); run(job); }
+//!
+//! // Good: VecDeque takes from the front in O(1)
+//! while let Some(job) = jobs.pop_front() { run(job); }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::{CheckerSeverity, Severity, Violation}};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1718: Removing or inserting at the front of a Vec
+    E1718VecFrontOps,
+    code = "E1718",
+    name = "Removing or inserting at the front of a Vec",
+    suggestions = "Use a VecDeque and pop_front()/push_front(), or reverse the order and work at the end with pop()/push()",
+    target_items = [Function, Impl],
+    config_entry_name = "e1718_vec_front_ops",
+    /// Configuration for E1718: Vec front operations checker
+    config = E1718Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Severity of calls inside a loop, where repeated front operations are quadratic
+        loop_severity: crate::config::SeverityLevel = crate::config::SeverityLevel::High,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = FrontOpsVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            in_loop: false,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct FrontOpsVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1718VecFrontOps,
+    in_loop: bool,
+}
+
+impl FrontOpsVisitor<'_> {
+    fn visit_loop_body(&mut self, visit: impl FnOnce(&mut Self)) {
+        let was_in_loop = self.in_loop;
+        self.in_loop = true;
+        visit(self);
+        self.in_loop = was_in_loop;
+    }
+}
+
+impl<'a> Visit<'a> for FrontOpsVisitor<'a> {
+    fn visit_expr_for_loop(&mut self, node: &'a syn::ExprForLoop) {
+        self.visit_loop_body(|visitor| syn::visit::visit_expr_for_loop(visitor, node));
+    }
+
+    fn visit_expr_while(&mut self, node: &'a syn::ExprWhile) {
+        self.visit_loop_body(|visitor| syn::visit::visit_expr_while(visitor, node));
+    }
+
+    fn visit_expr_loop(&mut self, node: &'a syn::ExprLoop) {
+        self.visit_loop_body(|visitor| syn::visit::visit_expr_loop(visitor, node));
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        let front_op = match (node.method.to_string().as_str(), node.args.len()) {
+            ("remove", 1) => Some(("remove(0)", "pop_front()")),
+            ("insert", 2) => Some(("insert(0, ..)", "push_front(..)")),
+            _ => None,
+        };
+        if let (Some((call, replacement)), Some(true)) = (front_op, node.args.first().map(is_zero)) {
+            let base: Severity = self.checker.severity().into();
+            let (severity, cost) = if self.in_loop {
+                let in_loop: Severity = CheckerSeverity::from(self.checker.config.loop_severity).into();
+                (base.max(in_loop), "inside a loop this makes the whole loop quadratic")
+            } else {
+                (base, "a queue-like Vec should be a VecDeque")
+            };
+            let start = node.method.span().start();
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    severity,
+                    format!(
+                        "'.{}' shifts every element of the Vec (O(n)); {}. VecDeque::{} is O(1).",
+                        call, cost, replacement
+                    ),
+                    self.file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_suggestion(self.checker.suggestions()),
+            );
+        }
+
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// Whether an expression is the integer literal `0` (any suffix)
+fn is_zero(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => {
+            lit.base10_parse::<u128>().is_ok_and(|value| value == 0)
+        }
+        syn::Expr::Paren(paren) => is_zero(&paren.expr),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1718VecFrontOps::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_front_ops() {
+        let code = r#"
+            fn example(mut v: Vec<u32>) {
+                let first = v.remove(0);
+                v.insert(0usize, first);
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1718");
+        assert_eq!(violations[0].severity, Severity::Medium);
+        assert_eq!((violations[0].line, violations[0].column), (3, 31));
+        assert!(violations[0].message.starts_with("'.remove(0)' shifts every element"));
+        assert!(violations[1].message.contains("VecDeque::push_front(..) is O(1)"));
+    }
+
+    #[test]
+    fn test_front_ops_in_loop_are_high_severity() {
+        let code = r#"
+            impl Queue {
+                fn drain(&mut self) {
+                    while !self.jobs.is_empty() {
+                        let job = self.jobs.remove(0);
+                        job.run();
+                    }
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::High);
+        assert!(violations[0].message.contains("quadratic"));
+    }
+
+    #[test]
+    fn test_other_indices_and_methods_pass() {
+        let code = r#"
+            fn example(mut v: Vec<u32>, i: usize) {
+                let _ = v.remove(i);
+                let _ = v.remove(1);
+                v.insert(v.len(), 3);
+                let _ = v.pop();
+                let _ = set.insert(0);
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+//! E1719: Mutual recursion between functions
+//!
+//! Detects free functions that call each other in a cycle (`a` calls `b`,
+//! `b` calls `a`). Like the direct recursion covered by E1706/E1707, every
+//! round trip adds stack frames that Rust never eliminates, but the recursion
+//! is spread over several functions and is easy to miss when reading one of
+//! them.
+//!
+//! A call graph is built from the functions defined at the top level of a
+//! file, using calls whose callee is a plain identifier (`helper(x)`). Each
+//! function on a cycle of two or more functions is reported with the shortest
+//! cycle through it. Calls are only resolved within a single file: cycles that
+//! pass through another module, a method or a closure value are not seen.
+//!
+//! Example:
+//! ```text
+//! // Bad: depth grows with the input, alternating between two functions
+//! fn is_even(n: u64) -> bool { if n == 0 { true } else { is_odd(n - 1) } }
+//! fn is_odd(n: u64) -> bool { if n == 0 { false } else { is_even(n - 1) } }
+//!
+//! // Good: a loop (or a closed form) keeps the stack flat
+//! fn is_even(n: u64) -> bool { n % 2 == 0 }
+//! ```
+
+use crate::{define_checker, violation::Violation};
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1719: Mutual recursion between functions
+    E1719MutualRecursion,
+    code = "E1719",
+    name = "Mutual recursion between functions",
+    suggestions = "Merge the functions into one loop or state machine, or use an explicit work stack so the depth no longer depends on the input",
+    target_items = [Function],
+    config_entry_name = "e1719_mutual_recursion",
+    /// Configuration for E1719: Mutual recursion checker
+    config = E1719Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, _item, _file_path) {
+        // The call graph needs every function in the file, see check_file
+        Ok(Vec::new())
+    }
+    check_file(self, file, file_path) {
+        let functions: Vec<&syn::ItemFn> = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Fn(func) => Some(func),
+                _ => None,
+            })
+            .collect();
+
+        let names: BTreeSet<String> = functions.iter().map(|func| func.sig.ident.to_string()).collect();
+        let mut graph: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for func in &functions {
+            let mut calls = CallCollector { local: &names, callees: BTreeSet::new() };
+            calls.visit_block(&func.block);
+            graph.insert(func.sig.ident.to_string(), calls.callees);
+        }
+
+        let mut violations = Vec::new();
+        for func in &functions {
+            let name = func.sig.ident.to_string();
+            if let Some(cycle) = shortest_cycle(&graph, &name) {
+                let start = func.sig.ident.span().start();
+                violations.push(
+                    Violation::new(
+                        self.code(),
+                        self.name(),
+                        self.severity().into(),
+                        format!(
+                            "Function '{}' is mutually recursive ({}). Each round trip adds stack frames, so deep inputs can overflow the stack.",
+                            name,
+                            cycle.join(" -> ")
+                        ),
+                        file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_suggestion(self.suggestions()),
+                );
+            }
+        }
+        Ok(violations)
+    }
+}
+
+/// Collects calls to functions of the same file made through a plain identifier
+struct CallCollector<'a> {
+    local: &'a BTreeSet<String>,
+    callees: BTreeSet<String>,
+}
+
+impl<'ast> Visit<'ast> for CallCollector<'_> {
+    fn visit_expr_call(&mut self, node: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(path) = &*node.func {
+            if let Some(ident) = path.path.get_ident() {
+                let name = ident.to_string();
+                if self.local.contains(&name) {
+                    self.callees.insert(name);
+                }
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_item_fn(&mut self, _node: &'ast syn::ItemFn) {
+        // Nested fn items are separate functions, not part of this body
+    }
+}
+
+/// Shortest cycle of two or more functions starting and ending at `start`,
+/// as the list of function names along it
+fn shortest_cycle(graph: &BTreeMap<String, BTreeSet<String>>, start: &str) -> Option<Vec<String>> {
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    for callee in graph.get(start)?.iter().filter(|callee| *callee != start) {
+        previous.insert(callee, start);
+        queue.push_back(callee);
+    }
+
+    while let Some(current) = queue.pop_front() {
+        for callee in graph.get(current).into_iter().flatten() {
+            if callee == start {
+                let mut path = vec![start.to_string()];
+                let mut node = current;
+                while node != start {
+                    path.push(node.to_string());
+                    node = previous[node];
+                }
+                path[1..].reverse();
+                path.push(start.to_string());
+                return Some(path);
+            }
+            if !previous.contains_key(callee.as_str()) {
+                previous.insert(callee, current);
+                queue.push_back(callee);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::Checker;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        E1719MutualRecursion::default().check_file(&syntax, "test.rs").unwrap()
+    }
+
+    #[test]
+    fn test_detects_two_function_cycle() {
+        let code = r#"
+            fn is_even(n: u64) -> bool {
+                if n == 0 { true } else { is_odd(n - 1) }
+            }
+
+            fn is_odd(n: u64) -> bool {
+                if n == 0 { false } else { is_even(n - 1) }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1719");
+        assert_eq!((violations[0].line, violations[0].column), (2, 16));
+        assert!(violations[0].message.contains("'is_even' is mutually recursive (is_even -> is_odd -> is_even)"));
+        assert!(violations[1].message.contains("(is_odd -> is_even -> is_odd)"));
+    }
+
+    #[test]
+    fn test_reports_longer_cycles_with_path() {
+        let code = r#"
+            fn parse_expr(t: &[u8]) -> u32 { parse_term(t) + log(t) }
+            fn parse_term(t: &[u8]) -> u32 { parse_atom(t) }
+            fn parse_atom(t: &[u8]) -> u32 { if t.is_empty() { 0 } else { parse_expr(&t[1..]) } }
+            fn log(t: &[u8]) -> u32 { t.len() as u32 }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 3);
+        assert!(violations[2].message.contains("(parse_atom -> parse_expr -> parse_term -> parse_atom)"));
+    }
+
+    #[test]
+    fn test_acyclic_and_self_recursion_pass() {
+        let code = r#"
+            fn main() { run(3); helper(); }
+            fn run(n: u32) { if n > 0 { run(n - 1); } helper(); }
+            fn helper() { other::main(); self.run(); }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
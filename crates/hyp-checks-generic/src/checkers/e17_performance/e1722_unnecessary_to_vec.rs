@@ -0,0 +1,259 @@
+//! E1722: Unnecessary copy before iterating
+//!
+//! Detects `.to_vec()`, `.to_owned()` and `.clone()` whose result is only
+//! iterated: the iterable of a `for` loop, or the receiver of `.iter()` /
+//! `.into_iter()`. The copy is a temporary that is never bound, so the whole
+//! collection is allocated and copied just to be walked once; iterating the
+//! original (`for x in slice`, `slice.iter().cloned()`) does the same work
+//! without the allocation.
+//!
+//! Heuristics, since the types are unknown:
+//! - A `for` loop is skipped when its body mentions the copied value's root
+//!   variable (e.g. `self`), because the copy is then often what keeps the
+//!   borrow checker happy while the original is mutated.
+//! - `.clone()` of a value whose name contains `iter` or `range` is skipped;
+//!   cloning an iterator or a range is cheap and needed to reuse it.
+//!
+//! Example:
+//! ```text
+//! // Bad: allocates a Vec only to loop over it
+//! for name in names.to_vec() { println!("{}", name); }
+//!
+//! // Good: borrow the original
+//! for name in names { println!("{}", name); }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1722: Unnecessary copy before iterating
+    E1722UnnecessaryToVec,
+    code = "E1722",
+    name = "Unnecessary copy before iterating",
+    suggestions = "Iterate the original by reference (`for x in &items`, `items.iter()`), adding `.cloned()` if owned items are needed",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1722_unnecessary_to_vec",
+    /// Configuration for E1722: Unnecessary copy before iterating checker
+    config = E1722Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = ToVecVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct ToVecVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1722UnnecessaryToVec,
+}
+
+impl ToVecVisitor<'_> {
+    fn report(&mut self, copy: &syn::ExprMethodCall, context: &str) {
+        let start = copy.method.span().start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    ".{}() copies the collection only to {}; iterate the original instead.",
+                    copy.method, context
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_span(start, copy.span().end())
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for ToVecVisitor<'a> {
+    fn visit_expr_for_loop(&mut self, node: &'a syn::ExprForLoop) {
+        let Some(copy) = iterated_copy(&node.expr) else {
+            syn::visit::visit_expr_for_loop(self, node);
+            return;
+        };
+
+        let reused = root_ident(&copy.receiver)
+            .is_some_and(|root| mentions_ident(node.body.to_token_stream(), &root));
+        if !reused {
+            self.report(copy, "loop over it");
+        }
+
+        // The `.iter()` of `for x in v.to_vec().iter()` is already covered by the loop
+        self.visit_expr(&copy.receiver);
+        self.visit_pat(&node.pat);
+        self.visit_block(&node.body);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if is_iter_call(node) {
+            if let syn::Expr::MethodCall(copy) = &*node.receiver {
+                if is_copy_call(copy) {
+                    self.report(copy, &format!("call .{}() on it", node.method));
+                }
+            }
+        }
+
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// The copying call a `for` loop iterates, directly or through `.iter()` / `.into_iter()`
+fn iterated_copy(expr: &syn::Expr) -> Option<&syn::ExprMethodCall> {
+    let syn::Expr::MethodCall(call) = expr else {
+        return None;
+    };
+    if is_copy_call(call) {
+        return Some(call);
+    }
+    match &*call.receiver {
+        syn::Expr::MethodCall(copy) if is_iter_call(call) && is_copy_call(copy) => Some(copy),
+        _ => None,
+    }
+}
+
+/// `.iter()` or `.into_iter()` without arguments
+fn is_iter_call(call: &syn::ExprMethodCall) -> bool {
+    (call.method == "iter" || call.method == "into_iter") && call.args.is_empty()
+}
+
+/// `.to_vec()`, `.to_owned()` or `.clone()` of something that is not an iterator or range
+fn is_copy_call(call: &syn::ExprMethodCall) -> bool {
+    if !call.args.is_empty() || call.turbofish.is_some() {
+        return false;
+    }
+    match call.method.to_string().as_str() {
+        "to_vec" | "to_owned" => true,
+        "clone" => !matches!(&*call.receiver, syn::Expr::Range(_) | syn::Expr::Paren(_))
+            && last_ident(&call.receiver).is_none_or(|name| {
+                let name = name.to_lowercase();
+                !name.contains("iter") && !name.contains("range")
+            }),
+        _ => false,
+    }
+}
+
+/// Name of the variable, field or method a receiver expression ends in
+fn last_ident(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        syn::Expr::Field(field) => match &field.member {
+            syn::Member::Named(ident) => Some(ident.to_string()),
+            syn::Member::Unnamed(_) => None,
+        },
+        syn::Expr::MethodCall(call) => Some(call.method.to_string()),
+        syn::Expr::Reference(reference) => last_ident(&reference.expr),
+        _ => None,
+    }
+}
+
+/// The local variable (or `self`) a receiver expression starts from
+fn root_ident(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(path) => path.path.get_ident().map(ToString::to_string),
+        syn::Expr::Field(field) => root_ident(&field.base),
+        syn::Expr::MethodCall(call) => root_ident(&call.receiver),
+        syn::Expr::Index(index) => root_ident(&index.expr),
+        syn::Expr::Reference(reference) => root_ident(&reference.expr),
+        syn::Expr::Paren(paren) => root_ident(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Whether `tokens` contain the identifier `name`, including inside macro calls
+fn mentions_ident(tokens: TokenStream, name: &str) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => mentions_ident(group.stream(), name),
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1722UnnecessaryToVec::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_loop_over_to_vec() {
+        let code = r#"
+            fn print_all(names: &[String]) {
+                for name in names.to_vec() {
+                    println!("{}", name);
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1722");
+        assert_eq!((violations[0].line, violations[0].column), (3, 35));
+        assert!(violations[0].message.contains(".to_vec() copies the collection only to loop over it"));
+    }
+
+    #[test]
+    fn test_detects_copy_before_iter() {
+        let code = r#"
+            fn total(items: &Vec<u32>, names: &[&str]) -> usize {
+                let sum: u32 = items.clone().iter().sum();
+                for name in names.to_owned().into_iter() {
+                    println!("{}", name);
+                }
+                sum as usize
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains(".clone() copies the collection only to call .iter() on it"));
+        assert!(violations[1].message.contains(".to_owned() copies the collection only to loop over it"));
+    }
+
+    #[test]
+    fn test_stored_or_needed_copies_pass() {
+        let code = r#"
+            impl Queue {
+                fn drain_pending(&mut self, items: &[u32]) -> Vec<u32> {
+                    let copy = items.to_vec();
+                    for item in copy.iter() {
+                        println!("{}", item);
+                    }
+                    for job in self.jobs.clone() {
+                        self.run(job);
+                    }
+                    for i in self.range.clone() {
+                        println!("{}", i);
+                    }
+                    copy
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
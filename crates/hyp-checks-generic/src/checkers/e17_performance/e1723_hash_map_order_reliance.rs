@@ -0,0 +1,343 @@
+//! E1723: Relying on HashMap iteration order
+//!
+//! Detects a `for` loop over a `HashMap` / `HashSet` whose body pushes into
+//! a `Vec`, `VecDeque` or `String` declared outside the loop, when that
+//! collection is never sorted afterwards. Hash iteration order is arbitrary
+//! and changes between runs (the default hasher is randomly seeded), so the
+//! collected output is nondeterministic: flaky tests, unstable reports, noisy
+//! diffs.
+//!
+//! The type is not inferred: only locals annotated as `HashMap` / `HashSet`
+//! (in a `let` or as a function parameter) are tracked. A `.sort*()` call on
+//! the collection anywhere in the function counts as restoring the order.
+//!
+//! Example:
+//! ```text
+//! // Bad: the lines come out in a different order on every run
+//! let counts: HashMap<String, usize> = count_words(text);
+//! let mut lines = Vec::new();
+//! for (word, n) in &counts { lines.push(format!("{word}: {n}")); }
+//!
+//! // Good: use a BTreeMap, or sort before relying on the order
+//! let counts: BTreeMap<String, usize> = count_words(text);
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use std::collections::HashSet;
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1723: Relying on HashMap iteration order
+    E1723HashMapOrderReliance,
+    code = "E1723",
+    name = "Relying on HashMap iteration order",
+    suggestions = "Use a BTreeMap/BTreeSet for a stable order, or sort the collected result (or the keys) before using it",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1723_hash_map_order_reliance",
+    /// Configuration for E1723: Relying on HashMap iteration order checker
+    config = E1723Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = FunctionVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct FunctionVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1723HashMapOrderReliance,
+}
+
+impl FunctionVisitor<'_> {
+    fn check_function(&mut self, sig: &syn::Signature, body: &syn::Block) {
+        let mut scan = BodyScan::default();
+        for input in &sig.inputs {
+            if let syn::FnArg::Typed(arg) = input {
+                scan.track(&arg.pat, &arg.ty);
+            }
+        }
+        scan.visit_block(body);
+
+        for found in scan.loops {
+            for target in found.targets {
+                if scan.sorted.contains(&target) {
+                    continue;
+                }
+                let start = found.for_loop.for_token.span.start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "Loop over hash collection '{}' pushes into '{}', which is never sorted; its order changes between runs.",
+                            found.map, target
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_span(start, found.for_loop.expr.span().end())
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for FunctionVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'a syn::TraitItemFn) {
+        if let Some(body) = &node.default {
+            self.check_function(&node.sig, body);
+        }
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+}
+
+/// A loop over a hash collection and the outer collections its body pushes into
+struct HashLoop<'a> {
+    for_loop: &'a syn::ExprForLoop,
+    map: String,
+    targets: Vec<String>,
+}
+
+/// Hash-typed locals, loops over them and sorted collections of one function body
+#[derive(Default)]
+struct BodyScan<'a> {
+    hash_locals: HashSet<String>,
+    loops: Vec<HashLoop<'a>>,
+    sorted: HashSet<String>,
+}
+
+impl BodyScan<'_> {
+    fn track(&mut self, pat: &syn::Pat, ty: &syn::Type) {
+        if let (syn::Pat::Ident(ident), true) = (pat, is_hash_type(ty)) {
+            self.hash_locals.insert(ident.ident.to_string());
+        }
+    }
+}
+
+impl<'a> Visit<'a> for BodyScan<'a> {
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        if let syn::Pat::Type(typed) = &node.pat {
+            self.track(&typed.pat, &typed.ty);
+        }
+        syn::visit::visit_local(self, node);
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'a syn::ExprForLoop) {
+        if let Some(map) = iterated_local(&node.expr).filter(|name| self.hash_locals.contains(name)) {
+            let mut pushes = PushScan::default();
+            pushes.visit_block(&node.body);
+            let mut targets: Vec<String> = Vec::new();
+            for target in pushes.targets {
+                if !pushes.declared.contains(&target) && !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+            if !targets.is_empty() {
+                self.loops.push(HashLoop { for_loop: node, map, targets });
+            }
+        }
+        syn::visit::visit_expr_for_loop(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if node.method.to_string().starts_with("sort") {
+            if let Some(name) = root_ident(&node.receiver) {
+                self.sorted.insert(name);
+            }
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_item(&mut self, _node: &'a syn::Item) {}
+}
+
+/// Receivers of ordered pushes in a loop body, and the locals the body declares itself
+#[derive(Default)]
+struct PushScan {
+    targets: Vec<String>,
+    declared: HashSet<String>,
+}
+
+impl<'a> Visit<'a> for PushScan {
+    fn visit_pat_ident(&mut self, node: &'a syn::PatIdent) {
+        self.declared.insert(node.ident.to_string());
+        syn::visit::visit_pat_ident(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if matches!(node.method.to_string().as_str(), "push" | "push_str" | "push_back") {
+            if let Some(name) = root_ident(&node.receiver) {
+                self.targets.push(name);
+            }
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_item(&mut self, _node: &'a syn::Item) {}
+}
+
+/// `HashMap<..>` / `HashSet<..>`, possibly behind a reference or a path like `std::collections::`
+fn is_hash_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(reference) => is_hash_type(&reference.elem),
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "HashMap" || segment.ident == "HashSet"),
+        _ => false,
+    }
+}
+
+/// The local a `for` loop walks: `m`, `&m`, `&mut m` or `m.iter()` / `.keys()` / `.values()` / ..
+fn iterated_local(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(path) => path.path.get_ident().map(ToString::to_string),
+        syn::Expr::Reference(reference) => iterated_local(&reference.expr),
+        syn::Expr::Paren(paren) => iterated_local(&paren.expr),
+        syn::Expr::MethodCall(call)
+            if call.args.is_empty()
+                && matches!(
+                    call.method.to_string().as_str(),
+                    "iter" | "iter_mut" | "into_iter" | "keys" | "values" | "values_mut" | "into_keys" | "into_values" | "drain"
+                ) =>
+        {
+            iterated_local(&call.receiver)
+        }
+        _ => None,
+    }
+}
+
+/// Name of the variable at the root of `a`, `a.b`, `&mut a`, `a[i]`
+fn root_ident(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(path) => path.path.get_ident().map(ToString::to_string),
+        syn::Expr::Field(field) => root_ident(&field.base),
+        syn::Expr::Reference(reference) => root_ident(&reference.expr),
+        syn::Expr::Paren(paren) => root_ident(&paren.expr),
+        syn::Expr::Index(index) => root_ident(&index.expr),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1723HashMapOrderReliance::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_push_from_hash_map_loop() {
+        let code = r#"
+            fn report(text: &str) -> Vec<String> {
+                let counts: HashMap<String, usize> = count_words(text);
+                let mut lines = Vec::new();
+                for (word, n) in &counts {
+                    lines.push(format!("{word}: {n}"));
+                }
+                lines
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1723");
+        assert_eq!((violations[0].line, violations[0].column), (5, 17));
+        assert!(violations[0]
+            .message
+            .contains("Loop over hash collection 'counts' pushes into 'lines', which is never sorted"));
+    }
+
+    #[test]
+    fn test_detects_hash_set_param_and_string_output() {
+        let code = r#"
+            impl Printer {
+                fn render(&mut self, names: &std::collections::HashSet<String>) -> String {
+                    let mut out = String::new();
+                    for name in names.iter() {
+                        out.push_str(name);
+                        self.seen.push(name.clone());
+                    }
+                    out
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("pushes into 'out'"));
+        assert!(violations[1].message.contains("pushes into 'self'"));
+    }
+
+    #[test]
+    fn test_sorted_output_and_unannotated_maps_pass() {
+        let code = r#"
+            fn sorted(counts: HashMap<String, usize>) -> Vec<String> {
+                let mut keys = Vec::new();
+                for key in counts.keys() {
+                    keys.push(key.clone());
+                }
+                keys.sort();
+                keys
+            }
+
+            fn inferred(text: &str) -> Vec<String> {
+                let counts = count_words(text);
+                let mut lines = Vec::new();
+                for (word, _) in &counts {
+                    lines.push(word.clone());
+                }
+                lines
+            }
+
+            fn ordered(counts: &BTreeMap<String, usize>, out: &mut Vec<String>) {
+                for word in counts.keys() {
+                    out.push(word.clone());
+                }
+            }
+
+            fn local_buffer(groups: &HashMap<u32, Vec<u32>>) -> u32 {
+                let mut total = 0;
+                for (_, members) in groups {
+                    let mut buf = Vec::new();
+                    buf.push(members.len());
+                    total += buf.len() as u32;
+                }
+                total
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
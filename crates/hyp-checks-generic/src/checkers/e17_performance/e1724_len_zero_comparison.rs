@@ -0,0 +1,198 @@
+//! E1724: Comparing `.len()` to zero
+//!
+//! Detects `x.len() == 0`, `x.len() != 0`, `x.len() > 0` and the mirrored
+//! forms (`0 == x.len()`, `0 < x.len()`, ..). `is_empty()` says what is
+//! being asked directly, and for some collections (linked structures,
+//! iterators over chars) it is cheaper than counting.
+//!
+//! A function named `is_empty` is skipped, since implementing it through
+//! `len() == 0` is the usual way to write it.
+//!
+//! Example:
+//! ```text
+//! // Bad
+//! if items.len() == 0 { return; }
+//! if name.len() > 0 { greet(name); }
+//!
+//! // Good
+//! if items.is_empty() { return; }
+//! if !name.is_empty() { greet(name); }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit, BinOp};
+
+define_checker! {
+    /// Checker for E1724: Comparing .len() to zero
+    E1724LenZeroComparison,
+    code = "E1724",
+    name = "Comparing .len() to zero",
+    suggestions = "Use .is_empty() for `len() == 0` and !x.is_empty() for `len() != 0` / `len() > 0`",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1724_len_zero_comparison",
+    /// Configuration for E1724: Comparing .len() to zero checker
+    config = E1724Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = LenZeroVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct LenZeroVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1724LenZeroComparison,
+}
+
+impl<'a> Visit<'a> for LenZeroVisitor<'a> {
+    fn visit_expr_binary(&mut self, node: &'a syn::ExprBinary) {
+        if let Some(negated) = emptiness_test(node) {
+            let replacement = if negated { "!x.is_empty()" } else { "x.is_empty()" };
+            let start = node.span().start();
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    self.checker.severity().into(),
+                    format!("Comparing .len() to 0 tests for emptiness; use `{}` instead.", replacement),
+                    self.file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_span(start, node.span().end())
+                .with_suggestion(self.checker.suggestions()),
+            );
+        }
+        syn::visit::visit_expr_binary(self, node);
+    }
+
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        if node.sig.ident != "is_empty" {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        if node.sig.ident != "is_empty" {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'a syn::TraitItemFn) {
+        if node.sig.ident != "is_empty" {
+            syn::visit::visit_trait_item_fn(self, node);
+        }
+    }
+}
+
+/// `Some(false)` for a `len() == 0` test, `Some(true)` for `len() != 0` / `len() > 0`
+fn emptiness_test(node: &syn::ExprBinary) -> Option<bool> {
+    if is_len_call(&node.left) && is_zero(&node.right) {
+        match node.op {
+            BinOp::Eq(_) => Some(false),
+            BinOp::Ne(_) | BinOp::Gt(_) => Some(true),
+            _ => None,
+        }
+    } else if is_zero(&node.left) && is_len_call(&node.right) {
+        match node.op {
+            BinOp::Eq(_) => Some(false),
+            BinOp::Ne(_) | BinOp::Lt(_) => Some(true),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// `x.len()` without arguments
+fn is_len_call(expr: &syn::Expr) -> bool {
+    matches!(expr, syn::Expr::MethodCall(call) if call.method == "len" && call.args.is_empty())
+}
+
+/// The integer literal `0` (with or without a suffix)
+fn is_zero(expr: &syn::Expr) -> bool {
+    matches!(
+        expr,
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) if int.base10_digits() == "0"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1724LenZeroComparison::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_len_equals_zero() {
+        let violations = check_code("fn f(v: &[u8]) -> bool {\n    v.len() == 0\n}\n");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1724");
+        assert_eq!((violations[0].line, violations[0].column), (2, 5));
+        assert!(violations[0].message.contains("use `x.is_empty()` instead"));
+    }
+
+    #[test]
+    fn test_detects_non_empty_comparisons() {
+        let code = r#"
+            impl Queue {
+                fn busy(&self) -> bool {
+                    self.items.len() != 0 || self.pending.len() > 0
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.message.contains("use `!x.is_empty()` instead")));
+    }
+
+    #[test]
+    fn test_detects_zero_on_the_left() {
+        let code = r#"
+            fn f(a: &str, b: &str) -> bool {
+                0 == a.len() && 0 < b.len() && 0usize != a.len()
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 3);
+        assert!(violations[0].message.contains("`x.is_empty()`"));
+        assert!(violations[1].message.contains("`!x.is_empty()`"));
+    }
+
+    #[test]
+    fn test_other_comparisons_and_is_empty_impl_pass() {
+        let code = r#"
+            fn f(v: &[u8]) -> bool {
+                v.len() == 3 || v.len() >= 0 || v.len() < 0 || v.count() == 0
+            }
+
+            impl Stack {
+                pub fn is_empty(&self) -> bool {
+                    self.len() == 0
+                }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+//! E1725: Clone immediately consumed
+//!
+//! Detects `.clone()` whose result is the receiver of a consuming method:
+//! `s.clone().into_bytes()`, `name.clone().to_owned()`,
+//! `config.clone().unwrap()`. The clone is a temporary that exists only to
+//! be consumed, so either the original could be consumed instead (when it
+//! is not used afterwards), or the consuming call copies a second time and
+//! the clone is redundant.
+//!
+//! The method names come from `consuming_methods`; a trailing `*` matches
+//! a prefix (`into_*`). `.clone().into_iter()` is left to E1722.
+//!
+//! Example:
+//! ```text
+//! // Bad: copies the String only to turn the copy into bytes
+//! let bytes = text.clone().into_bytes();
+//!
+//! // Good: consume the original, or borrow what is needed
+//! let bytes = text.into_bytes();
+//! let bytes = text.as_bytes().to_vec();
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1725: Clone immediately consumed
+    E1725CloneThenConsume,
+    code = "E1725",
+    name = "Clone immediately consumed",
+    suggestions = "Consume the original if it is not used afterwards, or borrow instead (as_ref(), as_bytes(), iter().cloned()) so only what is needed is copied",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1725_clone_then_consume",
+    /// Configuration for E1725: Clone immediately consumed checker
+    config = E1725Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Methods that consume or copy their receiver; a trailing `*` matches a prefix
+        consuming_methods: Vec<String> = vec![
+            "into_*".to_string(),
+            "to_owned".to_string(),
+            "to_string".to_string(),
+            "to_vec".to_string(),
+            "unwrap".to_string(),
+            "expect".to_string(),
+        ],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = CloneConsumeVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct CloneConsumeVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1725CloneThenConsume,
+}
+
+impl CloneConsumeVisitor<'_> {
+    fn is_consuming(&self, method: &str) -> bool {
+        // Iterating a copy is E1722's pattern
+        if method == "into_iter" {
+            return false;
+        }
+        self.checker.config.consuming_methods.iter().any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => method.starts_with(prefix),
+            None => method == pattern,
+        })
+    }
+}
+
+impl<'a> Visit<'a> for CloneConsumeVisitor<'a> {
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if let syn::Expr::MethodCall(clone) = &*node.receiver {
+            let method = node.method.to_string();
+            if clone.method == "clone" && clone.args.is_empty() && self.is_consuming(&method) {
+                let start = clone.method.span().start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            ".clone() is immediately consumed by .{}(); consume the original or borrow instead.",
+                            method
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_span(start, clone.span().end())
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_with(checker: &E1725CloneThenConsume, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_with(&E1725CloneThenConsume::default(), code)
+    }
+
+    #[test]
+    fn test_detects_clone_into_bytes() {
+        let code = r#"
+            fn encode(text: &String) -> Vec<u8> {
+                text.clone().into_bytes()
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1725");
+        assert_eq!((violations[0].line, violations[0].column), (3, 22));
+        assert!(violations[0].message.contains(".clone() is immediately consumed by .into_bytes()"));
+    }
+
+    #[test]
+    fn test_detects_double_copy_and_cloned_option_unwrap() {
+        let code = r#"
+            impl Settings {
+                fn name(&self) -> String {
+                    let fallback = self.default_name.clone().to_owned();
+                    self.name.clone().unwrap_or(fallback)
+                }
+
+                fn port(&self) -> u16 {
+                    self.port.clone().unwrap()
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("consumed by .to_owned()"));
+        assert!(violations[1].message.contains("consumed by .unwrap()"));
+    }
+
+    #[test]
+    fn test_borrowing_calls_and_into_iter_pass() {
+        let code = r#"
+            fn f(items: &Vec<u8>, name: &String) -> usize {
+                let copy = name.clone();
+                let total: usize = items.clone().into_iter().map(usize::from).sum();
+                copy.len() + name.clone().len() + total
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_custom_consuming_methods() {
+        let code = r#"
+            fn f(req: &Request) -> Response {
+                req.clone().send()
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+
+        let mut checker = E1725CloneThenConsume::default();
+        checker.config.consuming_methods = vec!["send".to_string()];
+        assert_eq!(check_with(&checker, code).len(), 1);
+    }
+}
@@ -0,0 +1,206 @@
+//! E1726: Needless `return` in tail position
+//!
+//! Detects `return x;` (or `return x`) as the last statement of a function,
+//! method or closure body. A block already evaluates to its final
+//! expression, so the keyword adds nothing there; reserving `return` for
+//! early exits makes those stand out.
+//!
+//! Only the body's own last statement counts as tail position: returns in
+//! the tail of an `if`/`match` inside it are left alone, and so is a bare
+//! `return;`. Functions must declare a return type, so the block is known
+//! to produce a value.
+//!
+//! Example:
+//! ```text
+//! // Bad
+//! fn area(w: u32, h: u32) -> u32 {
+//!     let area = w * h;
+//!     return area;
+//! }
+//!
+//! // Good
+//! fn area(w: u32, h: u32) -> u32 {
+//!     let area = w * h;
+//!     area
+//! }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1726: Needless return in tail position
+    E1726NeedlessReturn,
+    code = "E1726",
+    name = "Needless return in tail position",
+    suggestions = "Remove `return` and the trailing semicolon so the block ends with the expression",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1726_needless_return",
+    /// Configuration for E1726: Needless return checker
+    config = E1726Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = NeedlessReturnVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct NeedlessReturnVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1726NeedlessReturn,
+}
+
+impl NeedlessReturnVisitor<'_> {
+    fn check_fn_body(&mut self, sig: &syn::Signature, body: &syn::Block) {
+        if matches!(sig.output, syn::ReturnType::Type(..)) {
+            self.check_tail(body, &format!("'{}'", sig.ident));
+        }
+    }
+
+    fn check_tail(&mut self, body: &syn::Block, owner: &str) {
+        let Some(syn::Stmt::Expr(syn::Expr::Return(ret), _)) = body.stmts.last() else {
+            return;
+        };
+        if ret.expr.is_none() {
+            return;
+        }
+        let start = ret.return_token.span.start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "`return` as the last statement of {} is needless; end the block with the expression instead.",
+                    owner
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_span(start, ret.span().end())
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for NeedlessReturnVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.check_fn_body(&node.sig, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.check_fn_body(&node.sig, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'a syn::TraitItemFn) {
+        if let Some(body) = &node.default {
+            self.check_fn_body(&node.sig, body);
+        }
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_expr_closure(&mut self, node: &'a syn::ExprClosure) {
+        if let syn::Expr::Block(block) = &*node.body {
+            if block.label.is_none() {
+                self.check_tail(&block.block, "a closure");
+            }
+        }
+        syn::visit::visit_expr_closure(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1726NeedlessReturn::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_tail_return() {
+        let code = r#"
+            fn area(w: u32, h: u32) -> u32 {
+                let area = w * h;
+                return area;
+            }
+
+            impl Shape {
+                fn name(&self) -> &str { return &self.name }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1726");
+        assert_eq!((violations[0].line, violations[0].column), (4, 17));
+        assert!(violations[0].message.contains("last statement of 'area' is needless"));
+        assert!(violations[1].message.contains("'name'"));
+    }
+
+    #[test]
+    fn test_detects_tail_return_in_closure() {
+        let code = r#"
+            fn doubled(items: &[u32]) -> Vec<u32> {
+                items.iter().map(|x| { let y = x * 2; return y; }).collect()
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("of a closure"));
+    }
+
+    #[test]
+    fn test_early_return_passes() {
+        let code = r#"
+            fn first_even(items: &[u32]) -> Option<u32> {
+                for item in items {
+                    if item % 2 == 0 {
+                        return Some(*item);
+                    }
+                }
+                None
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_unit_return_and_nested_tails_pass() {
+        let code = r#"
+            fn log(msg: &str) {
+                if msg.is_empty() {
+                    return;
+                }
+                println!("{}", msg);
+                return;
+            }
+
+            fn sign(x: i32) -> i32 {
+                if x < 0 { return -1; } else { return 1; }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
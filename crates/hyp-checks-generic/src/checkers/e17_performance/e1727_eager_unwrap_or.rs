@@ -0,0 +1,192 @@
+//! E1727: Call evaluated eagerly in `unwrap_or` / `ok_or` / `map_or`
+//!
+//! Detects `.unwrap_or(arg)`, `.ok_or(arg)` and `.map_or(arg, f)` whose
+//! (default) argument is a function or method call. Arguments are evaluated
+//! before the method runs, so the call happens even when the value is
+//! present and its result is thrown away. The `_else` variants take a
+//! closure and only call it when needed.
+//!
+//! Not reported: tuple-struct and enum-variant constructors (`Some(x)`,
+//! `Point(1, 2)`), and argument-less calls named in `cheap_calls`
+//! (`Vec::new()`, `Default::default()`), which cost next to nothing.
+//!
+//! Example:
+//! ```text
+//! // Bad: load_defaults() runs even when the setting is present
+//! let settings = cached.unwrap_or(load_defaults());
+//!
+//! // Good: only runs for None
+//! let settings = cached.unwrap_or_else(load_defaults);
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1727: Call evaluated eagerly in unwrap_or / ok_or / map_or
+    E1727EagerUnwrapOr,
+    code = "E1727",
+    name = "Call evaluated eagerly in unwrap_or",
+    suggestions = "Use unwrap_or_else / ok_or_else / map_or_else with a closure so the call only runs when its result is needed",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1727_eager_unwrap_or",
+    /// Configuration for E1727: Eager unwrap_or checker
+    config = E1727Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Argument-less functions or methods cheap enough to call eagerly (matched on the last path segment)
+        cheap_calls: Vec<String> = vec!["new".to_string(), "default".to_string()],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = EagerUnwrapOrVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct EagerUnwrapOrVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1727EagerUnwrapOr,
+}
+
+impl EagerUnwrapOrVisitor<'_> {
+    /// Whether `arg` is a call worth deferring
+    fn is_eager_call(&self, arg: &syn::Expr) -> bool {
+        let is_cheap = |name: &syn::Ident, has_args: bool| {
+            !has_args && self.checker.config.cheap_calls.iter().any(|cheap| name == cheap)
+        };
+        match arg {
+            syn::Expr::Call(call) => match &*call.func {
+                syn::Expr::Path(func) => func.path.segments.last().is_some_and(|segment| {
+                    let is_constructor = segment.ident.to_string().starts_with(char::is_uppercase);
+                    !is_constructor && !is_cheap(&segment.ident, !call.args.is_empty())
+                }),
+                _ => true,
+            },
+            syn::Expr::MethodCall(call) => !is_cheap(&call.method, !call.args.is_empty()),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for EagerUnwrapOrVisitor<'a> {
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        let method = node.method.to_string();
+        let expected_args = match method.as_str() {
+            "unwrap_or" | "ok_or" => 1,
+            "map_or" => 2,
+            _ => 0,
+        };
+        if let Some(arg) = node.args.first().filter(|_| node.args.len() == expected_args) {
+            if self.is_eager_call(arg) {
+                let start = arg.span().start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "The argument of .{}() is a call that runs even when its result is discarded; use .{}_else() with a closure.",
+                            method, method
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_span(start, arg.span().end())
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_with(checker: &E1727EagerUnwrapOr, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_with(&E1727EagerUnwrapOr::default(), code)
+    }
+
+    #[test]
+    fn test_detects_call_in_unwrap_or() {
+        let code = r#"
+            fn settings(cached: Option<Settings>) -> Settings {
+                cached.unwrap_or(make_default())
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1727");
+        assert_eq!((violations[0].line, violations[0].column), (3, 34));
+        assert!(violations[0].message.contains(".unwrap_or() is a call"));
+        assert!(violations[0].message.contains("use .unwrap_or_else()"));
+    }
+
+    #[test]
+    fn test_detects_ok_or_and_map_or() {
+        let code = r#"
+            impl Store {
+                fn get(&self, key: &str) -> Result<usize, Error> {
+                    let entry = self.find(key).ok_or(Error::missing(key.to_string()))?;
+                    Ok(entry.size.map_or(self.estimate(key), |size| size * 2))
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains(".ok_or_else()"));
+        assert!(violations[1].message.contains(".map_or_else()"));
+    }
+
+    #[test]
+    fn test_cheap_arguments_pass() {
+        let code = r#"
+            fn f(a: Option<u32>, b: Option<Vec<u8>>, c: Option<Point>, fallback: u32) -> u32 {
+                let x = a.unwrap_or(0) + a.unwrap_or(fallback);
+                let v = b.unwrap_or(Vec::new());
+                let p = c.unwrap_or(Point(1, 2));
+                let d = a.ok_or(Error::Missing);
+                let s = a.map_or(0, |n| n + 1);
+                let lazy = a.unwrap_or_else(make_default);
+                x + v.len() as u32 + p.0 + lazy + s + d.unwrap_or(Default::default())
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_custom_cheap_calls() {
+        let code = r#"
+            fn f(a: Option<Id>) -> Id {
+                a.unwrap_or(Id::zero())
+            }
+        "#;
+        assert_eq!(check_code(code).len(), 1);
+
+        let mut checker = E1727EagerUnwrapOr::default();
+        checker.config.cheap_calls.push("zero".to_string());
+        assert!(check_with(&checker, code).is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+//! E1730: collect() into String inside a loop
+//!
+//! Detects `.collect::<String>()` results built inside a loop body and then
+//! appended to an outer accumulator with `push_str` or `+=`. Each iteration
+//! allocates a temporary `String` only to copy it into the accumulator; the
+//! iterator can extend the accumulator directly. Pairs with E1703.
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1730: collect() into String inside a loop
+    E1730CollectStringInLoop,
+    code = "E1730",
+    name = "collect() into String inside loop",
+    suggestions = "Append directly to the accumulator, e.g. `acc.extend(iter)` or `for c in iter { acc.push(c) }`, instead of collecting a temporary String each iteration",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1730_collect_string_in_loop",
+    /// Configuration for E1730: collect() into String inside a loop checker
+    config = E1730Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = CollectStringVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            in_loop: false,
+            collected_locals: Vec::new(),
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct CollectStringVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1730CollectStringInLoop,
+    in_loop: bool,
+    /// Locals bound to a collected String inside the current loop body
+    collected_locals: Vec<String>,
+}
+
+impl<'a> CollectStringVisitor<'a> {
+    fn with_loop(&mut self, visit: impl FnOnce(&mut Self)) {
+        let was_in_loop = self.in_loop;
+        let original_len = self.collected_locals.len();
+        self.in_loop = true;
+        visit(self);
+        self.in_loop = was_in_loop;
+        self.collected_locals.truncate(original_len);
+    }
+
+    /// Whether `expr` is a collected String: a direct `collect::<String>()` or a local bound to one
+    fn is_collected_string(&self, expr: &syn::Expr) -> bool {
+        match expr {
+            syn::Expr::Reference(reference) => self.is_collected_string(&reference.expr),
+            syn::Expr::Paren(paren) => self.is_collected_string(&paren.expr),
+            syn::Expr::Path(path) => path
+                .path
+                .get_ident()
+                .is_some_and(|ident| self.collected_locals.contains(&ident.to_string())),
+            _ => is_collect_to_string(expr),
+        }
+    }
+
+    fn report(&mut self, span: proc_macro2::Span, accumulator: &syn::Expr) {
+        let start = span.start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "collect::<String>() inside a loop allocates a temporary String every iteration before appending it to '{}'.",
+                    quote::quote!(#accumulator).to_string().replace(' ', "")
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for CollectStringVisitor<'a> {
+    fn visit_expr_for_loop(&mut self, node: &'a syn::ExprForLoop) {
+        self.visit_expr(&node.expr);
+        self.with_loop(|v| v.visit_block(&node.body));
+    }
+
+    fn visit_expr_while(&mut self, node: &'a syn::ExprWhile) {
+        self.with_loop(|v| syn::visit::visit_expr_while(v, node));
+    }
+
+    fn visit_expr_loop(&mut self, node: &'a syn::ExprLoop) {
+        self.with_loop(|v| syn::visit::visit_expr_loop(v, node));
+    }
+
+    fn visit_local(&mut self, node: &'a syn::Local) {
+        if self.in_loop {
+            if let Some(init) = &node.init {
+                let (name, annotated_string) = match &node.pat {
+                    syn::Pat::Ident(pat_ident) => (Some(&pat_ident.ident), false),
+                    syn::Pat::Type(pat_type) => match &*pat_type.pat {
+                        syn::Pat::Ident(pat_ident) => (Some(&pat_ident.ident), is_string_type(&pat_type.ty)),
+                        _ => (None, false),
+                    },
+                    _ => (None, false),
+                };
+                let collects = is_collect_to_string(&init.expr) || annotated_string && is_collect_call(&init.expr);
+                if let (Some(name), true) = (name, collects) {
+                    self.collected_locals.push(name.to_string());
+                }
+            }
+        }
+        syn::visit::visit_local(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        // acc.push_str(&chunk) where chunk was collected in this iteration
+        if self.in_loop && node.method == "push_str" {
+            if let Some(arg) = node.args.first() {
+                if self.is_collected_string(arg) {
+                    self.report(node.span(), &node.receiver);
+                }
+            }
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_binary(&mut self, node: &'a syn::ExprBinary) {
+        // acc += &chunk
+        if self.in_loop && matches!(node.op, syn::BinOp::AddAssign(_)) && self.is_collected_string(&node.right) {
+            self.report(node.span(), &node.left);
+        }
+        syn::visit::visit_expr_binary(self, node);
+    }
+}
+
+/// Whether `expr` is `.collect::<String>()`
+fn is_collect_to_string(expr: &syn::Expr) -> bool {
+    let syn::Expr::MethodCall(call) = expr else {
+        return false;
+    };
+    call.method == "collect"
+        && call.turbofish.as_ref().is_some_and(|turbofish| {
+            matches!(turbofish.args.first(), Some(syn::GenericArgument::Type(ty)) if is_string_type(ty))
+        })
+}
+
+/// Whether `expr` is any `.collect()` call
+fn is_collect_call(expr: &syn::Expr) -> bool {
+    matches!(expr, syn::Expr::MethodCall(call) if call.method == "collect")
+}
+
+/// Whether a type is `String`
+fn is_string_type(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(type_path) if type_path.path.segments.last().is_some_and(|s| s.ident == "String"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1730CollectStringInLoop::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_direct_push_str() {
+        let code = r#"
+            fn render(rows: &[Vec<char>]) -> String {
+                let mut out = String::new();
+                for row in rows {
+                    out.push_str(&row.iter().collect::<String>());
+                }
+                out
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1730");
+        assert!(violations[0].message.contains("'out'"));
+    }
+
+    #[test]
+    fn test_detects_local_then_add_assign() {
+        let code = r#"
+            fn render(words: &[&str]) -> String {
+                let mut out = String::new();
+                for word in words {
+                    let upper: String = word.chars().rev().collect();
+                    out += &upper;
+                }
+                out
+            }
+        "#;
+        assert_eq!(check_code(code).len(), 1);
+    }
+
+    #[test]
+    fn test_top_level_collect_not_flagged() {
+        let code = r#"
+            fn render(chars: &[char]) -> String {
+                let mut out = String::from("> ");
+                let body = chars.iter().collect::<String>();
+                out.push_str(&body);
+                out
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_collect_in_loop_not_appended_not_flagged() {
+        let code = r#"
+            fn render(rows: &[Vec<char>]) -> Vec<String> {
+                let mut lines = Vec::new();
+                for row in rows {
+                    let line = row.iter().collect::<String>();
+                    lines.push(line);
+                }
+                lines
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_local_from_previous_loop_not_leaked() {
+        let code = r#"
+            fn render(rows: &[Vec<char>], first: String) -> String {
+                let mut out = String::new();
+                for row in rows {
+                    let line = row.iter().collect::<String>();
+                    let _ = line;
+                }
+                let line = first;
+                out.push_str(&line);
+                out
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+}
@@ -0,0 +1,220 @@
+//! E1732: Character count compared against a byte capacity
+//!
+//! Detects `s.chars().count()` compared with a value that measures bytes: a
+//! `.capacity()`, an `.as_bytes().len()`, or a name such as `max_bytes`,
+//! `buf_len` or `CAPACITY`. For non-ASCII text the character count is smaller
+//! than the byte length, so a bounds check written this way lets oversized
+//! strings through; it also walks the whole string where `.len()` is O(1).
+//! Matching on names is a heuristic.
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1732: Character count compared against a byte capacity
+    E1732LenCharsConfusion,
+    code = "E1732",
+    name = "chars().count() compared against byte capacity",
+    suggestions = "Use .len() (bytes) when checking against byte capacities or buffer sizes; keep .chars().count() for limits measured in characters",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1732_len_chars_confusion",
+    /// Configuration for E1732: chars().count() vs byte capacity checker
+    config = E1732Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Medium,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Name words (split on `_`, case-insensitive) that mark a value as a byte size
+        byte_name_hints: Vec<String> = vec![
+            "byte".to_string(),
+            "bytes".to_string(),
+            "capacity".to_string(),
+            "cap".to_string(),
+            "buf".to_string(),
+            "buffer".to_string(),
+        ],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = LenCharsVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct LenCharsVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1732LenCharsConfusion,
+}
+
+impl<'a> LenCharsVisitor<'a> {
+    /// Whether an identifier contains one of the configured byte-size words
+    fn is_byte_name(&self, name: &str) -> bool {
+        name.split('_').filter(|word| !word.is_empty()).any(|word| {
+            self.checker
+                .config
+                .byte_name_hints
+                .iter()
+                .any(|hint| word.eq_ignore_ascii_case(hint))
+        })
+    }
+
+    /// Whether `expr` measures bytes: `x.capacity()`, `x.as_bytes().len()`, `buf.len()`, `max_bytes`, `self.cap`
+    fn is_byte_size(&self, expr: &syn::Expr) -> bool {
+        match expr {
+            syn::Expr::Paren(paren) => self.is_byte_size(&paren.expr),
+            syn::Expr::Cast(cast) => self.is_byte_size(&cast.expr),
+            syn::Expr::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| self.is_byte_name(&segment.ident.to_string())),
+            syn::Expr::Field(field) => match &field.member {
+                syn::Member::Named(ident) => self.is_byte_name(&ident.to_string()),
+                syn::Member::Unnamed(_) => false,
+            },
+            syn::Expr::MethodCall(call) if call.method == "capacity" => true,
+            syn::Expr::MethodCall(call) if call.method == "len" => match &*call.receiver {
+                syn::Expr::MethodCall(inner) => inner.method == "as_bytes",
+                receiver => self.is_byte_size(receiver),
+            },
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for LenCharsVisitor<'a> {
+    fn visit_expr_binary(&mut self, node: &'a syn::ExprBinary) {
+        let is_comparison = matches!(
+            node.op,
+            syn::BinOp::Lt(_) | syn::BinOp::Le(_) | syn::BinOp::Gt(_) | syn::BinOp::Ge(_) | syn::BinOp::Eq(_) | syn::BinOp::Ne(_)
+        );
+        if is_comparison {
+            let pair = if is_chars_count(&node.left) && self.is_byte_size(&node.right) {
+                Some((&node.left, &node.right))
+            } else if is_chars_count(&node.right) && self.is_byte_size(&node.left) {
+                Some((&node.right, &node.left))
+            } else {
+                None
+            };
+
+            if let Some((count, bytes)) = pair {
+                let start = node.span().start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "'{}' counts characters but is compared with '{}', which looks like a size in bytes. Non-ASCII text has more bytes than characters; use .len() for byte limits (heuristic, based on naming).",
+                            quote::quote!(#count).to_string().replace(' ', ""),
+                            quote::quote!(#bytes).to_string().replace(' ', "")
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_suggestion(self.checker.suggestions()),
+                );
+            }
+        }
+        syn::visit::visit_expr_binary(self, node);
+    }
+}
+
+/// Whether `expr` is `<expr>.chars().count()`
+fn is_chars_count(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Paren(paren) => is_chars_count(&paren.expr),
+        syn::Expr::MethodCall(call) => {
+            call.method == "count" && matches!(&*call.receiver, syn::Expr::MethodCall(inner) if inner.method == "chars")
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1732LenCharsConfusion::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_byte_named_limits() {
+        let code = r#"
+            fn fits(name: &str, max_bytes: usize) -> bool {
+                name.chars().count() <= max_bytes
+            }
+
+            const BUF_CAPACITY: usize = 64;
+            fn fits_const(name: &str) -> bool {
+                BUF_CAPACITY >= name.chars().count()
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1732");
+        assert!(violations[0].message.contains("'name.chars().count()'"));
+        assert!(violations[0].message.contains("'max_bytes'"));
+        assert!(violations[1].message.contains("'BUF_CAPACITY'"));
+    }
+
+    #[test]
+    fn test_detects_byte_measuring_calls() {
+        let code = r#"
+            impl Writer {
+                fn fits(&self, text: &str, other: &str) -> bool {
+                    text.chars().count() < self.buf.capacity()
+                        && text.chars().count() == other.as_bytes().len()
+                        && text.chars().count() < self.cap
+                }
+            }
+        "#;
+        assert_eq!(check_code(code).len(), 3);
+    }
+
+    #[test]
+    fn test_character_limits_not_flagged() {
+        let code = r#"
+            fn valid(name: &str, max_chars: usize, buf: &[u8]) -> bool {
+                name.chars().count() <= max_chars
+                    && name.len() <= buf.len()
+                    && name.chars().count() > 0
+                    && name.chars().count() < scapegoat_limit
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_custom_hints() {
+        let code = r#"
+            fn fits(name: &str, column_width: usize) -> bool {
+                name.chars().count() <= column_width
+            }
+        "#;
+        let checker = E1732LenCharsConfusion {
+            config: E1732Config {
+                byte_name_hints: vec!["width".to_string()],
+                ..E1732Config::default()
+            },
+        };
+        let syntax = syn::parse_file(code).unwrap();
+        assert_eq!(checker.check_item(&syntax.items[0], "test.rs").unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,313 @@
+//! E1733: Repeated map lookup with the same key
+//!
+//! Detects a map being looked up twice with the same key where one lookup
+//! would do: `contains_key(&k)` followed by `get(&k)`, `map[&k]` or `map[k]`,
+//! `insert(k, ..)` or `remove(&k)`, and `get(&k)` followed by `insert(k, ..)`.
+//! Each call hashes the key and probes the table again; the entry API or a
+//! single `get` does the work once.
+//!
+//! Example:
+//! ```text
+//! // Bad: two lookups
+//! if counts.contains_key(&word) {
+//!     *counts.get_mut(&word).unwrap() += 1;
+//! } else {
+//!     counts.insert(word, 1);
+//! }
+//!
+//! // Good: one lookup
+//! *counts.entry(word).or_insert(0) += 1;
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1733: Repeated map lookup with the same key
+    E1733RepeatedMapLookup,
+    code = "E1733",
+    name = "Repeated map lookup with the same key",
+    suggestions = "Use the entry API (`map.entry(k).or_insert(..)`, `and_modify`) or a single `get`/`get_mut` whose result is matched",
+    target_items = [Function, Impl, Trait],
+    config_entry_name = "e1733_repeated_map_lookup",
+    /// Configuration for E1733: Repeated map lookup checker
+    config = E1733Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = MapLookupVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            scopes: vec![Vec::new()],
+            reported: Vec::new(),
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+/// A map access: the receiver and key as normalized source text, and the method used
+struct Lookup {
+    map: String,
+    key: String,
+    method: String,
+}
+
+struct MapLookupVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1733RepeatedMapLookup,
+    /// Lookups seen so far, one frame per enclosing block
+    scopes: Vec<Vec<Lookup>>,
+    /// (map, key) pairs already reported
+    reported: Vec<(String, String)>,
+}
+
+impl<'a> MapLookupVisitor<'a> {
+    /// Each function starts with no known lookups
+    fn in_function(&mut self, visit: impl FnOnce(&mut Self)) {
+        let scopes = std::mem::replace(&mut self.scopes, vec![Vec::new()]);
+        let reported = std::mem::take(&mut self.reported);
+        visit(self);
+        self.scopes = scopes;
+        self.reported = reported;
+    }
+
+    /// Record an access and report it if an earlier lookup on the same map and key makes it redundant
+    fn access(&mut self, map: String, key: String, method: &str, span: proc_macro2::Span) {
+        let earlier = self
+            .scopes
+            .iter()
+            .flatten()
+            .find(|lookup| lookup.map == map && lookup.key == key && is_redundant_pair(&lookup.method, method))
+            .map(|lookup| lookup.method.clone());
+
+        if let Some(first) = earlier {
+            let pair = (map.clone(), key.clone());
+            if !self.reported.contains(&pair) {
+                let start = span.start();
+                self.violations.push(
+                    Violation::new(
+                        self.checker.code(),
+                        self.checker.name(),
+                        self.checker.severity().into(),
+                        format!(
+                            "'{}' is looked up with key '{}' by {}() and again by {}; each lookup hashes the key and probes the map.",
+                            map,
+                            key,
+                            first,
+                            if method == "index" { "indexing".to_string() } else { format!("{}()", method) }
+                        ),
+                        self.file_path,
+                        start.line,
+                        start.column + 1,
+                    )
+                    .with_suggestion(self.checker.suggestions()),
+                );
+                self.reported.push(pair);
+            }
+        }
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(Lookup {
+                map,
+                key,
+                method: method.to_string(),
+            });
+        }
+    }
+}
+
+/// Whether a `second` access after a `first` one on the same key repeats the lookup
+fn is_redundant_pair(first: &str, second: &str) -> bool {
+    match first {
+        "contains_key" => matches!(second, "get" | "get_mut" | "index" | "insert" | "remove"),
+        "get" | "get_mut" => second == "insert",
+        _ => false,
+    }
+}
+
+/// Normalized source text for a map receiver (`map`, `self.cache`); other receivers are ignored
+fn receiver_text(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(_) | syn::Expr::Field(_) => Some(quote::quote!(#expr).to_string().replace(' ', "")),
+        syn::Expr::Paren(paren) => receiver_text(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Normalized key text: `&k`, `k`, `k.clone()` and `k.to_string()` all become `k`
+fn key_text(expr: &syn::Expr) -> String {
+    match expr {
+        syn::Expr::Reference(reference) => key_text(&reference.expr),
+        syn::Expr::Paren(paren) => key_text(&paren.expr),
+        syn::Expr::MethodCall(call)
+            if call.args.is_empty() && matches!(call.method.to_string().as_str(), "clone" | "to_string" | "to_owned" | "into") =>
+        {
+            key_text(&call.receiver)
+        }
+        _ => quote::quote!(#expr).to_string().replace(' ', ""),
+    }
+}
+
+impl<'a> Visit<'a> for MapLookupVisitor<'a> {
+    fn visit_block(&mut self, node: &'a syn::Block) {
+        self.scopes.push(Vec::new());
+        syn::visit::visit_block(self, node);
+        self.scopes.pop();
+    }
+
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.in_function(|v| syn::visit::visit_item_fn(v, node));
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.in_function(|v| syn::visit::visit_impl_item_fn(v, node));
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        // Visit arguments first so a lookup nested in the key is recorded before this call
+        syn::visit::visit_expr_method_call(self, node);
+
+        let method = node.method.to_string();
+        if !matches!(method.as_str(), "contains_key" | "get" | "get_mut" | "insert" | "remove") {
+            return;
+        }
+        let expected_args = if method == "insert" { 2 } else { 1 };
+        if node.args.len() != expected_args {
+            return;
+        }
+        if let (Some(map), Some(key)) = (receiver_text(&node.receiver), node.args.first()) {
+            self.access(map, key_text(key), &method, node.span());
+        }
+    }
+
+    fn visit_expr_index(&mut self, node: &'a syn::ExprIndex) {
+        syn::visit::visit_expr_index(self, node);
+
+        // `m[&k]` or `m[k]` with `k` already a reference; a Vec or slice index is never
+        // reported because only a preceding `contains_key` makes indexing redundant
+        if let Some(map) = receiver_text(&node.expr) {
+            self.access(map, key_text(&node.index), "index", node.span());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1733RepeatedMapLookup::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_contains_key_then_get() {
+        let code = r#"
+            fn price(prices: &HashMap<String, u32>, item: &str) -> u32 {
+                if prices.contains_key(item) {
+                    *prices.get(item).unwrap()
+                } else {
+                    0
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1733");
+        assert!(violations[0].message.contains("'prices'"));
+        assert!(violations[0].message.contains("contains_key() and again by get()"));
+        assert_eq!(violations[0].line, 4);
+    }
+
+    #[test]
+    fn test_detects_get_then_insert_and_index() {
+        let code = r#"
+            impl Cache {
+                fn bump(&mut self, key: String) {
+                    let current = self.hits.get(&key).copied().unwrap_or(0);
+                    self.hits.insert(key.clone(), current + 1);
+                }
+
+                fn read(&self, key: &str) -> u32 {
+                    if self.hits.contains_key(key) { self.hits[&key] } else { 0 }
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("get() and again by insert()"));
+        assert!(violations[1].message.contains("contains_key() and again by indexing"));
+    }
+
+    #[test]
+    fn test_entry_and_single_lookup_not_flagged() {
+        let code = r#"
+            fn count(counts: &mut HashMap<String, u32>, word: String) {
+                *counts.entry(word).or_insert(0) += 1;
+            }
+
+            fn price(prices: &HashMap<String, u32>, item: &str) -> u32 {
+                prices.get(item).copied().unwrap_or(0)
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_different_keys_maps_and_functions_not_flagged() {
+        let code = r#"
+            fn copy(a: &HashMap<u32, u32>, b: &mut HashMap<u32, u32>, k: u32, j: u32) {
+                if a.contains_key(&k) {
+                    b.insert(k, 1);
+                    a.get(&j);
+                }
+            }
+
+            fn other(a: &mut HashMap<u32, u32>, k: u32) {
+                a.insert(k, 2);
+            }
+
+            fn vec_index(v: &Vec<u32>, i: usize) -> u32 {
+                if v.contains(&v[i]) { v[i] } else { 0 }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_sibling_block_lookups_do_not_leak() {
+        let code = r#"
+            fn f(m: &mut HashMap<u32, u32>, k: u32) {
+                { let _ = m.contains_key(&k); }
+                m.insert(k, 1);
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_detects_contains_key_then_index_by_reference_key() {
+        let code = r#"
+            fn price(prices: &HashMap<String, u32>, item: &str) -> u32 {
+                if prices.contains_key(item) { prices[item] } else { 0 }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'prices' is looked up with key 'item' by contains_key() and again by indexing"));
+    }
+}
@@ -11,6 +11,18 @@ pub mod e1708_inefficient_data_structure;
 pub mod e1709_unnecessary_boxing;
 pub mod e1710_large_stack_allocation;
 pub mod e1712_expensive_ops_in_loop;
+pub mod e1713_format_in_push_str;
+pub mod e1718_vec_front_ops;
+pub mod e1719_mutual_recursion;
+pub mod e1722_unnecessary_to_vec;
+pub mod e1723_hash_map_order_reliance;
+pub mod e1724_len_zero_comparison;
+pub mod e1725_clone_then_consume;
+pub mod e1726_needless_return;
+pub mod e1727_eager_unwrap_or;
+pub mod e1730_collect_string_in_loop;
+pub mod e1732_len_chars_confusion;
+pub mod e1733_repeated_map_lookup;
 pub mod registry;
 
 pub use e1701_oversized_struct_by_value::{E1701Config, E1701OversizedStructByValue};
@@ -24,3 +36,15 @@ pub use e1708_inefficient_data_structure::{E1708Config, E1708InefficientDataStru
 pub use e1709_unnecessary_boxing::{E1709Config, E1709UnnecessaryBoxing};
 pub use e1710_large_stack_allocation::{E1710Config, E1710LargeStackAllocation};
 pub use e1712_expensive_ops_in_loop::{E1712Config, E1712ExpensiveOpsInLoop};
+pub use e1713_format_in_push_str::{E1713Config, E1713FormatInPushStr};
+pub use e1719_mutual_recursion::{E1719Config, E1719MutualRecursion};
+pub use e1730_collect_string_in_loop::{E1730Config, E1730CollectStringInLoop};
+pub use e1732_len_chars_confusion::{E1732Config, E1732LenCharsConfusion};
+pub use e1733_repeated_map_lookup::{E1733Config, E1733RepeatedMapLookup};
+pub use e1718_vec_front_ops::{E1718Config, E1718VecFrontOps};
+pub use e1722_unnecessary_to_vec::{E1722Config, E1722UnnecessaryToVec};
+pub use e1723_hash_map_order_reliance::{E1723Config, E1723HashMapOrderReliance};
+pub use e1724_len_zero_comparison::{E1724Config, E1724LenZeroComparison};
+pub use e1725_clone_then_consume::{E1725Config, E1725CloneThenConsume};
+pub use e1726_needless_return::{E1726Config, E1726NeedlessReturn};
+pub use e1727_eager_unwrap_or::{E1727Config, E1727EagerUnwrapOr};
@@ -9,6 +9,18 @@ use crate::{
         E1707UnboundedRecursion, E1708Config, E1708InefficientDataStructure, E1709Config,
         E1709UnnecessaryBoxing, E1710Config, E1710LargeStackAllocation, E1712Config,
         E1712ExpensiveOpsInLoop,
+        E1730Config, E1730CollectStringInLoop,
+        E1732Config, E1732LenCharsConfusion,
+        E1733Config, E1733RepeatedMapLookup,
+        E1713Config, E1713FormatInPushStr,
+        E1719Config, E1719MutualRecursion,
+        E1718Config, E1718VecFrontOps,
+        E1722Config, E1722UnnecessaryToVec,
+        E1723Config, E1723HashMapOrderReliance,
+        E1724Config, E1724LenZeroComparison,
+        E1725Config, E1725CloneThenConsume,
+        E1726Config, E1726NeedlessReturn,
+        E1727Config, E1727EagerUnwrapOr,
     },
     register_checker,
     registry::CheckerRegistration,
@@ -28,5 +40,17 @@ pub fn e17_registrations() -> Vec<CheckerRegistration> {
         register_checker!(E1709UnnecessaryBoxing, E1709Config),
         register_checker!(E1710LargeStackAllocation, E1710Config),
         register_checker!(E1712ExpensiveOpsInLoop, E1712Config),
+        register_checker!(E1730CollectStringInLoop, E1730Config),
+        register_checker!(E1732LenCharsConfusion, E1732Config),
+        register_checker!(E1733RepeatedMapLookup, E1733Config),
+        register_checker!(E1713FormatInPushStr, E1713Config),
+        register_checker!(E1719MutualRecursion, E1719Config),
+        register_checker!(E1718VecFrontOps, E1718Config),
+        register_checker!(E1722UnnecessaryToVec, E1722Config),
+        register_checker!(E1723HashMapOrderReliance, E1723Config),
+        register_checker!(E1724LenZeroComparison, E1724Config),
+        register_checker!(E1725CloneThenConsume, E1725Config),
+        register_checker!(E1726NeedlessReturn, E1726Config),
+        register_checker!(E1727EagerUnwrapOr, E1727Config),
     ]
 }
@@ -2,10 +2,17 @@
 //!
 //! Detects use of glob imports (use foo::*) which can pollute the namespace
 //! and make code harder to understand.
+//!
+//! When a file has a single glob import, the violation carries a fix-it that
+//! replaces `*` with the capitalized names (types, traits, variants, consts)
+//! the file uses but neither defines nor imports explicitly. Traits used only
+//! through method calls and lowercase items (functions, modules) are not seen,
+//! so review the edit before applying it.
 
 use crate::{define_checker, violation::Violation};
 
-use syn::spanned::Spanned;
+use std::collections::{BTreeSet, HashSet};
+use syn::{spanned::Spanned, visit::Visit};
 
 define_checker! {
     /// Checker for E1801: Glob imports
@@ -22,58 +29,183 @@ define_checker! {
         /// Allow glob imports from these modules (e.g., prelude)
         allowed_globs: Vec<String> = vec!["prelude".to_string()],
     },
-    check_item(self, item, file_path) {
-        let mut violations = Vec::new();
-
-        if let syn::Item::Use(use_item) = item {
-            check_use_tree(&use_item.tree, file_path, self, &mut violations);
-        }
-
-        Ok(violations)
+    check_item(self, _item, _file_path) {
+        // The fix-it needs the names used in the whole file, see check_file
+        Ok(Vec::new())
     }
-}
+    check_file(self, file, file_path) {
+        let mut globs = Vec::new();
+        for item in &file.items {
+            if let syn::Item::Use(use_item) = item {
+                collect_globs(&use_item.tree, self, &mut globs);
+            }
+        }
 
-fn check_use_tree(
-    tree: &syn::UseTree,
-    file_path: &str,
-    checker: &E1801GlobImports,
-    violations: &mut Vec<Violation>,
-) {
-    use crate::checker::Checker;
+        // With several globs it is unknown which one provides a name
+        let replacement = match globs.as_slice() {
+            [_] => explicit_imports(file),
+            _ => None,
+        };
 
-    match tree {
-        syn::UseTree::Glob(glob) => {
-            let start = glob.span().start();
-            violations.push(
-                Violation::new(
-                    checker.code(),
-                    checker.name(),
-                    checker.severity().into(),
+        Ok(globs
+            .into_iter()
+            .map(|glob| {
+                let span = glob.star_token.span();
+                let violation = Violation::new(
+                    self.code(),
+                    self.name(),
+                    self.severity().into(),
                     "Glob import (use foo::*) can pollute namespace. Import specific items instead.",
                     file_path,
-                    start.line,
-                    start.column + 1,
+                    span.start().line,
+                    span.start().column + 1,
                 )
-                .with_suggestion(checker.suggestions()),
-            );
-        }
+                .with_suggestion(self.suggestions());
+                match &replacement {
+                    Some(names) => violation.with_fixit(span.start(), span.end(), names.clone()),
+                    None => violation,
+                }
+            })
+            .collect())
+    }
+}
+
+fn collect_globs<'a>(tree: &'a syn::UseTree, checker: &E1801GlobImports, globs: &mut Vec<&'a syn::UseGlob>) {
+    match tree {
+        syn::UseTree::Glob(glob) => globs.push(glob),
         syn::UseTree::Path(path) => {
             // Check if this is an allowed glob path (e.g., prelude)
             let path_name = path.ident.to_string();
             if checker.config.allowed_globs.contains(&path_name) {
                 return;
             }
-            check_use_tree(&path.tree, file_path, checker, violations);
+            collect_globs(&path.tree, checker, globs);
         }
         syn::UseTree::Group(group) => {
             for item in &group.items {
-                check_use_tree(item, file_path, checker, violations);
+                collect_globs(item, checker, globs);
             }
         }
         _ => {}
     }
 }
 
+/// Names in the std prelude, which never come from a glob import
+const PRELUDE: &[&str] = &[
+    "AsMut", "AsRef", "Box", "Clone", "Copy", "Default", "DoubleEndedIterator", "Drop", "Eq", "Err",
+    "ExactSizeIterator", "Extend", "Fn", "FnMut", "FnOnce", "From", "FromIterator", "Into",
+    "IntoIterator", "Iterator", "None", "Ok", "Option", "Ord", "PartialEq", "PartialOrd", "Result",
+    "Self", "Send", "Sized", "Some", "String", "Sync", "ToOwned", "ToString", "TryFrom", "TryInto",
+    "Unpin", "Vec",
+];
+
+/// Replacement for the `*` of the file's only glob: `Name` or `{A, B}`
+fn explicit_imports(file: &syn::File) -> Option<String> {
+    let mut usage = NameUsage::default();
+    usage.visit_file(file);
+    let names: Vec<_> = usage
+        .used
+        .into_iter()
+        .filter(|name| !usage.defined.contains(name) && !PRELUDE.contains(&name.as_str()))
+        .collect();
+    match names.as_slice() {
+        [] => None,
+        [name] => Some(name.clone()),
+        _ => Some(format!("{{{}}}", names.join(", "))),
+    }
+}
+
+/// Capitalized names a file refers to, and the names it defines or imports itself
+#[derive(Default)]
+struct NameUsage {
+    used: BTreeSet<String>,
+    defined: HashSet<String>,
+}
+
+impl NameUsage {
+    fn define(&mut self, ident: &syn::Ident) {
+        self.defined.insert(ident.to_string());
+    }
+}
+
+impl<'a> Visit<'a> for NameUsage {
+    fn visit_path(&mut self, node: &'a syn::Path) {
+        if node.leading_colon.is_none() {
+            if let Some(first) = node.segments.first() {
+                let name = first.ident.to_string();
+                if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                    self.used.insert(name);
+                }
+            }
+        }
+        syn::visit::visit_path(self, node);
+    }
+
+    fn visit_use_tree(&mut self, node: &'a syn::UseTree) {
+        match node {
+            syn::UseTree::Name(name) => self.define(&name.ident),
+            syn::UseTree::Rename(rename) => self.define(&rename.rename),
+            _ => {}
+        }
+        syn::visit::visit_use_tree(self, node);
+    }
+
+    fn visit_item_struct(&mut self, node: &'a syn::ItemStruct) {
+        self.define(&node.ident);
+        syn::visit::visit_item_struct(self, node);
+    }
+
+    fn visit_item_enum(&mut self, node: &'a syn::ItemEnum) {
+        self.define(&node.ident);
+        syn::visit::visit_item_enum(self, node);
+    }
+
+    fn visit_variant(&mut self, node: &'a syn::Variant) {
+        self.define(&node.ident);
+        syn::visit::visit_variant(self, node);
+    }
+
+    fn visit_item_trait(&mut self, node: &'a syn::ItemTrait) {
+        self.define(&node.ident);
+        syn::visit::visit_item_trait(self, node);
+    }
+
+    fn visit_item_type(&mut self, node: &'a syn::ItemType) {
+        self.define(&node.ident);
+        syn::visit::visit_item_type(self, node);
+    }
+
+    fn visit_item_union(&mut self, node: &'a syn::ItemUnion) {
+        self.define(&node.ident);
+        syn::visit::visit_item_union(self, node);
+    }
+
+    fn visit_item_const(&mut self, node: &'a syn::ItemConst) {
+        self.define(&node.ident);
+        syn::visit::visit_item_const(self, node);
+    }
+
+    fn visit_item_static(&mut self, node: &'a syn::ItemStatic) {
+        self.define(&node.ident);
+        syn::visit::visit_item_static(self, node);
+    }
+
+    fn visit_trait_item_type(&mut self, node: &'a syn::TraitItemType) {
+        self.define(&node.ident);
+        syn::visit::visit_trait_item_type(self, node);
+    }
+
+    fn visit_type_param(&mut self, node: &'a syn::TypeParam) {
+        self.define(&node.ident);
+        syn::visit::visit_type_param(self, node);
+    }
+
+    fn visit_const_param(&mut self, node: &'a syn::ConstParam) {
+        self.define(&node.ident);
+        syn::visit::visit_const_param(self, node);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,11 +219,7 @@ mod tests {
 
         let syntax = syn::parse_file(code).unwrap();
         let checker = E1801GlobImports::default();
-
-        let mut violations = Vec::new();
-        for item in &syntax.items {
-            violations.extend(checker.check_item(item, "test.rs").unwrap());
-        }
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
 
         assert_eq!(violations.len(), 1);
     }
@@ -104,12 +232,47 @@ mod tests {
 
         let syntax = syn::parse_file(code).unwrap();
         let checker = E1801GlobImports::default();
-
-        let mut violations = Vec::new();
-        for item in &syntax.items {
-            violations.extend(checker.check_item(item, "test.rs").unwrap());
-        }
+        let violations = checker.check_file(&syntax, "test.rs").unwrap();
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_fixit_lists_used_names() {
+        let code = r#"
+use std::collections::*;
+
+struct Index<K> {
+    by_key: HashMap<K, usize>,
+    order: VecDeque<K>,
+    seen: Option<BTreeSet<String>>,
+}
+"#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let violations = E1801GlobImports::default().check_file(&syntax, "test.rs").unwrap();
+
+        assert_eq!(violations.len(), 1);
+        let fixit = violations[0].replacement.as_ref().unwrap();
+        assert_eq!(fixit.span_start, (2, 23));
+        assert_eq!(fixit.span_end, (2, 24));
+        assert_eq!(fixit.new_text, "{BTreeSet, HashMap, VecDeque}");
+    }
+
+    #[test]
+    fn test_no_fixit_with_several_globs() {
+        let code = r#"
+            use std::collections::*;
+            use std::io::*;
+            use crate::prelude::*;
+
+            fn run(map: HashMap<u8, u8>) -> Result<()> { Ok(()) }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let violations = E1801GlobImports::default().check_file(&syntax, "test.rs").unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.replacement.is_none()));
+    }
 }
@@ -2,10 +2,30 @@
 //!
 //! Detects function parameters that take String where &str would suffice,
 //! forcing unnecessary allocations by callers.
+//!
+//! For a plain (non-`mut`) parameter that the body only borrows (`&name`,
+//! `name.as_str()`, `name.len()`, formatting, comparisons) the violation
+//! carries a fix-it that rewrites the type to `&str`; callers passing a
+//! `String` then need a `&`. Parameters that are moved or returned are
+//! reported without a fix-it, since `&str` would not compile there.
 
+use super::e1820_vec_param_instead_of_slice::mentions;
 use crate::{define_checker, violation::Violation};
 
-use syn::spanned::Spanned;
+use syn::{spanned::Spanned, visit::Visit};
+
+/// `String` methods taking `&self` that `&str` offers with compatible results
+const BORROWING_METHODS: &[&str] = &[
+    "as_str", "as_bytes", "len", "is_empty", "chars", "bytes", "lines", "split", "split_whitespace",
+    "trim", "starts_with", "ends_with", "contains", "find", "parse", "to_string", "to_owned",
+    "to_lowercase", "to_uppercase", "eq",
+];
+
+/// Macros that only take references to their format arguments
+const FORMAT_MACROS: &[&str] = &[
+    "format", "print", "println", "eprint", "eprintln", "write", "writeln", "panic", "assert", "assert_eq",
+    "assert_ne", "debug_assert", "trace", "debug", "info", "warn", "error",
+];
 
 define_checker! {
     /// Checker for E1810: String instead of &str
@@ -39,21 +59,35 @@ define_checker! {
                         };
 
                         let start = pat_type.span().start();
-                        violations.push(
-                            Violation::new(
-                                self.code(),
-                                self.name(),
-                                self.severity().into(),
-                                format!(
-                                    "Parameter '{}' takes String. Consider &str if ownership isn't needed.",
-                                    param_name
-                                ),
-                                file_path,
-                                start.line,
-                                start.column + 1,
-                            )
-                            .with_suggestion(self.suggestions()),
-                        );
+                        let violation = Violation::new(
+                            self.code(),
+                            self.name(),
+                            self.severity().into(),
+                            format!(
+                                "Parameter '{}' takes String. Consider &str if ownership isn't needed.",
+                                param_name
+                            ),
+                            file_path,
+                            start.line,
+                            start.column + 1,
+                        )
+                        .with_suggestion(self.suggestions());
+
+                        // A `mut` binding may be modified in place, and a moved one needs ownership
+                        let only_borrowed = match &*pat_type.pat {
+                            syn::Pat::Ident(ident) if ident.mutability.is_none() && ident.by_ref.is_none() => {
+                                let mut uses = OwnedUses { name: &param_name, count: 0 };
+                                uses.visit_block(&func.block);
+                                uses.count == 0
+                            }
+                            _ => false,
+                        };
+                        violations.push(if only_borrowed {
+                            let ty = pat_type.ty.span();
+                            violation.with_fixit(ty.start(), ty.end(), "&str")
+                        } else {
+                            violation
+                        });
                     }
                 }
             }
@@ -63,6 +97,78 @@ define_checker! {
     }
 }
 
+/// Counts the uses of a parameter in a function body that a `&str` would not support
+struct OwnedUses<'a> {
+    name: &'a str,
+    count: usize,
+}
+
+impl OwnedUses<'_> {
+    fn is_param(&self, expr: &syn::Expr) -> bool {
+        matches!(expr, syn::Expr::Path(path) if path.qself.is_none() && path.path.is_ident(self.name))
+    }
+}
+
+impl<'a> Visit<'a> for OwnedUses<'_> {
+    fn visit_expr_reference(&mut self, node: &'a syn::ExprReference) {
+        if !(node.mutability.is_none() && self.is_param(&node.expr)) {
+            syn::visit::visit_expr_reference(self, node);
+        }
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if self.is_param(&node.receiver) && BORROWING_METHODS.iter().any(|method| node.method == method) {
+            for arg in &node.args {
+                self.visit_expr(arg);
+            }
+        } else {
+            syn::visit::visit_expr_method_call(self, node);
+        }
+    }
+
+    fn visit_expr_binary(&mut self, node: &'a syn::ExprBinary) {
+        // `name == other` compares just as well with &str
+        let comparison = matches!(
+            node.op,
+            syn::BinOp::Eq(_) | syn::BinOp::Ne(_) | syn::BinOp::Lt(_) | syn::BinOp::Le(_) | syn::BinOp::Gt(_) | syn::BinOp::Ge(_)
+        );
+        for side in [&node.left, &node.right] {
+            if !(comparison && self.is_param(side)) {
+                self.visit_expr(side);
+            }
+        }
+    }
+
+    fn visit_expr_path(&mut self, node: &'a syn::ExprPath) {
+        if node.qself.is_none() && node.path.is_ident(self.name) {
+            self.count += 1;
+        }
+    }
+
+    fn visit_pat_ident(&mut self, node: &'a syn::PatIdent) {
+        // Shadowed: later uses may refer to something else
+        if node.ident == self.name {
+            self.count += 1;
+        }
+        syn::visit::visit_pat_ident(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'a syn::Macro) {
+        let formats = node
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| FORMAT_MACROS.iter().any(|name| segment.ident == name));
+        if !formats && mentions(node.tokens.clone(), self.name) {
+            self.count += 1;
+        }
+    }
+
+    fn visit_item(&mut self, _node: &'a syn::Item) {
+        // Nested items cannot see the parameter
+    }
+}
+
 fn is_owned_string(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty {
         if let Some(segment) = type_path.path.segments.last() {
@@ -133,4 +239,60 @@ mod tests {
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_fixit_rewrites_type() {
+        let code = r#"
+            pub fn greet(name: String, mut buf: String) {}
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let violations = E1810StringInsteadOfStr::default()
+            .check_item(&syntax.items[0], "test.rs")
+            .unwrap();
+
+        assert_eq!(violations.len(), 2);
+        let fixit = violations[0].replacement.as_ref().unwrap();
+        assert_eq!(fixit.span_start, (2, 32));
+        assert_eq!(fixit.span_end, (2, 38));
+        assert_eq!(fixit.new_text, "&str");
+        assert!(violations[1].replacement.is_none());
+    }
+
+    #[test]
+    fn test_no_fixit_for_moved_or_returned_params() {
+        let code = r#"
+            pub fn keep(name: String) -> String { name }
+            pub fn wrap(name: String) -> Vec<String> { vec![name] }
+            pub fn store(name: String) -> User { User { name } }
+            pub fn pass(name: String) { consume(name) }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1810StringInsteadOfStr::default();
+        for item in &syntax.items {
+            let violations = checker.check_item(item, "test.rs").unwrap();
+            assert_eq!(violations.len(), 1);
+            assert!(violations[0].replacement.is_none(), "{}", violations[0].message);
+        }
+    }
+
+    #[test]
+    fn test_fixit_for_borrowed_params() {
+        let code = r#"
+            pub fn greet(name: String, other: String) -> bool {
+                println!("Hello, {}!", name);
+                lookup(&name);
+                name.as_str().len() > 1 && name == other
+            }
+        "#;
+
+        let syntax = syn::parse_file(code).unwrap();
+        let violations = E1810StringInsteadOfStr::default()
+            .check_item(&syntax.items[0], "test.rs")
+            .unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.replacement.is_some()));
+    }
 }
@@ -0,0 +1,218 @@
+//! E1815: Builder method without `#[must_use]`
+//!
+//! Detects public methods that take `self`/`&self` and return `Self` (or the
+//! impl's own type) without a `#[must_use]` attribute. Such methods produce a
+//! new value instead of modifying the receiver, so a call whose result is
+//! dropped silently does nothing. Optionally (`check_result_fns`) public free
+//! functions returning `Result` are covered as well.
+//!
+//! Example:
+//! ```text
+//! // Bad: `builder.with_timeout(5);` compiles and has no effect
+//! pub fn with_timeout(self, secs: u64) -> Self { ... }
+//!
+//! // Good: the compiler warns when the result is ignored
+//! #[must_use]
+//! pub fn with_timeout(self, secs: u64) -> Self { ... }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1815: Builder method without #[must_use]
+    E1815MissingMustUse,
+    code = "E1815",
+    name = "Builder method without #[must_use]",
+    suggestions = "Add #[must_use] so the compiler warns when the returned value is dropped",
+    target_items = [Function, Impl],
+    config_entry_name = "e1815_missing_must_use",
+    /// Configuration for E1815: Missing #[must_use] checker
+    config = E1815Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Also report public free functions returning `Result` without #[must_use]
+        check_result_fns: bool = false,
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = MustUseVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+            self_type: None,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct MustUseVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1815MissingMustUse,
+    /// Name of the type of the inherent impl being visited
+    self_type: Option<String>,
+}
+
+impl<'a> MustUseVisitor<'a> {
+    fn report(&mut self, sig: &syn::Signature, what: &str) {
+        let start = sig.ident.span().start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "'{}' {} but is not #[must_use]; a call whose result is dropped has no effect.",
+                    sig.ident, what
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+
+    /// Whether `ty` is `Self` or the impl's own type
+    fn is_self_type(&self, ty: &syn::Type) -> bool {
+        let Some(name) = last_segment(ty) else {
+            return false;
+        };
+        name == "Self" || self.self_type.as_ref() == Some(&name)
+    }
+}
+
+impl<'a> Visit<'a> for MustUseVisitor<'a> {
+    fn visit_item_impl(&mut self, node: &'a syn::ItemImpl) {
+        // Trait methods carry their attributes on the trait declaration
+        if node.trait_.is_some() {
+            return;
+        }
+        let outer = std::mem::replace(&mut self.self_type, last_segment(&node.self_ty));
+        syn::visit::visit_item_impl(self, node);
+        self.self_type = outer;
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        let is_candidate = matches!(node.vis, syn::Visibility::Public(_))
+            && node.sig.receiver().is_some()
+            && !has_must_use(&node.attrs)
+            && matches!(&node.sig.output, syn::ReturnType::Type(_, ty) if self.is_self_type(ty));
+        if is_candidate {
+            self.report(&node.sig, "returns Self");
+        }
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        let is_candidate = self.checker.config.check_result_fns
+            && matches!(node.vis, syn::Visibility::Public(_))
+            && !has_must_use(&node.attrs)
+            && matches!(&node.sig.output, syn::ReturnType::Type(_, ty) if last_segment(ty).is_some_and(|name| name == "Result"));
+        if is_candidate {
+            self.report(&node.sig, "returns Result");
+        }
+        syn::visit::visit_item_fn(self, node);
+    }
+}
+
+fn has_must_use(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("must_use"))
+}
+
+/// Last path segment of a path type (`Config<T>` -> "Config")
+fn last_segment(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code_with(checker: &E1815MissingMustUse, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_code_with(&E1815MissingMustUse::default(), code)
+    }
+
+    #[test]
+    fn test_detects_builder_methods_without_must_use() {
+        let code = r#"
+            impl<T> ClientBuilder<T> {
+                pub fn with_timeout(mut self, secs: u64) -> Self {
+                    self.timeout = secs;
+                    self
+                }
+
+                pub fn with_retries(&self, retries: u32) -> ClientBuilder<T> {
+                    todo!()
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1815");
+        assert!(violations[0].message.contains("'with_timeout' returns Self"));
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[1].message.contains("'with_retries'"));
+    }
+
+    #[test]
+    fn test_must_use_and_non_builders_not_flagged() {
+        let code = r#"
+            impl ClientBuilder {
+                #[must_use]
+                pub fn with_timeout(mut self, secs: u64) -> Self {
+                    self
+                }
+
+                pub fn new() -> Self { todo!() }
+                fn with_private(self) -> Self { self }
+                pub fn set_timeout(&mut self, secs: u64) -> &mut Self { self }
+                pub fn build(self) -> Client { todo!() }
+            }
+
+            impl Clone for ClientBuilder {
+                fn clone(&self) -> Self { todo!() }
+            }
+
+            pub fn connect(addr: &str) -> Result<Client, Error> { todo!() }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_result_fns_opt_in() {
+        let checker = E1815MissingMustUse {
+            config: E1815Config {
+                check_result_fns: true,
+                ..E1815Config::default()
+            },
+        };
+        let code = r#"
+            pub fn connect(addr: &str) -> Result<Client, Error> { todo!() }
+            #[must_use]
+            pub fn parse(text: &str) -> io::Result<Config> { todo!() }
+            fn helper() -> Result<(), Error> { Ok(()) }
+        "#;
+        let violations = check_code_with(&checker, code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'connect' returns Result"));
+    }
+}
@@ -0,0 +1,221 @@
+//! E1816: `impl Trait` returned from a public function
+//!
+//! Detects `pub fn` signatures (free functions and inherent methods) whose
+//! return type contains `impl Trait` for a trait outside `allowed_traits`.
+//! Callers cannot name the returned type, so they cannot store it in a struct
+//! field, put it in a collection next to another implementation, or write it
+//! in their own signatures; every use has to go through the trait. For
+//! iterators, futures and closures that is idiomatic, for a crate's own
+//! domain traits it usually hides a concrete type callers would want.
+//!
+//! Each bound of `impl A + B` is checked; lifetimes are ignored.
+//!
+//! Example:
+//! ```text
+//! // Bad: callers can only ever see "some Storage"
+//! pub fn open(path: &Path) -> impl Storage { FileStorage::new(path) }
+//!
+//! // Good: name the type (or return Box<dyn Storage> if it must vary)
+//! pub fn open(path: &Path) -> FileStorage { FileStorage::new(path) }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use syn::visit::Visit;
+
+define_checker! {
+    /// Checker for E1816: impl Trait returned from a public function
+    E1816ImplTraitPublicReturn,
+    code = "E1816",
+    name = "impl Trait returned from a public function",
+    suggestions = "Return the concrete type (or a public newtype around it), or Box<dyn Trait> if the type must vary; add the trait to allowed_traits if opaque returns are intended",
+    target_items = [Function, Impl],
+    config_entry_name = "e1816_impl_trait_public_return",
+    /// Configuration for E1816: impl Trait public return checker
+    config = E1816Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Traits that may be returned as `impl Trait` (matched on the last path segment)
+        allowed_traits: Vec<String> = [
+            "Iterator",
+            "DoubleEndedIterator",
+            "ExactSizeIterator",
+            "IntoIterator",
+            "Future",
+            "Fn",
+            "FnMut",
+            "FnOnce",
+            "Send",
+            "Sync",
+            "Unpin",
+        ]
+        .iter()
+        .map(|name| name.to_string())
+        .collect(),
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = ImplReturnVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct ImplReturnVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1816ImplTraitPublicReturn,
+}
+
+impl ImplReturnVisitor<'_> {
+    fn check_signature(&mut self, vis: &syn::Visibility, sig: &syn::Signature) {
+        if !matches!(vis, syn::Visibility::Public(_)) {
+            return;
+        }
+        let syn::ReturnType::Type(_, ty) = &sig.output else {
+            return;
+        };
+
+        let mut collector = ImplTraitCollector::default();
+        collector.visit_type(ty);
+        let Some(trait_name) = collector
+            .traits
+            .into_iter()
+            .find(|name| !self.checker.config.allowed_traits.contains(name))
+        else {
+            return;
+        };
+
+        let start = sig.ident.span().start();
+        self.violations.push(
+            Violation::new(
+                self.checker.code(),
+                self.checker.name(),
+                self.checker.severity().into(),
+                format!(
+                    "Public function '{}' returns 'impl {}'; callers cannot name, store or replace the concrete type.",
+                    sig.ident, trait_name
+                ),
+                self.file_path,
+                start.line,
+                start.column + 1,
+            )
+            .with_suggestion(self.checker.suggestions()),
+        );
+    }
+}
+
+impl<'a> Visit<'a> for ImplReturnVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.check_signature(&node.vis, &node.sig);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'a syn::ItemImpl) {
+        // Trait impls follow the signature the trait declares
+        if node.trait_.is_none() {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.check_signature(&node.vis, &node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+}
+
+/// Collects the trait names bounded by `impl ..` anywhere in a type
+#[derive(Default)]
+struct ImplTraitCollector {
+    traits: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for ImplTraitCollector {
+    fn visit_type_impl_trait(&mut self, node: &'ast syn::TypeImplTrait) {
+        for bound in &node.bounds {
+            if let syn::TypeParamBound::Trait(bound) = bound {
+                if let Some(segment) = bound.path.segments.last() {
+                    self.traits.push(segment.ident.to_string());
+                }
+            }
+        }
+        syn::visit::visit_type_impl_trait(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1816ImplTraitPublicReturn::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_custom_trait() {
+        let code = r#"
+            pub fn open(path: &Path) -> impl Storage {
+                FileStorage::new(path)
+            }
+
+            impl Registry {
+                pub fn handlers(&self) -> Vec<impl Handler + Send + 'static> {
+                    todo!()
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1816");
+        assert_eq!((violations[0].line, violations[0].column), (2, 20));
+        assert!(violations[0].message.contains("Public function 'open' returns 'impl Storage'"));
+        assert!(violations[1].message.contains("'handlers' returns 'impl Handler'"));
+    }
+
+    #[test]
+    fn test_allowed_traits_and_private_fns_pass() {
+        let code = r#"
+            pub fn evens(limit: u32) -> impl Iterator<Item = u32> + Send {
+                (0..limit).filter(|n| n % 2 == 0)
+            }
+
+            pub fn adder(n: i32) -> impl Fn(i32) -> i32 {
+                move |x| x + n
+            }
+
+            fn open() -> impl Storage { todo!() }
+            pub(crate) fn open_crate() -> impl Storage { todo!() }
+
+            impl IntoIterator for Bag {
+                fn into_iter(self) -> impl Storage { todo!() }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_custom_allowlist() {
+        let code = "pub fn open() -> impl Storage { todo!() }";
+        let checker = E1816ImplTraitPublicReturn {
+            config: E1816Config {
+                allowed_traits: vec!["Storage".to_string()],
+                ..E1816Config::default()
+            },
+        };
+        let syntax = syn::parse_file(code).unwrap();
+        assert!(checker.check_item(&syntax.items[0], "test.rs").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+//! E1817: Public collection field
+//!
+//! Detects `pub` fields of public structs whose type is a standard collection
+//! (`Vec`, `HashMap`, `HashSet`, `BTreeMap`, `VecDeque` by default). Callers
+//! can then push, remove, clear or reorder the contents behind the owner's
+//! back, so any invariant over the collection (sorted, deduplicated, bounded,
+//! in sync with another field) cannot be upheld. E1802 looks at public fields
+//! in general; this rule only targets collections and can be enabled on its
+//! own.
+//!
+//! Only the outermost type is checked, matched on its last path segment, so
+//! `std::collections::HashMap<K, V>` is a collection and `Option<Vec<T>>` is
+//! not.
+//!
+//! Example:
+//! ```text
+//! // Bad: anyone can push an unsorted entry
+//! pub struct Schedule { pub slots: Vec<Slot> }
+//!
+//! // Good: mutation goes through methods that keep the order
+//! pub struct Schedule { slots: Vec<Slot> }
+//! impl Schedule {
+//!     pub fn slots(&self) -> &[Slot] { &self.slots }
+//!     pub fn insert(&mut self, slot: Slot) { .. }
+//! }
+//! ```
+
+use crate::{define_checker, violation::Violation};
+
+use syn::spanned::Spanned;
+
+define_checker! {
+    /// Checker for E1817: Public collection field
+    E1817PublicCollectionField,
+    code = "E1817",
+    name = "Public collection field",
+    suggestions = "Make the field private and expose accessors, e.g. fn items(&self) -> &[T] plus methods that keep the collection's invariants",
+    target_items = [Struct],
+    config_entry_name = "e1817_public_collection_field",
+    /// Configuration for E1817: Public collection field checker
+    config = E1817Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Collection types to report (matched on the last path segment)
+        collection_types: Vec<String> = ["Vec", "HashMap", "HashSet", "BTreeMap", "VecDeque"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+    },
+    check_item(self, item, file_path) {
+        let mut violations = Vec::new();
+
+        let syn::Item::Struct(struct_item) = item else {
+            return Ok(violations);
+        };
+        if !matches!(struct_item.vis, syn::Visibility::Public(_)) {
+            return Ok(violations);
+        }
+
+        for (index, field) in struct_item.fields.iter().enumerate() {
+            if !matches!(field.vis, syn::Visibility::Public(_)) {
+                continue;
+            }
+            let Some(collection) = self.collection_name(&field.ty) else {
+                continue;
+            };
+
+            let field_name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| index.to_string(), |ident| ident.to_string());
+            let start = field.span().start();
+            violations.push(
+                Violation::new(
+                    self.code(),
+                    self.name(),
+                    self.severity().into(),
+                    format!(
+                        "Field '{}.{}' is a public {}, so callers can change its contents without going through '{}'.",
+                        struct_item.ident, field_name, collection, struct_item.ident
+                    ),
+                    file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_suggestion(self.suggestions()),
+            );
+        }
+
+        Ok(violations)
+    }
+}
+
+impl E1817PublicCollectionField {
+    /// The configured collection the type is an instance of, if any
+    fn collection_name(&self, ty: &syn::Type) -> Option<String> {
+        let syn::Type::Path(type_path) = ty else {
+            return None;
+        };
+        let name = type_path.path.segments.last()?.ident.to_string();
+        self.config.collection_types.contains(&name).then_some(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::Checker;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1817PublicCollectionField::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_public_vec_field() {
+        let code = r#"
+            pub struct Packet {
+                pub items: Vec<u8>,
+                pub index: std::collections::HashMap<String, usize>,
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1817");
+        assert_eq!((violations[0].line, violations[0].column), (3, 17));
+        assert!(violations[0].message.contains("'Packet.items' is a public Vec"));
+        assert!(violations[1].message.contains("public HashMap"));
+    }
+
+    #[test]
+    fn test_non_collection_and_private_fields_pass() {
+        let code = r#"
+            pub struct Packet {
+                pub count: u32,
+                pub tags: Option<Vec<String>>,
+                items: Vec<u8>,
+                pub(crate) queue: std::collections::VecDeque<u8>,
+            }
+            struct Internal { pub items: Vec<u8> }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_configured_collections() {
+        let syntax = syn::parse_file("pub struct Bytes(pub Vec<u8>, pub SmallVec<u8>);").unwrap();
+        let mut checker = E1817PublicCollectionField::default();
+        checker.config.collection_types = vec!["SmallVec".to_string()];
+        let violations = checker.check_item(&syntax.items[0], "test.rs").unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'Bytes.1' is a public SmallVec"));
+    }
+}
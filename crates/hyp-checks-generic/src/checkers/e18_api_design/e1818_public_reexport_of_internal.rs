@@ -0,0 +1,194 @@
+//! E1818: Public re-export of an internal item
+//!
+//! Detects `pub use` statements whose path goes through a segment that looks
+//! internal: a leading underscore, or a name containing `internal`, `private`
+//! or `detail`. Such names signal "not part of the API", and re-exporting
+//! from them makes the item public anyway, so the module boundary no longer
+//! protects anything and renaming the internal module becomes a breaking
+//! change.
+//!
+//! Each leaf of a `use` tree is checked separately, so
+//! `pub use crate::{api::Client, internal::Pool};` reports only `Pool`.
+//! `pub(crate) use` and private `use` are not re-exports and are ignored.
+//! What counts as internal is set with `internal_patterns` (regexes matched
+//! against each path segment).
+//!
+//! Example:
+//! ```text
+//! // Bad: the internal module leaks into the public API
+//! pub use crate::internal::ConnectionPool;
+//!
+//! // Good: expose it from a module meant to be public
+//! pub use crate::pool::ConnectionPool;
+//! ```
+
+use crate::{define_checker, violation::Violation, AnalyzerError};
+
+use regex::Regex;
+use syn::spanned::Spanned;
+
+define_checker! {
+    /// Checker for E1818: Public re-export of an internal item
+    E1818PublicReexportOfInternal,
+    code = "E1818",
+    name = "Public re-export of internal item",
+    suggestions = "Move the item to a public module, or keep the re-export crate-private with pub(crate) use",
+    target_items = [Use],
+    config_entry_name = "e1818_public_reexport_of_internal",
+    /// Configuration for E1818: Public re-export of internal item checker
+    config = E1818Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Regexes matched against each path segment; a match marks the segment as internal
+        internal_patterns: Vec<String> = vec![
+            "^_".to_string(),
+            "(?i)internal".to_string(),
+            "(?i)private".to_string(),
+            "(?i)detail".to_string(),
+        ],
+    },
+    check_item(self, item, file_path) {
+        let syn::Item::Use(use_item) = item else {
+            return Ok(Vec::new());
+        };
+        if !matches!(use_item.vis, syn::Visibility::Public(_)) {
+            return Ok(Vec::new());
+        }
+
+        let patterns: Vec<Regex> = self
+            .config
+            .internal_patterns
+            .iter()
+            .map(|p| Regex::new(p).map_err(|e| AnalyzerError::Config(format!("Invalid internal pattern '{}': {}", p, e))))
+            .collect::<Result<_, _>>()?;
+        let mut leaves = Vec::new();
+        collect_leaves(&use_item.tree, &mut Vec::new(), &mut leaves);
+
+        let start = use_item.span().start();
+        let mut violations = Vec::new();
+        for path in leaves {
+            let Some(segment) = path
+                .iter()
+                .filter(|segment| !matches!(segment.as_str(), "crate" | "self" | "super" | "*"))
+                .find(|segment| patterns.iter().any(|re| re.is_match(segment)))
+            else {
+                continue;
+            };
+            violations.push(
+                Violation::new(
+                    self.code(),
+                    self.name(),
+                    self.severity().into(),
+                    format!(
+                        "'pub use {}' makes an item public through the internal-looking segment '{}'.",
+                        path.join("::"),
+                        segment
+                    ),
+                    file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_span(start, use_item.span().end())
+                .with_suggestion(self.suggestions()),
+            );
+        }
+
+        Ok(violations)
+    }
+}
+
+/// Full paths of the items a `use` tree imports (`a::b::{c, d::*}` gives `a::b::c` and `a::b::d::*`)
+fn collect_leaves(tree: &syn::UseTree, prefix: &mut Vec<String>, leaves: &mut Vec<Vec<String>>) {
+    match tree {
+        syn::UseTree::Path(path) => {
+            prefix.push(path.ident.to_string());
+            collect_leaves(&path.tree, prefix, leaves);
+            prefix.pop();
+        }
+        syn::UseTree::Name(name) => leaves.push([prefix.as_slice(), &[name.ident.to_string()]].concat()),
+        syn::UseTree::Rename(rename) => leaves.push([prefix.as_slice(), &[rename.ident.to_string()]].concat()),
+        syn::UseTree::Glob(_) => leaves.push([prefix.as_slice(), &["*".to_string()]].concat()),
+        syn::UseTree::Group(group) => {
+            for tree in &group.items {
+                collect_leaves(tree, prefix, leaves);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::Checker;
+
+    fn check_with(checker: &E1818PublicReexportOfInternal, code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        check_with(&E1818PublicReexportOfInternal::default(), code)
+    }
+
+    #[test]
+    fn test_detects_reexport_from_internal_module() {
+        let violations = check_code("\npub use crate::internal::Foo;\n");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1818");
+        assert_eq!((violations[0].line, violations[0].column), (2, 1));
+        assert!(violations[0]
+            .message
+            .contains("'pub use crate::internal::Foo' makes an item public through the internal-looking segment 'internal'"));
+    }
+
+    #[test]
+    fn test_detects_underscore_and_group_leaves() {
+        let code = r#"
+            pub use crate::{api::Client, pool_details::Pool, _hidden::*};
+            pub use self::PrivateState as State;
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 3);
+        assert!(violations[0].message.contains("segment 'pool_details'"));
+        assert!(violations[1].message.contains("'pub use crate::_hidden::*'"));
+        assert!(violations[2].message.contains("segment 'PrivateState'"));
+    }
+
+    #[test]
+    fn test_public_paths_and_crate_private_reexports_pass() {
+        let code = r#"
+            pub use crate::api::Foo;
+            pub(crate) use crate::internal::Bar;
+            use crate::internal::Baz;
+            pub use std::fmt::Display as _;
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_custom_internal_patterns() {
+        let code = "pub use crate::imp::Engine;\npub use crate::internal::Foo;\n";
+        let mut checker = E1818PublicReexportOfInternal::default();
+        checker.config.internal_patterns = vec!["^imp$".to_string()];
+        let violations = check_with(&checker, code);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("segment 'imp'"));
+    }
+
+    #[test]
+    fn test_invalid_internal_pattern_is_an_error() {
+        let mut checker = E1818PublicReexportOfInternal::default();
+        checker.config.internal_patterns.push("^(private".to_string());
+        let syntax = syn::parse_file("pub use crate::api::Foo;").unwrap();
+        let err = checker.check_item(&syntax.items[0], "test.rs").unwrap_err();
+        assert!(err.to_string().contains("Invalid internal pattern '^(private'"));
+    }
+}
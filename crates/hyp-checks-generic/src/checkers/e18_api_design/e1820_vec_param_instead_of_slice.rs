@@ -0,0 +1,297 @@
+//! E1820: Vec parameter instead of slice
+//!
+//! Detects `Vec<T>` parameters of `pub fn` signatures (free functions and
+//! inherent methods) that the body only reads: every use of the parameter
+//! is indexing or a call to one of `read_only_methods` (`.iter()`,
+//! `.len()`, ..), all of which a `&[T]` offers too. Taking the `Vec` by
+//! value forces callers to own one, so they allocate or clone just to call
+//! the function. This is E1810's `String`/`&str` rule for vectors.
+//!
+//! The use analysis is deliberately conservative: a `mut` binding, any
+//! other use (passing it on, `.push()`, `.into_iter()`, returning it,
+//! `for x in v`, a mention inside a macro) or a shadowing binding of the
+//! same name keeps the parameter from being reported.
+//!
+//! Example:
+//! ```text
+//! // Bad: callers must hand over a Vec they may need afterwards
+//! pub fn total(prices: Vec<u32>) -> u32 { prices.iter().sum() }
+//!
+//! // Good: works with a Vec, an array or part of either
+//! pub fn total(prices: &[u32]) -> u32 { prices.iter().sum() }
+//! ```
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{spanned::Spanned, visit::Visit};
+
+define_checker! {
+    /// Checker for E1820: Vec parameter instead of slice
+    E1820VecParamInsteadOfSlice,
+    code = "E1820",
+    name = "Vec parameter instead of slice",
+    suggestions = "Take &[T] for parameters that are only read, or impl IntoIterator<Item = T> if they are only iterated",
+    target_items = [Function, Impl],
+    config_entry_name = "e1820_vec_param_instead_of_slice",
+    /// Configuration for E1820: Vec parameter instead of slice checker
+    config = E1820Config {
+        /// Whether this checker is enabled
+        enabled: bool = true,
+        /// Severity level
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::Low,
+        /// Categories this checker belongs to
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Complexity],
+        /// Methods that only read the vector and that slices provide as well
+        read_only_methods: Vec<String> = ["iter", "len", "is_empty", "first", "last", "get", "contains"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+    },
+    check_item(self, item, file_path) {
+        let mut visitor = VecParamVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct VecParamVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1820VecParamInsteadOfSlice,
+}
+
+impl VecParamVisitor<'_> {
+    fn check_fn(&mut self, vis: &syn::Visibility, sig: &syn::Signature, body: &syn::Block) {
+        if !matches!(vis, syn::Visibility::Public(_)) {
+            return;
+        }
+        for input in &sig.inputs {
+            let syn::FnArg::Typed(pat_type) = input else {
+                continue;
+            };
+            // A `mut` binding may be modified in place, which &[T] cannot do
+            let syn::Pat::Ident(ident) = &*pat_type.pat else {
+                continue;
+            };
+            if ident.mutability.is_some() || ident.by_ref.is_some() || !is_vec(&pat_type.ty) {
+                continue;
+            }
+
+            let name = ident.ident.to_string();
+            let mut uses = ParamUses {
+                name: &name,
+                read_only_methods: &self.checker.config.read_only_methods,
+                reads: 0,
+                other: 0,
+            };
+            uses.visit_block(body);
+            if uses.reads == 0 || uses.other > 0 {
+                continue;
+            }
+
+            let start = pat_type.span().start();
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    self.checker.severity().into(),
+                    format!(
+                        "Parameter '{}' of '{}' takes Vec<T> but is only read; take &[T] so callers need not own a Vec.",
+                        name, sig.ident
+                    ),
+                    self.file_path,
+                    start.line,
+                    start.column + 1,
+                )
+                .with_span(start, pat_type.span().end())
+                .with_suggestion(self.checker.suggestions()),
+            );
+        }
+    }
+}
+
+impl<'a> Visit<'a> for VecParamVisitor<'a> {
+    fn visit_item_fn(&mut self, node: &'a syn::ItemFn) {
+        self.check_fn(&node.vis, &node.sig, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'a syn::ItemImpl) {
+        // Trait impls follow the signature the trait declares
+        if node.trait_.is_none() {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'a syn::ImplItemFn) {
+        self.check_fn(&node.vis, &node.sig, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+}
+
+/// Whether the type is `Vec<T>` (not a reference to one)
+fn is_vec(ty: &syn::Type) -> bool {
+    let syn::Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path.qself.is_none()
+        && type_path.path.segments.last().is_some_and(|segment| {
+            segment.ident == "Vec" && matches!(segment.arguments, syn::PathArguments::AngleBracketed(_))
+        })
+}
+
+/// Counts the uses of a parameter in a function body that a slice would also support, and all other uses
+struct ParamUses<'a> {
+    name: &'a str,
+    read_only_methods: &'a [String],
+    reads: usize,
+    other: usize,
+}
+
+impl ParamUses<'_> {
+    fn is_param(&self, expr: &syn::Expr) -> bool {
+        matches!(expr, syn::Expr::Path(path) if path.qself.is_none() && path.path.is_ident(self.name))
+    }
+}
+
+impl<'a> Visit<'a> for ParamUses<'_> {
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        if self.is_param(&node.receiver) && self.read_only_methods.iter().any(|method| node.method == method) {
+            self.reads += 1;
+            for arg in &node.args {
+                self.visit_expr(arg);
+            }
+        } else {
+            syn::visit::visit_expr_method_call(self, node);
+        }
+    }
+
+    fn visit_expr_index(&mut self, node: &'a syn::ExprIndex) {
+        if self.is_param(&node.expr) {
+            self.reads += 1;
+            self.visit_expr(&node.index);
+        } else {
+            syn::visit::visit_expr_index(self, node);
+        }
+    }
+
+    fn visit_expr_path(&mut self, node: &'a syn::ExprPath) {
+        if node.qself.is_none() && node.path.is_ident(self.name) {
+            self.other += 1;
+        }
+    }
+
+    fn visit_pat_ident(&mut self, node: &'a syn::PatIdent) {
+        // Shadowed: later uses may refer to something else
+        if node.ident == self.name {
+            self.other += 1;
+        }
+        syn::visit::visit_pat_ident(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'a syn::Macro) {
+        if mentions(node.tokens.clone(), self.name) {
+            self.other += 1;
+        }
+    }
+
+    fn visit_item(&mut self, _node: &'a syn::Item) {
+        // Nested items cannot see the parameter
+    }
+}
+
+/// Whether `name` appears in `tokens`, as an identifier or inlined in a format string (`"{v:?}"`)
+pub(crate) fn mentions(tokens: TokenStream, name: &str) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => mentions(group.stream(), name),
+        TokenTree::Literal(literal) => {
+            let text = literal.to_string();
+            text.contains(&format!("{{{}}}", name)) || text.contains(&format!("{{{}:", name))
+        }
+        TokenTree::Punct(_) => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_code(code: &str) -> Vec<Violation> {
+        let syntax = syn::parse_file(code).unwrap();
+        let checker = E1820VecParamInsteadOfSlice::default();
+        let mut violations = Vec::new();
+        for item in &syntax.items {
+            violations.extend(checker.check_item(item, "test.rs").unwrap());
+        }
+        violations
+    }
+
+    #[test]
+    fn test_detects_read_only_vec_params() {
+        let code = r#"
+            pub fn total(prices: Vec<u32>) -> u32 {
+                prices.iter().sum()
+            }
+
+            impl Report {
+                pub fn header(&self, columns: Vec<String>) -> String {
+                    if columns.is_empty() { String::new() } else { columns[0].clone() + &columns.len().to_string() }
+                }
+            }
+        "#;
+        let violations = check_code(code);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].code, "E1820");
+        assert_eq!((violations[0].line, violations[0].column), (2, 26));
+        assert!(violations[0].message.contains("Parameter 'prices' of 'total' takes Vec<T>"));
+        assert!(violations[1].message.contains("Parameter 'columns' of 'header'"));
+    }
+
+    #[test]
+    fn test_consumed_or_mutated_vecs_pass() {
+        let code = r#"
+            pub fn store(items: Vec<u8>) -> Store { Store { items } }
+            pub fn append(mut items: Vec<u8>) -> Vec<u8> { items.push(1); items }
+            pub fn drain(items: Vec<u8>) -> u32 { items.into_iter().map(u32::from).sum() }
+            pub fn each(items: Vec<u8>) { for item in items { use_it(item); } }
+            pub fn returned(items: Vec<u8>) -> Vec<u8> { if items.len() > 2 { items } else { Vec::new() } }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_conservative_cases_pass() {
+        let code = r#"
+            pub fn logged(items: Vec<u8>) -> usize { println!("{items:?}"); items.len() }
+            pub fn shadowed(items: Vec<u8>) -> usize { let items = items.len(); items }
+            pub fn unused(items: Vec<u8>) {}
+            pub fn borrowed(items: &Vec<u8>) -> usize { items.len() }
+            fn private(items: Vec<u8>) -> usize { items.len() }
+            impl Sink for Buffer {
+                fn write(&mut self, items: Vec<u8>) -> usize { items.len() }
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+    }
+
+    #[test]
+    fn test_custom_read_only_methods() {
+        let code = r#"
+            pub fn widest(rows: Vec<Row>) -> usize {
+                rows.iter().map(Row::width).max().unwrap_or(0) + rows.chunks(2).count()
+            }
+        "#;
+        assert!(check_code(code).is_empty());
+
+        let mut checker = E1820VecParamInsteadOfSlice::default();
+        checker.config.read_only_methods.push("chunks".to_string());
+        let syntax = syn::parse_file(code).unwrap();
+        assert_eq!(checker.check_item(&syntax.items[0], "test.rs").unwrap().len(), 1);
+    }
+}
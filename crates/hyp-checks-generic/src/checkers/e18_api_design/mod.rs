@@ -11,6 +11,11 @@ pub mod e1808_mutable_getter;
 pub mod e1809_fallible_new;
 pub mod e1810_string_instead_of_str;
 pub mod e1812_non_exhaustive_enum;
+pub mod e1815_missing_must_use;
+pub mod e1816_impl_trait_public_return;
+pub mod e1817_public_collection_field;
+pub mod e1818_public_reexport_of_internal;
+pub mod e1820_vec_param_instead_of_slice;
 pub mod registry;
 
 pub use e1801_glob_imports::{E1801Config, E1801GlobImports};
@@ -24,3 +29,8 @@ pub use e1808_mutable_getter::{E1808Config, E1808MutableGetter};
 pub use e1809_fallible_new::{E1809Config, E1809FallibleNew};
 pub use e1810_string_instead_of_str::{E1810Config, E1810StringInsteadOfStr};
 pub use e1812_non_exhaustive_enum::{E1812Config, E1812NonExhaustiveEnum};
+pub use e1815_missing_must_use::{E1815Config, E1815MissingMustUse};
+pub use e1816_impl_trait_public_return::{E1816Config, E1816ImplTraitPublicReturn};
+pub use e1817_public_collection_field::{E1817Config, E1817PublicCollectionField};
+pub use e1818_public_reexport_of_internal::{E1818Config, E1818PublicReexportOfInternal};
+pub use e1820_vec_param_instead_of_slice::{E1820Config, E1820VecParamInsteadOfSlice};
@@ -8,6 +8,11 @@ use crate::{
         E1805MissingDocumentation, E1806Config, E1806ExposingInternalDetails, E1807Config,
         E1807NonIdiomaticBuilder, E1808Config, E1808MutableGetter, E1809Config, E1809FallibleNew,
         E1810Config, E1810StringInsteadOfStr, E1812Config, E1812NonExhaustiveEnum,
+        E1815Config, E1815MissingMustUse,
+        E1816Config, E1816ImplTraitPublicReturn,
+        E1817Config, E1817PublicCollectionField,
+        E1818Config, E1818PublicReexportOfInternal,
+        E1820Config, E1820VecParamInsteadOfSlice,
     },
     register_checker,
     registry::CheckerRegistration,
@@ -27,5 +32,10 @@ pub fn e18_registrations() -> Vec<CheckerRegistration> {
         register_checker!(E1809FallibleNew, E1809Config),
         register_checker!(E1810StringInsteadOfStr, E1810Config),
         register_checker!(E1812NonExhaustiveEnum, E1812Config),
+        register_checker!(E1815MissingMustUse, E1815Config),
+        register_checker!(E1816ImplTraitPublicReturn, E1816Config),
+        register_checker!(E1817PublicCollectionField, E1817Config),
+        register_checker!(E1818PublicReexportOfInternal, E1818Config),
+        register_checker!(E1820VecParamInsteadOfSlice, E1820Config),
     ]
 }
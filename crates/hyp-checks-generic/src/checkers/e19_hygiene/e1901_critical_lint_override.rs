@@ -123,6 +123,18 @@ define_checker! {
         visitor.visit_item(item);
         Ok(visitor.violations)
     }
+    check_file(self, file, file_path) {
+        // Crate-level `#![allow(...)]` lives on the file, not on any item
+        let mut visitor = LintOverrideVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        for attr in &file.attrs {
+            visitor.check_attributes(std::slice::from_ref(attr), attr.span());
+        }
+        Ok(visitor.violations)
+    }
 }
 
 struct LintOverrideVisitor<'a> {
@@ -391,4 +403,25 @@ mod tests {
             "Disabled rule should not produce violations"
         );
     }
+
+    #[test]
+    fn test_detects_crate_level_override() {
+        let code = r#"
+            //! Crate docs
+            #![allow(clippy::unwrap_used)]
+            #![deny(missing_docs)]
+
+            fn dangerous() {}
+        "#;
+
+        let checker = E1901CriticalLintOverride::default();
+        let file = syn::parse_file(code).expect("Failed to parse");
+        let violations = checker.check_file(&file, "src/lib.rs").unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("unwrap_used"));
+        assert_eq!(violations[0].line, 3);
+
+        // Item-level checks do not see inner attributes, so nothing is reported twice
+        assert!(check_code_with_default_config(code, "src/lib.rs").is_empty());
+    }
 }
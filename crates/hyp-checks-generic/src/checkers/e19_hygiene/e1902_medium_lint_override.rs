@@ -122,6 +122,18 @@ define_checker! {
         visitor.visit_item(item);
         Ok(visitor.violations)
     }
+    check_file(self, file, file_path) {
+        // Crate-level `#![allow(...)]` lives on the file, not on any item
+        let mut visitor = LintOverrideVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        for attr in &file.attrs {
+            visitor.check_attributes(std::slice::from_ref(attr), attr.span());
+        }
+        Ok(visitor.violations)
+    }
 }
 
 struct LintOverrideVisitor<'a> {
@@ -328,4 +340,18 @@ mod tests {
             "Minor lint should not be flagged by E1902"
         );
     }
+
+    #[test]
+    fn test_detects_crate_level_override() {
+        let code = r#"
+            #![allow(clippy::cognitive_complexity)]
+            fn complex() {}
+        "#;
+
+        let checker = E1902MediumLintOverride::default();
+        let file = syn::parse_file(code).expect("Failed to parse");
+        let violations = checker.check_file(&file, "src/lib.rs").unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 2);
+    }
 }
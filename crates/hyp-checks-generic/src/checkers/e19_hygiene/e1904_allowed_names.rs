@@ -2,8 +2,12 @@
 //!
 //! Enforces project-specific naming and location rules for AST items.
 //! Prevents unwanted patterns like DTOs outside API layers or wildcard imports in specific modules.
+//!
+//! Locations are file paths (`allowed_paths`) or Rust module paths (`allowed_modules`).
+//! Module paths include inline `mod name { ... }` blocks, so several modules written in
+//! one file can still be told apart.
 
-use crate::{checker::Checker, define_checker, violation::Violation};
+use crate::{checker::Checker, define_checker, module_path::file_module_path, violation::Violation};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -158,7 +162,10 @@ pub struct NamingRule {
     /// Regex patterns for allowed file paths (empty = allowed everywhere)
     #[serde(default)]
     pub allowed_paths: Vec<String>,
-    /// Custom message template with placeholders: {type}, {name}, {path}, {allowed_paths}
+    /// Regex patterns for allowed module paths such as `^crate::api(::|$)`, checked in addition to `allowed_paths`
+    #[serde(default)]
+    pub allowed_modules: Vec<String>,
+    /// Custom message template with placeholders: {type}, {name}, {path}, {module}, {allowed_paths}
     #[serde(default = "default_message")]
     pub message: String,
 }
@@ -171,8 +178,15 @@ fn default_message() -> String {
     "{type} '{name}' in {path} is only allowed in: {allowed_paths}".to_string()
 }
 
+/// Compiled regexes of a [`NamingRule`]
+struct RulePatterns {
+    names: Vec<Regex>,
+    paths: Vec<Regex>,
+    modules: Vec<Regex>,
+}
+
 impl NamingRule {
-    fn compile_patterns(&self) -> Result<(Vec<Regex>, Vec<Regex>), String> {
+    fn compile_patterns(&self) -> Result<RulePatterns, String> {
         let name_regexes: Result<Vec<_>, _> = self
             .name_patterns
             .iter()
@@ -185,7 +199,17 @@ impl NamingRule {
             .map(|p| Regex::new(p).map_err(|e| format!("Invalid path pattern '{}': {}", p, e)))
             .collect();
 
-        Ok((name_regexes?, path_regexes?))
+        let module_regexes: Result<Vec<_>, _> = self
+            .allowed_modules
+            .iter()
+            .map(|p| Regex::new(p).map_err(|e| format!("Invalid module pattern '{}': {}", p, e)))
+            .collect();
+
+        Ok(RulePatterns {
+            names: name_regexes?,
+            paths: path_regexes?,
+            modules: module_regexes?,
+        })
     }
 }
 
@@ -209,6 +233,7 @@ define_checker! {
             violations: Vec::new(),
             file_path,
             checker: self,
+            module_path: file_module_path(file_path),
         };
         visitor.visit_item(item);
         Ok(visitor.violations)
@@ -219,6 +244,8 @@ struct NamingVisitor<'a> {
     violations: Vec<Violation>,
     file_path: &'a str,
     checker: &'a E1904AllowedNames,
+    /// Module the visited items are in, extended while inside inline `mod` blocks
+    module_path: String,
 }
 
 impl<'a> NamingVisitor<'a> {
@@ -252,23 +279,24 @@ impl<'a> NamingVisitor<'a> {
             }
 
             // Compile patterns
-            let Ok((name_regexes, path_regexes)) = rule.compile_patterns() else {
+            let Ok(patterns) = rule.compile_patterns() else {
                 continue;
             };
 
             // Check if name matches any pattern
-            let name_matches = name_regexes.iter().any(|re| re.is_match(name));
+            let name_matches = patterns.names.iter().any(|re| re.is_match(name));
             if !name_matches {
                 continue;
             }
 
-            // If allowed_paths is empty, the item is allowed everywhere
-            if path_regexes.is_empty() {
+            // If no allowed location is configured, the item is allowed everywhere
+            if patterns.paths.is_empty() && patterns.modules.is_empty() {
                 continue;
             }
 
-            // Check if path is allowed
-            let path_allowed = path_regexes.iter().any(|re| re.is_match(&normalized_path));
+            // Check if path or module is allowed
+            let path_allowed = patterns.paths.iter().any(|re| re.is_match(&normalized_path))
+                || patterns.modules.iter().any(|re| re.is_match(&self.module_path));
             if path_allowed {
                 continue;
             }
@@ -279,7 +307,8 @@ impl<'a> NamingVisitor<'a> {
                 .replace("{type}", &format!("{:?}", item_type))
                 .replace("{name}", name)
                 .replace("{path}", &normalized_path)
-                .replace("{allowed_paths}", &rule.allowed_paths.join(", "));
+                .replace("{module}", &self.module_path)
+                .replace("{allowed_paths}", &[&rule.allowed_paths[..], &rule.allowed_modules[..]].concat().join(", "));
 
             self.violations.push(
                 Violation::new(
@@ -291,6 +320,7 @@ impl<'a> NamingVisitor<'a> {
                     span.start().line,
                     span.start().column + 1,
                 )
+                .with_module_path(&self.module_path)
                 .with_suggestion(self.checker.suggestions()),
             );
         }
@@ -383,7 +413,15 @@ impl<'a> Visit<'a> for NamingVisitor<'a> {
             &name,
             node.ident.span(),
         );
-        syn::visit::visit_item_mod(self, node);
+        if node.content.is_some() {
+            let outer_len = self.module_path.len();
+            self.module_path.push_str("::");
+            self.module_path.push_str(&name);
+            syn::visit::visit_item_mod(self, node);
+            self.module_path.truncate(outer_len);
+        } else {
+            syn::visit::visit_item_mod(self, node);
+        }
     }
 
     fn visit_item_use(&mut self, node: &'a syn::ItemUse) {
@@ -421,6 +459,7 @@ mod tests {
             reference_type,
             name_patterns: name_patterns.into_iter().map(String::from).collect(),
             allowed_paths: allowed_paths.into_iter().map(String::from).collect(),
+            allowed_modules: Vec::new(),
             message: message.to_string(),
         }
     }
@@ -562,6 +601,7 @@ mod tests {
             reference_type: ReferenceType::Define,
             name_patterns: vec![".*DTO$".to_string()],
             allowed_paths: vec!["^.*/api/.*\\.rs$".to_string()],
+            allowed_modules: Vec::new(),
             message: "DTO in wrong location".to_string(),
         }];
 
@@ -584,6 +624,7 @@ mod tests {
             reference_type: ReferenceType::Define,
             name_patterns: vec![".*DTO$".to_string()],
             allowed_paths: vec!["^.*/api/.*\\.rs$".to_string()],
+            allowed_modules: Vec::new(),
             message: "DTO in wrong location".to_string(),
         }];
 
@@ -959,6 +1000,7 @@ mod tests {
             reference_type: ReferenceType::Define,
             name_patterns: vec!["[invalid regex".to_string()], // Invalid regex
             allowed_paths: vec!["^.*/api/.*\\.rs$".to_string()],
+            allowed_modules: Vec::new(),
             message: "Should not match".to_string(),
         }];
 
@@ -1040,4 +1082,35 @@ mod tests {
         let violations = check_code_with_config(code, rules, "src/models/user.rs");
         assert_eq!(violations.len(), 1, "Should match trait items");
     }
+
+    // ========== Module path tests ==========
+
+    #[test]
+    fn test_allowed_modules_distinguish_inline_modules() {
+        let code = r#"
+            mod api {
+                pub struct UserDTO { id: i32 }
+            }
+            mod db {
+                pub struct RowDTO { id: i32 }
+            }
+        "#;
+
+        let mut rule = make_rule(
+            vec![AstItemType::Struct],
+            ReferenceType::Define,
+            vec![".*DTO$"],
+            vec![],
+            "{type} '{name}' in {module} is only allowed in: {allowed_paths}",
+        );
+        rule.allowed_modules = vec!["^crate::server::api(::|$)".to_string()];
+
+        let violations = check_code_with_config(code, vec![rule], "src/server.rs");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "Struct 'RowDTO' in crate::server::db is only allowed in: ^crate::server::api(::|$)"
+        );
+        assert_eq!(violations[0].module_path.as_deref(), Some("crate::server::db"));
+    }
 }
@@ -0,0 +1,281 @@
+//! E1909: Forbidden function calls
+//!
+//! Bans project-specific calls (e.g. `std::process::exit`, `env::set_var`,
+//! `dbg!`) through configuration alone. Each rule matches a regex against the
+//! stringified callee of function calls, method calls and macro invocations,
+//! and may list file paths where the call is still permitted.
+
+use crate::{checker::Checker, define_checker, violation::Violation};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use syn::{spanned::Spanned, visit::Visit};
+
+/// A single forbidden call rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForbiddenCallRule {
+    /// Whether this rule is enabled (default: true)
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Regex matched against the callee: `std::process::exit` for calls,
+    /// `.unwrap` for method calls and `dbg!` for macros
+    pub path_pattern: String,
+    /// Custom message template with placeholders: {call}, {path}, {allowed_paths}
+    #[serde(default = "default_message")]
+    pub message: String,
+    /// Regex patterns for file paths where the call is allowed (empty = forbidden everywhere)
+    #[serde(default)]
+    pub allowed_paths: Vec<String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_message() -> String {
+    "Call to '{call}' is forbidden in {path}".to_string()
+}
+
+impl ForbiddenCallRule {
+    fn compile_patterns(&self) -> Result<(Regex, Vec<Regex>), String> {
+        let call_regex = Regex::new(&self.path_pattern)
+            .map_err(|e| format!("Invalid call pattern '{}': {}", self.path_pattern, e))?;
+
+        let path_regexes: Result<Vec<_>, _> = self
+            .allowed_paths
+            .iter()
+            .map(|p| Regex::new(p).map_err(|e| format!("Invalid path pattern '{}': {}", p, e)))
+            .collect();
+
+        Ok((call_regex, path_regexes?))
+    }
+}
+
+define_checker! {
+    /// Checker for E1909: Forbidden function calls
+    E1909ForbiddenCalls,
+    code = "E1909",
+    name = "Forbidden function call",
+    suggestions = "Use the project-approved alternative, or move the call to a location where it is allowed",
+    target_items = [Function, Impl, Trait, Const, Static],
+    config_entry_name = "e1909_forbidden_calls",
+    config = E1909Config {
+        enabled: bool = true,
+        severity: crate::config::SeverityLevel = crate::config::SeverityLevel::High,
+        categories: Vec<crate::config::CheckerCategory> = vec![crate::config::CheckerCategory::Compliance],
+        /// Forbidden call rules (empty by default - configure in Hyp.toml)
+        rules: Vec<ForbiddenCallRule> = vec![],
+    },
+    check_item(self, item, file_path) {
+        if self.config.rules.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut visitor = ForbiddenCallVisitor {
+            violations: Vec::new(),
+            file_path,
+            checker: self,
+        };
+        visitor.visit_item(item);
+        Ok(visitor.violations)
+    }
+}
+
+struct ForbiddenCallVisitor<'a> {
+    violations: Vec<Violation>,
+    file_path: &'a str,
+    checker: &'a E1909ForbiddenCalls,
+}
+
+impl<'a> ForbiddenCallVisitor<'a> {
+    fn check_rules(&mut self, call: &str, span: proc_macro2::Span) {
+        let normalized_path = Path::new(self.file_path)
+            .to_str()
+            .unwrap_or(self.file_path)
+            .replace('\\', "/");
+
+        for rule in &self.checker.config.rules {
+            if !rule.enabled {
+                continue;
+            }
+
+            let Ok((call_regex, path_regexes)) = rule.compile_patterns() else {
+                continue;
+            };
+
+            if !call_regex.is_match(call) {
+                continue;
+            }
+
+            let path_allowed = path_regexes.iter().any(|re| re.is_match(&normalized_path));
+            if path_allowed {
+                continue;
+            }
+
+            let message = rule
+                .message
+                .replace("{call}", call)
+                .replace("{path}", &normalized_path)
+                .replace("{allowed_paths}", &rule.allowed_paths.join(", "));
+
+            self.violations.push(
+                Violation::new(
+                    self.checker.code(),
+                    self.checker.name(),
+                    self.checker.severity().into(),
+                    &message,
+                    self.file_path,
+                    span.start().line,
+                    span.start().column + 1,
+                )
+                .with_suggestion(self.checker.suggestions()),
+            );
+        }
+    }
+}
+
+/// Stringify a path without the spaces `quote` puts around `::`
+fn path_to_string(path: &syn::Path) -> String {
+    quote::quote!(#path).to_string().replace(' ', "")
+}
+
+impl<'a> Visit<'a> for ForbiddenCallVisitor<'a> {
+    fn visit_expr_call(&mut self, node: &'a syn::ExprCall) {
+        if let syn::Expr::Path(path) = &*node.func {
+            let call = path_to_string(&path.path);
+            self.check_rules(&call, node.span());
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'a syn::ExprMethodCall) {
+        let call = format!(".{}", node.method);
+        self.check_rules(&call, node.method.span());
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'a syn::Macro) {
+        let call = format!("{}!", path_to_string(&node.path));
+        self.check_rules(&call, node.span());
+        syn::visit::visit_macro(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_rule(path_pattern: &str, allowed_paths: Vec<&str>) -> ForbiddenCallRule {
+        ForbiddenCallRule {
+            enabled: true,
+            path_pattern: path_pattern.to_string(),
+            message: default_message(),
+            allowed_paths: allowed_paths.into_iter().map(String::from).collect(),
+        }
+    }
+
+    fn check_code_with_rules(code: &str, rules: Vec<ForbiddenCallRule>, file_path: &str) -> Vec<Violation> {
+        let checker = E1909ForbiddenCalls {
+            config: E1909Config {
+                rules,
+                ..E1909Config::default()
+            },
+        };
+
+        let file = syn::parse_file(code).expect("Failed to parse");
+        let mut violations = Vec::new();
+        for item in &file.items {
+            violations.extend(checker.check_item(item, file_path).unwrap());
+        }
+        violations
+    }
+
+    const EXIT_CODE: &str = r#"
+        fn shutdown(code: i32) {
+            cleanup();
+            std::process::exit(code);
+        }
+    "#;
+
+    fn exit_rule() -> ForbiddenCallRule {
+        make_rule(r"^(std::)?process::exit$", vec![r"^(.*/)?src/main\.rs$"])
+    }
+
+    #[test]
+    fn test_bans_process_exit() {
+        let violations = check_code_with_rules(EXIT_CODE, vec![exit_rule()], "src/lib.rs");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "E1909");
+        assert_eq!(violations[0].message, "Call to 'std::process::exit' is forbidden in src/lib.rs");
+        assert_eq!(violations[0].line, 4);
+    }
+
+    #[test]
+    fn test_process_exit_allowed_in_main() {
+        assert!(check_code_with_rules(EXIT_CODE, vec![exit_rule()], "src/main.rs").is_empty());
+        assert!(check_code_with_rules(EXIT_CODE, vec![exit_rule()], "crates/app/src/main.rs").is_empty());
+        assert_eq!(check_code_with_rules(EXIT_CODE, vec![exit_rule()], "src/bin/main_helper.rs").len(), 1);
+    }
+
+    #[test]
+    fn test_short_path_and_empty_allowed_paths() {
+        let code = r#"
+            fn configure() {
+                env::set_var("RUST_LOG", "debug");
+                set_var("A", "b");
+            }
+        "#;
+        let rules = vec![make_rule(r"(^|::)set_var$", vec![])];
+        assert_eq!(check_code_with_rules(code, rules, "src/main.rs").len(), 2);
+    }
+
+    #[test]
+    fn test_method_calls_and_macros() {
+        let code = r#"
+            impl Loader {
+                fn load(&self) -> Config {
+                    let raw = self.read().unwrap();
+                    dbg!(&raw);
+                    parse(raw)
+                }
+            }
+        "#;
+        let rules = vec![make_rule(r"^\.unwrap$", vec![]), make_rule(r"^dbg!$", vec![])];
+        let violations = check_code_with_rules(code, rules, "src/loader.rs");
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("'.unwrap'"));
+        assert!(violations[1].message.contains("'dbg!'"));
+    }
+
+    #[test]
+    fn test_custom_message_and_disabled_rule() {
+        let mut rule = exit_rule();
+        rule.message = "Use a typed error instead of '{call}' (allowed in: {allowed_paths})".to_string();
+        let violations = check_code_with_rules(EXIT_CODE, vec![rule.clone()], "src/lib.rs");
+        assert_eq!(
+            violations[0].message,
+            r"Use a typed error instead of 'std::process::exit' (allowed in: ^(.*/)?src/main\.rs$)"
+        );
+
+        rule.enabled = false;
+        assert!(check_code_with_rules(EXIT_CODE, vec![rule], "src/lib.rs").is_empty());
+    }
+
+    #[test]
+    fn test_no_rules_reports_nothing() {
+        assert!(check_code_with_rules(EXIT_CODE, vec![], "src/lib.rs").is_empty());
+    }
+
+    #[test]
+    fn test_rules_from_toml() {
+        let toml_str = r#"
+            [[rules]]
+            path_pattern = "process::exit$"
+            allowed_paths = ["src/main\\.rs$"]
+        "#;
+        let config: E1909Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.rules.len(), 1);
+        assert!(config.rules[0].enabled);
+        assert_eq!(config.rules[0].message, default_message());
+        assert_eq!(check_code_with_rules(EXIT_CODE, config.rules, "src/lib.rs").len(), 1);
+    }
+}
@@ -11,6 +11,7 @@ pub mod e1905_suspicious_code;
 pub mod e1906_file_location;
 pub mod e1907_test_coverage_attr;
 pub mod e1908_unsafe_justification;
+pub mod e1909_forbidden_calls;
 
 pub use e1901_critical_lint_override::{E1901Config, E1901CriticalLintOverride};
 pub use e1902_medium_lint_override::{E1902Config, E1902MediumLintOverride};
@@ -20,5 +21,6 @@ pub use e1905_suspicious_code::{E1905Config, E1905SuspiciousCode};
 pub use e1906_file_location::{E1906Config, E1906FileLocation};
 pub use e1907_test_coverage_attr::{E1907Config, E1907TestCoverageAttr};
 pub use e1908_unsafe_justification::{E1908Config, E1908UnsafeJustification};
+pub use e1909_forbidden_calls::{E1909Config, E1909ForbiddenCalls};
 
 pub mod registry;
@@ -5,7 +5,7 @@ use crate::checkers::e19_hygiene::{
     E1901Config, E1901CriticalLintOverride, E1902Config, E1902MediumLintOverride, E1903Config,
     E1903MinorLintOverride, E1904AllowedNames, E1904Config, E1905Config, E1905SuspiciousCode,
     E1906Config, E1906FileLocation, E1907Config, E1907TestCoverageAttr, E1908Config,
-    E1908UnsafeJustification,
+    E1908UnsafeJustification, E1909Config, E1909ForbiddenCalls,
 };
 use crate::{register_checker, CheckerRegistration};
 
@@ -17,6 +17,7 @@ pub fn e19_registrations() -> Vec<CheckerRegistration> {
         register_checker!(E1906FileLocation, E1906Config),
         register_checker!(E1907TestCoverageAttr, E1907Config),
         register_checker!(E1908UnsafeJustification, E1908Config),
+        register_checker!(E1909ForbiddenCalls, E1909Config),
         register_checker!(E1901CriticalLintOverride, E1901Config),
         register_checker!(E1902MediumLintOverride, E1902Config),
         register_checker!(E1903MinorLintOverride, E1903Config),
@@ -3,17 +3,35 @@
 //! This module provides reusable CLI functionality that can be used by both
 //! the default `hyp` CLI and custom CLI tools built on top of `hyp-checks-generic`.
 
-use std::{collections::HashMap, collections::HashSet, path::Path, path::PathBuf};
+use std::{
+    cmp::Reverse, collections::BTreeMap, collections::BTreeSet, collections::HashMap, collections::HashSet,
+    path::Path, path::PathBuf, process::Command,
+};
 use walkdir::WalkDir;
 
 use crate::{
-    analyzer::{Analyzer, AnalyzerFilters},
-    config::{AnalyzerConfig, CheckerCategory},
+    analyzer::{parse_error_violation, Analyzer, AnalyzerFilters},
+    cache::{AnalysisCache, DEFAULT_CACHE_DIR},
+    config::{AnalyzerConfig, CheckerCategory, SeverityLevel, CONFIG_FILE_NAME},
+    fixit::apply_fixits,
+    hypignore::{find_ignore_file, HypIgnore},
     registry::{checkers_for_groups, CheckerGroup, CheckerRegistration},
-    violation::Violation,
-    Result,
+    violation::{highest_severity, FixIt, Severity, Violation, ViolationSummary},
+    AnalyzerError, Result,
 };
 
+/// Process exit code: no violations at or above the `--fail-on` threshold.
+pub const EXIT_CLEAN: i32 = 0;
+/// Process exit code: at least one violation at or above the `--fail-on` threshold.
+pub const EXIT_VIOLATIONS: i32 = 1;
+/// Process exit code: analysis could not complete (config, I/O or parse error).
+pub const EXIT_ERROR: i32 = 2;
+
+/// `CliOptions::source` entry that, given alone, makes `run_cli` read source code from stdin.
+pub const STDIN_SOURCE: &str = "-";
+/// File path reported for violations in source read from stdin.
+pub const STDIN_VIRTUAL_PATH: &str = "<stdin>";
+
 /// CLI output format options.
 #[derive(Debug, Clone, Copy)]
 pub enum CliOutputFormat {
@@ -21,17 +39,127 @@ pub enum CliOutputFormat {
     Text,
     /// JSON output.
     Json,
+    /// JSON Lines: one compact JSON object per violation, per line.
+    JsonLines,
+    /// GitHub-flavored Markdown (e.g. for PR comments).
+    Markdown,
+}
+
+impl CliOutputFormat {
+    /// Parse an output format from a string ("text", "json", "jsonl", "markdown"/"md")
+    pub fn parse_format(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "jsonl" | "json-lines" => Some(Self::JsonLines),
+            "markdown" | "md" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+}
+
+/// Grouping of the detailed violation listing in text output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportGrouping {
+    /// One section per severity, highest first.
+    Severity,
+    /// One section per file, violations sorted by line.
+    File,
+    /// One section per checker code.
+    Code,
+}
+
+impl ReportGrouping {
+    /// Parse a grouping from a string ("severity", "file", "code")
+    pub fn parse_grouping(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "severity" => Some(Self::Severity),
+            "file" => Some(Self::File),
+            "code" => Some(Self::Code),
+            _ => None,
+        }
+    }
+}
+
+/// When text output uses ANSI colors (`--color`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color only when stdout is a terminal and `NO_COLOR` is not set.
+    Auto,
+    /// Always color, even when piped.
+    Always,
+    /// Never color.
+    Never,
+}
+
+impl ColorChoice {
+    /// Parse a color choice from a string ("auto", "always", "never")
+    pub fn parse_choice(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    /// Whether to emit colors on stdout; a non-empty `NO_COLOR` disables `Auto` (see no-color.org)
+    pub fn enabled(self) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => {
+                use std::io::IsTerminal;
+                std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()) && std::io::stdout().is_terminal()
+            }
+        }
+    }
 }
 
 /// Load configuration from file path.
 ///
 /// Supports both TOML (Hyp.toml) and YAML (hyp.yaml) formats.
 /// The format is auto-detected based on file extension.
+///
+/// A top-level `extends` key names a base config (relative to this file, in
+/// either format) that is loaded first, recursively; see
+/// [`AnalyzerConfig::merged_onto`] for how the two are combined.
+///
+/// # Errors
+/// Returns an error if a file in the chain is missing or invalid, or if the
+/// `extends` chain is cyclic.
 pub fn load_config(config_path: &Path) -> Result<AnalyzerConfig> {
     if !config_path.exists() {
         return Ok(AnalyzerConfig::default());
     }
+    load_config_chain(config_path, &mut HashSet::new())
+}
 
+/// Load `config_path` and, recursively, the base configs it `extends`
+///
+/// `visited` holds the canonical paths already on the chain so that cyclic
+/// `extends` are reported instead of recursing forever.
+fn load_config_chain(config_path: &Path, visited: &mut HashSet<PathBuf>) -> Result<AnalyzerConfig> {
+    let canonical = config_path.canonicalize().map_err(|e| {
+        crate::AnalyzerError::Config(format!("cannot read config {}: {}", config_path.display(), e))
+    })?;
+    if !visited.insert(canonical) {
+        return Err(crate::AnalyzerError::Config(format!(
+            "cyclic extends: {} is already part of the config chain",
+            config_path.display()
+        )));
+    }
+
+    let mut config = parse_config_file(config_path)?;
+    let Some(base) = config.extends.take() else {
+        return Ok(config);
+    };
+    let base_path = config_path.parent().unwrap_or(Path::new(".")).join(base);
+    Ok(config.merged_onto(load_config_chain(&base_path, visited)?))
+}
+
+/// Parse a single TOML or YAML config file, chosen by extension
+fn parse_config_file(config_path: &Path) -> Result<AnalyzerConfig> {
     let content = std::fs::read_to_string(config_path)?;
     let extension = config_path
         .extension()
@@ -57,9 +185,10 @@ pub fn load_config(config_path: &Path) -> Result<AnalyzerConfig> {
 }
 
 /// Generic CLI options structure that any CLI frontend can use.
+#[derive(Clone)]
 pub struct CliOptions {
-    /// Path to source code (file or directory).
-    pub source: PathBuf,
+    /// Paths to source code (files, directories or glob patterns, see [`expand_source_paths`]).
+    pub source: Vec<PathBuf>,
     /// Path to configuration file.
     pub config_path: PathBuf,
     /// Minimum severity filter (1-3).
@@ -78,6 +207,70 @@ pub struct CliOptions {
     pub verbose: u8,
     /// Whether to check unit tests (default: false, tests are skipped).
     pub check_tests: bool,
+    /// Lowest violation severity that makes `run_cli` report failure.
+    pub fail_on: Severity,
+    /// Follow symbolic links while walking directories.
+    pub follow_symlinks: bool,
+    /// Walk into `.`-prefixed (hidden) directories.
+    pub include_hidden: bool,
+    /// Grouping of the detailed text listing (`None` lists violations in file order).
+    pub group_by: Option<ReportGrouping>,
+    /// Reuse per-file results from the on-disk cache in `DEFAULT_CACHE_DIR`.
+    pub use_cache: bool,
+    /// Per-checker severity overrides keyed by checker code (`--severity-override`).
+    pub severity_overrides: HashMap<String, SeverityLevel>,
+    /// Only analyze `.rs` files modified according to `git diff` (`--changed`).
+    pub changed_only: bool,
+    /// Suppress the banner and the summary report, printing only violations (`--quiet`).
+    pub quiet: bool,
+    /// Report files that fail to parse as `E0001` violations (`--report-parse-errors`).
+    pub report_parse_errors: bool,
+    /// Directory reported file paths are shown relative to (`--relative-to`, default: current directory).
+    pub relative_to: Option<PathBuf>,
+    /// Print a [`ViolationSummary`] as JSON instead of the violations (`--summary-json`).
+    pub summary_json: bool,
+    /// Fail when the [`quality_score`] is below this many LOC per violation (`--min-quality-score`).
+    pub min_quality_score: Option<f64>,
+    /// Color severity labels in text output (`--color`, `NO_COLOR`).
+    pub color: ColorChoice,
+    /// Warn about and ignore unknown checker names in the configuration instead of failing (`--lenient-config`).
+    pub lenient_config: bool,
+    /// Analyze every file under `config_path` only, ignoring `Hyp.toml` files in subdirectories (`--single-config`).
+    pub single_config: bool,
+}
+
+impl Default for CliOptions {
+    /// Options of a plain `hyp check .`: `Hyp.toml`, no filters, text output, failing on high
+    /// severity. The cache is off; front ends set the fields their flags control.
+    fn default() -> Self {
+        Self {
+            source: vec![PathBuf::from(".")],
+            config_path: PathBuf::from(CONFIG_FILE_NAME),
+            severity: None,
+            categories: None,
+            all: false,
+            include: None,
+            exclude: None,
+            format: CliOutputFormat::Text,
+            verbose: 0,
+            check_tests: false,
+            fail_on: Severity::High,
+            follow_symlinks: false,
+            include_hidden: false,
+            group_by: None,
+            use_cache: false,
+            severity_overrides: HashMap::new(),
+            changed_only: false,
+            quiet: false,
+            report_parse_errors: true,
+            relative_to: None,
+            summary_json: false,
+            min_quality_score: None,
+            color: ColorChoice::Auto,
+            lenient_config: false,
+            single_config: false,
+        }
+    }
 }
 
 /// Build an analyzer from an explicit list of checker registrations.
@@ -119,27 +312,20 @@ pub fn validate_config_against_registrations(
     config: &AnalyzerConfig,
     registrations: &[CheckerRegistration],
 ) -> Result<()> {
-    use std::collections::HashSet;
-
-    // Build a set of known config entry names
-    let known_config_names: HashSet<&str> = registrations
-        .iter()
-        .map(|r| r.config_entry_name)
-        .collect();
-
     // Validate that all configured checker names are known
-    for configured_name in config.configured_checker_keys() {
-        if !known_config_names.contains(configured_name.as_str()) {
-            let mut available: Vec<_> = known_config_names.into_iter().collect();
-            available.sort();
-            return Err(crate::AnalyzerError::Config(format!(
-                "Unknown checker '{}' in configuration. Available checkers: {}",
-                configured_name,
-                available.join(", ")
-            )));
-        }
+    if let Some(configured_name) = unknown_checker_names(config, registrations).first() {
+        let mut available: Vec<_> = registrations.iter().map(|r| r.config_entry_name).collect();
+        available.sort();
+        return Err(crate::AnalyzerError::Config(format!(
+            "Unknown checker '{}' in configuration. Available checkers: {}",
+            configured_name,
+            available.join(", ")
+        )));
     }
 
+    // Validate `[checkers] mode` / `allow`
+    config.allowlist().map_err(crate::AnalyzerError::Config)?;
+
     // Validate config types by trying to deserialize each configured checker
     for registration in registrations {
         if config.checkers.contains_key(registration.config_entry_name) {
@@ -151,6 +337,197 @@ pub fn validate_config_against_registrations(
     Ok(())
 }
 
+/// Configured checker names (selector keys excluded) that none of `registrations` knows, sorted.
+pub fn unknown_checker_names(config: &AnalyzerConfig, registrations: &[CheckerRegistration]) -> Vec<String> {
+    let mut unknown: Vec<String> = config
+        .configured_checker_keys()
+        .filter(|key| !registrations.iter().any(|r| r.config_entry_name == key.as_str()))
+        .cloned()
+        .collect();
+    unknown.sort();
+    unknown
+}
+
+/// Load the configuration for a CLI command and validate it against all known checkers.
+///
+/// `all_registrations` must be the full registry, not the set left after
+/// `--include`/`--exclude`, so that a configured checker excluded on the command
+/// line is not reported as unknown. With `opts.lenient_config` unknown checker
+/// names are printed as warnings and dropped instead of failing. Severity
+/// overrides are applied last.
+///
+/// # Errors
+/// Returns an error if the configuration cannot be loaded or is invalid.
+pub fn load_cli_config(opts: &CliOptions, all_registrations: &[CheckerRegistration]) -> Result<AnalyzerConfig> {
+    let mut config = load_config(&opts.config_path)?;
+    if opts.lenient_config {
+        for name in unknown_checker_names(&config, all_registrations) {
+            eprintln!("Warning: Unknown checker '{}' in configuration, ignoring", name);
+            config.checkers.remove(&name);
+        }
+    }
+    validate_config_against_registrations(&config, all_registrations)?;
+    apply_severity_overrides(&mut config, &opts.severity_overrides, all_registrations);
+    Ok(config)
+}
+
+/// Outcome of [`check_config_file`].
+#[derive(Debug, Default)]
+pub struct ConfigCheck {
+    /// Number of checker entries configured under `[checkers]` (selector keys excluded).
+    pub configured_checkers: usize,
+    /// Problems that make `hyp check` reject the configuration.
+    pub errors: Vec<String>,
+    /// Problems that do not stop an analysis.
+    pub warnings: Vec<String>,
+}
+
+/// Validate a configuration file without analyzing any source.
+///
+/// Unlike [`validate_config_against_registrations`], which stops at the first
+/// problem, every unknown checker name, invalid parameter and invalid path
+/// pattern is collected. Messages are prefixed with `file:line` when the
+/// checker's key is found in `config_path` itself (not in an `extends` base).
+/// With `lenient`, unknown checker names are warnings instead of errors.
+pub fn check_config_file(config_path: &Path, registrations: &[CheckerRegistration], lenient: bool) -> ConfigCheck {
+    let mut check = ConfigCheck::default();
+    if !config_path.exists() {
+        check
+            .warnings
+            .push(format!("{} not found, built-in defaults apply", config_path.display()));
+        return check;
+    }
+    let config = match load_config(config_path) {
+        Ok(config) => config,
+        Err(e) => {
+            check.errors.push(format!("{}: {}", config_path.display(), config_error_message(e)));
+            return check;
+        }
+    };
+    let content = std::fs::read_to_string(config_path).unwrap_or_default();
+    let location = |key: &str| match config_line(&content, key) {
+        Some(line) => format!("{}:{}", config_path.display(), line),
+        None => config_path.display().to_string(),
+    };
+
+    let mut keys: Vec<&String> = config.configured_checker_keys().collect();
+    keys.sort();
+    check.configured_checkers = keys.len();
+    for key in keys {
+        let Some(registration) = registrations.iter().find(|r| r.config_entry_name == key.as_str()) else {
+            let message = format!("{}: Unknown checker '{}'", location(key), key);
+            if lenient {
+                check.warnings.push(message);
+            } else {
+                check.errors.push(message);
+            }
+            continue;
+        };
+        let checker = match (registration.factory)(&config) {
+            Ok(Some(checker)) => checker,
+            Ok(None) => continue,
+            Err(e) => {
+                check.errors.push(format!("{}: {}", location(key), e));
+                continue;
+            }
+        };
+        if let Err(e) = crate::analyzer::validate_path_patterns(checker.as_ref()) {
+            check.errors.push(format!("{}: {}", location(key), config_error_message(e)));
+        }
+    }
+    if let Err(e) = config.allowlist() {
+        check.errors.push(format!("{}: {}", location("mode"), e));
+    }
+    check
+}
+
+/// Error text without the "Configuration error:" prefix, which every `ConfigCheck` entry would repeat
+fn config_error_message(error: AnalyzerError) -> String {
+    match error {
+        AnalyzerError::Config(message) => message,
+        other => other.to_string(),
+    }
+}
+
+/// 1-based line of `config_path` content that first mentions `key` as a whole word
+fn config_line(content: &str, key: &str) -> Option<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    content
+        .lines()
+        .position(|line| {
+            line.match_indices(key).any(|(at, _)| {
+                !line[..at].ends_with(is_word) && !line[at + key.len()..].starts_with(is_word)
+            })
+        })
+        .map(|index| index + 1)
+}
+
+/// Run `hyp validate-config`: print the result of [`check_config_file`].
+///
+/// Returns [`EXIT_CLEAN`] when the configuration has no errors, otherwise [`EXIT_ERROR`].
+pub fn run_validate_config<F>(config_path: &Path, lenient: bool, make_registrations: F) -> i32
+where
+    F: FnOnce() -> Vec<CheckerRegistration>,
+{
+    let check = check_config_file(config_path, &make_registrations(), lenient);
+    println!("Config: {}", config_path.display());
+    println!("Configured checkers: {}", check.configured_checkers);
+    for warning in &check.warnings {
+        println!("warning: {}", warning);
+    }
+    for error in &check.errors {
+        println!("error: {}", error);
+    }
+    if check.errors.is_empty() {
+        println!("Configuration is valid");
+        EXIT_CLEAN
+    } else {
+        println!("Configuration has {} error(s)", check.errors.len());
+        EXIT_ERROR
+    }
+}
+
+/// Text `hyp explain` prints for a checker code (case-insensitive), or `None` if no checker has it
+pub fn explain_checker(code: &str) -> Option<String> {
+    let registration = crate::registry::registration_for_code(&code.to_uppercase())?;
+    let desc = &registration.descriptor;
+    let severity = match desc.default_severity.as_u8() {
+        1 => "1 (Low)",
+        2 => "2 (Medium)",
+        _ => "3 (High)",
+    };
+    let categories: Vec<&str> = desc.default_categories.iter().map(|c| c.as_str()).collect();
+
+    let mut text = format!("{}: {}\n\n", desc.code, desc.name);
+    text.push_str(&format!("Default severity: {}\n", severity));
+    text.push_str(&format!("Categories:       {}\n", categories.join(", ")));
+    text.push_str(&format!("Config entry:     [checkers.{}]\n", registration.config_entry_name));
+    if let Some(url) = &desc.doc_url {
+        text.push_str(&format!("Docs:             {}\n", url));
+    }
+    if desc.rationale != desc.suggestions {
+        text.push_str(&format!("\n{}\n", desc.rationale));
+    }
+    text.push_str(&format!("\nSuggestion: {}\n", desc.suggestions));
+    Some(text)
+}
+
+/// Run `hyp explain <code>`: print [`explain_checker`] for the code.
+///
+/// Returns [`EXIT_CLEAN`], or [`EXIT_ERROR`] for an unknown code.
+pub fn run_explain(code: &str) -> i32 {
+    match explain_checker(code) {
+        Some(text) => {
+            print!("{}", text);
+            EXIT_CLEAN
+        }
+        None => {
+            eprintln!("Unknown checker code '{}'. Run `hyp list --all` to see the available checkers.", code);
+            EXIT_ERROR
+        }
+    }
+}
+
 /// Build an analyzer from one or more logical checker groups.
 ///
 /// # Errors
@@ -164,12 +541,52 @@ pub fn build_analyzer_from_groups(
     Analyzer::new_with_checkers(config, filters, registrations)
 }
 
+/// Apply per-checker severity overrides (`--severity-override E1606=high`) to the configuration.
+///
+/// Each override is written into the checker's `severity` config entry, so it replaces both
+/// the built-in default and any `Hyp.toml` value, and it is the severity reported in
+/// `Violation.severity`. Overrides are applied before the `--severity` minimum filter runs:
+/// `--severity 3 --severity-override E1606=high` therefore includes E1606, while
+/// `--severity 3 --severity-override E1002=low` drops E1002 from the run.
+///
+/// Codes that match no registered checker are reported on stderr and ignored.
+pub fn apply_severity_overrides(
+    config: &mut AnalyzerConfig,
+    overrides: &HashMap<String, SeverityLevel>,
+    registrations: &[CheckerRegistration],
+) {
+    let mut codes: Vec<_> = overrides.keys().collect();
+    codes.sort();
+
+    for code in codes {
+        let Some(registration) = registrations
+            .iter()
+            .find(|r| r.descriptor.code.eq_ignore_ascii_case(code))
+        else {
+            eprintln!("Warning: Unknown checker '{}' in --severity-override, ignoring", code);
+            continue;
+        };
+
+        let entry = config
+            .checkers
+            .entry(registration.config_entry_name.to_string())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        if let Some(table) = entry.as_object_mut() {
+            table.insert(
+                "severity".to_string(),
+                serde_json::Value::from(overrides[code].as_u8()),
+            );
+        }
+    }
+}
+
 /// Apply CLI include/exclude filtering to registrations (case-insensitive).
 ///
 /// Supports substring matching:
 /// - "e1" matches e1001-e1999
 /// - "e10" matches e1000-e1099
 /// - "e1001" matches exactly e1001
+/// - a trailing `*` is optional: "e15*" is the same as "e15"
 pub fn filter_registrations(
     registrations: Vec<CheckerRegistration>,
     opts: &CliOptions,
@@ -185,6 +602,15 @@ pub fn filter_registrations(
 /// e11.enabled = false  # Disables all E11xx checkers
 /// e14.enabled = false  # Disables all E14xx checkers
 /// ```
+///
+/// and an allowlist mode that disables every checker not matching `allow`:
+/// ```toml
+/// [checkers]
+/// mode = "allowlist"
+/// allow = ["E1402", "E15*"]
+/// ```
+///
+/// `--include` replaces the config allowlist, and `--exclude` applies last.
 pub fn filter_registrations_with_config(
     registrations: Vec<CheckerRegistration>,
     opts: &CliOptions,
@@ -225,6 +651,9 @@ pub fn filter_registrations_with_config(
         })
         .unwrap_or_default();
 
+    // Invalid modes are reported by validate_config_against_registrations
+    let allow_patterns = config.and_then(|cfg| cfg.allowlist().ok().flatten());
+
     registrations
         .into_iter()
         .filter(|reg| {
@@ -237,9 +666,10 @@ pub fn filter_registrations_with_config(
                 }
             }
 
-            // If include is present, only allow codes that match any include pattern
-            if let Some(ref patterns) = include_patterns {
-                let matches = patterns.iter().any(|pattern| code_lc.starts_with(pattern));
+            // If include is present, only allow codes that match any include pattern;
+            // otherwise the config allowlist (if any) decides
+            if let Some(patterns) = include_patterns.as_ref().or(allow_patterns.as_ref()) {
+                let matches = patterns.iter().any(|pattern| matches_code_pattern(&code_lc, pattern));
                 if !matches {
                     return false;
                 }
@@ -247,7 +677,7 @@ pub fn filter_registrations_with_config(
 
             // Exclude overrides include - if code matches any exclude pattern, filter it out
             if let Some(ref patterns) = exclude_patterns {
-                let matches = patterns.iter().any(|pattern| code_lc.starts_with(pattern));
+                let matches = patterns.iter().any(|pattern| matches_code_pattern(&code_lc, pattern));
                 if matches {
                     return false;
                 }
@@ -258,13 +688,18 @@ pub fn filter_registrations_with_config(
         .collect()
 }
 
+/// Whether a lowercase checker code matches an include/exclude/allow pattern (prefix, optional trailing `*`)
+fn matches_code_pattern(code_lc: &str, pattern: &str) -> bool {
+    code_lc.starts_with(pattern.trim_end_matches('*'))
+}
+
 /// Print a table of *eligible* checkers based on config + CLI filters.
 pub fn print_checker_list_from_registrations(
     opts: &CliOptions,
     registrations: Vec<CheckerRegistration>,
 ) -> Result<()> {
-    // Load config (same as run_cli)
-    let config = load_config(&opts.config_path)?;
+    // Load and validate config against all checkers (same as run_cli)
+    let config = load_cli_config(opts, &registrations)?;
 
     // Build filters (severity/category already populated in opts)
     let mut filters = AnalyzerFilters::default();
@@ -276,7 +711,7 @@ pub fn print_checker_list_from_registrations(
     }
 
     let registrations = filter_registrations_with_config(registrations, opts, Some(&config));
-    let analyzer = build_analyzer_from_registrations(config, filters, registrations)?;
+    let analyzer = build_analyzer_from_registrations_skip_validation(config, filters, registrations)?;
 
     println!("\nEligible Checkers:\n");
     println!("{:<8} {:<30} {:<10} Categories", "Code", "Name", "Severity");
@@ -307,8 +742,8 @@ pub fn print_guidelines_from_registrations(
     opts: &CliOptions,
     registrations: Vec<CheckerRegistration>,
 ) -> Result<()> {
-    // Load config (same as run_cli)
-    let config = load_config(&opts.config_path)?;
+    // Load and validate config against all checkers (same as run_cli)
+    let config = load_cli_config(opts, &registrations)?;
 
     // Build filters (severity/category already populated in opts)
     let mut filters = AnalyzerFilters::default();
@@ -320,7 +755,7 @@ pub fn print_guidelines_from_registrations(
     }
 
     let registrations = filter_registrations_with_config(registrations, opts, Some(&config));
-    let analyzer = build_analyzer_from_registrations(config, filters, registrations)?;
+    let analyzer = build_analyzer_from_registrations_skip_validation(config, filters, registrations)?;
 
     println!("Do not use the following patterns:\n");
 
@@ -336,13 +771,21 @@ pub fn print_guidelines_from_registrations(
     Ok(())
 }
 
-/// Main CLI runner that handles the full analysis workflow.
-pub fn run_cli<F>(opts: CliOptions, make_registrations: F) -> Result<()>
+/// Build the analyzer described by CLI options: config, filters, validation,
+/// severity overrides, include/exclude and the optional result cache.
+///
+/// Unless `opts.single_config` is set, files below a directory with its own
+/// `Hyp.toml` are analyzed under that configuration instead (see
+/// [`Analyzer::with_nested_configs`]), built from the same CLI options.
+///
+/// # Errors
+/// Returns an error if the configuration or `.hypignore` cannot be loaded or is invalid.
+pub fn build_cli_analyzer<F>(opts: &CliOptions, make_registrations: F) -> Result<Analyzer>
 where
     F: FnOnce() -> Vec<CheckerRegistration>,
 {
-    // 1. Load configuration
-    let config = load_config(&opts.config_path)?;
+    // 1. Get ALL registrations first for config validation
+    let all_registrations = make_registrations();
 
     // 2. Build filters
     let mut filters = AnalyzerFilters::default();
@@ -353,23 +796,68 @@ where
         filters.categories = Some(cats.clone());
     }
     filters.check_tests = opts.check_tests;
+    filters.follow_symlinks = opts.follow_symlinks;
+    filters.skip_hidden = !opts.include_hidden;
+    filters.report_parse_errors = opts.report_parse_errors;
+    filters.ignore = HypIgnore::load(&find_ignore_file(&opts.config_path))?;
+
+    // 3. Build the analyzer for the root configuration
+    let analyzer = build_config_analyzer(opts, filters.clone(), &all_registrations)?;
+    if opts.single_config {
+        return Ok(analyzer);
+    }
 
-    // 3. Get ALL registrations first for config validation
-    let all_registrations = make_registrations();
+    // 4. Nested configurations get their own analyzer, with the same CLI options
+    let opts = opts.clone();
+    let root_config = opts.config_path.clone();
+    Ok(analyzer.with_nested_configs(&root_config, move |config_path| {
+        let nested_opts = CliOptions {
+            config_path: config_path.to_path_buf(),
+            ..opts.clone()
+        };
+        build_config_analyzer(&nested_opts, filters.clone(), &all_registrations).map_err(|e| {
+            AnalyzerError::Config(format!("{}: {}", config_path.display(), config_error_message(e)))
+        })
+    }))
+}
 
-    // 4. Validate config against ALL known checkers (before CLI filtering)
-    validate_config_against_registrations(&config, &all_registrations)?;
+/// Analyzer for the configuration at `opts.config_path`
+fn build_config_analyzer(
+    opts: &CliOptions,
+    filters: AnalyzerFilters,
+    all_registrations: &[CheckerRegistration],
+) -> Result<Analyzer> {
+    // Load configuration, validated against ALL known checkers (before CLI filtering)
+    let config = load_cli_config(opts, all_registrations)?;
 
-    // 5. Apply CLI include/exclude and config category filtering
+    // Apply CLI include/exclude and config category filtering
     let registrations =
-        filter_registrations_with_config(all_registrations, &opts, Some(&config));
+        filter_registrations_with_config(all_registrations.to_vec(), opts, Some(&config));
 
-    // 6. Build analyzer with filtered registrations (skip validation since we already did it)
+    // Build analyzer with filtered registrations (skip validation since we already did it)
     let analyzer = build_analyzer_from_registrations_skip_validation(config, filters, registrations)?;
+    Ok(if opts.use_cache {
+        analyzer.with_cache(AnalysisCache::new(DEFAULT_CACHE_DIR))
+    } else {
+        analyzer
+    })
+}
+
+/// Main CLI runner that handles the full analysis workflow.
+///
+/// Returns the process exit code: [`EXIT_VIOLATIONS`] if any violation is at or
+/// above `opts.fail_on` or the [`quality_score`] is below `opts.min_quality_score`,
+/// otherwise [`EXIT_CLEAN`]. Errors should be mapped to [`EXIT_ERROR`] by the caller.
+pub fn run_cli<F>(opts: CliOptions, make_registrations: F) -> Result<i32>
+where
+    F: FnOnce() -> Vec<CheckerRegistration>,
+{
+    let analyzer = build_cli_analyzer(&opts, make_registrations)?;
+    let sources = expand_source_paths(&opts.source)?;
 
-    // 4. Print enabled checkers (non-verbose mode)
-    if opts.verbose == 0 {
-        eprintln!("Analyzing: {}", opts.source.display());
+    // 4. Print enabled checkers (non-verbose, non-quiet mode)
+    if opts.verbose == 0 && !opts.quiet {
+        eprintln!("Analyzing: {}", display_paths(&sources));
         eprintln!("\nEnabled checkers ({}):", analyzer.checker_count());
         for checker in analyzer.enabled_checkers() {
             let severity_str = match checker.severity {
@@ -389,63 +877,475 @@ where
         eprintln!();
     }
 
-    // 5. Run analysis (verbose vs normal)
-    let violations = if opts.verbose > 0 {
-        analyze_with_verbose(&analyzer, &opts.source, opts.verbose)?
+    // 5. Run analysis (stdin, changed files, verbose or normal)
+    let stdin_content = match sources.as_slice() {
+        [source] => read_stdin_source(source, std::io::stdin())?,
+        _ => None,
+    };
+    let changed_files = if opts.changed_only && stdin_content.is_none() {
+        let files = sources
+            .iter()
+            .map(|source| changed_files_under(source, &analyzer))
+            .collect::<Option<Vec<_>>>()
+            .map(|files| files.concat());
+        if let (Some(files), false) = (&files, opts.quiet) {
+            eprintln!("Analyzing {} changed file(s)", files.len());
+        }
+        files
     } else {
-        analyzer.analyze(&opts.source)?
+        None
+    };
+    let violations = if let Some(content) = &stdin_content {
+        analyzer.analyze_source(content, STDIN_VIRTUAL_PATH)?
+    } else if let Some(files) = &changed_files {
+        let mut violations = Vec::new();
+        for file in files {
+            violations.extend(analyzer.analyze_file(file)?);
+        }
+        violations
+    } else {
+        let mut violations = Vec::new();
+        for source in &sources {
+            if opts.verbose > 0 {
+                violations.extend(analyze_with_verbose(&analyzer, source, opts.verbose)?);
+            } else {
+                violations.extend(analyzer.analyze(source)?);
+            }
+        }
+        violations
     };
 
-    // 6. Sort violations by file path, then by code
+    // 6. Show paths relative to --relative-to; checkers have already matched on the real paths
     let mut violations = violations;
+    let base = opts.relative_to.clone().unwrap_or_else(|| PathBuf::from("."));
+    for violation in &mut violations {
+        violation.file_path = relativize(&violation.file_path, &base);
+    }
+
+    // 7. Sort violations by file path, then by code
     violations.sort_by(|a, b| {
         a.file_path
             .cmp(&b.file_path)
             .then_with(|| a.code.cmp(&b.code))
     });
 
-    // 7. Count LOC for quality score
-    let total_loc = count_total_loc(&opts.source);
-
-    // 8. Output formatting
-    match opts.format {
-        CliOutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&violations)?);
-        }
-        CliOutputFormat::Text => {
-            // Always show detailed violations
-            if !violations.is_empty() {
-                println!("\nFound {} violation(s):\n", violations.len());
-                for v in &violations {
-                    println!(
-                        "[{}] {} - {}",
-                        v.code,
-                        v.name,
-                        match v.severity {
-                            crate::violation::Severity::High => "HIGH",
-                            crate::violation::Severity::Medium => "MEDIUM",
-                            crate::violation::Severity::Low => "LOW",
+    // 8. Count LOC for quality score
+    let total_loc = match (&stdin_content, &changed_files) {
+        (Some(content), _) => content.lines().count(),
+        (None, Some(files)) => files.iter().map(|file| count_total_loc(file, &analyzer)).sum(),
+        (None, None) => sources.iter().map(|source| count_total_loc(source, &analyzer)).sum(),
+    };
+
+    // 9. Output formatting
+    if opts.summary_json {
+        println!("{}", serde_json::to_string_pretty(&ViolationSummary::from_violations(&violations))?);
+    } else {
+        match opts.format {
+            CliOutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&violations)?);
+            }
+            CliOutputFormat::JsonLines => {
+                print!("{}", to_json_lines(&violations)?);
+            }
+            CliOutputFormat::Markdown => {
+                print!("{}", to_markdown(&violations, total_loc));
+            }
+            CliOutputFormat::Text => {
+                let color = opts.color.enabled();
+                // Always show detailed violations
+                if let Some(grouping) = opts.group_by {
+                    print!("{}", format_grouped_violations(&violations, grouping));
+                } else if !violations.is_empty() {
+                    if !opts.quiet {
+                        println!("\nFound {} violation(s):\n", violations.len());
+                    }
+                    for v in &violations {
+                        println!("[{}] {} - {}", v.code, v.name, paint_severity(v.severity, color));
+                        println!("  File: {}:{}", v.file_path, v.line);
+                        println!("  {}", v.message);
+                        if let Some(suggestion) = &v.suggestion {
+                            println!("  Suggestion: {}", suggestion);
                         }
-                    );
-                    println!("  File: {}:{}", v.file_path, v.line);
-                    println!("  {}", v.message);
-                    if let Some(suggestion) = &v.suggestion {
-                        println!("  Suggestion: {}", suggestion);
+                        println!();
                     }
-                    println!();
+                }
+
+                if !opts.quiet {
+                    print_structured_report(&violations, total_loc, color);
                 }
             }
+        }
+    }
 
-            // Always print the analysis report
-            print_structured_report(&violations, total_loc);
+    // 10. Quality gate; zero violations score infinity and always pass
+    let score = quality_score(violations.len(), total_loc);
+    if let Some(min) = opts.min_quality_score.filter(|min| score < *min) {
+        eprintln!("Quality score {:.1} LOC/error is below the required {:.1}", score, min);
+        return Ok(EXIT_VIOLATIONS);
+    }
+
+    Ok(exit_code_for(&violations, opts.fail_on))
+}
+
+/// Display form of `path` relative to `base`
+///
+/// Relative paths (and a relative `base`) are resolved against the current
+/// directory first. Paths outside `base` are returned unchanged.
+pub fn relativize(path: &str, base: &Path) -> String {
+    let absolute = |p: &Path| match std::env::current_dir() {
+        Ok(cwd) if !p.is_absolute() => cwd.join(p),
+        _ => p.to_path_buf(),
+    };
+    match absolute(Path::new(path)).strip_prefix(absolute(base)) {
+        Ok(relative) if !relative.as_os_str().is_empty() => relative.to_string_lossy().into_owned(),
+        _ => path.to_string(),
+    }
+}
+
+/// Run `hyp fix`: apply the fix-its checkers attached to violations under the `opts.source` paths.
+///
+/// A unified diff of every changed file is printed to stdout. Files are only
+/// rewritten when `dry_run` is false. Fix-its that overlap an earlier one in
+/// the same file are skipped with a warning. Returns the number of applied
+/// (or, with `dry_run`, applicable) fix-its.
+///
+/// # Errors
+/// Returns an error if the configuration is invalid or a file cannot be read or written.
+pub fn run_fix<F>(opts: &CliOptions, make_registrations: F, dry_run: bool) -> Result<usize>
+where
+    F: FnOnce() -> Vec<CheckerRegistration>,
+{
+    let analyzer = build_cli_analyzer(opts, make_registrations)?;
+    let mut violations = Vec::new();
+    for source in expand_source_paths(&opts.source)? {
+        violations.extend(analyzer.analyze(&source)?);
+    }
+
+    let mut by_file: BTreeMap<&str, Vec<FixIt>> = BTreeMap::new();
+    for violation in &violations {
+        if let Some(fixit) = &violation.replacement {
+            by_file.entry(&violation.file_path).or_default().push(fixit.clone());
+        }
+    }
+
+    let mut total = 0;
+    for (file, fixits) in &by_file {
+        let content = std::fs::read_to_string(file)?;
+        let fixed = apply_fixits(file.trim_start_matches("./"), &content, fixits);
+        print!("{}", fixed.diff);
+        if fixed.skipped > 0 {
+            eprintln!("Warning: skipped {} overlapping fix-it(s) in {}", fixed.skipped, file);
+        }
+        if !dry_run && fixed.applied > 0 {
+            std::fs::write(file, &fixed.content)?;
+        }
+        total += fixed.applied;
+    }
+
+    eprintln!(
+        "{} {} fix-it(s) in {} file(s)",
+        if dry_run { "Would apply" } else { "Applied" },
+        total,
+        by_file.len()
+    );
+    Ok(total)
+}
+
+/// Map violations to a process exit code given the `fail_on` threshold.
+///
+/// Returns [`EXIT_VIOLATIONS`] if any violation is at or above `fail_on`,
+/// otherwise [`EXIT_CLEAN`].
+pub fn exit_code_for(violations: &[Violation], fail_on: Severity) -> i32 {
+    match highest_severity(violations) {
+        Some(highest) if highest >= fail_on => EXIT_VIOLATIONS,
+        _ => EXIT_CLEAN,
+    }
+}
+
+/// Compare two analysis runs and return `(added, removed)` violations.
+///
+/// Violations are matched on `(code, file_path, message)` so that unrelated
+/// edits shifting line numbers do not show up as changes. Matching counts
+/// occurrences: if a file had two identical E1408 hits and now has three,
+/// one is reported as added.
+pub fn diff_runs(old: &[Violation], new: &[Violation]) -> (Vec<Violation>, Vec<Violation>) {
+    fn key(v: &Violation) -> (&str, &str, &str) {
+        (v.code.as_str(), v.file_path.as_str(), v.message.as_str())
+    }
+
+    let unmatched = |from: &[Violation], against: &[Violation]| {
+        let mut remaining: HashMap<(&str, &str, &str), usize> = HashMap::new();
+        for v in against {
+            *remaining.entry(key(v)).or_default() += 1;
+        }
+        from.iter()
+            .filter(|v| match remaining.get_mut(&key(v)) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    (unmatched(new, old), unmatched(old, new))
+}
+
+/// Load a violation list written by `hyp check -f json`.
+pub fn load_violations(path: &Path) -> Result<Vec<Violation>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    serde_json::from_str(&content).map_err(|e| {
+        crate::AnalyzerError::Serialization(format!("{}: {}", path.display(), e))
+    })
+}
+
+/// Diff two JSON violation dumps and print the added and removed violations.
+///
+/// Text output has an "Added" and a "Removed" section followed by the net
+/// change; JSON output is an object with `added`, `removed` and `net` fields
+/// (printed on a single line for JSON Lines).
+pub fn run_diff(old_path: &Path, new_path: &Path, format: CliOutputFormat) -> Result<()> {
+    let old = load_violations(old_path)?;
+    let new = load_violations(new_path)?;
+    let (added, removed) = diff_runs(&old, &new);
+    let net = added.len() as i64 - removed.len() as i64;
+
+    match format {
+        CliOutputFormat::Json | CliOutputFormat::JsonLines => {
+            let diff = serde_json::json!({
+                "added": added,
+                "removed": removed,
+                "net": net,
+            });
+            if matches!(format, CliOutputFormat::JsonLines) {
+                println!("{}", serde_json::to_string(&diff)?);
+            } else {
+                println!("{}", serde_json::to_string_pretty(&diff)?);
+            }
+        }
+        CliOutputFormat::Text | CliOutputFormat::Markdown => {
+            let print_section = |title: &str, violations: &[Violation]| {
+                println!("{} ({}):", title, violations.len());
+                for v in violations {
+                    println!("  [{}] {}:{} - {}", v.code, v.file_path, v.line, v.message);
+                }
+                println!();
+            };
+            print_section("Added", &added);
+            print_section("Removed", &removed);
+            println!(
+                "Net: {:+} ({} -> {} violations)",
+                net,
+                old.len(),
+                new.len()
+            );
         }
     }
 
     Ok(())
 }
 
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "HIGH",
+        Severity::Medium => "MEDIUM",
+        Severity::Low => "LOW",
+    }
+}
+
+/// Severity label, in red / yellow / blue when `color` is set
+fn paint_severity(severity: Severity, color: bool) -> String {
+    let code = match severity {
+        Severity::High => "31",
+        Severity::Medium => "33",
+        Severity::Low => "34",
+    };
+    if color {
+        format!("\x1b[{}m{}\x1b[0m", code, severity_label(severity))
+    } else {
+        severity_label(severity).to_string()
+    }
+}
+
+/// Format the detailed violation listing grouped by severity, file or code.
+///
+/// Each group is printed once as a header followed by its violations sorted by
+/// location. Identical consecutive entries (same location, code and message)
+/// are collapsed into one line with a repeat count, and each checker's
+/// suggestion is printed once per group instead of after every violation.
+pub fn format_grouped_violations(violations: &[Violation], grouping: ReportGrouping) -> String {
+    if violations.is_empty() {
+        return String::new();
+    }
+
+    let mut groups: BTreeMap<(Reverse<Severity>, &str), Vec<&Violation>> = BTreeMap::new();
+    for v in violations {
+        let key = match grouping {
+            // Reverse puts the highest severity first
+            ReportGrouping::Severity => (Reverse(v.severity), ""),
+            ReportGrouping::File => (Reverse(Severity::Low), v.file_path.as_str()),
+            ReportGrouping::Code => (Reverse(Severity::Low), v.code.as_str()),
+        };
+        groups.entry(key).or_default().push(v);
+    }
+
+    let mut out = format!("\nFound {} violation(s):\n\n", violations.len());
+    for group in groups.values_mut() {
+        group.sort_by(|a, b| {
+            (&a.file_path, a.line, a.column, &a.code).cmp(&(&b.file_path, b.line, b.column, &b.code))
+        });
+        let first = group[0];
+        let header = match grouping {
+            ReportGrouping::Severity => severity_label(first.severity).to_string(),
+            ReportGrouping::File => first.file_path.clone(),
+            ReportGrouping::Code => format!("[{}] {} - {}", first.code, first.name, severity_label(first.severity)),
+        };
+        out.push_str(&format!("{} ({} violation(s))\n", header, group.len()));
+
+        let entries = group.iter().map(|v| match grouping {
+            ReportGrouping::Severity => format!("{}:{}:{} [{}] {}", v.file_path, v.line, v.column, v.code, v.message),
+            ReportGrouping::File => format!(
+                "{}:{} [{}] {} - {}",
+                v.line,
+                v.column,
+                v.code,
+                severity_label(v.severity),
+                v.message
+            ),
+            ReportGrouping::Code => format!("{}:{}:{} {}", v.file_path, v.line, v.column, v.message),
+        });
+        let mut collapsed: Vec<(String, usize)> = Vec::new();
+        for entry in entries {
+            match collapsed.last_mut() {
+                Some((last, count)) if *last == entry => *count += 1,
+                _ => collapsed.push((entry, 1)),
+            }
+        }
+        for (entry, count) in collapsed {
+            if count > 1 {
+                out.push_str(&format!("  {} (x{})\n", entry, count));
+            } else {
+                out.push_str(&format!("  {}\n", entry));
+            }
+        }
+
+        let mut suggestions: BTreeMap<&str, &str> = BTreeMap::new();
+        for v in group.iter() {
+            if let Some(suggestion) = &v.suggestion {
+                suggestions.entry(v.code.as_str()).or_insert(suggestion.as_str());
+            }
+        }
+        for (code, suggestion) in suggestions {
+            match grouping {
+                ReportGrouping::Code => out.push_str(&format!("  Suggestion: {}\n", suggestion)),
+                _ => out.push_str(&format!("  Suggestion [{}]: {}\n", code, suggestion)),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Render violations as JSON Lines: one compact JSON object per violation,
+/// each terminated by a newline. An empty slice renders as an empty string.
+pub fn to_json_lines(violations: &[Violation]) -> Result<String> {
+    let mut out = String::new();
+    for v in violations {
+        out.push_str(&serde_json::to_string(v)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Render violations as a GitHub-flavored Markdown report.
+///
+/// The report starts with a summary table (code, name, severity, count) sorted
+/// by severity and then count, descending. It is followed by one collapsible
+/// `<details>` section per file, listing line-linked violations, and ends with
+/// the quality score line.
+pub fn to_markdown(violations: &[Violation], total_loc: usize) -> String {
+    let mut out = String::from("## Hyp analysis report\n\n");
+    out.push_str(&format!(
+        "Scanned **{}** lines of code, found **{}** violation(s).\n\n",
+        total_loc,
+        violations.len()
+    ));
+
+    if !violations.is_empty() {
+        let mut by_code: BTreeMap<&str, (&Violation, usize)> = BTreeMap::new();
+        for v in violations {
+            by_code.entry(v.code.as_str()).or_insert((v, 0)).1 += 1;
+        }
+        let mut rows: Vec<_> = by_code.into_values().collect();
+        rows.sort_by_key(|(v, count)| (Reverse(v.severity), Reverse(*count)));
+
+        out.push_str("| Code | Name | Severity | Count |\n");
+        out.push_str("|------|------|----------|------:|\n");
+        for (v, count) in rows {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                v.code,
+                escape_markdown(&v.name),
+                severity_label(v.severity),
+                count
+            ));
+        }
+        out.push('\n');
+
+        let mut by_file: BTreeMap<&str, Vec<&Violation>> = BTreeMap::new();
+        for v in violations {
+            by_file.entry(v.file_path.as_str()).or_default().push(v);
+        }
+        for (file, mut file_violations) in by_file {
+            file_violations.sort_by_key(|v| (v.line, v.column));
+            out.push_str(&format!(
+                "<details>\n<summary><code>{}</code> ({} violation(s))</summary>\n\n",
+                file,
+                file_violations.len()
+            ));
+            for v in file_violations {
+                out.push_str(&format!(
+                    "- [{}:{}]({}#L{}) **{}** {} - {}\n",
+                    file,
+                    v.line,
+                    file,
+                    v.line,
+                    v.code,
+                    severity_label(v.severity),
+                    escape_markdown(&v.message)
+                ));
+            }
+            out.push_str("\n</details>\n\n");
+        }
+    }
+
+    let score = quality_score(violations.len(), total_loc);
+    out.push_str(&format!("**Quality score:** {:.1} LOC/error\n", score));
+    out
+}
+
+/// Escape text so it renders literally inside a Markdown table or list
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Lines of code per violation, the "QUALITY SCORE" of the report
+///
+/// Higher is better. With no violations the score is infinite, so it passes
+/// any `--min-quality-score` threshold.
+pub fn quality_score(violations: usize, loc: usize) -> f64 {
+    if violations == 0 {
+        f64::INFINITY
+    } else {
+        loc as f64 / violations as f64
+    }
+}
+
 /// Print a structured analysis report grouped by severity
-fn print_structured_report(violations: &[Violation], total_loc: usize) {
+fn print_structured_report(violations: &[Violation], total_loc: usize, color: bool) {
     use std::collections::HashMap;
     use crate::violation::Severity;
 
@@ -477,13 +1377,13 @@ fn print_structured_report(violations: &[Violation], total_loc: usize) {
     println!("Found: {} violation(s)\n", violations.len());
 
     // Helper to print a severity section
-    let print_section = |title: &str, map: &HashMap<String, Vec<&Violation>>| {
+    let print_section = |severity: Severity, map: &HashMap<String, Vec<&Violation>>| {
         if map.is_empty() {
             return;
         }
 
         let total: usize = map.values().map(|v| v.len()).sum();
-        println!("{} Severity: {} errors", title, total);
+        println!("{} Severity: {} errors", paint_severity(severity, color), total);
 
         // Sort codes alphabetically
         let mut codes: Vec<_> = map.keys().collect();
@@ -501,56 +1401,155 @@ fn print_structured_report(violations: &[Violation], total_loc: usize) {
     };
 
     // Print sections in order: HIGH, MEDIUM, LOW
-    print_section("HIGH", &high_violations);
-    print_section("MEDIUM", &medium_violations);
-    print_section("LOW", &low_violations);
+    print_section(Severity::High, &high_violations);
+    print_section(Severity::Medium, &medium_violations);
+    print_section(Severity::Low, &low_violations);
 
     // Calculate and print quality score
     println!("===================================================================================");
-    let score = if violations.is_empty() {
-        0.0
-    } else {
-        total_loc as f64 / violations.len() as f64
-    };
-    println!("QUALITY SCORE: {:.1} LOC/error", score);
+    println!("QUALITY SCORE: {:.1} LOC/error", quality_score(violations.len(), total_loc));
     println!("===================================================================================\n");
 }
 
-/// Count total lines of code in analyzed files
-fn count_total_loc(path: &PathBuf) -> usize {
-    use walkdir::WalkDir;
-    use std::fs;
+/// Expand the path arguments of `hyp check` into the paths to analyze.
+///
+/// Arguments naming an existing file or directory, and [`STDIN_SOURCE`], are
+/// kept as given, so paths containing `*` or `[` still work. Any other argument
+/// is treated as a glob pattern (`src/**/*.rs`) and replaced by its matches in
+/// sorted order. A path listed twice is analyzed once.
+///
+/// # Errors
+/// Returns an error if a pattern is invalid or matches nothing.
+pub fn expand_source_paths(sources: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut expanded: Vec<PathBuf> = Vec::new();
+    for source in sources {
+        let pattern = source.to_string_lossy();
+        let is_pattern = pattern.contains(['*', '?', '[']);
+        if !is_pattern || source.exists() || pattern == STDIN_SOURCE {
+            if !expanded.contains(source) {
+                expanded.push(source.clone());
+            }
+            continue;
+        }
 
-    let mut total = 0;
+        let matches = glob::glob(&pattern)
+            .map_err(|e| AnalyzerError::Config(format!("Invalid path pattern '{}': {}", pattern, e)))?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| AnalyzerError::Config(format!("Cannot expand '{}': {}", pattern, e)))?;
+        if matches.is_empty() {
+            return Err(AnalyzerError::Config(format!("No files match '{}'", pattern)));
+        }
+        for path in matches {
+            if !expanded.contains(&path) {
+                expanded.push(path);
+            }
+        }
+    }
+    Ok(expanded)
+}
 
-    for entry in WalkDir::new(path)
-        .follow_links(true)
-        .into_iter()
-        .filter_map(|e| e.ok())
-    {
-        let path = entry.path();
-        if path.is_file() {
-            if let Some(ext) = path.extension() {
-                if ext == "rs" {
-                    if let Ok(content) = fs::read_to_string(path) {
-                        total += content.lines().count();
-                    }
-                }
+/// Paths joined for the "Analyzing:" line
+fn display_paths(paths: &[PathBuf]) -> String {
+    paths.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// Read all of `input` when `source` is [`STDIN_SOURCE`]; `None` for a real path
+fn read_stdin_source(source: &Path, mut input: impl std::io::Read) -> Result<Option<String>> {
+    if source != Path::new(STDIN_SOURCE) {
+        return Ok(None);
+    }
+    let mut content = String::new();
+    input.read_to_string(&mut content)?;
+    Ok(Some(content))
+}
+
+/// List the `.rs` files modified in the git work tree containing `repo_root`.
+///
+/// Combines `git diff --name-only HEAD` (unstaged and staged changes) with
+/// `git diff --name-only --cached` (staged changes) and returns absolute paths
+/// of files that still exist, sorted and deduplicated. Untracked files are not
+/// included until they are staged.
+///
+/// # Errors
+/// Returns an error if git cannot be run or `repo_root` is not inside a git repository.
+pub fn changed_rust_files(repo_root: &Path) -> Result<Vec<PathBuf>> {
+    let toplevel = PathBuf::from(run_git(repo_root, &["rev-parse", "--show-toplevel"])?.trim());
+    let mut files = BTreeSet::new();
+    for args in [["diff", "--name-only", "HEAD"], ["diff", "--name-only", "--cached"]] {
+        for line in run_git(repo_root, &args)?.lines() {
+            let path = toplevel.join(line);
+            if path.extension().is_some_and(|ext| ext == "rs") && path.is_file() {
+                files.insert(path);
             }
         }
     }
+    Ok(files.into_iter().collect())
+}
 
-    total
+/// Run `git -C dir <args>` and return its stdout
+fn run_git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|e| AnalyzerError::Config(format!("failed to run git: {}", e)))?;
+    if !output.status.success() {
+        return Err(AnalyzerError::Config(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Changed files under `source` for `--changed`, as paths below `source`
+///
+/// Returns `None` after printing a warning when git cannot tell (no git binary,
+/// not a repository), so the caller falls back to analyzing the whole path.
+fn changed_files_under(source: &Path, analyzer: &Analyzer) -> Option<Vec<PathBuf>> {
+    let repo_root = if source.is_file() {
+        source.parent().filter(|parent| !parent.as_os_str().is_empty()).unwrap_or(Path::new("."))
+    } else {
+        source
+    };
+    let changed = changed_rust_files(repo_root).and_then(|files| Ok((files, source.canonicalize()?)));
+    let (files, base) = match changed {
+        Ok(found) => found,
+        Err(e) => {
+            eprintln!("Warning: --changed ignored, analyzing all files: {}", e);
+            return None;
+        }
+    };
+
+    let files: Vec<PathBuf> = files
+        .iter()
+        .filter_map(|file| match file.strip_prefix(&base).ok()? {
+            rel if rel.as_os_str().is_empty() => Some(source.to_path_buf()),
+            rel => Some(source.join(rel)),
+        })
+        .filter(|file| !analyzer.rust_files(file).is_empty())
+        .collect();
+    Some(files)
+}
+
+/// Count total lines of code in analyzed files (same walk as the analyzer)
+fn count_total_loc(path: &Path, analyzer: &Analyzer) -> usize {
+    analyzer
+        .rust_files(path)
+        .iter()
+        .filter_map(|file| std::fs::read_to_string(file).ok())
+        .map(|content| content.lines().count())
+        .sum()
 }
 
 /// Analyze with verbose output showing detailed progress.
 pub fn analyze_with_verbose(
     analyzer: &Analyzer,
-    path: &PathBuf,
+    path: &Path,
     verbose_level: u8,
 ) -> Result<Vec<Violation>> {
-    use walkdir::WalkDir;
-
     let mut all_violations = Vec::new();
     let mut files_analyzed = 0;
     let mut total_items = 0;
@@ -575,20 +1574,7 @@ pub fn analyze_with_verbose(
     }
     eprintln!();
 
-    let paths: Vec<_> = if path.is_file() {
-        vec![path.to_path_buf()]
-    } else {
-        WalkDir::new(path)
-            .follow_links(true)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                e.path().extension().and_then(|s| s.to_str()) == Some("rs")
-                    && !e.path().components().any(|c| c.as_os_str() == "target")
-            })
-            .map(|e| e.path().to_path_buf())
-            .collect()
-    };
+    let paths = analyzer.rust_files(path);
 
     for file_path in paths {
         files_analyzed += 1;
@@ -688,14 +1674,14 @@ pub fn find_config_file() -> PathBuf {
     let mut current = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
 
     loop {
-        let config_path = current.join("Hyp.toml");
+        let config_path = current.join(CONFIG_FILE_NAME);
         if config_path.exists() {
             return config_path;
         }
 
         if !current.pop() {
             // Reached filesystem root, return default (non-existent) path
-            return PathBuf::from("Hyp.toml");
+            return PathBuf::from(CONFIG_FILE_NAME);
         }
     }
 }
@@ -760,6 +1746,31 @@ where
     Ok(())
 }
 
+/// Machine-readable catalog of every registered checker.
+///
+/// Returns a JSON array with one object per checker holding its code, name,
+/// default severity, default categories, suggestions and the config entry
+/// name used in Hyp.toml. Unlike [`print_default_config`], which emits TOML
+/// for editing, this is meant for tools such as config UIs.
+pub fn checker_catalog_json(registrations: &[CheckerRegistration]) -> serde_json::Value {
+    let checkers = registrations
+        .iter()
+        .map(|reg| {
+            let desc = &reg.descriptor;
+            serde_json::json!({
+                "code": desc.code,
+                "name": desc.name,
+                "default_severity": desc.default_severity.as_u8(),
+                "default_categories": desc.default_categories,
+                "suggestions": desc.suggestions,
+                "rationale": desc.rationale,
+                "config_entry_name": reg.config_entry_name,
+            })
+        })
+        .collect();
+    serde_json::Value::Array(checkers)
+}
+
 // =============================================================================
 // Example Validation
 // =============================================================================
@@ -829,12 +1840,16 @@ pub struct ValidationSummary {
     pub bad_not_detected: Vec<FunctionValidation>,
     /// Functions where good code was incorrectly flagged.
     pub good_incorrectly_detected: Vec<FunctionValidation>,
+    /// Functions that met their expectation (bad detected or good left clean).
+    pub passed: Vec<FunctionValidation>,
+    /// `E0001` violations for files that failed to parse (only when reporting parse errors).
+    pub parse_errors: Vec<Violation>,
 }
 
 impl ValidationSummary {
     /// Total number of issues (failures).
     pub fn total_issues(&self) -> usize {
-        self.bad_not_detected.len() + self.good_incorrectly_detected.len()
+        self.bad_not_detected.len() + self.good_incorrectly_detected.len() + self.parse_errors.len()
     }
 
     /// Whether all validations passed.
@@ -849,13 +1864,23 @@ impl ValidationSummary {
 /// - All `eXXXX_bad_*` functions trigger at least the corresponding EXXXX error
 /// - All `eXXXX_good_*` functions do NOT trigger any EXXXX error
 ///
+/// Functions of checkers that `config` or the registrations leave disabled are
+/// skipped, so the results reflect a customized rule set.
+///
 /// # Arguments
 /// * `source` - Path to the hyp-examples source directory
-/// * `registrations_fn` - Function that returns checker registrations to use
+/// * `config` - Configuration to analyze with, already validated against all checkers (see [`load_cli_config`])
+/// * `registrations_fn` - Function that returns checker registrations to use (may be a filtered subset)
+/// * `report_parse_errors` - Count files that fail to parse as issues instead of skipping them
 ///
 /// # Returns
 /// A `ValidationSummary` containing all results.
-pub fn run_validation<F>(source: &Path, registrations_fn: F) -> Result<ValidationSummary>
+pub fn run_validation<F>(
+    source: &Path,
+    config: AnalyzerConfig,
+    registrations_fn: F,
+    report_parse_errors: bool,
+) -> Result<ValidationSummary>
 where
     F: FnOnce() -> Vec<CheckerRegistration>,
 {
@@ -874,12 +1899,13 @@ where
         return Ok(ValidationSummary::default());
     }
 
-    // Create analyzer with all checkers enabled
-    let config = AnalyzerConfig::default();
     let registrations = registrations_fn();
-    let analyzer = Analyzer::new_with_checkers(config, AnalyzerFilters::default(), registrations)?;
+    let analyzer =
+        Analyzer::new_with_checkers_skip_unknown_validation(config, AnalyzerFilters::default(), registrations)?;
+    let enabled_codes: HashSet<String> = analyzer.enabled_checkers().into_iter().map(|c| c.code).collect();
 
     let mut all_validations: Vec<FunctionValidation> = Vec::new();
+    let mut parse_errors: Vec<Violation> = Vec::new();
     let mut files_processed = 0;
 
     for entry in &rust_files {
@@ -889,6 +1915,13 @@ where
             Err(_) => continue,
         };
 
+        if report_parse_errors {
+            if let Err(e) = syn::parse_file(&file_content) {
+                parse_errors.push(parse_error_violation(&e, &path.to_string_lossy()));
+                continue;
+            }
+        }
+
         // Parse the file to find function names
         let functions = extract_function_names(&file_content);
         if functions.is_empty() {
@@ -944,6 +1977,9 @@ where
                 Some(code) => code,
                 None => continue,
             };
+            if !enabled_codes.contains(&error_code.to_uppercase()) {
+                continue;
+            }
 
             // Determine if this is a bad or good function
             let is_bad = func_name.contains("_bad_") && !func_name.ends_with("_entry");
@@ -1004,6 +2040,7 @@ where
     let mut summary = ValidationSummary {
         files_processed,
         total_functions: all_validations.len(),
+        parse_errors,
         ..Default::default()
     };
 
@@ -1012,6 +2049,7 @@ where
             summary.bad_total += 1;
             if v.is_valid() {
                 summary.bad_passed += 1;
+                summary.passed.push(v);
             } else {
                 summary.bad_not_detected.push(v);
             }
@@ -1019,6 +2057,7 @@ where
             summary.good_total += 1;
             if v.is_valid() {
                 summary.good_passed += 1;
+                summary.passed.push(v);
             } else {
                 summary.good_incorrectly_detected.push(v);
             }
@@ -1028,8 +2067,109 @@ where
     Ok(summary)
 }
 
+/// Write validation results as a JUnit XML report.
+///
+/// Every validated function becomes a `<testcase>` with the file as
+/// `classname` and the function as `name`. Undetected bad functions and
+/// flagged good functions carry a `<failure>` listing the expected and the
+/// detected codes. Files that failed to parse are reported as `<error>` cases.
+///
+/// # Errors
+/// Returns an error if the report cannot be written to `path`.
+pub fn write_junit(summary: &ValidationSummary, path: &Path) -> Result<()> {
+    let mut cases: Vec<&FunctionValidation> = summary
+        .passed
+        .iter()
+        .chain(&summary.bad_not_detected)
+        .chain(&summary.good_incorrectly_detected)
+        .collect();
+    cases.sort_by(|a, b| a.file.cmp(&b.file).then(a.line_number.cmp(&b.line_number)));
+
+    let failures = summary.bad_not_detected.len() + summary.good_incorrectly_detected.len();
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!(
+        "<testsuites tests=\"{}\" failures=\"{}\" errors=\"{}\">\n",
+        cases.len() + summary.parse_errors.len(),
+        failures,
+        summary.parse_errors.len()
+    ));
+    out.push_str(&format!(
+        "  <testsuite name=\"hyp verify-examples\" tests=\"{}\" failures=\"{}\" errors=\"{}\">\n",
+        cases.len() + summary.parse_errors.len(),
+        failures,
+        summary.parse_errors.len()
+    ));
+
+    for v in &summary.parse_errors {
+        out.push_str(&format!(
+            "    <testcase classname=\"{}\" name=\"parse\">\n      <error message=\"{}\"/>\n    </testcase>\n",
+            escape_xml(&v.file_path),
+            escape_xml(&v.message)
+        ));
+    }
+
+    for v in cases {
+        out.push_str(&format!(
+            "    <testcase classname=\"{}\" name=\"{}\"",
+            escape_xml(&v.file),
+            escape_xml(&v.function)
+        ));
+        if v.is_valid() {
+            out.push_str("/>\n");
+            continue;
+        }
+
+        let message = if v.expected_detection {
+            format!("Expected {} was not detected", v.error_code.to_uppercase())
+        } else {
+            String::from("Good function has unexpected violations")
+        };
+        let expected = if v.expected_detection {
+            v.error_code.to_uppercase()
+        } else {
+            String::from("(none)")
+        };
+        let mut detail = format!("{}:{}\nExpected: {}\nDetected:", v.file, v.line_number, expected);
+        if v.detected_violations.is_empty() {
+            detail.push_str(" (none)");
+        }
+        for violation in &v.detected_violations {
+            detail.push_str(&format!("\n  - {} - {} - line: {}", violation.code, violation.name, violation.line));
+        }
+        out.push_str(&format!(
+            ">\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+            escape_xml(&message),
+            escape_xml(&detail)
+        ));
+    }
+
+    out.push_str("  </testsuite>\n</testsuites>\n");
+    std::fs::write(path, out)
+        .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    Ok(())
+}
+
+/// Escape text for use in XML attribute values and character data
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
 /// Print validation results to stdout.
 pub fn print_validation_results(summary: &ValidationSummary) {
+    if !summary.parse_errors.is_empty() {
+        println!("❌ FILES THAT FAILED TO PARSE ({}):", summary.parse_errors.len());
+        println!("   No functions in these files were validated:\n");
+        for v in &summary.parse_errors {
+            println!("   * {}:{}", v.file_path, v.line);
+            println!("     {}", v.message);
+            println!();
+        }
+    }
+
     // Print violations
     if !summary.bad_not_detected.is_empty() {
         println!(
@@ -1116,6 +2256,9 @@ pub fn print_validation_results(summary: &ValidationSummary) {
                 "  * Or update the _bad_ functions if they don't actually demonstrate the problem"
             );
         }
+        if !summary.parse_errors.is_empty() {
+            println!("  * Fix the syntax errors in the example files that failed to parse");
+        }
         if !summary.good_incorrectly_detected.is_empty() {
             println!("  * Fix hyp checkers that are too aggressive");
             println!("  * Or rename _good_ functions if they actually contain problems");
@@ -1226,6 +2369,30 @@ pub fn split_csv(s: &str) -> Vec<String> {
         .collect()
 }
 
+/// Parse `--severity-override` values such as `E1606=high,E1702=low` into a map keyed by code.
+///
+/// Malformed entries and unknown levels are reported on stderr and skipped; unknown checker
+/// codes are reported later by [`apply_severity_overrides`].
+pub fn parse_severity_overrides(s: &str) -> HashMap<String, SeverityLevel> {
+    let mut overrides = HashMap::new();
+    for entry in split_csv(s) {
+        let Some((code, level)) = entry.split_once('=') else {
+            eprintln!("Warning: Invalid severity override '{}', expected CODE=LEVEL, ignoring", entry);
+            continue;
+        };
+        match SeverityLevel::parse_level(level) {
+            Some(level) => {
+                overrides.insert(code.trim().to_uppercase(), level);
+            }
+            None => {
+                eprintln!("Warning: Unknown severity '{}' in override '{}', ignoring", level.trim(), entry);
+                eprintln!("Valid severities: low, medium, high (or 1-3)");
+            }
+        }
+    }
+    overrides
+}
+
 /// Parse category string into HashSet.
 pub fn parse_categories(category: &Option<String>) -> Option<HashSet<CheckerCategory>> {
     if let Some(cat_str) = category {
@@ -1251,3 +2418,601 @@ pub fn parse_categories(category: &Option<String>) -> Option<HashSet<CheckerCate
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(code: &str, severity: Severity, file: &str, line: usize, message: &str) -> Violation {
+        Violation::new(code, format!("{} name", code), severity, message, file, line, 5)
+            .with_suggestion(format!("fix {}", code))
+    }
+
+    fn sample() -> Vec<Violation> {
+        vec![
+            violation("E1408", Severity::Medium, "src/b.rs", 20, "unchecked index"),
+            violation("E1002", Severity::High, "src/a.rs", 9, "unwrap"),
+            violation("E1408", Severity::Medium, "src/a.rs", 3, "unchecked index"),
+            violation("E1408", Severity::Medium, "src/a.rs", 3, "unchecked index"),
+            violation("E1112", Severity::Low, "src/a.rs", 1, "magic number"),
+        ]
+    }
+
+    #[test]
+    fn test_group_by_file() {
+        let out = format_grouped_violations(&sample(), ReportGrouping::File);
+        let expected = "\nFound 5 violation(s):\n\n\
+            src/a.rs (4 violation(s))\n\
+            \x20 1:5 [E1112] LOW - magic number\n\
+            \x20 3:5 [E1408] MEDIUM - unchecked index (x2)\n\
+            \x20 9:5 [E1002] HIGH - unwrap\n\
+            \x20 Suggestion [E1002]: fix E1002\n\
+            \x20 Suggestion [E1112]: fix E1112\n\
+            \x20 Suggestion [E1408]: fix E1408\n\
+            \n\
+            src/b.rs (1 violation(s))\n\
+            \x20 20:5 [E1408] MEDIUM - unchecked index\n\
+            \x20 Suggestion [E1408]: fix E1408\n\n";
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_group_by_code() {
+        let out = format_grouped_violations(&sample(), ReportGrouping::Code);
+        let sections: Vec<&str> = out.lines().filter(|l| l.starts_with('[')).collect();
+        assert_eq!(
+            sections,
+            vec![
+                "[E1002] E1002 name - HIGH (1 violation(s))",
+                "[E1112] E1112 name - LOW (1 violation(s))",
+                "[E1408] E1408 name - MEDIUM (3 violation(s))",
+            ]
+        );
+        assert!(out.contains("  src/a.rs:3:5 unchecked index (x2)\n  src/b.rs:20:5 unchecked index\n  Suggestion: fix E1408\n"));
+    }
+
+    #[test]
+    fn test_group_by_severity_orders_highest_first() {
+        let out = format_grouped_violations(&sample(), ReportGrouping::Severity);
+        let headers: Vec<&str> = out.lines().filter(|l| l.contains("violation(s))")).collect();
+        assert_eq!(
+            headers,
+            vec!["HIGH (1 violation(s))", "MEDIUM (3 violation(s))", "LOW (1 violation(s))"]
+        );
+        assert!(out.contains("  src/a.rs:9:5 [E1002] unwrap\n"));
+    }
+
+    #[test]
+    fn test_diff_runs_ignores_line_shifts() {
+        let old = sample();
+        let mut new = sample();
+        for v in &mut new {
+            v.line += 10;
+        }
+        let (added, removed) = diff_runs(&old, &new);
+        assert!(added.is_empty());
+        assert!(removed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_runs_added_and_removed() {
+        let old = sample();
+        let mut new: Vec<Violation> = sample().into_iter().filter(|v| v.code != "E1002").collect();
+        new.push(violation("E1408", Severity::Medium, "src/a.rs", 40, "unchecked index"));
+        new.push(violation("E1001", Severity::High, "src/c.rs", 1, "panic"));
+
+        let (added, removed) = diff_runs(&old, &new);
+        let added: Vec<_> = added.iter().map(|v| (v.code.as_str(), v.file_path.as_str())).collect();
+        let removed: Vec<_> = removed.iter().map(|v| (v.code.as_str(), v.file_path.as_str())).collect();
+        // The third identical E1408 in src/a.rs is new even though the key already existed
+        assert_eq!(added, vec![("E1408", "src/a.rs"), ("E1001", "src/c.rs")]);
+        assert_eq!(removed, vec![("E1002", "src/a.rs")]);
+    }
+
+    #[test]
+    fn test_changed_rust_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(["-c", "user.name=hyp", "-c", "user.email=hyp@example.com"])
+                .arg("-C")
+                .arg(root)
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?}", args);
+        };
+        // Outside a repository git fails and the caller falls back to full analysis
+        assert!(changed_rust_files(root).is_err());
+
+        git(&["init", "-q"]);
+        for name in ["lib.rs", "main.rs", "notes.md"] {
+            std::fs::write(root.join(name), "fn a() {}\n").unwrap();
+        }
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+
+        std::fs::write(root.join("lib.rs"), "fn b() {}\n").unwrap();
+        std::fs::write(root.join("notes.md"), "changed\n").unwrap();
+        std::fs::write(root.join("staged.rs"), "fn c() {}\n").unwrap();
+        std::fs::write(root.join("untracked.rs"), "fn d() {}\n").unwrap();
+        git(&["add", "staged.rs"]);
+
+        let names: Vec<_> = changed_rust_files(root)
+            .unwrap()
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, vec!["lib.rs", "staged.rs"]);
+    }
+
+    #[test]
+    fn test_load_config_extends() {
+        let dir = tempfile::tempdir().unwrap();
+        let crate_dir = dir.path().join("crates/api");
+        std::fs::create_dir_all(&crate_dir).unwrap();
+        std::fs::write(
+            dir.path().join("hyp.yaml"),
+            "checkers:\n  e1002_direct_unwrap_expect:\n    enabled: false\n    severity: 1\n  e1106_long_function:\n    max_lines: 80\n",
+        )
+        .unwrap();
+        std::fs::write(
+            crate_dir.join("Hyp.toml"),
+            "extends = \"../../hyp.yaml\"\n\n[checkers]\ne1002_direct_unwrap_expect.severity = 3\n",
+        )
+        .unwrap();
+
+        let config = load_config(&crate_dir.join("Hyp.toml")).unwrap();
+        assert!(config.extends.is_none());
+        assert_eq!(config.checkers["e1106_long_function"]["max_lines"], 80);
+        // The child entry replaces the base entry wholesale: `enabled` is not inherited
+        assert_eq!(
+            config.checkers["e1002_direct_unwrap_expect"],
+            serde_json::json!({ "severity": 3 })
+        );
+    }
+
+    #[test]
+    fn test_load_config_cyclic_extends() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.toml"), "extends = \"b.toml\"\n").unwrap();
+        std::fs::write(dir.path().join("b.toml"), "extends = \"a.toml\"\n").unwrap();
+        let err = load_config(&dir.path().join("a.toml")).unwrap_err().to_string();
+        assert!(err.contains("cyclic extends"), "{}", err);
+
+        std::fs::write(dir.path().join("c.toml"), "extends = \"missing.toml\"\n").unwrap();
+        let err = load_config(&dir.path().join("c.toml")).unwrap_err().to_string();
+        assert!(err.contains("missing.toml"), "{}", err);
+    }
+
+    #[test]
+    fn test_load_violations_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("run.json");
+        std::fs::write(&path, serde_json::to_string_pretty(&sample()).unwrap()).unwrap();
+        let loaded = load_violations(&path).unwrap();
+        assert_eq!(loaded.len(), 5);
+        assert_eq!(loaded[0].code, "E1408");
+
+        std::fs::write(&path, "not json").unwrap();
+        let err = load_violations(&path).unwrap_err().to_string();
+        assert!(err.contains("run.json"), "{}", err);
+    }
+
+    #[test]
+    fn test_group_empty_and_parse() {
+        assert!(format_grouped_violations(&[], ReportGrouping::File).is_empty());
+        assert_eq!(ReportGrouping::parse_grouping("File"), Some(ReportGrouping::File));
+        assert_eq!(ReportGrouping::parse_grouping("line"), None);
+    }
+
+    #[test]
+    fn test_parse_severity_overrides() {
+        let overrides = parse_severity_overrides("e1606=high, E1702=1,E1001,E1002=fatal");
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides["E1606"], SeverityLevel::High);
+        assert_eq!(overrides["E1702"], SeverityLevel::Low);
+    }
+
+    #[test]
+    fn test_severity_override_reaches_violations() {
+        let registrations = crate::registry::get_all_checkers();
+        let mut config = AnalyzerConfig::default();
+        let overrides = HashMap::from([
+            ("E1001".to_string(), SeverityLevel::Low),
+            ("E9999".to_string(), SeverityLevel::High),
+        ]);
+        apply_severity_overrides(&mut config, &overrides, &registrations);
+        assert_eq!(config.checkers.len(), 1);
+
+        let analyzer = Analyzer::new_with_checkers(config, AnalyzerFilters::default(), registrations).unwrap();
+        let violations = analyzer.analyze_source("fn f() { panic!(\"boom\"); }", "lib.rs").unwrap();
+        let panic = violations.iter().find(|v| v.code == "E1001").unwrap();
+        assert_eq!(panic.severity, Severity::Low);
+    }
+
+    fn cli_options(include: Option<&str>, exclude: Option<&str>) -> CliOptions {
+        CliOptions {
+            include: include.map(split_csv),
+            exclude: exclude.map(split_csv),
+            report_parse_errors: false,
+            color: ColorChoice::Never,
+            ..CliOptions::default()
+        }
+    }
+
+    fn filtered_codes(opts: &CliOptions, config: &AnalyzerConfig) -> Vec<String> {
+        filter_registrations_with_config(crate::registry::get_all_checkers(), opts, Some(config))
+            .iter()
+            .map(|reg| reg.descriptor.code.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_relativize() {
+        let cwd = std::env::current_dir().unwrap();
+        let absolute = cwd.join("src").join("lib.rs");
+        assert_eq!(relativize(&absolute.to_string_lossy(), Path::new(".")), Path::new("src").join("lib.rs").to_string_lossy());
+        assert_eq!(relativize("./src/lib.rs", &cwd.join("src")), "lib.rs");
+        assert_eq!(relativize("src/lib.rs", Path::new("other")), "src/lib.rs");
+        assert_eq!(relativize("/elsewhere/lib.rs", &cwd), "/elsewhere/lib.rs");
+        assert_eq!(relativize(STDIN_VIRTUAL_PATH, Path::new(".")), STDIN_VIRTUAL_PATH);
+    }
+
+    #[test]
+    fn test_allowlist_mode() {
+        let config = AnalyzerConfig::from_toml(
+            "[checkers]\nmode = \"allowlist\"\nallow = [\"E1402\", \"E15*\"]\n[checkers.e1106_long_function]\nmax_lines = 80",
+        )
+        .unwrap();
+        validate_config_against_registrations(&config, &crate::registry::get_all_checkers()).unwrap();
+
+        let codes = filtered_codes(&cli_options(None, None), &config);
+        assert!(codes.contains(&"E1402".to_string()) && codes.contains(&"E1502".to_string()));
+        assert!(codes.iter().all(|code| *code == "E1402" || code.starts_with("E15")));
+
+        // --include replaces the allowlist, --exclude still applies
+        assert_eq!(filtered_codes(&cli_options(Some("e1001"), None), &config), vec!["E1001"]);
+        let codes = filtered_codes(&cli_options(None, Some("e15")), &config);
+        assert_eq!(codes, vec!["E1402"]);
+
+        // Without the mode, `allow` alone changes nothing
+        let config = AnalyzerConfig::from_toml("[checkers]\nallow = [\"E1402\"]").unwrap();
+        assert_eq!(filtered_codes(&cli_options(None, None), &config).len(), crate::registry::get_all_checkers().len());
+    }
+
+    #[test]
+    fn test_invalid_checker_mode() {
+        let registrations = crate::registry::get_all_checkers();
+        let config = AnalyzerConfig::from_toml("[checkers]\nmode = \"denylist\"").unwrap();
+        assert!(validate_config_against_registrations(&config, &registrations).is_err());
+        let config = AnalyzerConfig::from_toml("[checkers]\nmode = \"allowlist\"\nallow = \"E14\"").unwrap();
+        assert!(validate_config_against_registrations(&config, &registrations).is_err());
+    }
+
+    #[test]
+    fn test_checker_catalog_json() {
+        let registrations = crate::registry::get_all_checkers();
+        let catalog = checker_catalog_json(&registrations);
+        let entries = catalog.as_array().unwrap();
+        assert_eq!(entries.len(), registrations.len());
+
+        let panic = entries.iter().find(|e| e["code"] == "E1001").unwrap();
+        assert_eq!(panic["default_severity"], 3);
+        assert_eq!(panic["default_categories"][0], "operations");
+        assert!(!panic["suggestions"].as_str().unwrap().is_empty());
+        assert!(panic["config_entry_name"].as_str().unwrap().starts_with("e1001_"));
+        assert_ne!(panic["rationale"], panic["suggestions"]);
+    }
+
+    #[test]
+    fn test_explain_checker() {
+        let text = explain_checker("e1016").unwrap();
+        assert!(text.starts_with("E1016: "));
+        assert!(text.contains("Default severity: 3 (High)"));
+        assert!(text.contains("PoisonError"));
+        assert!(text.contains("Suggestion: "));
+
+        assert!(explain_checker("E9999").is_none());
+        assert_eq!(run_explain("E9999"), EXIT_ERROR);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let violations = vec![
+            Violation::new("E1702", "Unnecessary allocation", Severity::Low, "a", "src/b.rs", 3, 1),
+            Violation::new("E1702", "Unnecessary allocation", Severity::Low, "b", "src/b.rs", 1, 1),
+            Violation::new("E1311", "Box<dyn Error> returned", Severity::Low, "c", "src/a.rs", 9, 5),
+            Violation::new("E1002", "Unwrap | expect", Severity::High, "d", "src/a.rs", 2, 1),
+        ];
+        let report = to_markdown(&violations, 100);
+
+        let rows: Vec<&str> = report.lines().filter(|l| l.starts_with("| E")).collect();
+        assert_eq!(
+            rows,
+            [
+                "| E1002 | Unwrap \\| expect | HIGH | 1 |",
+                "| E1702 | Unnecessary allocation | LOW | 2 |",
+                "| E1311 | Box&lt;dyn Error&gt; returned | LOW | 1 |",
+            ]
+        );
+        assert!(report.contains("<summary><code>src/a.rs</code> (2 violation(s))</summary>"));
+        assert!(report.contains("- [src/b.rs:1](src/b.rs#L1) **E1702** LOW - b\n- [src/b.rs:3]"));
+        assert!(report.ends_with("**Quality score:** 25.0 LOC/error\n"));
+    }
+
+    #[test]
+    fn test_quality_score() {
+        assert_eq!(quality_score(4, 100), 25.0);
+        assert_eq!(quality_score(3, 0), 0.0);
+        assert!(quality_score(0, 100).is_infinite());
+        assert!(quality_score(0, 0) > 1_000_000.0);
+    }
+
+    #[test]
+    fn test_min_quality_score_gate() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("lib.rs"), "fn f() {\n    panic!(\"x\");\n}\n").unwrap();
+        let opts = |min: Option<f64>| CliOptions {
+            source: vec![dir.path().to_path_buf()],
+            config_path: dir.path().join("Hyp.toml"),
+            severity_overrides: HashMap::from([("E1001".to_string(), SeverityLevel::Low)]),
+            quiet: true,
+            min_quality_score: min,
+            ..cli_options(Some("e1001"), None)
+        };
+
+        // One Low violation in 3 lines: --fail-on high passes, the gate decides
+        assert_eq!(run_cli(opts(None), crate::registry::get_all_checkers).unwrap(), EXIT_CLEAN);
+        assert_eq!(run_cli(opts(Some(2.0)), crate::registry::get_all_checkers).unwrap(), EXIT_CLEAN);
+        assert_eq!(run_cli(opts(Some(10.0)), crate::registry::get_all_checkers).unwrap(), EXIT_VIOLATIONS);
+
+        std::fs::write(dir.path().join("lib.rs"), "fn f() {}\n").unwrap();
+        assert_eq!(run_cli(opts(Some(1e9)), crate::registry::get_all_checkers).unwrap(), EXIT_CLEAN);
+    }
+
+    #[test]
+    fn test_run_cli_with_two_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let clean = dir.path().join("clean.rs");
+        let failing = dir.path().join("failing.rs");
+        std::fs::write(&clean, "fn f() {}\n").unwrap();
+        std::fs::write(&failing, "fn g() {\n    panic!(\"x\");\n}\n").unwrap();
+        let opts = |source: Vec<PathBuf>| CliOptions {
+            source,
+            config_path: dir.path().join("Hyp.toml"),
+            quiet: true,
+            ..cli_options(Some("e1001"), None)
+        };
+
+        assert_eq!(run_cli(opts(vec![clean.clone()]), crate::registry::get_all_checkers).unwrap(), EXIT_CLEAN);
+        assert_eq!(
+            run_cli(opts(vec![clean, failing]), crate::registry::get_all_checkers).unwrap(),
+            EXIT_VIOLATIONS
+        );
+    }
+
+    #[test]
+    fn test_expand_source_paths() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        for file in ["b.rs", "a.rs", "notes.txt", "sub/c.rs"] {
+            std::fs::write(dir.path().join(file), "").unwrap();
+        }
+        let pattern = |p: &str| dir.path().join(p);
+
+        let expanded = expand_source_paths(&[pattern("*.rs"), pattern("a.rs"), pattern("sub")]).unwrap();
+        assert_eq!(expanded, vec![pattern("a.rs"), pattern("b.rs"), pattern("sub")]);
+        let recursive = expand_source_paths(&[pattern("**/*.rs")]).unwrap();
+        assert_eq!(recursive, vec![pattern("a.rs"), pattern("b.rs"), pattern("sub/c.rs")]);
+        assert_eq!(
+            expand_source_paths(&[PathBuf::from(STDIN_SOURCE)]).unwrap(),
+            vec![PathBuf::from(STDIN_SOURCE)]
+        );
+
+        let err = expand_source_paths(&[pattern("*.md")]).unwrap_err();
+        assert!(err.to_string().contains("No files match"));
+        assert!(expand_source_paths(&[pattern("[")]).is_err());
+    }
+
+    #[test]
+    fn test_color_choice() {
+        assert_eq!(ColorChoice::parse_choice("Always"), Some(ColorChoice::Always));
+        assert_eq!(ColorChoice::parse_choice("rainbow"), None);
+        assert!(ColorChoice::Always.enabled());
+        assert!(!ColorChoice::Never.enabled());
+
+        assert_eq!(paint_severity(Severity::High, true), "\x1b[31mHIGH\x1b[0m");
+        assert_eq!(paint_severity(Severity::Low, true), "\x1b[34mLOW\x1b[0m");
+        assert_eq!(paint_severity(Severity::Medium, false), "MEDIUM");
+    }
+
+    #[test]
+    fn test_check_config_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("Hyp.toml");
+        let registrations = crate::registry::get_all_checkers();
+
+        let missing = check_config_file(&path, &registrations, false);
+        assert!(missing.errors.is_empty());
+        assert_eq!(missing.warnings.len(), 1);
+
+        std::fs::write(
+            &path,
+            "[checkers.e1001_direct_panic]\nenabled = true\n\n[checkers.e9999_unknown]\nenabled = true\n\n\
+             [checkers.e1120_excessive_early_returns]\nmax_returns = \"five\"\n",
+        )
+        .unwrap();
+        let check = check_config_file(&path, &registrations, false);
+        assert_eq!(check.configured_checkers, 3);
+        assert_eq!(check.errors.len(), 2);
+        assert!(check.errors[0].ends_with(":7: Invalid configuration for checker 'e1120_excessive_early_returns': invalid type: string \"five\", expected usize"));
+        assert!(check.errors[1].ends_with("Hyp.toml:4: Unknown checker 'e9999_unknown'"));
+        assert_eq!(run_validate_config(&path, false, crate::registry::get_all_checkers), EXIT_ERROR);
+
+        std::fs::write(&path, "[checkers.e1001_direct_panic]\nenabled = false\n").unwrap();
+        assert_eq!(run_validate_config(&path, false, crate::registry::get_all_checkers), EXIT_CLEAN);
+    }
+
+    #[test]
+    fn test_nearest_config_wins() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let panic_fn = "pub fn f() { panic!(\"boom\"); }\n";
+        for crate_dir in ["api", "tools"] {
+            std::fs::create_dir_all(dir.path().join(crate_dir).join("src")).unwrap();
+            std::fs::write(dir.path().join(crate_dir).join("src/lib.rs"), panic_fn).unwrap();
+        }
+        std::fs::write(dir.path().join("Hyp.toml"), "[checkers.e1001_direct_panic]\nseverity = 1\n").unwrap();
+        std::fs::write(dir.path().join("api/Hyp.toml"), "[checkers.e1001_direct_panic]\nseverity = 3\n").unwrap();
+        std::fs::write(dir.path().join("tools/Hyp.toml"), "[checkers.e1001_direct_panic]\nenabled = false\n").unwrap();
+
+        let opts = CliOptions {
+            config_path: dir.path().join("Hyp.toml"),
+            ..cli_options(Some("e1001"), None)
+        };
+        let severities = |opts: &CliOptions| -> Vec<(bool, Severity)> {
+            let analyzer = build_cli_analyzer(opts, crate::registry::get_all_checkers).unwrap();
+            let mut found: Vec<(bool, Severity)> = analyzer
+                .analyze(dir.path())
+                .unwrap()
+                .iter()
+                .map(|v| (v.file_path.contains("api"), v.severity))
+                .collect();
+            found.sort_by_key(|(in_api, _)| *in_api);
+            found
+        };
+
+        // api/ raises the severity, tools/ disables the checker
+        assert_eq!(severities(&opts), vec![(true, Severity::High)]);
+
+        let single = CliOptions {
+            single_config: true,
+            ..opts
+        };
+        assert_eq!(severities(&single), vec![(false, Severity::Low), (true, Severity::Low)]);
+    }
+
+    #[test]
+    fn test_lenient_config_and_cli_excluded_checker() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("Hyp.toml");
+        let registrations = crate::registry::get_all_checkers();
+        std::fs::write(&path, "[checkers.e1016_mutex_unwrap]\nenabled = true\n").unwrap();
+
+        // Excluding a configured checker on the command line must not make it unknown
+        let opts = CliOptions {
+            config_path: path.clone(),
+            ..cli_options(None, Some("e1016"))
+        };
+        assert!(load_cli_config(&opts, &registrations).is_ok());
+        assert!(print_checker_list_from_registrations(&opts, crate::registry::get_all_checkers()).is_ok());
+        assert!(print_guidelines_from_registrations(&opts, crate::registry::get_all_checkers()).is_ok());
+
+        std::fs::write(&path, "[checkers.e1016_mutex_unwrap]\nenabled = true\n\n[checkers.e9999_unknown]\nenabled = true\n")
+            .unwrap();
+        assert!(load_cli_config(&opts, &registrations).is_err());
+        let lenient = CliOptions {
+            lenient_config: true,
+            ..opts
+        };
+        let config = load_cli_config(&lenient, &registrations).unwrap();
+        assert!(!config.checkers.contains_key("e9999_unknown"));
+        assert!(config.checkers.contains_key("e1016_mutex_unwrap"));
+
+        let check = check_config_file(&path, &registrations, true);
+        assert!(check.errors.is_empty());
+        assert!(check.warnings[0].ends_with("Hyp.toml:4: Unknown checker 'e9999_unknown'"));
+        assert_eq!(run_validate_config(&path, true, crate::registry::get_all_checkers), EXIT_CLEAN);
+    }
+
+    #[test]
+    fn test_to_json_lines() {
+        let violations = vec![
+            Violation::new("E1002", "Unwrap", Severity::High, "a", "src/a.rs", 2, 1),
+            Violation::new("E1702", "Allocation", Severity::Low, "b", "src/b.rs", 3, 4),
+        ];
+        let output = to_json_lines(&violations).unwrap();
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(output.ends_with('\n'));
+        let second: Violation = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!((second.code.as_str(), second.line, second.column), ("E1702", 3, 4));
+        assert!(to_json_lines(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_read_stdin_source() {
+        let input = std::io::Cursor::new("fn f() { panic!(); }\n");
+        let content = read_stdin_source(Path::new("-"), input).unwrap();
+        assert_eq!(content.as_deref(), Some("fn f() { panic!(); }\n"));
+
+        let untouched = std::io::Cursor::new("ignored");
+        assert!(read_stdin_source(Path::new("src"), untouched).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_run_validation_skips_disabled_checkers() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("e1402_division_by_zero.rs"),
+            "pub fn e1402_bad_divide(a: u32, b: u32) -> u32 {\n    a / b\n}\n\npub fn e1402_good_divide(a: u32, b: u32) -> Option<u32> {\n    a.checked_div(b)\n}\n",
+        )
+        .unwrap();
+
+        let summary = run_validation(dir.path(), AnalyzerConfig::default(), crate::registry::get_all_checkers, false).unwrap();
+        assert_eq!((summary.bad_total, summary.good_total), (1, 1));
+
+        let config = AnalyzerConfig::from_toml("[checkers.e1402_division_by_zero]\nenabled = false").unwrap();
+        let summary = run_validation(dir.path(), config, crate::registry::get_all_checkers, false).unwrap();
+        assert_eq!(summary.total_functions, 0);
+        assert!(summary.all_passed());
+    }
+
+    #[test]
+    fn test_run_validation_reports_parse_errors() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("e1001_broken.rs"), "pub fn e1001_bad_panic( {\n").unwrap();
+
+        let skipped = run_validation(dir.path(), AnalyzerConfig::default(), crate::registry::get_all_checkers, false).unwrap();
+        assert!(skipped.all_passed());
+
+        let reported = run_validation(dir.path(), AnalyzerConfig::default(), crate::registry::get_all_checkers, true).unwrap();
+        assert_eq!(reported.total_issues(), 1);
+        assert_eq!(reported.parse_errors[0].code, "E0001");
+        assert_eq!(reported.parse_errors[0].line, 1);
+    }
+
+    #[test]
+    fn test_write_junit() {
+        let validation = |function: &str, expected_detection: bool, detected: &[&str]| FunctionValidation {
+            file: String::from("src/e1001_panic.rs"),
+            function: function.to_string(),
+            line_number: 3,
+            error_code: String::from("e1001"),
+            expected_detection,
+            detected_codes: detected.iter().map(|c| c.to_string()).collect(),
+            detected_violations: detected
+                .iter()
+                .map(|c| ViolationDetail { code: c.to_string(), name: String::from("Vec<u8> & co"), line: 4 })
+                .collect(),
+        };
+        let summary = ValidationSummary {
+            passed: vec![validation("e1001_bad_panic", true, &["E1001"])],
+            bad_not_detected: vec![validation("e1001_bad_missed", true, &[])],
+            good_incorrectly_detected: vec![validation("e1001_good_result", false, &["E1702"])],
+            ..Default::default()
+        };
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("junit.xml");
+        write_junit(&summary, &path).unwrap();
+        let xml = std::fs::read_to_string(&path).unwrap();
+
+        assert!(xml.contains("<testsuite name=\"hyp verify-examples\" tests=\"3\" failures=\"2\" errors=\"0\">"));
+        assert!(xml.contains("<testcase classname=\"src/e1001_panic.rs\" name=\"e1001_bad_panic\"/>"));
+        assert!(xml.contains("<failure message=\"Expected E1001 was not detected\">src/e1001_panic.rs:3\nExpected: E1001\nDetected: (none)</failure>"));
+        assert!(xml.contains("Expected: (none)\nDetected:\n  - E1702 - Vec&lt;u8&gt; &amp; co - line: 4</failure>"));
+    }
+}
@@ -2,7 +2,16 @@
 
 use crate::violation::CheckerSeverity;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
+
+/// File name of a hyp configuration (looked up in the current and parent directories)
+pub const CONFIG_FILE_NAME: &str = "Hyp.toml";
+
+/// `[checkers] mode` that disables every checker not matched by `[checkers] allow`
+pub const ALLOWLIST_MODE: &str = "allowlist";
+
+/// `[checkers] mode` in which all checkers run unless disabled (the default)
+pub const DEFAULT_MODE: &str = "default";
 
 /// Category of a checker
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -111,6 +120,16 @@ impl SeverityLevel {
         }
     }
 
+    /// Parse from a name ("low", "medium"/"med", "high") or a number ("1"-"3")
+    pub fn parse_level(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "low" | "1" => Some(Self::Low),
+            "medium" | "med" | "2" => Some(Self::Medium),
+            "high" | "3" => Some(Self::High),
+            _ => None,
+        }
+    }
+
     /// Convert from u8, returns None if invalid
     pub fn from_u8(value: u8) -> Option<Self> {
         match value {
@@ -132,9 +151,59 @@ impl From<SeverityLevel> for CheckerSeverity {
     }
 }
 
+/// Defaults for all checkers from the `[global]` table
+///
+/// Each value is a fallback: CLI flags and per-checker settings take precedence.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct GlobalConfig {
+    /// Checkers below this severity are not run unless their own table sets `enabled = true`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_severity: Option<SeverityLevel>,
+    /// Category given to checkers whose table does not set `categories`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_category: Option<CheckerCategory>,
+    /// Check unit tests unless `--check-tests` already enables it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check_tests: Option<bool>,
+    /// Record the Rust module path (`crate::api::user`) of every violation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module_paths: Option<bool>,
+    /// Drop a violation when a checker that subsumes it reports at the same position (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dedupe_overlapping: Option<bool>,
+    /// Base URL of the checker docs; a violation's `doc_url` is this followed by its code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc_url_base: Option<String>,
+}
+
+impl GlobalConfig {
+    /// Whether no global default is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Field-wise merge: values set here win over those in `base`
+    fn merged_onto(self, base: GlobalConfig) -> GlobalConfig {
+        GlobalConfig {
+            min_severity: self.min_severity.or(base.min_severity),
+            default_category: self.default_category.or(base.default_category),
+            check_tests: self.check_tests.or(base.check_tests),
+            module_paths: self.module_paths.or(base.module_paths),
+            dedupe_overlapping: self.dedupe_overlapping.or(base.dedupe_overlapping),
+            doc_url_base: self.doc_url_base.or(base.doc_url_base),
+        }
+    }
+}
+
 /// Main analyzer configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AnalyzerConfig {
+    /// Base config file this one builds on, relative to this file's directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<PathBuf>,
+    /// Defaults applied to every checker (`[global]` table)
+    #[serde(default, skip_serializing_if = "GlobalConfig::is_empty")]
+    pub global: GlobalConfig,
     /// Per-checker configurations (raw YAML values)
     #[serde(default)]
     pub checkers: HashMap<String, serde_json::Value>,
@@ -142,11 +211,16 @@ pub struct AnalyzerConfig {
 
 impl AnalyzerConfig {
     /// Load configuration from YAML string (legacy support)
+    ///
+    /// An `extends` key is parsed but not followed; `load_config` resolves it
+    /// relative to the file it was read from.
     pub fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
         serde_yaml::from_str(yaml)
     }
 
     /// Load configuration from TOML string
+    ///
+    /// As with [`AnalyzerConfig::from_yaml`], `extends` is parsed but not followed.
     pub fn from_toml(toml_str: &str) -> Result<Self, toml::de::Error> {
         toml::from_str(toml_str)
     }
@@ -155,6 +229,7 @@ impl AnalyzerConfig {
     ///
     /// This is a generic method that can retrieve configuration for any checker.
     /// The configuration type must implement `DeserializeOwned` and `Default`.
+    /// `[global]` defaults are filled in for fields the checker table does not set.
     ///
     /// # Errors
     /// Returns an error if the configuration exists but cannot be deserialized.
@@ -162,8 +237,18 @@ impl AnalyzerConfig {
     where
         T: serde::de::DeserializeOwned + Default,
     {
-        match self.checkers.get(key) {
-            Some(v) => serde_json::from_value::<T>(v.clone()).map_err(|e| {
+        let mut entry = self.checkers.get(key).cloned();
+        if let Some(category) = self.global.default_category {
+            let table = entry.get_or_insert_with(|| serde_json::json!({}));
+            if let Some(fields) = table.as_object_mut() {
+                fields
+                    .entry("categories")
+                    .or_insert_with(|| serde_json::json!([category.as_str()]));
+            }
+        }
+
+        match entry {
+            Some(v) => serde_json::from_value::<T>(v).map_err(|e| {
                 format!(
                     "Invalid configuration for checker '{}': {}",
                     key, e
@@ -173,9 +258,75 @@ impl AnalyzerConfig {
         }
     }
 
+    /// Whether the checker's own table sets `enabled = true`
+    pub fn is_explicitly_enabled(&self, key: &str) -> bool {
+        self.checkers
+            .get(key)
+            .and_then(|entry| entry.get("enabled"))
+            .and_then(serde_json::Value::as_bool)
+            == Some(true)
+    }
+
     /// Get all configured checker keys
+    ///
+    /// Keys that select checkers rather than configure one (see
+    /// [`AnalyzerConfig::is_selector_key`]) are left out.
     pub fn configured_checker_keys(&self) -> impl Iterator<Item = &String> {
-        self.checkers.keys()
+        self.checkers.keys().filter(|key| !Self::is_selector_key(key))
+    }
+
+    /// Whether a `[checkers]` key selects checkers instead of configuring one:
+    /// `mode`, `allow`, or a code prefix such as `e11`
+    pub fn is_selector_key(key: &str) -> bool {
+        let key = key.to_lowercase();
+        key == "mode"
+            || key == "allow"
+            || (key.len() > 1 && key.len() <= 3 && key.starts_with('e') && key[1..].chars().all(|c| c.is_ascii_digit()))
+    }
+
+    /// Code patterns of `[checkers] allow`, lowercased, when `[checkers] mode = "allowlist"`
+    ///
+    /// Returns `Ok(None)` in the default mode.
+    ///
+    /// # Errors
+    /// Returns an error if `mode` is not a known mode or `allow` is not an array of strings.
+    pub fn allowlist(&self) -> Result<Option<Vec<String>>, String> {
+        let mode = match self.checkers.get("mode") {
+            None => DEFAULT_MODE,
+            Some(value) => value.as_str().unwrap_or_default(),
+        };
+        if mode != ALLOWLIST_MODE && mode != DEFAULT_MODE {
+            return Err(format!(
+                "Invalid [checkers] mode {}: expected \"{}\" or \"{}\"",
+                self.checkers["mode"], DEFAULT_MODE, ALLOWLIST_MODE
+            ));
+        }
+
+        let allow = match self.checkers.get("allow") {
+            None => Vec::new(),
+            Some(value) => value
+                .as_array()
+                .and_then(|items| items.iter().map(|item| item.as_str().map(str::to_lowercase)).collect())
+                .ok_or_else(|| String::from("Invalid [checkers] allow: expected an array of checker codes"))?,
+        };
+        Ok((mode == ALLOWLIST_MODE).then_some(allow))
+    }
+
+    /// Layer this (child) configuration on top of `base`.
+    ///
+    /// Merging is per checker key: a checker entry present in the child replaces
+    /// the base entry wholesale, so fields the child does not repeat fall back to
+    /// the checker defaults rather than to the base values. Base entries the
+    /// child does not mention are kept. `[global]` values are merged one by one.
+    /// The result no longer `extends` anything.
+    pub fn merged_onto(self, base: AnalyzerConfig) -> AnalyzerConfig {
+        let mut checkers = base.checkers;
+        checkers.extend(self.checkers);
+        AnalyzerConfig {
+            extends: None,
+            global: self.global.merged_onto(base.global),
+            checkers,
+        }
     }
 }
 
@@ -288,6 +439,38 @@ mod tests {
         assert!(!checker_config.enabled); // default is false
     }
 
+    #[test]
+    fn test_global_default_category_fills_unset_categories() {
+        let toml = r#"
+            [global]
+            default_category = "compliance"
+
+            [checkers.set_checker]
+            categories = ["operations"]
+        "#;
+        let config = AnalyzerConfig::from_toml(toml).unwrap();
+
+        #[derive(Debug, Deserialize, Default)]
+        struct TestConfig {
+            #[serde(default)]
+            categories: Vec<CheckerCategory>,
+        }
+
+        let unset: TestConfig = config.get_checker_config("other_checker").unwrap();
+        assert_eq!(unset.categories, vec![CheckerCategory::Compliance]);
+        let set: TestConfig = config.get_checker_config("set_checker").unwrap();
+        assert_eq!(set.categories, vec![CheckerCategory::Operations]);
+    }
+
+    #[test]
+    fn test_global_merges_field_by_field() {
+        let base = AnalyzerConfig::from_toml("[global]\nmin_severity = 2\ncheck_tests = true").unwrap();
+        let child = AnalyzerConfig::from_yaml("global:\n  min_severity: high\n").unwrap();
+        let merged = child.merged_onto(base);
+        assert_eq!(merged.global.min_severity, Some(SeverityLevel::High));
+        assert_eq!(merged.global.check_tests, Some(true));
+    }
+
     #[test]
     fn test_checker_category_parse() {
         assert_eq!(CheckerCategory::parse_category("operations"), Some(CheckerCategory::Operations));
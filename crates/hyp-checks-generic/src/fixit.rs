@@ -0,0 +1,263 @@
+//! Applying checker fix-its to source text
+//!
+//! Checkers attach a [`FixIt`] to violations they can resolve mechanically.
+//! [`apply_fixits`] applies the non-overlapping ones to a file's content and
+//! renders the change as a unified diff for review (`hyp fix --dry-run`).
+
+use crate::violation::FixIt;
+
+/// Lines of unchanged context around each diff hunk
+const DIFF_CONTEXT: usize = 3;
+
+/// Outcome of applying fix-its to one file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedSource {
+    /// Content with the applied fix-its
+    pub content: String,
+    /// Unified diff from the original content (empty when nothing changed)
+    pub diff: String,
+    /// Number of fix-its applied
+    pub applied: usize,
+    /// Number of fix-its skipped because they overlap an earlier one or point outside the file
+    pub skipped: usize,
+}
+
+/// A fix-it resolved to byte offsets
+struct Edit<'a> {
+    start: usize,
+    end: usize,
+    first_line: usize,
+    last_line: usize,
+    new_text: &'a str,
+}
+
+/// A run of consecutive original lines (0-indexed, inclusive) and what replaces them
+struct Block {
+    first: usize,
+    last: usize,
+    new_lines: Vec<String>,
+}
+
+/// Apply the fix-its to `content`, labelling the diff with `path`
+///
+/// Fix-its are applied in source order; one that overlaps an already accepted
+/// fix-it, or has a position outside the file, is skipped.
+pub fn apply_fixits(path: &str, content: &str, fixits: &[FixIt]) -> FixedSource {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let mut line_starts = Vec::with_capacity(lines.len());
+    let mut offset = 0;
+    for line in &lines {
+        line_starts.push(offset);
+        offset += line.len();
+    }
+
+    let to_offset = |(line, column): (usize, usize)| -> Option<usize> {
+        let text = lines.get(line.checked_sub(1)?)?;
+        let within = match text.char_indices().nth(column.checked_sub(1)?) {
+            Some((index, _)) => index,
+            None if column - 1 == text.chars().count() => text.len(),
+            None => return None,
+        };
+        Some(line_starts[line - 1] + within)
+    };
+
+    let mut resolved: Vec<Edit> = Vec::new();
+    let mut skipped = 0;
+    for fixit in fixits {
+        match (to_offset(fixit.span_start), to_offset(fixit.span_end)) {
+            (Some(start), Some(end)) if start <= end => resolved.push(Edit {
+                start,
+                end,
+                first_line: fixit.span_start.0 - 1,
+                last_line: fixit.span_end.0 - 1,
+                new_text: &fixit.new_text,
+            }),
+            _ => skipped += 1,
+        }
+    }
+    resolved.sort_by_key(|edit| (edit.start, edit.end));
+
+    let mut edits: Vec<Edit> = Vec::new();
+    for edit in resolved {
+        if edits.last().is_some_and(|prev| edit.start < prev.end) {
+            skipped += 1;
+        } else {
+            edits.push(edit);
+        }
+    }
+    let applied = edits.len();
+
+    // Group edits that touch the same lines into blocks of whole replaced lines
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut block_edits: Vec<&Edit> = Vec::new();
+    for (index, edit) in edits.iter().enumerate() {
+        block_edits.push(edit);
+        let joins_next = edits
+            .get(index + 1)
+            .is_some_and(|next| next.first_line <= edit.last_line);
+        if joins_next {
+            continue;
+        }
+
+        let first = block_edits[0].first_line;
+        let last = edit.last_line;
+        let block_start = line_starts[first];
+        let block_end = line_starts[last] + lines[last].len();
+        let mut text = String::new();
+        let mut cursor = block_start;
+        for edit in block_edits.drain(..) {
+            text.push_str(&content[cursor..edit.start]);
+            text.push_str(edit.new_text);
+            cursor = edit.end;
+        }
+        text.push_str(&content[cursor..block_end]);
+        blocks.push(Block {
+            first,
+            last,
+            new_lines: text.split_inclusive('\n').map(str::to_string).collect(),
+        });
+    }
+
+    let mut fixed = String::with_capacity(content.len());
+    let mut next_line = 0;
+    for block in &blocks {
+        fixed.extend(lines[next_line..block.first].iter().copied());
+        fixed.extend(block.new_lines.iter().map(String::as_str));
+        next_line = block.last + 1;
+    }
+    fixed.extend(lines[next_line..].iter().copied());
+
+    FixedSource {
+        content: fixed,
+        diff: unified_diff(path, &lines, &blocks),
+        applied,
+        skipped,
+    }
+}
+
+/// Render the replaced blocks as a unified diff with [`DIFF_CONTEXT`] lines of context
+fn unified_diff(path: &str, lines: &[&str], blocks: &[Block]) -> String {
+    if blocks.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("--- a/{}\n+++ b/{}\n", path, path);
+    // Difference between new and old line numbers before the current hunk
+    let mut shift: isize = 0;
+    let mut index = 0;
+    while index < blocks.len() {
+        // Blocks whose context would touch are printed as one hunk
+        let mut end = index;
+        while blocks
+            .get(end + 1)
+            .is_some_and(|next| next.first - blocks[end].last <= 2 * DIFF_CONTEXT + 1)
+        {
+            end += 1;
+        }
+        let hunk = &blocks[index..=end];
+
+        let from = hunk[0].first.saturating_sub(DIFF_CONTEXT);
+        let to = (hunk[hunk.len() - 1].last + DIFF_CONTEXT).min(lines.len() - 1);
+        let mut body = String::new();
+        let mut new_count = 0;
+        let mut line = from;
+        let mut blocks_iter = hunk.iter().peekable();
+        while line <= to {
+            match blocks_iter.next_if(|block| block.first == line) {
+                Some(block) => {
+                    for old in &lines[block.first..=block.last] {
+                        push_diff_line(&mut body, '-', old);
+                    }
+                    for new in &block.new_lines {
+                        push_diff_line(&mut body, '+', new);
+                    }
+                    new_count += block.new_lines.len();
+                    line = block.last + 1;
+                }
+                None => {
+                    push_diff_line(&mut body, ' ', lines[line]);
+                    new_count += 1;
+                    line += 1;
+                }
+            }
+        }
+
+        let old_count = to - from + 1;
+        let new_start = (from as isize + 1 + shift).max(0);
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n{}",
+            from + 1,
+            old_count,
+            new_start,
+            new_count,
+            body
+        ));
+        shift += new_count as isize - old_count as isize;
+        index = end + 1;
+    }
+    out
+}
+
+fn push_diff_line(out: &mut String, marker: char, line: &str) {
+    out.push(marker);
+    out.push_str(line.trim_end_matches(['\n', '\r']));
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixit(start: (usize, usize), end: (usize, usize), new_text: &str) -> FixIt {
+        FixIt {
+            span_start: start,
+            span_end: end,
+            new_text: new_text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_and_diff() {
+        let content = "use std::io::*;\n\npub fn greet(name: String) {}\n";
+        let fixits = vec![
+            fixit((3, 20), (3, 26), "&str"),
+            fixit((1, 14), (1, 15), "Write"),
+            // Overlaps the first fix-it
+            fixit((3, 22), (3, 28), "x"),
+        ];
+
+        let fixed = apply_fixits("src/lib.rs", content, &fixits);
+        assert_eq!(fixed.content, "use std::io::Write;\n\npub fn greet(name: &str) {}\n");
+        assert_eq!((fixed.applied, fixed.skipped), (2, 1));
+        assert_eq!(
+            fixed.diff,
+            "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,3 +1,3 @@\n\
+             -use std::io::*;\n+use std::io::Write;\n \n\
+             -pub fn greet(name: String) {}\n+pub fn greet(name: &str) {}\n"
+        );
+    }
+
+    #[test]
+    fn test_separate_hunks_and_multibyte_columns() {
+        let mut content = String::from("let s = \"é\"; f(String);\n");
+        for _ in 0..10 {
+            content.push_str("x\n");
+        }
+        content.push_str("g(String);\n");
+        let fixits = vec![fixit((1, 16), (1, 22), "&str"), fixit((12, 3), (12, 9), "&str")];
+
+        let fixed = apply_fixits("a.rs", &content, &fixits);
+        assert!(fixed.content.starts_with("let s = \"é\"; f(&str);\n"));
+        assert!(fixed.content.ends_with("g(&str);\n"));
+        assert!(fixed.diff.contains("@@ -1,4 +1,4 @@\n"));
+        assert!(fixed.diff.contains("@@ -9,4 +9,4 @@\n"));
+    }
+
+    #[test]
+    fn test_no_fixits_leaves_content() {
+        let fixed = apply_fixits("a.rs", "fn a() {}\n", &[fixit((9, 1), (9, 2), "x")]);
+        assert_eq!(fixed.content, "fn a() {}\n");
+        assert!(fixed.diff.is_empty());
+        assert_eq!(fixed.skipped, 1);
+    }
+}
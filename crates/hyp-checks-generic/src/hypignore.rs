@@ -0,0 +1,142 @@
+//! `.hypignore` support
+//!
+//! A `.hypignore` file uses gitignore syntax to exclude files from analysis
+//! (e.g. vendored or generated code). Patterns are relative to the directory
+//! containing the `.hypignore` file, and a leading `!` re-includes a path
+//! excluded by an earlier pattern.
+
+use crate::{AnalyzerError, Result};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::path::{Path, PathBuf};
+
+/// File name of the ignore file, looked up next to `Hyp.toml`
+pub const HYPIGNORE_FILE_NAME: &str = ".hypignore";
+
+/// Compiled set of `.hypignore` patterns
+#[derive(Debug, Clone)]
+pub struct HypIgnore {
+    root: PathBuf,
+    matcher: Gitignore,
+}
+
+impl HypIgnore {
+    /// Load a `.hypignore` file. Returns `Ok(None)` if the file does not exist.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or contains an invalid glob.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)?;
+        let root = path.parent().unwrap_or_else(|| Path::new("."));
+        Self::from_patterns(root, &content).map(Some)
+    }
+
+    /// Build a matcher from gitignore-style `content`, with patterns relative to `root`.
+    ///
+    /// # Errors
+    /// Returns an error if any pattern is not a valid glob.
+    pub fn from_patterns(root: &Path, content: &str) -> Result<Self> {
+        let root = absolute(root);
+        let mut builder = GitignoreBuilder::new(&root);
+        for line in content.lines() {
+            builder.add_line(None, line).map_err(|e| {
+                AnalyzerError::Config(format!("Invalid {} pattern '{}': {}", HYPIGNORE_FILE_NAME, line, e))
+            })?;
+        }
+        let matcher = builder.build().map_err(|e| {
+            AnalyzerError::Config(format!("Invalid {}: {}", HYPIGNORE_FILE_NAME, e))
+        })?;
+        Ok(Self { root, matcher })
+    }
+
+    /// Whether `path` is excluded by the patterns.
+    ///
+    /// Paths outside the `.hypignore` directory are never ignored.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let path = absolute(path);
+        if !path.starts_with(&self.root) {
+            return false;
+        }
+        self.matcher
+            .matched_path_or_any_parents(&path, path.is_dir())
+            .is_ignore()
+    }
+}
+
+/// Locate the `.hypignore` file that sits next to the given config file.
+pub fn find_ignore_file(config_path: &Path) -> PathBuf {
+    match config_path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.join(HYPIGNORE_FILE_NAME),
+        _ => PathBuf::from(HYPIGNORE_FILE_NAME),
+    }
+}
+
+/// Resolve a path to an absolute, normalized form so relative and absolute
+/// spellings of the same file compare equal.
+fn absolute(path: &Path) -> PathBuf {
+    path.canonicalize()
+        .or_else(|_| std::path::absolute(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn setup() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("src/generated")).unwrap();
+        fs::write(dir.path().join("src/lib.rs"), "").unwrap();
+        fs::write(dir.path().join("src/generated/proto.rs"), "").unwrap();
+        fs::write(dir.path().join("src/generated/keep.rs"), "").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_directory_pattern_excludes_files() {
+        let dir = setup();
+        let ignore = HypIgnore::from_patterns(dir.path(), "src/generated/\n").unwrap();
+
+        assert!(ignore.is_ignored(&dir.path().join("src/generated/proto.rs")));
+        assert!(!ignore.is_ignored(&dir.path().join("src/lib.rs")));
+    }
+
+    #[test]
+    fn test_negation_reincludes_file() {
+        let dir = setup();
+        let ignore =
+            HypIgnore::from_patterns(dir.path(), "# generated\nsrc/generated/**\n!src/generated/keep.rs\n")
+                .unwrap();
+
+        assert!(ignore.is_ignored(&dir.path().join("src/generated/proto.rs")));
+        assert!(!ignore.is_ignored(&dir.path().join("src/generated/keep.rs")));
+    }
+
+    #[test]
+    fn test_path_outside_root_not_ignored() {
+        let dir = setup();
+        let other = TempDir::new().unwrap();
+        let ignore = HypIgnore::from_patterns(dir.path(), "*.rs\n").unwrap();
+
+        assert!(!ignore.is_ignored(&other.path().join("main.rs")));
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let dir = TempDir::new().unwrap();
+        assert!(HypIgnore::load(&dir.path().join(HYPIGNORE_FILE_NAME)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_find_ignore_file_next_to_config() {
+        assert_eq!(
+            find_ignore_file(Path::new("/work/project/Hyp.toml")),
+            PathBuf::from("/work/project/.hypignore")
+        );
+        assert_eq!(find_ignore_file(Path::new("Hyp.toml")), PathBuf::from(".hypignore"));
+    }
+}
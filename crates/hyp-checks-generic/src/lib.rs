@@ -37,24 +37,37 @@
 use thiserror::Error;
 
 pub mod analyzer;
+pub mod cache;
 pub mod checker;
 pub mod checker_config_macro;
 pub mod checkers;
 pub mod cli_helper;
 pub mod config;
+pub mod fixit;
+pub mod hypignore;
+pub mod module_path;
 pub mod registry;
 pub mod violation;
+pub mod watch;
 
-pub use analyzer::{Analyzer, AnalyzerFilters, CheckerGuideline, CheckerInfo};
+pub use analyzer::{parse_error_violation, Analyzer, AnalyzerFilters, CheckerGuideline, CheckerInfo, PARSE_ERROR_CODE, PREVIEW_PATH};
+pub use cache::{AnalysisCache, DEFAULT_CACHE_DIR};
 pub use checker::{Checker, CheckerDescriptor, ItemType};
 pub use cli_helper::{
-    filter_registrations_with_config, find_config_file, load_config, parse_categories,
-    print_default_config, print_validation_results, run_validation, split_csv, CliOptions,
-    CliOutputFormat, FunctionValidation, ValidationSummary,
+    apply_severity_overrides, build_cli_analyzer, changed_rust_files, check_config_file, checker_catalog_json, expand_source_paths, filter_registrations_with_config, find_config_file, load_cli_config, load_config,
+    parse_categories, parse_severity_overrides,
+    print_default_config, print_validation_results, quality_score, relativize, run_validation, split_csv, to_json_lines, to_markdown, write_junit, CliOptions,
+    CliOutputFormat, ColorChoice, FunctionValidation, ReportGrouping, ValidationSummary, diff_runs,
+    exit_code_for, format_grouped_violations, load_violations, explain_checker, run_diff, run_explain, run_fix, run_validate_config, unknown_checker_names, ConfigCheck,
+    EXIT_CLEAN, EXIT_ERROR, EXIT_VIOLATIONS, STDIN_SOURCE, STDIN_VIRTUAL_PATH,
 };
-pub use config::{AnalyzerConfig, CheckerCategory, SeverityLevel};
-pub use registry::{get_all_checkers, CheckerFactory, CheckerRegistration};
-pub use violation::{CheckerSeverity, Severity, Violation};
+pub use config::{AnalyzerConfig, CheckerCategory, GlobalConfig, SeverityLevel, CONFIG_FILE_NAME};
+pub use fixit::{apply_fixits, FixedSource};
+pub use hypignore::{find_ignore_file, HypIgnore};
+pub use module_path::{file_module_path, module_path_at};
+pub use registry::{get_all_checkers, registration_for_code, CheckerFactory, CheckerRegistration};
+pub use violation::{highest_severity, CheckerSeverity, FixIt, Severity, Violation, ViolationSummary};
+pub use watch::WatchState;
 
 // Re-export checker configs for convenience
 pub use checkers::e10_unsafe_code::{
@@ -90,7 +103,7 @@ pub use checkers::e18_api_design::{
     E1801Config, E1802Config, E1803Config, E1804Config, E1805Config, E1806Config, E1807Config,
     E1808Config, E1809Config, E1810Config,
 };
-pub use checkers::e19_hygiene::{E1904Config, E1905Config, E1906Config, E1908Config};
+pub use checkers::e19_hygiene::{E1904Config, E1905Config, E1906Config, E1908Config, E1909Config};
 
 /// Errors that can occur during code analysis
 #[derive(Error, Debug)]
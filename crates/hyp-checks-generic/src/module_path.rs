@@ -0,0 +1,86 @@
+//! Rust module paths of source locations
+//!
+//! A file's module path is derived from where it sits below `src/`:
+//! `src/api/user.rs` and `src/api/user/mod.rs` are `crate::api::user`,
+//! `src/lib.rs` and `src/main.rs` are `crate`. Inline `mod name { ... }`
+//! blocks extend the path of the file they are written in, so items in
+//! `mod v2 { ... }` inside `src/api.rs` are in `crate::api::v2`.
+
+use syn::spanned::Spanned;
+
+/// Module path of a whole file, from its location below the last `src` directory
+///
+/// Files outside a `src` directory are named after their file stem.
+pub fn file_module_path(file_path: &str) -> String {
+    let normalized = file_path.replace('\\', "/");
+    let components: Vec<&str> = normalized.split('/').filter(|c| !c.is_empty() && *c != ".").collect();
+    let below_src = match components.iter().rposition(|c| *c == "src") {
+        Some(index) => &components[index + 1..],
+        None => &components[components.len().saturating_sub(1)..],
+    };
+
+    let mut path = vec!["crate"];
+    for (index, component) in below_src.iter().enumerate() {
+        let is_file = index + 1 == below_src.len();
+        let name = if is_file { component.strip_suffix(".rs").unwrap_or(component) } else { component };
+        let is_root = index == 0 && (name == "lib" || name == "main");
+        if !(is_file && (name == "mod" || is_root)) {
+            path.push(name);
+        }
+    }
+    path.join("::")
+}
+
+/// Module path of `line` in `file`: the file's path plus any inline modules around it
+pub fn module_path_at(file: &syn::File, file_path: &str, line: usize) -> String {
+    let mut path = file_module_path(file_path);
+    let mut items = &file.items;
+    while let Some((ident, content)) = items.iter().find_map(|item| enclosing_module(item, line)) {
+        path.push_str("::");
+        path.push_str(&ident.to_string());
+        items = content;
+    }
+    path
+}
+
+/// Name and items of `item` if it is an inline module spanning `line`
+fn enclosing_module(item: &syn::Item, line: usize) -> Option<(&syn::Ident, &Vec<syn::Item>)> {
+    let syn::Item::Mod(module) = item else {
+        return None;
+    };
+    let (_, content) = module.content.as_ref()?;
+    let span = module.span();
+    (span.start().line <= line && line <= span.end().line).then_some((&module.ident, content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_module_path() {
+        assert_eq!(file_module_path("src/lib.rs"), "crate");
+        assert_eq!(file_module_path("./crates/app/src/main.rs"), "crate");
+        assert_eq!(file_module_path("crates/app/src/api/user.rs"), "crate::api::user");
+        assert_eq!(file_module_path("src\\api\\mod.rs"), "crate::api");
+        assert_eq!(file_module_path("src/api/lib.rs"), "crate::api::lib");
+        assert_eq!(file_module_path("test.rs"), "crate::test");
+    }
+
+    #[test]
+    fn test_module_path_at_descends_into_inline_modules() {
+        let code = "fn top() {}\n\
+                    mod api {\n\
+                        mod user {\n\
+                            fn load() {}\n\
+                        }\n\
+                        fn route() {}\n\
+                    }\n\
+                    mod external;\n";
+        let file = syn::parse_file(code).unwrap();
+        assert_eq!(module_path_at(&file, "src/lib.rs", 1), "crate");
+        assert_eq!(module_path_at(&file, "src/lib.rs", 4), "crate::api::user");
+        assert_eq!(module_path_at(&file, "src/server.rs", 6), "crate::server::api");
+        assert_eq!(module_path_at(&file, "src/lib.rs", 8), "crate");
+    }
+}
@@ -11,6 +11,7 @@ use crate::{
 pub type CheckerFactory = fn(&AnalyzerConfig) -> Result<Option<Box<dyn Checker>>, String>;
 
 /// Registry entry for a checker.
+#[derive(Clone)]
 pub struct CheckerRegistration {
     /// Descriptor with default metadata.
     pub descriptor: CheckerDescriptor,
@@ -131,3 +132,11 @@ pub fn get_all_checkers() -> Vec<CheckerRegistration> {
         CheckerGroup::E19,
     ])
 }
+
+/// Find the registration for a single checker code (e.g. "E1001") across all groups.
+/// Lets tooling instantiate and exercise one checker without building a full analyzer.
+pub fn registration_for_code(code: &str) -> Option<CheckerRegistration> {
+    get_all_checkers()
+        .into_iter()
+        .find(|registration| registration.descriptor.code == code)
+}
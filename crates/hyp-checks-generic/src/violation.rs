@@ -1,6 +1,8 @@
 //! Violation types and reporting
 
+use crate::config::CheckerCategory;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Severity level of a checker (1-3)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -46,6 +48,27 @@ pub enum Severity {
     High,
 }
 
+impl Severity {
+    /// Parse a severity from a string ("low", "medium"/"med", "high")
+    pub fn parse_severity(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "low" => Some(Self::Low),
+            "medium" | "med" => Some(Self::Medium),
+            "high" => Some(Self::High),
+            _ => None,
+        }
+    }
+
+    /// Convert to the 1-3 scale used by `--severity` and `min_severity`
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            Self::Low => 1,
+            Self::Medium => 2,
+            Self::High => 3,
+        }
+    }
+}
+
 impl From<CheckerSeverity> for Severity {
     fn from(checker_severity: CheckerSeverity) -> Self {
         match checker_severity {
@@ -68,20 +91,77 @@ pub struct Violation {
     /// Severity level
     pub severity: Severity,
 
+    /// Categories of the checker that reported the violation
+    ///
+    /// Filled in by the analyzer from the checker's configured categories.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub category: Vec<CheckerCategory>,
+
     /// Description of the problem
     pub message: String,
 
     /// File path where violation was found
     pub file_path: String,
 
+    /// Rust module the violation is in (e.g. `crate::api::user`), including inline `mod` blocks
+    ///
+    /// Set by checkers that match on module paths, and by the analyzer when
+    /// `[global] module_paths` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module_path: Option<String>,
+
     /// Line number (1-indexed)
     pub line: usize,
 
-    /// Column number (1-indexed)
+    /// Column number (1-indexed), counted in characters
     pub column: usize,
 
+    /// Column number (1-indexed) counted in UTF-16 code units, as LSP clients expect
+    ///
+    /// Differs from `column` when the line has characters outside the Basic
+    /// Multilingual Plane (e.g. emoji) before the violation. Filled in by the
+    /// analyzer from the source text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column_utf16: Option<usize>,
+
+    /// End line number (1-indexed), when the checker reports the full span
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+
+    /// End column number (1-indexed, exclusive), when the checker reports the full span
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+
+    /// `end_column` counted in UTF-16 code units
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_column_utf16: Option<usize>,
+
     /// Optional suggestion for fixing
     pub suggestion: Option<String>,
+
+    /// Mechanical edit that resolves the violation, for autofix tooling
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<FixIt>,
+
+    /// Documentation page of the checker, for editors to link from hovers
+    ///
+    /// Filled in by the analyzer from `[global] doc_url_base`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc_url: Option<String>,
+}
+
+/// A concrete source edit: replace the text between two positions with `new_text`
+///
+/// Positions are `(line, column)` pairs, 1-indexed like [`Violation::line`] and
+/// [`Violation::column`]; columns count characters and the end is exclusive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixIt {
+    /// First replaced position
+    pub span_start: (usize, usize),
+    /// Position just past the replaced text
+    pub span_end: (usize, usize),
+    /// Replacement text
+    pub new_text: String,
 }
 
 impl Violation {
@@ -99,17 +179,244 @@ impl Violation {
             code: code.into(),
             name: name.into(),
             severity,
+            category: Vec::new(),
             message: message.into(),
             file_path: file_path.into(),
+            module_path: None,
             line,
             column,
+            column_utf16: None,
+            end_line: None,
+            end_column: None,
+            end_column_utf16: None,
             suggestion: None,
+            replacement: None,
+            doc_url: None,
         }
     }
 
+    /// Set the full source span of this violation from 0-indexed-column `syn` positions
+    ///
+    /// Editor integrations use the end position to underline the whole construct.
+    pub fn with_span(mut self, start: proc_macro2::LineColumn, end: proc_macro2::LineColumn) -> Self {
+        self.line = start.line;
+        self.column = start.column + 1;
+        self.end_line = Some(end.line);
+        self.end_column = Some(end.column + 1);
+        self
+    }
+
+    /// Fill in the UTF-16 columns by mapping the character columns onto `source`
+    ///
+    /// `source` is the full text of the file the violation was found in.
+    pub fn set_utf16_columns(&mut self, source: &str) {
+        let line_text = |line: usize| source.lines().nth(line.saturating_sub(1)).unwrap_or("");
+        self.column_utf16 = Some(utf16_column(line_text(self.line), self.column));
+        self.end_column_utf16 = match (self.end_line, self.end_column) {
+            (Some(line), Some(column)) => Some(utf16_column(line_text(line), column)),
+            _ => None,
+        };
+    }
+
+    /// Record the module the violation is in
+    pub fn with_module_path(mut self, module_path: impl Into<String>) -> Self {
+        self.module_path = Some(module_path.into());
+        self
+    }
+
     /// Add a suggestion to this violation
     pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
         self.suggestion = Some(suggestion.into());
         self
     }
+
+    /// Attach a fix-it replacing the source between 0-indexed-column `syn` positions
+    pub fn with_fixit(
+        mut self,
+        start: proc_macro2::LineColumn,
+        end: proc_macro2::LineColumn,
+        new_text: impl Into<String>,
+    ) -> Self {
+        self.replacement = Some(FixIt {
+            span_start: (start.line, start.column + 1),
+            span_end: (end.line, end.column + 1),
+            new_text: new_text.into(),
+        });
+        self
+    }
+}
+
+/// Convert a 1-indexed character column on `line_text` into a 1-indexed UTF-16 column
+///
+/// `proc_macro2` (and so [`Violation::column`]) counts characters; LSP positions
+/// count UTF-16 code units, where characters outside the BMP take two. Columns
+/// past the end of the line are extended one unit per missing character.
+pub fn utf16_column(line_text: &str, char_column: usize) -> usize {
+    let before = char_column.saturating_sub(1);
+    let mut chars = line_text.chars();
+    let units: usize = chars.by_ref().take(before).map(char::len_utf16).sum();
+    let missing = before.saturating_sub(line_text.chars().count());
+    units + missing + 1
+}
+
+/// Highest severity among the given violations, or `None` if there are none
+pub fn highest_severity(violations: &[Violation]) -> Option<Severity> {
+    violations.iter().map(|v| v.severity).max()
+}
+
+/// Violation counts by severity, category and code, as printed by `--summary-json`
+///
+/// Every severity and category is listed, with zero counts included; codes
+/// only appear if they were reported. A violation counts once for each of
+/// its categories.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViolationSummary {
+    /// Counts keyed by `high`, `medium` and `low`
+    pub by_severity: BTreeMap<String, usize>,
+    /// Counts keyed by `operations`, `complexity` and `compliance`
+    pub by_category: BTreeMap<String, usize>,
+    /// Counts keyed by checker code
+    pub by_code: BTreeMap<String, usize>,
+    /// Number of violations
+    pub total: usize,
+}
+
+impl ViolationSummary {
+    /// Count the given violations
+    pub fn from_violations(violations: &[Violation]) -> Self {
+        let mut by_severity: BTreeMap<String, usize> = [Severity::High, Severity::Medium, Severity::Low]
+            .iter()
+            .map(|severity| (severity_key(*severity).to_string(), 0))
+            .collect();
+        let mut by_category: BTreeMap<String, usize> = [
+            CheckerCategory::Operations,
+            CheckerCategory::Complexity,
+            CheckerCategory::Compliance,
+        ]
+        .iter()
+        .map(|category| (category.as_str().to_string(), 0))
+        .collect();
+        let mut by_code = BTreeMap::new();
+
+        for violation in violations {
+            *by_severity.entry(severity_key(violation.severity).to_string()).or_default() += 1;
+            for category in &violation.category {
+                *by_category.entry(category.as_str().to_string()).or_default() += 1;
+            }
+            *by_code.entry(violation.code.clone()).or_default() += 1;
+        }
+
+        Self {
+            by_severity,
+            by_category,
+            by_code,
+            total: violations.len(),
+        }
+    }
+}
+
+/// Lowercase severity name, as accepted by `--fail-on`
+fn severity_key(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "high",
+        Severity::Medium => "medium",
+        Severity::Low => "low",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(severity: Severity) -> Violation {
+        Violation::new("E0000", "test", severity, "msg", "test.rs", 1, 1)
+    }
+
+    #[test]
+    fn test_highest_severity() {
+        assert_eq!(highest_severity(&[]), None);
+        assert_eq!(
+            highest_severity(&[violation(Severity::Low), violation(Severity::Medium)]),
+            Some(Severity::Medium)
+        );
+        assert_eq!(
+            highest_severity(&[violation(Severity::High), violation(Severity::Low)]),
+            Some(Severity::High)
+        );
+    }
+
+    #[test]
+    fn test_with_span_sets_end_position() {
+        let start = proc_macro2::LineColumn { line: 3, column: 4 };
+        let end = proc_macro2::LineColumn { line: 5, column: 9 };
+        let v = violation(Severity::Low).with_span(start, end);
+        assert_eq!((v.line, v.column), (3, 5));
+        assert_eq!((v.end_line, v.end_column), (Some(5), Some(10)));
+
+        let json = serde_json::to_value(&v).unwrap();
+        assert_eq!(json["end_line"], 5);
+        assert_eq!(json["end_column"], 10);
+    }
+
+    #[test]
+    fn test_start_only_violation_round_trips() {
+        let json = serde_json::to_string(&violation(Severity::High)).unwrap();
+        assert!(!json.contains("end_line"));
+
+        let parsed: Violation = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.end_line, None);
+        assert_eq!(parsed.end_column, None);
+    }
+
+    #[test]
+    fn test_utf16_columns() {
+        assert_eq!(utf16_column("let x = 1;", 5), 5);
+        // 'é' is one UTF-16 unit, '🦀' is two
+        assert_eq!(utf16_column("let é = \"🦀\"; x", 10), 10);
+        assert_eq!(utf16_column("let é = \"🦀\"; x", 12), 13);
+        assert_eq!(utf16_column("ab", 5), 5);
+
+        let source = "fn a() {}\nlet s = \"🦀🦀\"; s.unwrap();\n";
+        let start = proc_macro2::LineColumn { line: 2, column: 14 };
+        let end = proc_macro2::LineColumn { line: 2, column: 24 };
+        let mut v = violation(Severity::Low).with_span(start, end);
+        v.set_utf16_columns(source);
+        assert_eq!((v.column, v.column_utf16), (15, Some(17)));
+        assert_eq!((v.end_column, v.end_column_utf16), (Some(25), Some(27)));
+
+        let json = serde_json::to_value(&v).unwrap();
+        assert_eq!(json["column"], 15);
+        assert_eq!(json["column_utf16"], 17);
+    }
+
+    #[test]
+    fn test_violation_summary() {
+        let mut panic = Violation::new("E1001", "panic", Severity::High, "msg", "a.rs", 1, 1);
+        panic.category = vec![CheckerCategory::Operations];
+        let mut long_fn = Violation::new("E1106", "long", Severity::Low, "msg", "a.rs", 9, 1);
+        long_fn.category = vec![CheckerCategory::Complexity, CheckerCategory::Compliance];
+        let summary = ViolationSummary::from_violations(&[panic.clone(), panic, long_fn, violation(Severity::Low)]);
+
+        assert_eq!(summary.total, 4);
+        assert_eq!(summary.by_severity["high"], 2);
+        assert_eq!(summary.by_severity["medium"], 0);
+        assert_eq!(summary.by_severity["low"], 2);
+        assert_eq!(summary.by_category["operations"], 2);
+        assert_eq!(summary.by_category["complexity"], 1);
+        assert_eq!(summary.by_category["compliance"], 1);
+        assert_eq!(summary.by_code["E1001"], 2);
+        assert_eq!(summary.by_code.len(), 3);
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["by_severity"]["high"], 2);
+        assert_eq!(json["total"], 4);
+        assert_eq!(ViolationSummary::from_violations(&[]).by_category["operations"], 0);
+    }
+
+    #[test]
+    fn test_parse_severity() {
+        assert_eq!(Severity::parse_severity("HIGH"), Some(Severity::High));
+        assert_eq!(Severity::parse_severity("med"), Some(Severity::Medium));
+        assert_eq!(Severity::parse_severity("critical"), None);
+    }
 }
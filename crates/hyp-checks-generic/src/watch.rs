@@ -0,0 +1,145 @@
+//! Incremental re-analysis for watch mode
+//!
+//! [`WatchState`] keeps the violations of every analyzed file so a frontend
+//! watching the file system only re-runs the files it is told have changed.
+//! Receiving and debouncing file system events is left to the frontend.
+
+use crate::{
+    analyzer::Analyzer,
+    violation::{Severity, Violation},
+    Result,
+};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Violations of every Rust file under a watched path, keyed by file
+pub struct WatchState {
+    root: PathBuf,
+    files: BTreeMap<PathBuf, Vec<Violation>>,
+}
+
+impl WatchState {
+    /// Analyze every Rust file under `root` (a file or a directory)
+    ///
+    /// Like [`Analyzer::analyze_directory`], files that fail to analyze are
+    /// skipped with a warning.
+    pub fn new(analyzer: &Analyzer, root: &Path) -> Self {
+        let mut files = BTreeMap::new();
+        for file in analyzer.rust_files(root) {
+            match analyzer.analyze_file(&file) {
+                Ok(violations) => {
+                    files.insert(file, violations);
+                }
+                Err(e) => eprintln!("Warning: Failed to analyze {}: {}", file.display(), e),
+            }
+        }
+        Self {
+            root: root.to_path_buf(),
+            files,
+        }
+    }
+
+    /// Re-analyze one changed path, or forget it if it no longer exists
+    ///
+    /// Returns `false` for paths that are not analyzed Rust files under the
+    /// watched root (e.g. `target/` output or editor swap files).
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or parsed; the previous
+    /// results for it are kept.
+    pub fn update(&mut self, analyzer: &Analyzer, path: &Path) -> Result<bool> {
+        let watched = if self.root.is_dir() {
+            analyzer.is_rust_file_under(&self.root, path)
+        } else {
+            path == self.root
+        };
+        if !watched {
+            return Ok(false);
+        }
+
+        if path.is_file() {
+            let violations = analyzer.analyze_file(path)?;
+            self.files.insert(path.to_path_buf(), violations);
+        } else {
+            self.files.remove(path);
+        }
+        Ok(true)
+    }
+
+    /// Current violations of one file (empty if it is clean or unknown)
+    pub fn file_violations(&self, path: &Path) -> &[Violation] {
+        self.files.get(path).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Current violations of all files, in file order
+    pub fn violations(&self) -> impl Iterator<Item = &Violation> {
+        self.files.values().flatten()
+    }
+
+    /// Number of analyzed files
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// One-line totals, e.g. `5 violation(s) in 2 of 14 file(s): 1 high, 3 medium, 1 low`
+    pub fn summary(&self) -> String {
+        let count = |severity: Severity| self.violations().filter(|v| v.severity == severity).count();
+        let dirty = self.files.values().filter(|violations| !violations.is_empty()).count();
+        format!(
+            "{} violation(s) in {} of {} file(s): {} high, {} medium, {} low",
+            self.violations().count(),
+            dirty,
+            self.file_count(),
+            count(Severity::High),
+            count(Severity::Medium),
+            count(Severity::Low),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_reanalyzes_only_changed_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let lib = dir.path().join("lib.rs");
+        let util = dir.path().join("util.rs");
+        std::fs::write(&lib, "fn start_server() { panic!(\"a\"); }\n").unwrap();
+        std::fs::write(&util, "fn stop_server() {}\n").unwrap();
+
+        let analyzer = Analyzer::with_defaults().unwrap();
+        let mut state = WatchState::new(&analyzer, dir.path());
+        assert_eq!(state.file_count(), 2);
+        assert!(state.file_violations(&lib).iter().any(|v| v.code == "E1001"));
+        assert!(state.file_violations(&util).is_empty());
+        assert!(state.summary().contains(" in 1 of 2 file(s)"), "{}", state.summary());
+
+        std::fs::write(&util, "fn stop_server() { panic!(\"b\"); }\n").unwrap();
+        assert!(state.update(&analyzer, &util).unwrap());
+        assert!(state.file_violations(&util).iter().any(|v| v.code == "E1001"));
+        assert!(state.summary().contains(" in 2 of 2 file(s)"), "{}", state.summary());
+
+        std::fs::remove_file(&lib).unwrap();
+        assert!(state.update(&analyzer, &lib).unwrap());
+        assert_eq!(state.file_count(), 1);
+        assert!(state.violations().all(|v| v.file_path.ends_with("util.rs")));
+    }
+
+    #[test]
+    fn test_update_ignores_unwatched_paths() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("target")).unwrap();
+        let output = dir.path().join("target/out.rs");
+        let swap = dir.path().join("lib.rs.swp");
+        std::fs::write(&output, "fn start_server() { panic!(\"a\"); }\n").unwrap();
+        std::fs::write(&swap, "").unwrap();
+
+        let analyzer = Analyzer::with_defaults().unwrap();
+        let mut state = WatchState::new(&analyzer, dir.path());
+        assert!(!state.update(&analyzer, &output).unwrap());
+        assert!(!state.update(&analyzer, &swap).unwrap());
+        assert_eq!(state.file_count(), 0);
+    }
+}
@@ -34,6 +34,12 @@ use problem_examples::e11_code_surface_complexity::e1109_excessive_chaining::e11
 use problem_examples::e11_code_surface_complexity::e1110_nested_callbacks::e1110_entry;
 use problem_examples::e11_code_surface_complexity::e1111_excessive_tuple_complexity::e1111_entry;
 use problem_examples::e11_code_surface_complexity::e1112_magic_numbers::e1112_entry;
+use problem_examples::e11_code_surface_complexity::e1114_todo_comment::e1114_entry;
+use problem_examples::e11_code_surface_complexity::e1115_large_return_value::e1115_entry;
+use problem_examples::e11_code_surface_complexity::e1117_excessive_generics::e1117_entry;
+use problem_examples::e11_code_surface_complexity::e1118_complex_boolean_condition::e1118_entry;
+use problem_examples::e11_code_surface_complexity::e1119_wildcard_match_arm::e1119_entry;
+use problem_examples::e11_code_surface_complexity::e1120_excessive_early_returns::e1120_entry;
 use problem_examples::e12_code_pattern_complexity::e1201_complex_generics::e1201_entry;
 use problem_examples::e12_code_pattern_complexity::e1202_complex_lifetimes::e1202_entry;
 use problem_examples::e12_code_pattern_complexity::e1203_complicated_borrowing::e1203_entry;
@@ -51,6 +57,10 @@ use problem_examples::e12_code_pattern_complexity::e1214_macro_generated::e1214_
 use problem_examples::e12_code_pattern_complexity::e1215_type_level_programming::e1215_entry;
 use problem_examples::e12_code_pattern_complexity::e1216_chained_transform::e1216_entry;
 use problem_examples::e12_code_pattern_complexity::e1217_abba_deadlock::e1217_entry;
+use problem_examples::e12_code_pattern_complexity::e1218_excessive_supertraits::e1218_entry;
+use problem_examples::e12_code_pattern_complexity::e1219_dyn_box_in_loop::e1219_entry;
+use problem_examples::e12_code_pattern_complexity::e1247_direct_recursive_field::e1247_entry;
+use problem_examples::e12_code_pattern_complexity::e1249_phantom_only_struct::e1249_entry;
 use problem_examples::e13_error_handling::e1301_unhandled_result::e1301_entry;
 use problem_examples::e13_error_handling::e1302_constructor_without_result::e1302_entry;
 use problem_examples::e13_error_handling::e1303_ignored_errors::e1303_entry;
@@ -61,6 +71,15 @@ use problem_examples::e13_error_handling::e1307_string_errors::e1307_entry;
 use problem_examples::e13_error_handling::e1308_not_using_question_mark::e1308_entry;
 use problem_examples::e13_error_handling::e1309_panic_in_drop::e1309_entry;
 use problem_examples::e13_error_handling::e1310_error_context_loss::e1310_entry;
+use problem_examples::e13_error_handling::e1311_box_dyn_error_in_public_api::e1311_entry;
+use problem_examples::e13_error_handling::e1312_option_question_mark::e1312_entry;
+use problem_examples::e13_error_handling::e1319_empty_expect_message::e1319_entry;
+use problem_examples::e13_error_handling::e1320_error_type_missing_error_impl::e1320_entry;
+use problem_examples::e13_error_handling::e1321_env_var_unwrap::e1321_entry;
+use problem_examples::e13_error_handling::e1322_returned_guard::e1322_entry;
+use problem_examples::e13_error_handling::e1323_question_mark_in_unit_main::e1323_entry;
+use problem_examples::e13_error_handling::e1324_box_dyn_in_error_enum::e1324_entry;
+use problem_examples::e13_error_handling::e1326_ignored_result_in_loop::e1326_entry;
 use problem_examples::e14_type_safety::e1401_integer_overflow::e1401_entry;
 use problem_examples::e14_type_safety::e1402_division_by_zero::e1402_entry;
 use problem_examples::e14_type_safety::e1403_modulo_by_zero::e1403_entry;
@@ -73,6 +92,11 @@ use problem_examples::e14_type_safety::e1409_partial_initialization::e1409_entry
 use problem_examples::e14_type_safety::e1410_float_equality::e1410_entry;
 use problem_examples::e14_type_safety::e1411_type_confusion_transmute::e1411_entry;
 use problem_examples::e14_type_safety::e1412_prohibit_unions::e1412_entry;
+use problem_examples::e14_type_safety::e1414_sign_changing_cast::e1414_entry;
+use problem_examples::e14_type_safety::e1416_unchecked_shift::e1416_entry;
+use problem_examples::e14_type_safety::e1417_float_ordering::e1417_entry;
+use problem_examples::e14_type_safety::e1497_len_cast_truncation::e1497_entry;
+use problem_examples::e14_type_safety::e1499_derive_clone_shared_state::e1499_entry;
 use problem_examples::e15_concurrency::e1501_non_send_across_threads::e1501_entry;
 use problem_examples::e15_concurrency::e1502_lock_across_await::e1502_entry;
 use problem_examples::e15_concurrency::e1503_lock_poisoning::e1503_entry;
@@ -84,6 +108,11 @@ use problem_examples::e15_concurrency::e1508_sleep_instead_of_sync::e1508_entry;
 use problem_examples::e15_concurrency::e1509_channel_lifetime::e1509_entry;
 use problem_examples::e15_concurrency::e1510_mutex_instead_of_rwlock::e1510_entry;
 use problem_examples::e15_concurrency::e1511_unbounded_spawning::e1511_entry;
+use problem_examples::e15_concurrency::e1514_non_send_spawn_capture::e1514_entry;
+use problem_examples::e15_concurrency::e1515_arc_ref_cell::e1515_entry;
+use problem_examples::e15_concurrency::e1519_unbounded_accept_loop::e1519_entry;
+use problem_examples::e15_concurrency::e1520_unbounded_channel::e1520_entry;
+use problem_examples::e15_concurrency::e1521_arc_clone_in_loop::e1521_entry;
 use problem_examples::e16_memory_safety::e1601_aliasing_violation::e1601_entry;
 use problem_examples::e16_memory_safety::e1602_use_after_free::e1602_entry;
 use problem_examples::e16_memory_safety::e1603_dangling_reference::e1603_entry;
@@ -95,6 +124,7 @@ use problem_examples::e16_memory_safety::e1608_double_free::e1608_entry;
 use problem_examples::e16_memory_safety::e1609_invalid_slice::e1609_entry;
 use problem_examples::e16_memory_safety::e1610_unaligned_deref::e1610_entry;
 use problem_examples::e16_memory_safety::e1611_consuming_self::e1611_entry;
+use problem_examples::e16_memory_safety::e1613_temp_borrow_return::e1613_entry;
 use problem_examples::e17_performance::e1701_oversized_struct::e1701_entry;
 use problem_examples::e17_performance::e1702_unnecessary_allocations::e1702_entry;
 use problem_examples::e17_performance::e1703_string_concat_loop::e1703_entry;
@@ -106,6 +136,18 @@ use problem_examples::e17_performance::e1708_inefficient_data_structure::e1708_e
 use problem_examples::e17_performance::e1709_unnecessary_boxing::e1709_entry;
 use problem_examples::e17_performance::e1710_large_stack_allocation::e1710_entry;
 use problem_examples::e17_performance::e1712_expensive_ops_in_loop::e1712_entry;
+use problem_examples::e17_performance::e1713_format_in_push_str::e1713_entry;
+use problem_examples::e17_performance::e1718_vec_front_ops::e1718_entry;
+use problem_examples::e17_performance::e1719_mutual_recursion::e1719_entry;
+use problem_examples::e17_performance::e1722_unnecessary_to_vec::e1722_entry;
+use problem_examples::e17_performance::e1723_hash_map_order_reliance::e1723_entry;
+use problem_examples::e17_performance::e1724_len_zero_comparison::e1724_entry;
+use problem_examples::e17_performance::e1725_clone_then_consume::e1725_entry;
+use problem_examples::e17_performance::e1726_needless_return::e1726_entry;
+use problem_examples::e17_performance::e1727_eager_unwrap_or::e1727_entry;
+use problem_examples::e17_performance::e1730_collect_string_in_loop::e1730_entry;
+use problem_examples::e17_performance::e1732_len_chars_confusion::e1732_entry;
+use problem_examples::e17_performance::e1733_repeated_map_lookup::e1733_entry;
 use problem_examples::e18_api_design::e1801_glob_imports::e1801_entry;
 use problem_examples::e18_api_design::e1802_public_fields::e1802_entry;
 use problem_examples::e18_api_design::e1803_bad_naming::e1803_entry;
@@ -117,6 +159,11 @@ use problem_examples::e18_api_design::e1808_mutable_getter::e1808_entry;
 use problem_examples::e18_api_design::e1809_fallible_new::e1809_entry;
 use problem_examples::e18_api_design::e1810_string_instead_of_str::e1810_entry;
 use problem_examples::e18_api_design::e1812_non_exhaustive_enum::e1812_entry;
+use problem_examples::e18_api_design::e1815_missing_must_use::e1815_entry;
+use problem_examples::e18_api_design::e1816_impl_trait_public_return::e1816_entry;
+use problem_examples::e18_api_design::e1817_public_collection_field::e1817_entry;
+use problem_examples::e18_api_design::e1818_public_reexport_of_internal::e1818_entry;
+use problem_examples::e18_api_design::e1820_vec_param_instead_of_slice::e1820_entry;
 use problem_examples::e19_hygiene::e1901_critical_lint_override::e1901_entry;
 use problem_examples::e19_hygiene::e1902_medium_lint_override::e1902_entry;
 use problem_examples::e19_hygiene::e1903_minor_lint_override::e1903_entry;
@@ -233,6 +280,12 @@ fn show_category(category: &str) {
             println!("E1110 - Nested callbacks/closures");
             println!("E1111 - Excessive tuple complexity");
             println!("E1112 - Hardcoded magic numbers");
+            println!("E1114 - Tech-debt marker in comment");
+            println!("E1115 - Large value returned by value");
+            println!("E1117 - Public function with too many generic parameters");
+            println!("E1118 - Complex boolean condition");
+            println!("E1119 - Catch-all match arm hides new enum variants");
+            println!("E1120 - Excessive early returns");
         }
         "e12" => {
             println!("E12* - Code Pattern Complexity Problems\n");
@@ -253,6 +306,10 @@ fn show_category(category: &str) {
             println!("E1215 - Type-level programming");
             println!("E1216 - Chained transformations");
             println!("E1217 - ABBA deadlock pattern");
+            println!("E1218 - Trait with too many supertraits");
+            println!("E1219 - Box<dyn Trait> constructed inside a loop");
+            println!("E1247 - Recursive field without indirection");
+            println!("E1249 - Struct made only of PhantomData fields");
         }
         "e13" => {
             println!("E13* - Error Handling Problems\n");
@@ -266,6 +323,15 @@ fn show_category(category: &str) {
             println!("E1308 - Not using ? operator when appropriate");
             println!("E1309 - Panic in Drop implementation");
             println!("E1310 - Error context loss");
+            println!("E1311 - E1311 - Box<dyn Error> returned from public API");
+            println!("E1312 - `?` on an Option in a Result-returning function");
+            println!("E1319 - expect() with an empty message");
+            println!("E1320 - Error type without std::error::Error implementation");
+            println!("E1321 - Environment variable read with unwrap()");
+            println!("E1322 - Lock guard returned from function");
+            println!("E1323 - main() returning () with unwrap/expect calls");
+            println!("E1324 - Type-erased variant in typed error enum");
+            println!("E1326 - E1326 - Result ignored inside a loop");
         }
         "e14" => {
             println!("E14* - Type Safety Problems\n");
@@ -281,6 +347,11 @@ fn show_category(category: &str) {
             println!("E1410 - Float equality comparison with ==");
             println!("E1411 - Type confusion with transmute");
             println!("E1412 - Union types prohibited");
+            println!("E1414 - Sign-changing as cast");
+            println!("E1416 - Shift that may overflow the type width");
+            println!("E1417 - Float ordering comparison without NaN handling");
+            println!("E1497 - Collection length cast to narrower integer");
+            println!("E1499 - Derived Clone shares mutable state");
         }
         "e15" => {
             println!("E15* - Concurrency Problems\n");
@@ -295,6 +366,11 @@ fn show_category(category: &str) {
             println!("E1509 - Channel sender/receiver lifetime issues");
             println!("E1510 - Arc<Mutex<T>> instead of RwLock");
             println!("E1511 - Unbounded task/thread spawning in loops");
+            println!("E1514 - !Send value captured by spawned task");
+            println!("E1515 - RefCell or Cell shared through Arc");
+            println!("E1519 - Unbounded spawning in accept/receive loop");
+            println!("E1520 - Unbounded channel created");
+            println!("E1521 - Arc cloned in loop without being moved");
         }
         "e16" => {
             println!("E16* - Memory Safety Problems\n");
@@ -309,6 +385,7 @@ fn show_category(category: &str) {
             println!("E1609 - Slice from raw parts with invalid length");
             println!("E1610 - Unaligned pointer dereference");
             println!("E1611 - Method consumes self unnecessarily");
+            println!("E1613 - Reference to a temporary returned from a function");
         }
         "e17" => {
             println!("E17* - Performance Problems\n");
@@ -323,6 +400,18 @@ fn show_category(category: &str) {
             println!("E1709 - Unnecessary boxing");
             println!("E1710 - Large stack allocation");
             println!("E1712 - Expensive operations inside loops");
+            println!("E1713 - format! appended to a String");
+            println!("E1718 - Removing or inserting at the front of a Vec");
+            println!("E1719 - Mutual recursion between functions");
+            println!("E1722 - Unnecessary copy before iterating");
+            println!("E1723 - Relying on HashMap iteration order");
+            println!("E1724 - Comparing .len() to zero");
+            println!("E1725 - Clone immediately consumed");
+            println!("E1726 - Needless return in tail position");
+            println!("E1727 - Call evaluated eagerly in unwrap_or");
+            println!("E1730 - collect() into String inside loop");
+            println!("E1732 - chars().count() compared against byte capacity");
+            println!("E1733 - Repeated map lookup with the same key");
         }
         "e18" => {
             println!("E18* - API Design Problems\n");
@@ -337,6 +426,11 @@ fn show_category(category: &str) {
             println!("E1809 - Using new() for fallible construction");
             println!("E1810 - Accepting String instead of &str");
             println!("E1812 - Public enum without #[non_exhaustive]");
+            println!("E1815 - Builder method without #[must_use]");
+            println!("E1816 - impl Trait returned from a public function");
+            println!("E1817 - Public collection field");
+            println!("E1818 - Public re-export of internal item");
+            println!("E1820 - Vec parameter instead of slice");
         }
         "e19" => {
             println!("E19* - Code Hygiene Problems\n");
@@ -396,6 +490,12 @@ fn run_problem(problem: &str) {
             "E1110" => Some(e1110_entry()),
             "E1111" => Some(e1111_entry()),
             "E1112" => Some(e1112_entry()),
+            "E1114" => Some(e1114_entry()),
+            "E1115" => Some(e1115_entry()),
+            "E1117" => Some(e1117_entry()),
+            "E1118" => Some(e1118_entry()),
+            "E1119" => Some(e1119_entry()),
+            "E1120" => Some(e1120_entry()),
 
             // E12: Code Pattern Complexity
             "E1201" => Some(e1201_entry()),
@@ -415,6 +515,10 @@ fn run_problem(problem: &str) {
             "E1215" => Some(e1215_entry()),
             "E1216" => Some(e1216_entry()),
             "E1217" => Some(e1217_entry()),
+            "E1218" => Some(e1218_entry()),
+            "E1219" => Some(e1219_entry()),
+            "E1247" => Some(e1247_entry()),
+            "E1249" => Some(e1249_entry()),
 
             // E13: Error Handling
             "E1301" => Some(e1301_entry()),
@@ -427,6 +531,15 @@ fn run_problem(problem: &str) {
             "E1308" => Some(e1308_entry()),
             "E1309" => Some(e1309_entry()),
             "E1310" => Some(e1310_entry()),
+            "E1311" => Some(e1311_entry()),
+            "E1312" => Some(e1312_entry()),
+            "E1319" => Some(e1319_entry()),
+            "E1320" => Some(e1320_entry()),
+            "E1321" => Some(e1321_entry()),
+            "E1322" => Some(e1322_entry()),
+            "E1323" => Some(e1323_entry()),
+            "E1324" => Some(e1324_entry()),
+            "E1326" => Some(e1326_entry()),
 
             // E14: Type Safety
             "E1401" => Some(e1401_entry()),
@@ -441,6 +554,11 @@ fn run_problem(problem: &str) {
             "E1410" => Some(e1410_entry()),
             "E1411" => Some(e1411_entry()),
             "E1412" => Some(e1412_entry()),
+            "E1414" => Some(e1414_entry()),
+            "E1416" => Some(e1416_entry()),
+            "E1417" => Some(e1417_entry()),
+            "E1497" => Some(e1497_entry()),
+            "E1499" => Some(e1499_entry()),
 
             // E15: Concurrency
             "E1501" => Some(e1501_entry()),
@@ -454,6 +572,11 @@ fn run_problem(problem: &str) {
             "E1509" => Some(e1509_entry()),
             "E1510" => Some(e1510_entry()),
             "E1511" => Some(e1511_entry()),
+            "E1514" => Some(e1514_entry()),
+            "E1515" => Some(e1515_entry()),
+            "E1519" => Some(e1519_entry()),
+            "E1520" => Some(e1520_entry()),
+            "E1521" => Some(e1521_entry()),
 
             // E16: Memory Safety
             "E1601" => Some(e1601_entry()),
@@ -467,6 +590,7 @@ fn run_problem(problem: &str) {
             "E1609" => Some(e1609_entry()),
             "E1610" => Some(e1610_entry()),
             "E1611" => Some(e1611_entry()),
+            "E1613" => Some(e1613_entry()),
 
             // E17: Performance
             "E1701" => Some(e1701_entry()),
@@ -480,6 +604,18 @@ fn run_problem(problem: &str) {
             "E1709" => Some(e1709_entry()),
             "E1710" => Some(e1710_entry()),
             "E1712" => Some(e1712_entry()),
+            "E1713" => Some(e1713_entry()),
+            "E1718" => Some(e1718_entry()),
+            "E1719" => Some(e1719_entry()),
+            "E1722" => Some(e1722_entry()),
+            "E1723" => Some(e1723_entry()),
+            "E1724" => Some(e1724_entry()),
+            "E1725" => Some(e1725_entry()),
+            "E1726" => Some(e1726_entry()),
+            "E1727" => Some(e1727_entry()),
+            "E1730" => Some(e1730_entry()),
+            "E1732" => Some(e1732_entry()),
+            "E1733" => Some(e1733_entry()),
 
             // E18: API Design
             "E1801" => Some(e1801_entry()),
@@ -493,6 +629,11 @@ fn run_problem(problem: &str) {
             "E1809" => Some(e1809_entry()),
             "E1810" => Some(e1810_entry()),
             "E1812" => Some(e1812_entry()),
+            "E1815" => Some(e1815_entry()),
+            "E1816" => Some(e1816_entry()),
+            "E1817" => Some(e1817_entry()),
+            "E1818" => Some(e1818_entry()),
+            "E1820" => Some(e1820_entry()),
 
             // E19: Code Hygiene
             "E1901" => Some(e1901_entry()),
@@ -520,14 +661,14 @@ fn run_problem(problem: &str) {
             eprintln!("\n✗ Unknown problem code: {}", problem);
             eprintln!("\nAvailable problems:");
             eprintln!("  E10* - Unsafe Code: e1001-e1017");
-            eprintln!("  E11* - Code Complexity: e1101-e1112");
-            eprintln!("  E12* - Pattern Complexity: e1201-e1217");
-            eprintln!("  E13* - Error Handling: e1301-e1310");
-            eprintln!("  E14* - Type Safety: e1401-e1412");
-            eprintln!("  E15* - Concurrency: e1501-e1511");
-            eprintln!("  E16* - Memory Safety: e1601-e1611");
-            eprintln!("  E17* - Performance: e1701-e1712");
-            eprintln!("  E18* - API Design: e1801-e1812");
+            eprintln!("  E11* - Code Complexity: e1101-e1119");
+            eprintln!("  E12* - Pattern Complexity: e1201-e1249");
+            eprintln!("  E13* - Error Handling: e1301-e1326");
+            eprintln!("  E14* - Type Safety: e1401-e1499");
+            eprintln!("  E15* - Concurrency: e1501-e1521");
+            eprintln!("  E16* - Memory Safety: e1601-e1613");
+            eprintln!("  E17* - Performance: e1701-e1733");
+            eprintln!("  E18* - API Design: e1801-e1817");
             eprintln!("\nUse 'hyp-examples show <category>' for details");
             std::process::exit(1);
         }
@@ -570,6 +711,12 @@ macro_rules! define_problems {
             ("E1110", "Nested callbacks", e1110_entry),
             ("E1111", "Excessive tuple complexity", e1111_entry),
             ("E1112", "Hardcoded magic numbers", e1112_entry),
+            ("E1114", "TODO comment", e1114_entry),
+            ("E1115", "Large return value", e1115_entry),
+            ("E1117", "Excessive generics", e1117_entry),
+            ("E1118", "Complex boolean condition", e1118_entry),
+            ("E1119", "Wildcard match arm", e1119_entry),
+            ("E1120", "Excessive early returns", e1120_entry),
 
             // E12: Code Pattern Complexity
             ("E1201", "Complex generics", e1201_entry),
@@ -589,6 +736,10 @@ macro_rules! define_problems {
             ("E1215", "Type level programming", e1215_entry),
             ("E1216", "Chained transform", e1216_entry),
             ("E1217", "ABBA deadlock", e1217_entry),
+            ("E1218", "Excessive supertraits", e1218_entry),
+            ("E1219", "Box<dyn> in loop", e1219_entry),
+            ("E1247", "Direct recursive field", e1247_entry),
+            ("E1249", "PhantomData-only struct", e1249_entry),
 
             // E13: Error Handling
             ("E1301", "Unhandled result", e1301_entry),
@@ -601,6 +752,15 @@ macro_rules! define_problems {
             ("E1308", "Not using question mark", e1308_entry),
             ("E1309", "Panic in drop", e1309_entry),
             ("E1310", "Error context loss", e1310_entry),
+            ("E1311", "E1311 - Box<dyn Error> returned from public API", e1311_entry),
+            ("E1312", "Option ? in Result fn", e1312_entry),
+            ("E1319", "Empty expect message", e1319_entry),
+            ("E1320", "Error type without Error impl", e1320_entry),
+            ("E1321", "Env var unwrap", e1321_entry),
+            ("E1322", "Returned lock guard", e1322_entry),
+            ("E1323", "E1323 - main() returning () with unwrap/expect calls", e1323_entry),
+            ("E1324", "E1324 - Type-erased variant in typed error enum", e1324_entry),
+            ("E1326", "E1326 - Result ignored inside a loop", e1326_entry),
 
             // E14: Type Safety
             ("E1401", "Integer overflow", e1401_entry),
@@ -615,6 +775,11 @@ macro_rules! define_problems {
             ("E1410", "Float equality", e1410_entry),
             ("E1411", "Type confusion transmute", e1411_entry),
             ("E1412", "Union types prohibited", e1412_entry),
+            ("E1414", "Sign changing cast", e1414_entry),
+            ("E1416", "Unchecked shift", e1416_entry),
+            ("E1417", "Float ordering", e1417_entry),
+            ("E1497", "Len cast truncation", e1497_entry),
+            ("E1499", "Derive Clone shared state", e1499_entry),
 
             // E15: Concurrency
             ("E1501", "Non-Send across threads", e1501_entry),
@@ -628,6 +793,11 @@ macro_rules! define_problems {
             ("E1509", "Channel lifetime", e1509_entry),
             ("E1510", "Mutex instead of RwLock", e1510_entry),
             ("E1511", "Unbounded spawning", e1511_entry),
+            ("E1514", "E1514 - !Send value captured by spawned task", e1514_entry),
+            ("E1515", "RefCell or Cell shared through Arc", e1515_entry),
+            ("E1519", "Unbounded accept loop", e1519_entry),
+            ("E1520", "Unbounded channel", e1520_entry),
+            ("E1521", "Arc clone in loop", e1521_entry),
 
             // E16: Memory Safety
             ("E1601", "Aliasing violations", e1601_entry),
@@ -641,6 +811,7 @@ macro_rules! define_problems {
             ("E1609", "Invalid slice", e1609_entry),
             ("E1610", "Unaligned deref", e1610_entry),
             ("E1611", "Consuming self unnecessarily", e1611_entry),
+            ("E1613", "Temporary borrow return", e1613_entry),
 
             // E17: Performance
             ("E1701", "Oversized struct", e1701_entry),
@@ -654,6 +825,18 @@ macro_rules! define_problems {
             ("E1709", "Unnecessary boxing", e1709_entry),
             ("E1710", "Large stack allocation", e1710_entry),
             ("E1712", "Expensive ops in loop", e1712_entry),
+            ("E1713", "Format in push_str", e1713_entry),
+            ("E1718", "Vec front operations", e1718_entry),
+            ("E1719", "Mutual recursion", e1719_entry),
+            ("E1722", "Unnecessary copy before iterating", e1722_entry),
+            ("E1723", "Relying on HashMap iteration order", e1723_entry),
+            ("E1724", "Comparing .len() to zero", e1724_entry),
+            ("E1725", "Clone immediately consumed", e1725_entry),
+            ("E1726", "Needless tail return", e1726_entry),
+            ("E1727", "Eager unwrap_or argument", e1727_entry),
+            ("E1730", "Collect String in loop", e1730_entry),
+            ("E1732", "E1732 - chars().count() compared against byte capacity", e1732_entry),
+            ("E1733", "E1733 - Repeated map lookup with the same key", e1733_entry),
 
             // E18: API Design
             ("E1801", "Glob imports", e1801_entry),
@@ -667,6 +850,11 @@ macro_rules! define_problems {
             ("E1809", "Fallible new", e1809_entry),
             ("E1810", "String instead of &str", e1810_entry),
             ("E1812", "Non-exhaustive enum", e1812_entry),
+            ("E1815", "Missing must_use", e1815_entry),
+            ("E1816", "impl Trait public return", e1816_entry),
+            ("E1817", "Public collection field", e1817_entry),
+            ("E1818", "Re-export of internal item", e1818_entry),
+            ("E1820", "Vec param instead of slice", e1820_entry),
 
             // E19: Code Hygiene
             ("E1901", "Critical lint overrides", e1901_entry),
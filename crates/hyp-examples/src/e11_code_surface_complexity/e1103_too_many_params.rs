@@ -63,11 +63,13 @@ impl ComputationBuilder {
         }
     }
 
+    #[must_use]
     pub fn e1103_good_a(mut self, value: i32) -> Self {
         self.params.a = value;
         self
     }
 
+    #[must_use]
     pub fn e1103_good_b(mut self, value: i32) -> Self {
         self.params.b = value;
         self
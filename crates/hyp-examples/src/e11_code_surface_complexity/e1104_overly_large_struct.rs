@@ -173,11 +173,13 @@ impl GoodConfigBuilder {
         }
     }
 
+    #[must_use]
     pub fn e1104_good_with_host(mut self, host: &str) -> Self {
         self.config.network.host = host.to_string();
         self
     }
 
+    #[must_use]
     pub fn e1104_good_with_tls(mut self, cert: &str, key: &str) -> Self {
         self.config.tls.enabled = true;
         self.config.tls.cert_path = cert.to_string();
@@ -78,11 +78,13 @@ impl FlagsBuilder {
         Self { flags: FeatureFlags::default() }
     }
 
+    #[must_use]
     pub fn e1105_good_enable_feature_a(mut self) -> Self {
         self.flags.feature_a = true;
         self
     }
 
+    #[must_use]
     pub fn e1105_good_enable_feature_b(mut self) -> Self {
         self.flags.feature_b = true;
         self
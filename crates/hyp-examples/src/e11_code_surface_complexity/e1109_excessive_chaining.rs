@@ -115,12 +115,14 @@ impl DataPipeline {
         Self { data }
     }
 
+    #[must_use]
     pub fn e1109_good_filter_positive(self) -> Self {
         Self {
             data: self.data.into_iter().filter(|x| *x > 0).collect()
         }
     }
 
+    #[must_use]
     pub fn e1109_good_double_stage(self) -> Self {
         Self {
             data: self.data.into_iter().map(|x| x * 2).collect()
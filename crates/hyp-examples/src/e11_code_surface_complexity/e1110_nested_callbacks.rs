@@ -86,9 +86,16 @@ pub fn e1110_bad_callback_hell() {
     let _result = process(vec![5, 10, 15, 20]);
 }
 
+/// PROBLEM E1110: Closures returning closures five levels deep (a curried chain)
+pub fn e1110_bad_curried_chain() -> i32 {
+    let add = |a: i32| move |b: i32| move |c: i32| move |d: i32| move |e: i32| a + b + c + d + e;
+    add(1)(2)(3)(4)(5)
+}
+
 pub fn e1110_entry() -> Result<(), Box<dyn std::error::Error>> {
     e1110_bad_nested_callbacks();
     e1110_bad_callback_hell();
+    let _ = e1110_bad_curried_chain();
     Ok(())
 }
 
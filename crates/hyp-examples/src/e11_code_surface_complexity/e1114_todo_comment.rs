@@ -0,0 +1,89 @@
+/// E1114: Tech-debt marker in comment
+/// Severity: LOW
+/// LLM confusion: 1 (LOWEST)
+///
+/// Description: `TODO`, `FIXME`, `XXX` and `HACK` comments record work that was deferred. A few
+/// are harmless; hundreds of them, with no owner and no ticket, are tech debt nobody tracks.
+/// LLM-generated code is especially prone to leaving `// TODO: handle errors` behind. E1114
+/// reports each untracked marker so the debt is visible in review.
+///
+/// ## The Untracked Debt Problem
+///
+/// ```text
+/// fn load() -> Config {
+///     // TODO: handle missing file
+///     // HACK: hardcoded path until config lands
+///     ...
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// Fix the issue, or reference a tracker entry so the marker has an owner:
+/// ```text
+/// // TODO(#1234): handle missing file
+/// ```
+///
+/// Mitigation: Configure `max_markers_per_file` to tolerate a small budget, and keep
+/// `allow_referenced = true` so `TODO(#issue)` markers are accepted.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1114: Deferred error handling left as a bare TODO
+pub fn e1114_bad_todo(input: &str) -> usize {
+    // TODO: handle empty input
+    input.len()
+}
+
+/// PROBLEM E1114: Workaround marked as HACK with no follow-up
+pub fn e1114_bad_hack(values: &[i32]) -> i32 {
+    /* HACK: assumes the list is sorted */
+    values.first().copied().unwrap_or_default()
+}
+
+/// Entry point for problem demonstration
+pub fn e1114_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = e1114_bad_todo("abc");
+    let _ = e1114_bad_hack(&[3, 1, 2]);
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: The case is handled instead of deferred
+pub fn e1114_good_handled(input: &str) -> Option<usize> {
+    if input.is_empty() {
+        return None;
+    }
+    Some(input.len())
+}
+
+/// GOOD: Deferred work references a tracker entry
+pub fn e1114_good_tracked(values: &[i32]) -> Option<i32> {
+    // TODO(#1234): switch to a streaming minimum once the API is stable
+    values.iter().copied().min()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handled() {
+        assert_eq!(e1114_good_handled(""), None);
+        assert_eq!(e1114_good_handled("ab"), Some(2));
+    }
+
+    #[test]
+    fn test_tracked() {
+        assert_eq!(e1114_good_tracked(&[3, 1, 2]), Some(1));
+    }
+}
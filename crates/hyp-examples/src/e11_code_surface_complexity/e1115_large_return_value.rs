@@ -0,0 +1,98 @@
+/// E1115: Large value returned by value
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: Returning a type by value moves all of its bytes. For a few words that is free,
+/// but a function returning a 4 KiB array or a struct full of buffers copies that block on every
+/// call, and the temporary lives on the stack of a thread that may have very little of it.
+/// The size is estimated from the type's shape (arrays, tuples, local structs and enums), so
+/// the check is a hint rather than an exact layout computation.
+///
+/// ## The Silent Copy Problem
+///
+/// ```text
+/// struct Frame { pixels: [u32; 1024] }   // 4 KiB
+///
+/// fn blank_frame() -> Frame {             // 4 KiB moved out of every call
+///     Frame { pixels: [0; 1024] }
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// struct Frame { pixels: [u32; 1024] }
+///
+/// fn blank_frame() -> Box<Frame> {
+///     Box::new(Frame { pixels: [0; 1024] })
+/// }
+/// ```
+///
+/// Mitigation: Return `Box<T>`, fill a caller-provided `&mut T`, or use a heap-backed
+/// collection. List types that are deliberately returned by value in `allowed_types`.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// Pixels in a small frame buffer
+pub const E1115_PIXELS: usize = 1024;
+
+/// A frame buffer held inline (4 KiB)
+pub struct E1115Frame {
+    pub pixels: [u32; E1115_PIXELS],
+}
+
+/// PROBLEM E1115: The whole 4 KiB frame is moved out of the function
+pub fn e1115_bad_blank_frame() -> E1115Frame {
+    E1115Frame { pixels: [0; E1115_PIXELS] }
+}
+
+/// PROBLEM E1115: A large array returned by value
+pub fn e1115_bad_blank_row() -> [u64; 256] {
+    [0; 256]
+}
+
+/// Entry point for problem demonstration
+pub fn e1115_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let frame = e1115_bad_blank_frame();
+    let row = e1115_bad_blank_row();
+    println!("{} {}", frame.pixels.len(), row.len());
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Only the box pointer is returned
+pub fn e1115_good_blank_frame() -> Box<E1115Frame> {
+    Box::new(E1115Frame { pixels: [0; E1115_PIXELS] })
+}
+
+/// GOOD: The caller owns the row and it is cleared in place
+pub fn e1115_good_clear_row(row: &mut [u64]) {
+    row.fill(0);
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blank_frame() {
+        let frame = e1115_good_blank_frame();
+        assert!(frame.pixels.iter().all(|pixel| *pixel == 0));
+    }
+
+    #[test]
+    fn test_clear_row() {
+        let mut row = [7u64; 4];
+        e1115_good_clear_row(&mut row);
+        assert_eq!(row, [0; 4]);
+    }
+}
@@ -0,0 +1,81 @@
+/// E1117: Public function with too many generic parameters
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: Each generic parameter on a public function is part of its API. With five of
+/// them, callers end up writing `merge::<_, _, _, _, MyError>(...)` to pin down the one type
+/// inference could not work out, error messages list every parameter, and documentation is
+/// hard to scan. Parameters that always change together usually belong behind one trait.
+///
+/// ## The Turbofish Wall Problem
+///
+/// ```text
+/// pub fn merge<K, V, S, F, E>(a: Map<K, V, S>, b: Map<K, V, S>, f: F) -> Result<Map<K, V, S>, E>
+/// // caller: merge::<_, _, _, _, ParseError>(a, b, resolve)
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// pub trait Table {
+///     type Key;
+///     type Value;
+/// }
+///
+/// pub fn merge<T: Table, F>(a: T, b: T, resolve: F) -> T
+/// where
+///     F: Fn(T, T) -> T,
+/// {
+///     resolve(a, b)
+/// }
+/// ```
+///
+/// Mitigation: Group type parameters that travel together into a trait with associated
+/// types, take `impl Trait` arguments, or use concrete types where callers never vary them.
+
+use std::fmt::Display;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1117: Five type parameters on a public function
+pub fn e1117_bad_describe<A, B, C, D, E>(a: A, b: B, c: C, d: D, e: E) -> String
+where
+    A: Display,
+    B: Display,
+    C: Display,
+    D: Display,
+    E: Display,
+{
+    format!("{} {} {} {} {}", a, b, c, d, e)
+}
+
+/// Entry point for problem demonstration
+pub fn e1117_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1117_bad_describe(1, 'b', "c", 4.0, true));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: One type parameter covers every value
+pub fn e1117_good_describe<T: Display>(values: &[T]) -> String {
+    values.iter().map(ToString::to_string).collect::<Vec<_>>().join(" ")
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(e1117_good_describe(&["a", "b"]), "a b");
+    }
+}
@@ -0,0 +1,86 @@
+/// E1118: Complex boolean condition
+/// Severity: LOW
+/// LLM confusion: 1 (LOWEST)
+///
+/// Description: A condition such as `a && !b || c && !d || e` has to be decoded before it can
+/// be checked: which operator binds tighter, what the negations cover, which combination of
+/// flags actually lets execution through. Every extra operator doubles the cases a reader (and
+/// a test suite) has to think about, and precedence mistakes between `&&` and `||` compile
+/// silently. Naming the parts turns the condition back into a sentence.
+///
+/// ## The Operator Soup Problem
+///
+/// ```text
+/// if user.active && !user.banned || user.admin && !maintenance || force {
+///     // who gets in here?
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// let (active, banned, admin, maintenance) = (true, false, false, false);
+/// let may_log_in = (active && !banned) || admin;
+/// if may_log_in && !maintenance {
+///     println!("welcome");
+/// }
+/// ```
+///
+/// Mitigation: Extract sub-conditions into well-named `let` bindings or small predicate
+/// functions so each condition has at most a few operators.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// Flags of a login attempt
+#[derive(Debug, Clone, Copy, Default)]
+pub struct E1118Login {
+    active: bool,
+    banned: bool,
+    admin: bool,
+    maintenance: bool,
+    force: bool,
+}
+
+/// PROBLEM E1118: five logical operators in one condition
+pub fn e1118_bad_may_enter(login: &E1118Login) -> bool {
+    if login.active && !login.banned || login.admin && !login.maintenance || login.force {
+        return true;
+    }
+    false
+}
+
+/// Entry point for problem demonstration
+pub fn e1118_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1118_bad_may_enter(&E1118Login::default()));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Each part of the rule has a name
+pub fn e1118_good_may_enter(login: &E1118Login) -> bool {
+    let in_good_standing = login.active && !login.banned;
+    let admin_outside_maintenance = login.admin && !login.maintenance;
+    in_good_standing || admin_outside_maintenance || login.force
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_may_enter() {
+        let admin = E1118Login { admin: true, ..E1118Login::default() };
+        assert!(e1118_good_may_enter(&admin));
+        assert!(!e1118_good_may_enter(&E1118Login { maintenance: true, ..admin }));
+        assert!(e1118_good_may_enter(&E1118Login { force: true, ..E1118Login::default() }));
+    }
+}
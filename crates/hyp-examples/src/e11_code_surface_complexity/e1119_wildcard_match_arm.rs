@@ -0,0 +1,100 @@
+/// E1119: Catch-all match arm hides new enum variants
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A `match` over a crate-local enum that ends in `_ => {}` compiles today and
+/// keeps compiling after someone adds a variant. The new variant falls into the catch-all arm
+/// and is silently ignored, instead of the compiler listing every match that has to decide
+/// what to do with it.
+///
+/// ## The Silent Variant Problem
+///
+/// ```text
+/// match event {
+///     Event::Click(pos) => click(pos),
+///     _ => {}          // a later `Event::Resize` lands here without a warning
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// enum Event { Click(u32), Key(char), Focus }
+///
+/// fn handle(event: Event) -> u32 {
+///     match event {
+///         Event::Click(pos) => pos,
+///         Event::Key(_) | Event::Focus => 0,
+///     }
+/// }
+/// ```
+///
+/// Mitigation: List the remaining variants explicitly, grouping them with `|` when they share
+/// a body, so adding a variant is a compile error at every match that must handle it.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// Input events used by the examples
+#[derive(Debug, Clone, Copy)]
+pub enum E1119Event {
+    Click(u32),
+    Key(char),
+    Focus,
+}
+
+/// PROBLEM E1119: Catch-all arm with an empty body
+pub fn e1119_bad_count_clicks(events: &[E1119Event]) -> u32 {
+    let mut clicks = 0;
+    for event in events {
+        match event {
+            E1119Event::Click(_) => clicks += 1,
+            _ => {}
+        }
+    }
+    clicks
+}
+
+/// PROBLEM E1119: Catch-all arm with a unit body
+pub fn e1119_bad_log_keys(event: E1119Event) {
+    match event {
+        E1119Event::Key(key) => println!("key: {}", key),
+        _ => (),
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1119_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let events = [E1119Event::Click(1), E1119Event::Key('a'), E1119Event::Focus];
+    println!("clicks: {}", e1119_bad_count_clicks(&events));
+    e1119_bad_log_keys(E1119Event::Key('b'));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Every variant is listed
+pub fn e1119_good_is_input(event: E1119Event) -> bool {
+    match event {
+        E1119Event::Click(_) | E1119Event::Key(_) => true,
+        E1119Event::Focus => false,
+    }
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_input() {
+        assert!(e1119_good_is_input(E1119Event::Key('x')));
+        assert!(!e1119_good_is_input(E1119Event::Focus));
+    }
+}
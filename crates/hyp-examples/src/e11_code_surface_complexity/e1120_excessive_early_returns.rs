@@ -0,0 +1,108 @@
+/// E1120: Excessive early returns
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A function with many `return` statements has many exits, and every one of them
+/// has to be found before the function's result can be reasoned about. Returns buried in loops
+/// and match arms are easy to miss, and code added at the end of the function (logging, metrics,
+/// cleanup) silently does not run for the paths that left early. A single `match` or `if`
+/// expression that evaluates to the result keeps every outcome in one place.
+///
+/// ## The Many Exits Problem
+///
+/// ```text
+/// fn token_kind(token: &str) -> &'static str {
+///     if token.is_empty() { return "empty"; }
+///     if token == "fn" { return "keyword"; }
+///     if token == "let" { return "keyword"; }
+///     if token.starts_with('"') { return "string"; }
+///     if is_number(token) { return "number"; }
+///     if is_ident(token) { return "identifier"; }
+///     "symbol"
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn token_kind(token: &str) -> &'static str {
+///     match token {
+///         "" => "empty",
+///         "fn" | "let" => "keyword",
+///         _ if token.starts_with('"') => "string",
+///         _ => "other",
+///     }
+/// }
+/// ```
+///
+/// Mitigation: Turn chains of guarded returns into a `match` or an `if`/`else` expression, extract
+/// the parts into helper functions, or keep early returns to a few guard clauses at the top.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1120: six exits spread over the function
+pub fn e1120_bad_token_kind(token: &str) -> &'static str {
+    if token.is_empty() {
+        return "empty";
+    }
+    if token == "fn" {
+        return "keyword";
+    }
+    if token == "let" {
+        return "keyword";
+    }
+    if token.starts_with('"') {
+        return "string";
+    }
+    if token.chars().all(|c| c.is_ascii_digit()) {
+        return "number";
+    }
+    if token.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return "identifier";
+    }
+    "symbol"
+}
+
+/// Entry point for problem demonstration
+pub fn e1120_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1120_bad_token_kind("let"));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: One match expression lists every outcome
+pub fn e1120_good_token_kind(token: &str) -> &'static str {
+    let is_number = token.chars().all(|c| c.is_ascii_digit());
+    let is_identifier = token.chars().all(|c| c.is_alphanumeric() || c == '_');
+    match token {
+        "" => "empty",
+        "fn" | "let" => "keyword",
+        _ if token.starts_with('"') => "string",
+        _ if is_number => "number",
+        _ if is_identifier => "identifier",
+        _ => "symbol",
+    }
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_kind() {
+        assert_eq!(e1120_good_token_kind(""), "empty");
+        assert_eq!(e1120_good_token_kind("let"), "keyword");
+        assert_eq!(e1120_good_token_kind("42"), "number");
+        assert_eq!(e1120_good_token_kind("max_len"), "identifier");
+        assert_eq!(e1120_good_token_kind("+="), "symbol");
+    }
+}
@@ -12,3 +12,9 @@ pub mod e1109_excessive_chaining;
 pub mod e1110_nested_callbacks;
 pub mod e1111_excessive_tuple_complexity;
 pub mod e1112_magic_numbers;
+pub mod e1114_todo_comment;
+pub mod e1115_large_return_value;
+pub mod e1117_excessive_generics;
+pub mod e1118_complex_boolean_condition;
+pub mod e1119_wildcard_match_arm;
+pub mod e1120_excessive_early_returns;
@@ -0,0 +1,122 @@
+/// E1218: Trait with too many supertraits
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: Every supertrait is an obligation that each implementor must satisfy. A trait
+/// declared as `trait Store: Clone + Debug + Default + Send + Sync` cannot be implemented by a
+/// type holding a file handle (not `Clone`) or a connection pool (no sensible `Default`), and
+/// test doubles have to implement all of it just to stub one method. Such "god traits" couple
+/// unrelated concerns into one hierarchy.
+///
+/// ## The God Trait Problem
+///
+/// ```text
+/// trait Store: Clone + Debug + Default + PartialEq + Send + Sync {
+///     fn get(&self, key: &str) -> Option<String>;
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// trait Store: Send + Sync {
+///     fn get(&self, key: &str) -> Option<String>;
+/// }
+///
+/// // Callers that need more ask for it where they use it
+/// fn snapshot<S: Store + Clone>(store: &S) -> S {
+///     store.clone()
+/// }
+/// ```
+///
+/// Mitigation: Require only what the trait's own methods need; put extra bounds on the
+/// functions that use them, or split the trait into smaller focused traits.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1218: Six supertraits for a key-value lookup
+pub fn e1218_bad_god_trait() -> usize {
+    trait Store: Clone + std::fmt::Debug + Default + PartialEq + Send + Sync {
+        fn get(&self, key: &str) -> Option<usize>;
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct MemoryStore;
+
+    impl Store for MemoryStore {
+        fn get(&self, key: &str) -> Option<usize> {
+            Some(key.len())
+        }
+    }
+
+    MemoryStore.get("key").unwrap_or_default()
+}
+
+/// PROBLEM E1218: Extra supertraits hidden in a `where Self:` clause
+pub fn e1218_bad_where_self() -> usize {
+    trait Handler: Send + Sync
+    where
+        Self: Clone + std::fmt::Debug,
+    {
+        fn id(&self) -> usize;
+    }
+
+    #[derive(Clone, Debug)]
+    struct Echo;
+
+    impl Handler for Echo {
+        fn id(&self) -> usize {
+            1
+        }
+    }
+
+    Echo.id()
+}
+
+/// Entry point for problem demonstration
+pub fn e1218_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{} {}", e1218_bad_god_trait(), e1218_bad_where_self());
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: The trait requires only thread safety; cloning is asked for where it is used
+pub fn e1218_good_focused_trait(key: &str) -> usize {
+    trait Store: Send + Sync {
+        fn get(&self, key: &str) -> Option<usize>;
+    }
+
+    #[derive(Clone)]
+    struct MemoryStore;
+
+    impl Store for MemoryStore {
+        fn get(&self, key: &str) -> Option<usize> {
+            Some(key.len())
+        }
+    }
+
+    fn snapshot<S: Store + Clone>(store: &S) -> S {
+        store.clone()
+    }
+
+    snapshot(&MemoryStore).get(key).unwrap_or_default()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focused_trait() {
+        assert_eq!(e1218_good_focused_trait("abc"), 3);
+    }
+}
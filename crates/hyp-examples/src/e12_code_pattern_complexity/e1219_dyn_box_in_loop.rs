@@ -0,0 +1,94 @@
+/// E1219: Box<dyn Trait> constructed inside a loop
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `Box::new(x)` coerced to `Box<dyn Trait>` inside a loop performs a heap
+/// allocation on every iteration, and every method call through the box goes through a vtable
+/// that the optimizer cannot inline. When the concrete type is known at the call site, or the set
+/// of possible types is small and closed, a concrete value, a generic parameter or an enum does
+/// the same job without either cost.
+///
+/// ## The Allocation Per Iteration Problem
+///
+/// ```text
+/// fn total_area(radii: &[f64]) -> f64 {
+///     let mut total = 0.0;
+///     for r in radii {
+///         let shape: Box<dyn Shape> = Box::new(Circle::new(*r));   // one allocation per radius
+///         total += shape.area();                                   // dynamic dispatch
+///     }
+///     total
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn render_all<T: std::fmt::Display>(values: &[T]) -> Vec<String> {
+///     values.iter().map(|value| value.to_string()).collect()
+/// }
+/// ```
+///
+/// Mitigation: Use the concrete type, a generic parameter or an enum over the possible types. If
+/// the boxed value does not change between iterations, create it once before the loop.
+
+use std::fmt::Display;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1219: each value is boxed and then formatted through a vtable
+pub fn e1219_bad_render(values: &[u32]) -> Vec<String> {
+    let mut rendered = Vec::new();
+    for value in values {
+        let item: Box<dyn Display> = Box::new(*value);
+        rendered.push(item.to_string());
+    }
+    rendered
+}
+
+/// PROBLEM E1219: a list of trait objects built one allocation at a time
+pub fn e1219_bad_collect(names: &[&str]) -> Vec<Box<dyn Display>> {
+    let mut items: Vec<Box<dyn Display>> = Vec::new();
+    for name in names {
+        items.push(Box::new(name.to_string()));
+    }
+    items
+}
+
+/// Entry point for problem demonstration
+pub fn e1219_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{:?}", e1219_bad_render(&[1, 2]));
+    println!("{}", e1219_bad_collect(&["a"]).len());
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: A generic parameter keeps the concrete type and static dispatch
+pub fn e1219_good_render<T: Display>(values: &[T]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+/// GOOD: Store the concrete type when every element has the same one
+pub fn e1219_good_collect(names: &[String]) -> Vec<String> {
+    names.iter().map(|name| name.to_uppercase()).collect()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_and_collect() {
+        assert_eq!(e1219_good_render(&[1, 2]), vec!["1".to_string(), "2".to_string()]);
+        assert_eq!(e1219_good_collect(&["a".to_string()]), vec!["A".to_string()]);
+    }
+}
@@ -0,0 +1,119 @@
+/// E1247: Recursive field without indirection
+/// Severity: HIGH
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A struct or enum that stores a value of its own type directly (not behind a
+/// pointer) has no finite size: a `Node` contains a `Node`, which contains a `Node`, and so on.
+/// The compiler rejects this with "recursive type has infinite size" (E0072), which newcomers
+/// often find cryptic. Wrapping the field in `Option` does not help because `Option<Node>` still
+/// stores the `Node` inline. The fix is indirection: `Box`, `Rc`/`Arc`, a reference, or a `Vec`,
+/// all of which have a fixed size regardless of what they point to.
+///
+/// This is a companion to E1210 (recursive type definitions): E1210 flags recursion that compiles
+/// but is hard to follow, E1247 explains recursion that cannot compile at all.
+///
+/// ## The Infinite Size Problem
+///
+/// ```text
+/// struct Node {
+///     value: i32,
+///     next: Option<Node>,   // error[E0072]: recursive type `Node` has infinite size
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// struct Node {
+///     value: i32,
+///     next: Option<Box<Node>>,  // Box is always pointer-sized
+/// }
+/// ```
+///
+/// Mitigation: The compiler already refuses these types; Hyp reports them with a clearer
+/// explanation so generated or snippet code can be fixed without a build.
+///
+/// NOTE: The bad examples below are disabled with `#[cfg(any())]` (always false) because they
+/// do not compile. Hyp parses source without evaluating `cfg`, so it still sees them.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1247: Linked list node storing the next node inline
+pub fn e1247_bad_inline_next() {
+    #[cfg(any())]
+    struct Node {
+        value: i32,
+        next: Option<Node>,
+    }
+}
+
+/// PROBLEM E1247: Enum variant storing itself inline
+pub fn e1247_bad_inline_variant() {
+    #[cfg(any())]
+    enum List {
+        Cons(i32, List),
+        Nil,
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1247_entry() -> Result<(), Box<dyn std::error::Error>> {
+    e1247_bad_inline_next();
+    e1247_bad_inline_variant();
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Box gives the recursive field a fixed size
+pub fn e1247_good_boxed_next() -> i32 {
+    struct Node {
+        value: i32,
+        next: Option<Box<Node>>,
+    }
+
+    let list = Node {
+        value: 1,
+        next: Some(Box::new(Node {
+            value: 2,
+            next: None,
+        })),
+    };
+
+    list.value + list.next.as_deref().map_or(0, |next| next.value)
+}
+
+/// GOOD: Vec of children also provides indirection
+pub fn e1247_good_vec_children() -> usize {
+    struct Tree {
+        children: Vec<Tree>,
+    }
+
+    let tree = Tree {
+        children: vec![Tree { children: Vec::new() }],
+    };
+    tree.children.len()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boxed_next() {
+        assert_eq!(e1247_good_boxed_next(), 3);
+    }
+
+    #[test]
+    fn test_vec_children() {
+        assert_eq!(e1247_good_vec_children(), 1);
+    }
+}
@@ -0,0 +1,119 @@
+/// E1249: Struct made only of PhantomData fields
+/// Severity: LOW
+/// LLM confusion: 3 (MED)
+///
+/// Description: `PhantomData<T>` is a zero-sized field that tells the compiler a struct "uses"
+/// `T`. One marker is a normal idiom (units, typed IDs, variance). A struct whose fields are *all*
+/// `PhantomData`, several of them, carries no runtime data at all: it exists only to encode
+/// state in the type system. Such type-level state machines make every signature longer, every
+/// error message harder to read, and are often replaceable by a plain enum or a runtime field.
+///
+/// This is a companion to E1208 (phantom types) and E1215 (type-level programming).
+///
+/// ## The Over-Engineering Problem
+///
+/// ```text
+/// struct Request<Method, Auth, Body> {
+///     _method: PhantomData<Method>,
+///     _auth: PhantomData<Auth>,
+///     _body: PhantomData<Body>,
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// enum Method { Get, Post }
+///
+/// struct Request {
+///     method: Method,
+///     authenticated: bool,
+/// }
+/// ```
+///
+/// Mitigation: Keep type-level markers to one per struct unless the compile-time guarantee is
+/// worth the complexity, and document why it is.
+
+use std::marker::PhantomData;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1249: Three phantom markers and no data
+pub struct E1249BadRequest<Method, Auth, Body> {
+    _method: PhantomData<Method>,
+    _auth: PhantomData<Auth>,
+    _body: PhantomData<Body>,
+}
+
+/// PROBLEM E1249: Tuple struct of unit markers
+pub struct E1249BadUnits<Length, Time>(PhantomData<Length>, PhantomData<Time>);
+
+/// PROBLEM E1249: Build a request whose state lives only in the type
+pub fn e1249_bad_phantom_request() -> E1249BadRequest<u8, u16, u32> {
+    E1249BadRequest {
+        _method: PhantomData,
+        _auth: PhantomData,
+        _body: PhantomData,
+    }
+}
+
+/// PROBLEM E1249: Build a unit marker value
+pub fn e1249_bad_phantom_units() -> E1249BadUnits<u8, u16> {
+    E1249BadUnits(PhantomData, PhantomData)
+}
+
+/// Entry point for problem demonstration
+pub fn e1249_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = e1249_bad_phantom_request();
+    let _ = e1249_bad_phantom_units();
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Request state stored as plain runtime data
+pub fn e1249_good_runtime_state(authenticated: bool) -> bool {
+    struct Request {
+        authenticated: bool,
+    }
+
+    let request = Request { authenticated };
+    request.authenticated
+}
+
+/// GOOD: One marker next to real data (typed ID)
+pub fn e1249_good_typed_id(value: u64) -> u64 {
+    struct Id<T> {
+        value: u64,
+        _kind: PhantomData<T>,
+    }
+
+    let id: Id<String> = Id {
+        value,
+        _kind: PhantomData,
+    };
+    id.value
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runtime_state() {
+        assert!(e1249_good_runtime_state(true));
+    }
+
+    #[test]
+    fn test_typed_id() {
+        assert_eq!(e1249_good_typed_id(7), 7);
+    }
+}
@@ -17,3 +17,7 @@ pub mod e1214_macro_generated;
 pub mod e1215_type_level_programming;
 pub mod e1216_chained_transform;
 pub mod e1217_abba_deadlock;
+pub mod e1218_excessive_supertraits;
+pub mod e1219_dyn_box_in_loop;
+pub mod e1247_direct_recursive_field;
+pub mod e1249_phantom_only_struct;
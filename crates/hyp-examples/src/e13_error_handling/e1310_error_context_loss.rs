@@ -90,6 +90,14 @@ pub fn e1310_bad_generic_message() -> Result<i32, String> {
     content.trim().parse().map_err(|_| "Error".to_string()) // Two different errors, same message!
 }
 
+/// PROBLEM E1310: The error is named but never used, so it is discarded all the same
+pub fn e1310_bad_unused_error() -> Result<u16, String> {
+    std::env::var("PORT")
+        .map_err(|_e| "PORT is not set".to_string())?
+        .parse()
+        .map_err(|_err| "PORT is not a number".to_string())
+}
+
 /// Entry point for problem demonstration
 pub fn e1310_entry() -> Result<(), Box<dyn std::error::Error>> {
     let _ = e1310_bad_context_loss();
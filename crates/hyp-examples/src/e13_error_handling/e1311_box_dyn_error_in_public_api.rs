@@ -0,0 +1,104 @@
+/// E1311: Box<dyn Error> returned from public API
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `Box<dyn Error>` is convenient inside a binary, but as the error type of a
+/// public library function it hides every failure mode behind one opaque trait object. Callers
+/// cannot `match` on it, cannot tell a retryable I/O error from bad input without downcasting,
+/// and the signature no longer documents what can go wrong.
+///
+/// ## The Opaque Error Problem
+///
+/// ```text
+/// pub fn parse_port(text: &str) -> Result<u16, Box<dyn Error>>;
+///
+/// match parse_port(input) {
+///     Err(e) => /* empty input or bad digits? only e.to_string() tells */,
+///     Ok(port) => ...
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// #[derive(Debug, thiserror::Error)]
+/// pub enum PortError {
+///     #[error("port is empty")]
+///     Empty,
+///     #[error(transparent)]
+///     Invalid(#[from] std::num::ParseIntError),
+/// }
+///
+/// pub fn parse_port(text: &str) -> Result<u16, PortError> {
+///     if text.is_empty() {
+///         return Err(PortError::Empty);
+///     }
+///     Ok(text.parse()?)
+/// }
+/// ```
+///
+/// Mitigation: Return a concrete error enum from public APIs; keep `Box<dyn Error>` or
+/// `anyhow::Error` for `main` and other application-level code.
+use std::error::Error;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1311: Public function returning a boxed trait object error
+pub fn e1311_bad_parse_port(text: &str) -> Result<u16, Box<dyn Error>> {
+    if text.is_empty() {
+        return Err("port is empty".into());
+    }
+    Ok(text.trim().parse()?)
+}
+
+/// PROBLEM E1311: Thread-safe variant hides the error type just the same
+pub fn e1311_bad_parse_retries(text: &str) -> Result<u8, Box<dyn Error + Send + Sync>> {
+    Ok(text.trim().parse()?)
+}
+
+/// Entry point for problem demonstration
+pub fn e1311_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = e1311_bad_parse_port("80a");
+    let _ = e1311_bad_parse_retries("x");
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Each failure mode is a variant callers can match on
+#[derive(Debug, thiserror::Error)]
+pub enum E1311GoodPortError {
+    #[error("port is empty")]
+    Empty,
+    #[error(transparent)]
+    Invalid(#[from] std::num::ParseIntError),
+}
+
+/// GOOD: Public function with a concrete error type
+pub fn e1311_good_parse_port(text: &str) -> Result<u16, E1311GoodPortError> {
+    if text.is_empty() {
+        return Err(E1311GoodPortError::Empty);
+    }
+    let port = text.trim().parse()?;
+    Ok(port)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_port() {
+        assert!(matches!(e1311_good_parse_port("8080"), Ok(8080)));
+        assert!(matches!(e1311_good_parse_port(""), Err(E1311GoodPortError::Empty)));
+        assert!(matches!(e1311_good_parse_port("x"), Err(E1311GoodPortError::Invalid(_))));
+    }
+}
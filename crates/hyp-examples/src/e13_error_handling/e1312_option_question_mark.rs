@@ -0,0 +1,104 @@
+/// E1312: `?` on an Option in a Result-returning function
+/// Severity: MED
+/// LLM confusion: 1 (VERY LOW)
+///
+/// Description: `?` on an `Option` only works in a function that itself returns `Option`. In a
+/// function returning `Result`, `map.get(key)?` fails to compile because `Option` has no
+/// conversion into the error type, and the compiler error ("the `?` operator can only be used
+/// on `Result`s ... in a function that returns `Result`") does not point at the fix. The missing
+/// step is `.ok_or(..)` / `.ok_or_else(..)`, which is also where the error should say *what*
+/// was absent.
+///
+/// ## The Missing Conversion Problem
+///
+/// ```text
+/// fn port(config: &HashMap<String, String>) -> Result<u16, String> {
+///     let raw = config.get("port")?;   // error[E0277]: `?` couldn't convert the error
+///     raw.parse().map_err(|e| format!("bad port: {e}"))
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// fn port(config: &HashMap<String, String>) -> Result<u16, String> {
+///     let raw = config.get("port").ok_or_else(|| "missing key 'port'".to_string())?;
+///     raw.parse().map_err(|e| format!("bad port: {e}"))
+/// }
+/// ```
+///
+/// Mitigation: Convert every Option to a Result with `.ok_or_else()` before applying `?`, and
+/// give the error enough context to identify the missing value.
+///
+/// NOTE: The bad examples below are disabled with `#[cfg(any())]` (always false) because they
+/// do not compile. Hyp parses source without evaluating `cfg`, so it still sees them.
+use std::collections::HashMap;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1312: `?` on a map lookup in a function returning Result
+pub fn e1312_bad_config_lookup() {
+    #[cfg(any())]
+    fn port(config: &HashMap<String, String>) -> Result<u16, String> {
+        let raw = config.get("port")?;
+        raw.parse().map_err(|e| format!("bad port: {e}"))
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1312_entry() -> Result<(), Box<dyn std::error::Error>> {
+    e1312_bad_config_lookup();
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// Error for the good example below
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum E1312ConfigError {
+    Missing(&'static str),
+    Invalid(std::num::ParseIntError),
+}
+
+impl std::fmt::Display for E1312ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            E1312ConfigError::Missing(key) => write!(f, "missing key '{key}'"),
+            E1312ConfigError::Invalid(err) => write!(f, "invalid port: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for E1312ConfigError {}
+
+/// GOOD: The absent key is turned into a descriptive error before `?`
+pub fn e1312_good_config_lookup(config: &HashMap<String, String>) -> Result<u16, E1312ConfigError> {
+    let raw = config
+        .get("port")
+        .ok_or(E1312ConfigError::Missing("port"))?;
+    raw.parse().map_err(E1312ConfigError::Invalid)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_lookup() {
+        let mut config = HashMap::new();
+        assert_eq!(e1312_good_config_lookup(&config), Err(E1312ConfigError::Missing("port")));
+        config.insert(String::from("port"), String::from("8080"));
+        assert_eq!(e1312_good_config_lookup(&config), Ok(8080));
+    }
+}
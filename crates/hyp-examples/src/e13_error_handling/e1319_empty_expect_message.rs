@@ -0,0 +1,74 @@
+/// E1319: expect() with an empty message
+/// Severity: HIGH
+/// LLM confusion: 1 (LOWEST)
+///
+/// Description: `expect` exists to say why a value cannot be missing. `expect("")` keeps the
+/// panic but throws that away: the output is a bare `: called Option::unwrap() on a None value`
+/// with no hint of the invariant that was broken. It is worse than `unwrap()` because a reviewer
+/// skimming the code sees an `expect` and assumes a message was written.
+///
+/// ## The Blank Panic Problem
+///
+/// ```text
+/// let port = settings.port.expect("");
+/// // thread 'main' panicked at src/main.rs:12:30:
+/// // (empty)
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// let port: Option<u16> = Some(8080);
+/// let port = port.expect("port is filled in by Settings::with_defaults");
+/// assert_eq!(port, 8080);
+/// ```
+///
+/// Mitigation: Describe the invariant that guarantees the value in the message, or handle the
+/// missing case with `?`, `ok_or` or a default.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1319: the panic message is empty
+pub fn e1319_bad_empty_expect(port: Option<u16>) -> u16 {
+    port.expect("")
+}
+
+/// PROBLEM E1319: whitespace is still no message
+pub fn e1319_bad_blank_expect(name: Option<&str>) -> usize {
+    name.expect("  ").len()
+}
+
+/// Entry point for problem demonstration
+pub fn e1319_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1319_bad_empty_expect(Some(8080)));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// Port used when none is configured
+pub const E1319_DEFAULT_PORT: u16 = 8080;
+
+/// GOOD: A missing port is handled instead of panicking
+pub fn e1319_good_default_port(port: Option<u16>) -> u16 {
+    port.unwrap_or(E1319_DEFAULT_PORT)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_port() {
+        assert_eq!(e1319_good_default_port(None), 8080);
+        assert_eq!(e1319_good_default_port(Some(1)), 1);
+    }
+}
@@ -0,0 +1,110 @@
+/// E1320: Error type without a std::error::Error implementation
+/// Severity: MED
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A custom error type that never implements `std::error::Error` works inside its own
+/// module and nowhere else. Callers cannot use `?` to turn it into `Box<dyn Error>` or
+/// `anyhow::Error`, cannot wrap it as the `source()` of their own error, and error reporters
+/// cannot print it. Each caller ends up writing a `map_err` just to get it into the ecosystem's
+/// error types.
+///
+/// ## The Island Error Problem
+///
+/// ```text
+/// pub enum ParseError { Empty, BadDigit(char) }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let n = parse("12")?;   // error: `?` couldn't convert ParseError into Box<dyn Error>
+///     Ok(())
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// #[derive(Debug)]
+/// pub enum ParseError {
+///     Empty,
+/// }
+///
+/// impl std::fmt::Display for ParseError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "input is empty")
+///     }
+/// }
+///
+/// impl std::error::Error for ParseError {}
+/// ```
+///
+/// Mitigation: Implement `Display` and `std::error::Error` for every error type, or derive them
+/// with `thiserror`. If the impl lives in another file, add the type to `allowed_types`.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1320: no Display, no Error impl
+#[derive(Debug)]
+pub struct E1320BadFlagError {
+    input: String,
+}
+
+/// PROBLEM E1320: the error type cannot be boxed into `Box<dyn Error>`
+pub fn e1320_bad_parse_flag(input: &str) -> Result<bool, E1320BadFlagError> {
+    match input {
+        "on" => Ok(true),
+        "off" => Ok(false),
+        _ => Err(E1320BadFlagError { input: input.to_string() }),
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1320_entry() -> Result<(), Box<dyn std::error::Error>> {
+    if let Err(e) = e1320_bad_parse_flag("maybe") {
+        println!("bad flag {}", e.input);
+    }
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// Error returned by [`e1320_good_parse_flag`]
+#[derive(Debug, PartialEq, Eq)]
+pub struct E1320FlagError {
+    input: String,
+}
+
+impl std::fmt::Display for E1320FlagError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is neither 'on' nor 'off'", self.input)
+    }
+}
+
+impl std::error::Error for E1320FlagError {}
+
+/// GOOD: The error type plugs into `?`, `Box<dyn Error>` and error reporters
+pub fn e1320_good_parse_flag(input: &str) -> Result<bool, E1320FlagError> {
+    match input {
+        "on" => Ok(true),
+        "off" => Ok(false),
+        _ => Err(E1320FlagError { input: input.to_string() }),
+    }
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flag() {
+        assert_eq!(e1320_good_parse_flag("on"), Ok(true));
+        let boxed: Box<dyn std::error::Error> = Box::new(e1320_good_parse_flag("maybe").unwrap_err());
+        assert_eq!(boxed.to_string(), "'maybe' is neither 'on' nor 'off'");
+    }
+}
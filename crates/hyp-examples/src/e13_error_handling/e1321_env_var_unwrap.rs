@@ -0,0 +1,98 @@
+/// E1321: Environment variable read with unwrap()
+/// Severity: MED
+/// LLM confusion: 1 (LOWEST)
+///
+/// Description: `std::env::var("PORT").unwrap()` is the quickest way to read configuration and
+/// the quickest way to crash in production. If the variable is missing (a typo in a deployment
+/// manifest, a new environment) or not valid UTF-8, the process panics with
+/// `called Result::unwrap() on an Err value: NotPresent`, which doesn't even say which variable
+/// was missing.
+///
+/// ## The Crash-On-Missing-Config Problem
+///
+/// ```text
+/// let port = std::env::var("PORT").unwrap();
+/// // thread 'main' panicked at 'called `Result::unwrap()` on an `Err` value: NotPresent'
+/// ```
+///
+/// ## The Right Solutions
+///
+/// ### Option 1: Fall back to a default
+/// ```rust
+/// let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
+/// ```
+///
+/// ### Option 2: Return an error naming the variable
+/// ```rust
+/// fn port() -> Result<String, String> {
+///     std::env::var("PORT").map_err(|e| format!("PORT: {e}"))
+/// }
+/// ```
+///
+/// Mitigation: Load configuration once at startup into a typed struct and report every missing
+/// variable by name.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1321: Panics if PORT is not set
+pub fn e1321_bad_env_var_unwrap() -> String {
+    std::env::var("PORT").unwrap()
+}
+
+/// PROBLEM E1321: expect() still panics, just with a nicer message
+pub fn e1321_bad_env_var_expect() -> String {
+    std::env::var("DATABASE_URL").expect("DATABASE_URL must be set")
+}
+
+/// Entry point for problem demonstration
+pub fn e1321_entry() -> Result<(), Box<dyn std::error::Error>> {
+    // Not called: both functions panic when the variables are unset
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Default value when the variable is missing
+pub fn e1321_good_default() -> String {
+    std::env::var("PORT").unwrap_or_else(|_| String::from("8080"))
+}
+
+/// Configuration error that keeps the variable name
+#[derive(Debug)]
+pub struct E1321MissingVar {
+    pub name: &'static str,
+    pub source: std::env::VarError,
+}
+
+/// GOOD: Propagate an error that names the variable
+pub fn e1321_good_named_error() -> Result<String, E1321MissingVar> {
+    std::env::var("DATABASE_URL").map_err(|source| E1321MissingVar {
+        name: "DATABASE_URL",
+        source,
+    })
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert!(!e1321_good_default().is_empty());
+    }
+
+    #[test]
+    fn test_named_error() {
+        if let Err(err) = e1321_good_named_error() {
+            assert_eq!(err.name, "DATABASE_URL");
+        }
+    }
+}
@@ -0,0 +1,109 @@
+/// E1322: Lock guard returned from function
+/// Severity: MED
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A `MutexGuard` (or `RwLockReadGuard`/`RwLockWriteGuard`) keeps its lock held
+/// until it is dropped. A function that returns the guard hands that decision to every caller:
+/// one caller binds it with `let` and holds the lock for the rest of a long function, another
+/// calls back into the same type and deadlocks on the second `lock()`. The critical section is
+/// no longer visible in the code that owns the lock.
+///
+/// ## The Leaked Critical Section Problem
+///
+/// ```text
+/// fn entries(&self) -> MutexGuard<'_, Vec<String>> { self.entries.lock().unwrap() }
+///
+/// let entries = cache.entries();
+/// cache.insert("x");          // deadlock: insert() locks `entries` again
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::sync::Mutex;
+///
+/// struct Cache { entries: Mutex<Vec<String>> }
+///
+/// impl Cache {
+///     fn with_entries<R>(&self, f: impl FnOnce(&mut Vec<String>) -> R) -> R {
+///         let mut guard = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+///         f(&mut guard)
+///     } // lock released here, before the caller continues
+/// }
+/// ```
+///
+/// Mitigation: Keep guards local to the function that takes the lock. Return values computed
+/// under the lock, or accept a closure that runs while it is held.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// Shared cache used by the examples
+pub struct E1322Cache {
+    entries: Mutex<Vec<String>>,
+}
+
+impl E1322Cache {
+    /// Create an empty cache
+    pub fn create() -> Self {
+        Self {
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+/// PROBLEM E1322: The caller decides how long the lock is held
+pub fn e1322_bad_return_guard(cache: &E1322Cache) -> Option<MutexGuard<'_, Vec<String>>> {
+    cache.entries.lock().ok()
+}
+
+/// Entry point for problem demonstration
+pub fn e1322_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let cache = E1322Cache::create();
+    let _ = e1322_bad_return_guard(&cache);
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Compute the value under the lock and return it
+pub fn e1322_good_return_value(cache: &E1322Cache) -> usize {
+    let guard = cache.entries.lock().unwrap_or_else(PoisonError::into_inner);
+    guard.len()
+}
+
+/// GOOD: Closure-based API keeps the critical section inside this function
+pub fn e1322_good_with_lock<R>(cache: &E1322Cache, f: impl FnOnce(&mut Vec<String>) -> R) -> R {
+    let mut guard = cache.entries.lock().unwrap_or_else(PoisonError::into_inner);
+    f(&mut guard)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_return_value() {
+        let cache = E1322Cache::create();
+        assert_eq!(e1322_good_return_value(&cache), 0);
+    }
+
+    #[test]
+    fn test_with_lock() {
+        let cache = E1322Cache::create();
+        let len = e1322_good_with_lock(&cache, |entries| {
+            entries.push(String::from("a"));
+            entries.len()
+        });
+        assert_eq!(len, 1);
+    }
+}
@@ -0,0 +1,83 @@
+/// E1323: main() returning () with unwrap/expect calls
+/// Severity: LOW
+/// LLM confusion: 1 (LOWEST)
+///
+/// Description: A `fn main()` that returns `()` cannot use `?`, so every fallible step ends up
+/// as `unwrap()` or `expect()`. Each failure prints a panic message with a source location
+/// instead of the error itself, and adding `?` later fails to compile. Since Rust 1.26 `main`
+/// may return `Result`; the error is printed with its `Debug` output and the process exits
+/// with a non-zero code.
+///
+/// ## The Panicking Entry Point Problem
+///
+/// ```text
+/// fn main() {
+///     let text = std::fs::read_to_string("app.toml").unwrap();
+///     let port: u16 = text.trim().parse().expect("invalid port");
+///     serve(port);
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let port: u16 = "8080".parse()?;
+///     println!("listening on {}", port);
+///     Ok(())
+/// }
+/// ```
+///
+/// Mitigation: Return `Result<(), Box<dyn std::error::Error>>` (or `anyhow::Result<()>`) from
+/// `main` and propagate errors with `?`.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1323: Entry point that panics on every failure
+pub fn e1323_bad_unit_main() {
+    fn main() {
+        let text = std::fs::read_to_string("/nonexistent/app.toml").unwrap_or_default();
+        let port: u16 = text.trim().parse().unwrap();
+        let name = std::env::args().next().expect("program name");
+        println!("{} listening on {}", name, port);
+    }
+
+    let _ = main as fn();
+}
+
+/// Entry point for problem demonstration
+pub fn e1323_entry() -> Result<(), Box<dyn std::error::Error>> {
+    e1323_bad_unit_main();
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: main returns Result and propagates errors with `?`
+pub fn e1323_good_result_main(port: &str) -> Result<u16, std::num::ParseIntError> {
+    fn main(port: &str) -> Result<u16, std::num::ParseIntError> {
+        let port: u16 = port.trim().parse()?;
+        Ok(port)
+    }
+
+    main(port)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_result_main() {
+        assert_eq!(e1323_good_result_main(" 8080 "), Ok(8080));
+        assert!(e1323_good_result_main("port").is_err());
+    }
+}
@@ -0,0 +1,104 @@
+/// E1324: Type-erased variant in typed error enum
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A typed error enum tells callers exactly what can go wrong and lets them
+/// `match` on it. Adding an `Other(Box<dyn Error>)` or `Unexpected(anyhow::Error)` variant
+/// "just in case" brings the type erasure back: new failure modes silently flow into the
+/// catch-all, callers can only print them, and the enum no longer documents the API.
+///
+/// ## The Catch-All Variant Problem
+///
+/// ```text
+/// pub enum ConfigError {
+///     Missing(String),
+///     Other(Box<dyn std::error::Error>),   // io, parse, anything
+/// }
+///
+/// match err {
+///     ConfigError::Other(e) => /* is it retryable? no way to tell */,
+///     ...
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// #[derive(Debug, thiserror::Error)]
+/// pub enum ConfigError {
+///     #[error("missing key {0}")]
+///     Missing(String),
+///     #[error(transparent)]
+///     Io(#[from] std::io::Error),
+///     #[error(transparent)]
+///     Parse(#[from] std::num::ParseIntError),
+/// }
+/// ```
+///
+/// Mitigation: Add one variant per underlying error type, with `#[from]` so `?` converts
+/// automatically. Use `anyhow`/`Box<dyn Error>` at application boundaries, not inside a typed enum.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1324: Typed error enum with a type-erased catch-all variant
+#[derive(Debug)]
+pub enum E1324BadConfigError {
+    Missing(String),
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// PROBLEM E1324: Parse failures disappear into the catch-all variant
+pub fn e1324_bad_parse_port(text: &str) -> Result<u16, E1324BadConfigError> {
+    if text.is_empty() {
+        return Err(E1324BadConfigError::Missing(String::from("port")));
+    }
+    text.trim()
+        .parse()
+        .map_err(|e| E1324BadConfigError::Other(Box::new(e)))
+}
+
+/// Entry point for problem demonstration
+pub fn e1324_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = e1324_bad_parse_port("port");
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Every failure mode has its own variant
+#[derive(Debug, thiserror::Error)]
+pub enum E1324GoodConfigError {
+    #[error("missing key {0}")]
+    Missing(&'static str),
+    #[error(transparent)]
+    Parse(#[from] std::num::ParseIntError),
+}
+
+/// GOOD: `?` converts into the typed variant
+pub fn e1324_good_parse_port(text: &str) -> Result<u16, E1324GoodConfigError> {
+    if text.is_empty() {
+        return Err(E1324GoodConfigError::Missing("port"));
+    }
+    let port = text.trim().parse()?;
+    Ok(port)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_port() {
+        assert_eq!(e1324_good_parse_port(" 8080").ok(), Some(8080));
+        assert!(matches!(e1324_good_parse_port(""), Err(E1324GoodConfigError::Missing("port"))));
+        assert!(matches!(e1324_good_parse_port("x"), Err(E1324GoodConfigError::Parse(_))));
+    }
+}
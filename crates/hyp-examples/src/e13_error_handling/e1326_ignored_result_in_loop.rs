@@ -0,0 +1,113 @@
+/// E1326: Result ignored inside a loop
+/// Severity: HIGH
+/// LLM confusion: 3 (MED)
+///
+/// Description: `let _ = fallible();` throws one error away. Inside a loop it throws one away
+/// per iteration: a batch export to a full disk "succeeds" with zero rows written, and a
+/// producer keeps sending into a channel whose receiver is long gone. Nothing is logged and the
+/// caller has no way to tell that any element failed.
+///
+/// ## The Per-Iteration Swallow Problem
+///
+/// ```text
+/// for row in rows {
+///     let _ = out.write_all(row.as_bytes());   // disk full? every row is lost silently
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::io::Write;
+///
+/// fn export(out: &mut impl Write, rows: &[&str]) -> std::io::Result<()> {
+///     for row in rows {
+///         out.write_all(row.as_bytes())?;
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+/// Mitigation: Propagate with `?` to stop at the first failure, or collect the errors and
+/// report them once the loop is done.
+use std::io::Write;
+use std::sync::mpsc::Sender;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1326: A disconnected receiver makes every send fail, unnoticed
+pub fn e1326_bad_send_each(tx: &Sender<u32>, items: &[u32]) {
+    for item in items {
+        let _ = tx.send(*item);
+    }
+}
+
+/// PROBLEM E1326: Write errors are dropped row by row
+pub fn e1326_bad_write_each(out: &mut Vec<u8>, rows: &[&str]) {
+    for row in rows {
+        let _ = out.write_all(row.as_bytes());
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1326_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    drop(rx);
+    e1326_bad_send_each(&tx, &[1, 2, 3]);
+    let mut out = Vec::new();
+    e1326_bad_write_each(&mut out, &["a", "b"]);
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: The first failed send stops the loop and reaches the caller
+pub fn e1326_good_send_each(tx: &Sender<u32>, items: &[u32]) -> Result<(), std::sync::mpsc::SendError<u32>> {
+    for item in items {
+        tx.send(*item)?;
+    }
+    Ok(())
+}
+
+/// GOOD: Every row is attempted and the failures are returned with their index
+pub fn e1326_good_write_each<W: Write>(out: &mut W, rows: &[String]) -> Vec<(usize, std::io::Error)> {
+    let mut failures = Vec::new();
+    for (index, row) in rows.iter().enumerate() {
+        if let Err(error) = out.write_all(row.as_bytes()) {
+            failures.push((index, error));
+        }
+    }
+    failures
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_each_reports_disconnect() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        assert!(e1326_good_send_each(&tx, &[1, 2]).is_ok());
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+        drop(rx);
+        assert!(e1326_good_send_each(&tx, &[3]).is_err());
+    }
+
+    #[test]
+    fn test_write_each_collects_failures() {
+        let mut buf = [0u8; 3];
+        let mut out = &mut buf[..];
+        let rows = [String::from("ab"), String::from("cd"), String::from("e")];
+        let failures = e1326_good_write_each(&mut out, &rows);
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].0, 1);
+    }
+}
@@ -10,3 +10,12 @@ pub mod e1307_string_errors;
 pub mod e1308_not_using_question_mark;
 pub mod e1309_panic_in_drop;
 pub mod e1310_error_context_loss;
+pub mod e1311_box_dyn_error_in_public_api;
+pub mod e1312_option_question_mark;
+pub mod e1319_empty_expect_message;
+pub mod e1320_error_type_missing_error_impl;
+pub mod e1321_env_var_unwrap;
+pub mod e1322_returned_guard;
+pub mod e1323_question_mark_in_unit_main;
+pub mod e1324_box_dyn_in_error_enum;
+pub mod e1326_ignored_result_in_loop;
@@ -0,0 +1,82 @@
+/// E1414: Sign-changing as cast
+/// Severity: MED
+/// LLM confusion: 3 (MED)
+///
+/// Description: Casting between a signed and an unsigned integer of the same width with `as`
+/// never fails and never changes a bit, which is exactly the problem: `-1i32 as u32` is
+/// `4294967295` and `u64::MAX as i64` is `-1`. A negative offset turns into an enormous index, a
+/// large hash turns into a negative key, and nothing in the code signals that it happened.
+///
+/// ## The Reinterpretation Problem
+///
+/// ```text
+/// fn to_index(offset: i64) -> u64 {
+///     offset as u64          // -1 becomes 18446744073709551615
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn to_index(offset: i64) -> Option<u64> {
+///     u64::try_from(offset).ok()   // None for negative offsets
+/// }
+/// ```
+///
+/// Mitigation: Use `TryFrom`/`try_into()` and handle the error, or check the range before the
+/// conversion. Keep `as` for the cases where reinterpreting the bits is the intent, and say so.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1414: A negative offset becomes a huge index
+pub fn e1414_bad_offset_to_index(offset: i64) -> u64 {
+    offset as u64
+}
+
+/// PROBLEM E1414: Large hashes come out negative
+pub fn e1414_bad_hash_to_key(hash: u32) -> i32 {
+    hash as i32
+}
+
+/// Entry point for problem demonstration
+pub fn e1414_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1414_bad_offset_to_index(-1));
+    println!("{}", e1414_bad_hash_to_key(u32::MAX));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Negative offsets are rejected instead of wrapping
+pub fn e1414_good_offset_to_index(offset: i64) -> Option<u64> {
+    u64::try_from(offset).ok()
+}
+
+/// GOOD: Widening to a larger signed type is lossless
+pub fn e1414_good_hash_to_key(hash: u32) -> i64 {
+    i64::from(hash)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_to_index() {
+        assert_eq!(e1414_good_offset_to_index(-1), None);
+        assert_eq!(e1414_good_offset_to_index(7), Some(7));
+    }
+
+    #[test]
+    fn test_hash_to_key() {
+        assert_eq!(e1414_good_hash_to_key(u32::MAX), i64::from(u32::MAX));
+    }
+}
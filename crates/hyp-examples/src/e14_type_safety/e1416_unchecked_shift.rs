@@ -0,0 +1,83 @@
+/// E1416: Shift that may overflow the type width
+/// Severity: MED
+/// LLM confusion: 3 (MED)
+///
+/// Description: `x << n` is only defined for `n` below the bit width of `x`. Debug builds panic
+/// with "attempt to shift left with overflow", release builds mask the amount, so `1u32 << 32`
+/// quietly evaluates to `1` instead of `0`. When the amount comes from a parameter or a
+/// computation, the out-of-range case is easy to miss and behaves differently per build profile.
+///
+/// ## The Masked Shift Problem
+///
+/// ```text
+/// fn low_bits_mask(bits: u32) -> u32 {
+///     (1 << bits) - 1        // bits == 32: panics in debug, returns 0 in release
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn low_bits_mask(bits: u32) -> u32 {
+///     1u32.checked_shl(bits).map_or(u32::MAX, |bit| bit - 1)
+/// }
+/// ```
+///
+/// Mitigation: Use `checked_shl`/`checked_shr` and handle `None`, mask the amount explicitly
+/// (`n & 31`), or use `wrapping_shl`/`wrapping_shr` where masking is the intended behavior.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1416: Shift amount comes straight from the caller
+pub fn e1416_bad_scale(value: u32, bits: u32) -> u32 {
+    value << bits
+}
+
+/// PROBLEM E1416: Computed shift amount overflows for index >= 8
+pub fn e1416_bad_extract_byte(value: u64, index: u32) -> u8 {
+    (value >> (index * 8)) as u8
+}
+
+/// Entry point for problem demonstration
+pub fn e1416_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1416_bad_scale(3, 4));
+    println!("{}", e1416_bad_extract_byte(0xABCD, 1));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Out-of-range shift amounts are handled explicitly
+pub fn e1416_good_scale(value: u32, bits: u32) -> Option<u32> {
+    value.checked_shl(bits)
+}
+
+/// GOOD: No shift at all; out-of-range indexes yield None
+pub fn e1416_good_extract_byte(value: u64, index: usize) -> Option<u8> {
+    value.to_le_bytes().get(index).copied()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(e1416_good_scale(3, 4), Some(48));
+        assert_eq!(e1416_good_scale(1, 32), None);
+    }
+
+    #[test]
+    fn test_extract_byte() {
+        assert_eq!(e1416_good_extract_byte(0xABCD, 1), Some(0xAB));
+        assert_eq!(e1416_good_extract_byte(0xABCD, 8), None);
+    }
+}
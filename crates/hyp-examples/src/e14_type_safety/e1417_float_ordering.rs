@@ -0,0 +1,91 @@
+/// E1417: Float ordering comparison without NaN handling
+/// Severity: LOW
+/// LLM confusion: 3 (MED)
+///
+/// Description: `<`, `>`, `<=` and `>=` return `false` whenever either float is NaN. Code like
+/// `if a > b { a } else { b }` therefore quietly picks `b` for a NaN `a`, a running maximum
+/// ignores NaN samples or gets stuck on one, and range checks such as `x >= 0.0` reject NaN
+/// without saying so. The comparison looks total but is not.
+///
+/// ## The NaN Comparison Problem
+///
+/// ```text
+/// fn larger(a: f64, b: f64) -> f64 {
+///     if a > b { a } else { b }   // larger(f64::NAN, 1.0) == 1.0, larger(1.0, f64::NAN) is NaN
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn larger(a: f64, b: f64) -> f64 {
+///     if a.total_cmp(&b).is_gt() { a } else { b }   // NaN has a defined place in the order
+/// }
+/// ```
+///
+/// Mitigation: Use `total_cmp` (or `f64::max`, which ignores NaN) when an ordering must exist,
+/// or check `is_nan()` first and handle the NaN case explicitly.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1417: Result depends on argument order when either value is NaN
+pub fn e1417_bad_larger(a: f64, b: f64) -> f64 {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// PROBLEM E1417: A NaN reading is reported as below the threshold
+pub fn e1417_bad_below_threshold(reading: f32, threshold: f32) -> bool {
+    reading < threshold
+}
+
+/// Entry point for problem demonstration
+pub fn e1417_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1417_bad_larger(f64::NAN, 1.0));
+    println!("{}", e1417_bad_below_threshold(f32::NAN, 1.0));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Total ordering gives NaN a defined place
+pub fn e1417_good_larger(a: f64, b: f64) -> f64 {
+    if a.total_cmp(&b).is_gt() {
+        a
+    } else {
+        b
+    }
+}
+
+/// GOOD: NaN readings are rejected explicitly
+pub fn e1417_good_below_threshold(reading: f32, threshold: f32) -> Option<bool> {
+    reading.partial_cmp(&threshold).map(|order| order.is_lt())
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_larger() {
+        assert_eq!(e1417_good_larger(2.0, 1.0), 2.0);
+        assert!(e1417_good_larger(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_below_threshold() {
+        assert_eq!(e1417_good_below_threshold(0.5, 1.0), Some(true));
+        assert_eq!(e1417_good_below_threshold(f32::NAN, 1.0), None);
+    }
+}
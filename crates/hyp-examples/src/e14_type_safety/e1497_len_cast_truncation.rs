@@ -0,0 +1,90 @@
+/// E1497: Collection length cast to a narrower integer
+/// Severity: MED
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `.len()` always returns `usize`, which is 64 bits wide on most targets. Casting
+/// it with `as i32`, `as u32`, `as u16` or `as u8` keeps only the low bits, so a large collection
+/// reports a wrong (or even negative) length without any error. Length fields in wire formats and
+/// FFI calls are the usual victims.
+///
+/// ## The Truncation Problem
+///
+/// ```text
+/// let v = vec![0u8; 3_000_000_000];
+/// let n = v.len() as i32;   // -1_294_967_296, not 3 billion!
+/// ```
+///
+/// ## The Right Solutions
+///
+/// ### Option 1: Checked conversion
+/// ```rust
+/// fn len_u32(v: &[u8]) -> Option<u32> {
+///     u32::try_from(v.len()).ok()
+/// }
+/// ```
+///
+/// ### Option 2: Keep usize, or widen to u64 (never loses bits)
+/// ```rust
+/// fn len_u64(v: &[u8]) -> u64 {
+///     v.len() as u64
+/// }
+/// ```
+///
+/// Mitigation: Use `#![warn(clippy::cast_possible_truncation)]` and
+/// `#![warn(clippy::cast_possible_wrap)]`. Prefer `try_from` for length fields.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1497: Length truncated (and possibly negative) as i32
+pub fn e1497_bad_len_as_i32(items: &[u8]) -> i32 {
+    items.len() as i32
+}
+
+/// PROBLEM E1497: Length prefix for a wire format silently truncated
+pub fn e1497_bad_len_prefix(payload: &[u8]) -> [u8; 2] {
+    let len = payload.len() as u16;
+    len.to_be_bytes()
+}
+
+/// Entry point for problem demonstration
+pub fn e1497_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let data = [1u8, 2, 3];
+    let _ = e1497_bad_len_as_i32(&data);
+    let _ = e1497_bad_len_prefix(&data);
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Keep the length as usize end to end
+pub fn e1497_good_keep_usize(items: &[u8]) -> usize {
+    items.len()
+}
+
+/// GOOD: Checked conversion surfaces the overflow
+pub fn e1497_good_try_from(payload: &[u8]) -> Option<[u8; 2]> {
+    u16::try_from(payload.len()).ok().map(u16::to_be_bytes)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keep_usize() {
+        assert_eq!(e1497_good_keep_usize(&[1, 2, 3]), 3);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(e1497_good_try_from(&[1, 2, 3]), Some([0, 3]));
+    }
+}
@@ -0,0 +1,121 @@
+/// E1499: Derived Clone shares mutable state
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `#[derive(Clone)]` clones each field with its own `Clone` impl. For `Arc<T>` and
+/// `Rc<T>` that means bumping a reference count, not copying `T`. When `T` is a `Mutex`,
+/// `RefCell` or atomic, every "clone" is another handle to the same mutable state: a write through
+/// one is visible through all of them. That is often exactly what a handle type wants, but a
+/// caller reading `let snapshot = state.clone();` reasonably expects an independent value.
+///
+/// ## The Shared Clone Problem
+///
+/// ```text
+/// #[derive(Clone)]
+/// struct Counter { hits: Arc<Mutex<u64>> }
+///
+/// let a = Counter::default();
+/// let b = a.clone();
+/// *b.hits.lock().unwrap() += 1;   // a sees the increment too!
+/// ```
+///
+/// ## The Right Solutions
+///
+/// ### Option 1: Deep-copy in a manual Clone impl
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+///
+/// struct Counter { hits: Arc<Mutex<u64>> }
+///
+/// impl Clone for Counter {
+///     fn clone(&self) -> Self {
+///         let value = self.hits.lock().map(|v| *v).unwrap_or_default();
+///         Counter { hits: Arc::new(Mutex::new(value)) }
+///     }
+/// }
+/// ```
+///
+/// ### Option 2: Keep the shared handle but make it explicit
+/// Don't derive `Clone`; expose a `handle()` method (or name the type `CounterHandle`) so sharing
+/// is part of the API contract.
+///
+/// Mitigation: Reserve derived `Clone` on `Arc<Mutex<_>>` fields for handle types whose docs say
+/// clones share state.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1499: Clone looks like a snapshot but shares the counter
+#[derive(Clone, Default)]
+pub struct E1499BadCounter {
+    hits: Arc<Mutex<u64>>,
+}
+
+/// PROBLEM E1499: Cloned history shares the same buffer
+#[derive(Clone, Default)]
+pub struct E1499BadHistory {
+    entries: Rc<RefCell<Vec<String>>>,
+}
+
+/// PROBLEM E1499: "Copy" of the counter is modified through the original
+pub fn e1499_bad_clone_counter() -> u64 {
+    let original = E1499BadCounter::default();
+    let copy = original.clone();
+    if let Ok(mut hits) = original.hits.lock() {
+        *hits = 1;
+    }
+    copy.hits.lock().map(|hits| *hits).unwrap_or_default()
+}
+
+/// PROBLEM E1499: Pushing to the clone also changes the original
+pub fn e1499_bad_clone_history() -> usize {
+    let original = E1499BadHistory::default();
+    let copy = original.clone();
+    copy.entries.borrow_mut().push("event".to_string());
+    let len = original.entries.borrow().len();
+    len
+}
+
+/// Entry point for problem demonstration
+pub fn e1499_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = e1499_bad_clone_counter();
+    let _ = e1499_bad_clone_history();
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Plain owned data, clones are independent
+#[derive(Clone, Default)]
+pub struct E1499GoodHistory {
+    entries: Vec<&'static str>,
+}
+
+/// GOOD: Clone of owned data does not affect the original
+pub fn e1499_good_independent_clone() -> usize {
+    let original = E1499GoodHistory::default();
+    let mut copy = original.clone();
+    copy.entries.push("event");
+    original.entries.len()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_independent_clone() {
+        assert_eq!(e1499_good_independent_clone(), 0);
+    }
+}
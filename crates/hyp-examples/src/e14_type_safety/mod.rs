@@ -13,3 +13,8 @@ pub mod e1410_float_equality;
 pub mod e1411_type_confusion_transmute;
 pub mod e1412_prohibit_unions;
 pub mod e1413_64bit_integer_overflow;
+pub mod e1414_sign_changing_cast;
+pub mod e1416_unchecked_shift;
+pub mod e1417_float_ordering;
+pub mod e1497_len_cast_truncation;
+pub mod e1499_derive_clone_shared_state;
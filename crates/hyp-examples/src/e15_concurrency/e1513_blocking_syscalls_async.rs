@@ -6,10 +6,14 @@
 /// preventing other tasks from making progress. With a limited thread pool (typically
 /// equal to CPU cores), even a single blocking call can cause cascading timeouts.
 ///
+/// Only code lexically inside an `async fn` or `async` block is affected: the same call in a
+/// plain `fn`, or in a closure handed to `spawn_blocking`, runs on a thread that may block.
+///
 /// Mitigation: Use async alternatives:
 /// - tokio::fs::* for file I/O
 /// - tokio::net::* for networking
 /// - tokio::time::sleep for sleeping
+/// - tokio::io::stdin with AsyncBufReadExt for console input
 use std::time::Duration;
 
 // ============================================================================
@@ -49,6 +53,18 @@ pub async fn e1513_bad_multiple_blocking() {
     std::fs::write("c.txt", "data").ok();
 }
 
+/// PROBLEM E1513: An async block spawned from sync code is still async context
+pub fn e1513_bad_spawned_block() -> tokio::task::JoinHandle<String> {
+    tokio::spawn(async { std::fs::read_to_string("config.json").unwrap_or_default() })
+}
+
+/// PROBLEM E1513: Waiting for console input parks the executor thread
+pub async fn e1513_bad_stdin_read_line() -> String {
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line).unwrap_or_default();
+    line
+}
+
 pub fn e1513_entry() -> Result<(), Box<dyn std::error::Error>> {
     // Note: These are intentionally not awaited for the example
     Ok(())
@@ -67,9 +83,9 @@ pub async fn e1513_good_fs_read() -> String {
 }
 
 /// GOOD: Use tokio::fs::write
-pub async fn e1513_good_fs_write(data: &[u8]) {
+pub async fn e1513_good_fs_write(data: &[u8]) -> std::io::Result<()> {
     // Non-blocking async file write
-    tokio::fs::write("output.txt", data).await.unwrap();
+    tokio::fs::write("output.txt", data).await
 }
 
 /// GOOD: Use tokio::time::sleep
@@ -99,6 +115,20 @@ pub async fn e1513_good_spawn_blocking() -> String {
     .unwrap_or_default()
 }
 
+/// GOOD: Blocking I/O is fine in synchronous code
+pub fn e1513_good_sync_file_size(path: &std::path::Path) -> std::io::Result<u64> {
+    std::fs::metadata(path).map(|meta| meta.len())
+}
+
+/// GOOD: Read console input through tokio's async stdin
+pub async fn e1513_good_stdin_read_line() -> std::io::Result<String> {
+    use tokio::io::AsyncBufReadExt;
+
+    let mut line = String::new();
+    tokio::io::BufReader::new(tokio::io::stdin()).read_line(&mut line).await?;
+    Ok(line)
+}
+
 /// GOOD: Use async file operations with proper error handling
 pub async fn e1513_good_config_read() -> Result<String, Box<dyn std::error::Error>> {
     let content = tokio::fs::read_to_string("config.json").await?;
@@ -119,6 +149,11 @@ mod tests {
         // This works correctly without blocking
     }
 
+    #[test]
+    fn test_sync_file_size_missing_file() {
+        assert!(e1513_good_sync_file_size(std::path::Path::new("/nonexistent/e1513")).is_err());
+    }
+
     #[tokio::test]
     async fn test_spawn_blocking() {
         let result = tokio::task::spawn_blocking(|| 42).await;
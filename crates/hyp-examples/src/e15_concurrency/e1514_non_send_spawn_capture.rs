@@ -0,0 +1,109 @@
+/// E1514: !Send value captured by spawned task
+/// Severity: MED
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `tokio::spawn` and `std::thread::spawn` may run the task on another thread, so
+/// everything the task captures must be `Send`. Capturing an `Rc`, a `RefCell` reference, a raw
+/// pointer or a `MutexGuard` fails to compile, and the compiler error points at a trait bound
+/// deep inside the future type rather than at the variable. E1514 names the captured variable.
+///
+/// ## The Non-Send Capture Problem
+///
+/// ```text
+/// let state = Rc::new(State::default());
+/// tokio::spawn(async move {
+///     state.tick().await;      // error: `Rc<State>` cannot be sent between threads safely
+/// });
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// let state = Arc::new(5);
+/// let value = std::thread::scope(|s| s.spawn(move || *state).join().unwrap());
+/// assert_eq!(value, 5);
+/// ```
+///
+/// Mitigation: Use `Arc` instead of `Rc` and `Mutex`/`RwLock` instead of `RefCell`, read what
+/// the task needs out of a guard before spawning, or keep the task on one thread with
+/// `spawn_local`.
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::scope;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// Stand-in for `tokio::spawn` without the `Send` bound, so the problems below compile
+fn spawn<F: Future<Output = ()> + 'static>(task: F) {
+    drop(task);
+}
+
+/// PROBLEM E1514: Rc captured by an async move task
+pub fn e1514_bad_rc_capture(names: Vec<String>) {
+    let shared = Rc::new(names);
+    spawn(async move {
+        let _ = shared.len();
+    });
+}
+
+/// PROBLEM E1514: RefCell shared with a spawned task
+pub fn e1514_bad_refcell_capture(log: Rc<RefCell<Vec<String>>>) {
+    spawn(async move {
+        log.borrow_mut().push(String::from("started"));
+    });
+}
+
+/// Entry point for problem demonstration
+pub fn e1514_entry() -> Result<(), Box<dyn std::error::Error>> {
+    e1514_bad_rc_capture(vec![String::from("a")]);
+    e1514_bad_refcell_capture(Rc::new(RefCell::new(Vec::new())));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Arc is Send, so the thread can own a handle to the shared data
+pub fn e1514_good_arc_capture(names: Arc<Vec<String>>) -> usize {
+    scope(|s| {
+        let handle = s.spawn(move || names.len());
+        handle.join().unwrap_or_default()
+    })
+}
+
+/// GOOD: Read the value under the lock, then spawn with the plain value
+pub fn e1514_good_copy_before_spawn(counter: &Mutex<usize>) -> usize {
+    let value = *counter.lock().unwrap_or_else(PoisonError::into_inner);
+    scope(|s| {
+        let handle = s.spawn(move || value);
+        handle.join().unwrap_or_default()
+    })
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arc_capture() {
+        let names = Arc::new(vec![String::from("a"), String::from("b")]);
+        assert_eq!(e1514_good_arc_capture(names), 2);
+    }
+
+    #[test]
+    fn test_copy_before_spawn() {
+        let counter = Mutex::new(3);
+        assert_eq!(e1514_good_copy_before_spawn(&counter), 3);
+    }
+}
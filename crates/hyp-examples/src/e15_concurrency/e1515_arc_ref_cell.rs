@@ -0,0 +1,83 @@
+/// E1515: RefCell or Cell shared through Arc
+/// Severity: MEDIUM
+/// LLM confusion: 3 (MED)
+///
+/// Description: `Arc` exists to share a value between threads, and `RefCell` exists to mutate a
+/// value that is only ever touched from one thread. Put together, `Arc<RefCell<T>>` is neither
+/// `Send` nor `Sync` (because `RefCell` is not `Sync`), so the first time it is handed to
+/// `thread::spawn` or `tokio::spawn` the build fails with a long trait-bound error that points at
+/// the spawn, not at the type chosen several modules away. Until then it compiles fine, which is
+/// why it spreads: it looks thread-safe because of the `Arc`. On a single thread the atomic
+/// reference count is pure overhead over `Rc`.
+///
+/// ## The Looks-Thread-Safe Problem
+///
+/// ```text
+/// let cache = Arc::new(RefCell::new(Vec::new()));
+/// let worker_cache = Arc::clone(&cache);
+/// std::thread::spawn(move || worker_cache.borrow_mut().push(1));
+/// // error[E0277]: `RefCell<Vec<i32>>` cannot be shared between threads safely
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+///
+/// let cache = Arc::new(Mutex::new(Vec::new()));
+/// let worker_cache = Arc::clone(&cache);
+/// std::thread::spawn(move || worker_cache.lock().unwrap_or_else(|e| e.into_inner()).push(1))
+///     .join()
+///     .ok();
+/// ```
+///
+/// Mitigation: Use `Arc<Mutex<T>>` / `Arc<RwLock<T>>` (or an atomic instead of `Cell`) when the
+/// value is shared between threads, and `Rc<RefCell<T>>` when it never leaves its thread.
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1515: an Arc that can never cross a thread boundary
+#[allow(clippy::arc_with_non_send_sync)]
+pub fn e1515_bad_shared_log() -> usize {
+    let log = Arc::new(RefCell::new(Vec::new()));
+    let writer = Arc::clone(&log);
+    writer.borrow_mut().push("started");
+    let entries = log.borrow().len();
+    entries
+}
+
+/// Entry point for problem demonstration
+pub fn e1515_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1515_bad_shared_log());
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: A Mutex makes the shared log usable from other threads
+pub fn e1515_good_shared_log() -> usize {
+    let log = Arc::new(Mutex::new(vec!["started"]));
+    let reader = Arc::clone(&log);
+    let entries = reader.lock().map(|entries| entries.len()).unwrap_or(0);
+    entries
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_log() {
+        assert_eq!(e1515_good_shared_log(), 1);
+    }
+}
@@ -0,0 +1,108 @@
+/// E1519: Unbounded spawning in accept/receive loop
+/// Severity: HIGH
+/// LLM confusion: 3 (MED)
+///
+/// Description: A server loop that accepts a connection (or receives a message) and spawns a
+/// task for it looks correct and works in testing. In production the number of live tasks
+/// tracks the incoming rate: a traffic spike or a slow downstream service leaves thousands of
+/// tasks or threads alive at once, each holding a socket and buffers, until memory or file
+/// descriptors run out. Nothing in the loop ever waits for earlier work to finish.
+///
+/// ## The Accept-And-Spawn Problem
+///
+/// ```text
+/// loop {
+///     let (socket, _) = listener.accept().await?;
+///     tokio::spawn(handle(socket));          // no limit on live tasks
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use futures::StreamExt;
+///
+/// async fn handle_all(jobs: Vec<u64>, limit: usize) -> Vec<u64> {
+///     futures::stream::iter(jobs)
+///         .map(|job| async move { job })
+///         .buffer_unordered(limit)        // at most `limit` in flight
+///         .collect()
+///         .await
+/// }
+/// ```
+///
+/// Mitigation: Acquire a `Semaphore` permit before each spawn and hold it in the task, cap a
+/// `JoinSet` by waiting on `join_next()`, or feed a fixed pool of workers through a bounded
+/// channel. E1511 flags spawning in any loop; this check targets server-style loops.
+
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::Receiver;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+fn e1519_handle(stream: TcpStream) -> Option<std::net::SocketAddr> {
+    stream.peer_addr().ok()
+}
+
+/// PROBLEM E1519: One thread per accepted connection, no limit
+pub fn e1519_bad_accept_loop(listener: &TcpListener) -> std::io::Result<()> {
+    loop {
+        let (stream, _) = listener.accept()?;
+        std::thread::spawn(move || e1519_handle(stream));
+    }
+}
+
+/// PROBLEM E1519: One task per received message
+pub async fn e1519_bad_receive_loop(mut jobs: tokio::sync::mpsc::Receiver<u64>) {
+    while let Some(job) = jobs.recv().await {
+        tokio::spawn(async move { job.checked_mul(2) });
+    }
+}
+
+/// PROBLEM E1519: One thread per message from a std channel
+pub fn e1519_bad_std_receive_loop(jobs: Receiver<u64>) {
+    while let Ok(job) = jobs.recv() {
+        std::thread::spawn(move || job.checked_mul(2));
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1519_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    drop(tx);
+    e1519_bad_std_receive_loop(rx);
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: At most `limit` jobs are in flight at any time
+pub async fn e1519_good_buffer_unordered(jobs: Vec<u64>, limit: usize) -> Vec<u64> {
+    use futures::StreamExt;
+
+    futures::stream::iter(jobs)
+        .map(|job| async move { job.saturating_mul(2) })
+        .buffer_unordered(limit.max(1))
+        .collect()
+        .await
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_buffer_unordered() {
+        let mut doubled = e1519_good_buffer_unordered(vec![1, 2, 3], 2).await;
+        doubled.sort_unstable();
+        assert_eq!(doubled, vec![2, 4, 6]);
+    }
+}
@@ -0,0 +1,84 @@
+/// E1520: Unbounded channel created
+/// Severity: MEDIUM
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `std::sync::mpsc::channel()` and `tokio::sync::mpsc::unbounded_channel()` never
+/// make the sender wait. While the consumer keeps up this is invisible, but when it slows down
+/// (a stalled database, a burst of traffic) every message is kept in memory and the queue grows
+/// until the process is killed. A bounded channel turns that overload into backpressure: the
+/// producer waits, or sees an error from `try_send`, instead of allocating forever.
+///
+/// ## The Growing Queue Problem
+///
+/// ```text
+/// let (tx, rx) = std::sync::mpsc::channel();
+/// // producer: tx.send(event) never blocks, even with a million queued events
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// let (tx, rx) = std::sync::mpsc::sync_channel::<u64>(64);
+/// tx.send(1).ok();               // waits once 64 messages are queued
+/// assert_eq!(rx.recv().ok(), Some(1));
+/// ```
+///
+/// Mitigation: Use `std::sync::mpsc::sync_channel(n)` or `tokio::sync::mpsc::channel(n)` and pick
+/// a capacity that bounds memory. Where unbounded is intentional, list the file in the checker's
+/// `allowed_files`.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1520: std channel with no capacity limit
+pub fn e1520_bad_std_channel(events: &[u64]) -> usize {
+    let (tx, rx) = std::sync::mpsc::channel();
+    for event in events {
+        tx.send(*event).ok();
+    }
+    drop(tx);
+    rx.iter().count()
+}
+
+/// PROBLEM E1520: tokio unbounded channel
+pub fn e1520_bad_tokio_unbounded(event: u64) -> bool {
+    let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+    tx.send(event).is_ok()
+}
+
+/// Entry point for problem demonstration
+pub fn e1520_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1520_bad_std_channel(&[1, 2, 3]));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Bounded channel; `try_send` reports a full queue instead of growing it
+pub fn e1520_good_sync_channel(events: &[u64], capacity: usize) -> Vec<u64> {
+    let (tx, rx) = std::sync::mpsc::sync_channel(capacity.max(1));
+    for event in events {
+        if tx.try_send(*event).is_err() {
+            break;
+        }
+    }
+    drop(tx);
+    rx.iter().collect()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_channel() {
+        assert_eq!(e1520_good_sync_channel(&[1, 2, 3], 2), vec![1, 2]);
+    }
+}
@@ -0,0 +1,109 @@
+/// E1521: Arc cloned in loop without being moved
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `Arc::clone` does not copy the data, but it is not free either: every clone is
+/// an atomic increment of the reference count and every drop an atomic decrement, and under
+/// contention those atomics bounce a cache line between cores. Cloning an `Arc` at the top of a
+/// loop body "to be safe" and then only calling methods on the clone pays that cost on every
+/// iteration for nothing - the original `Arc` can simply be borrowed. A per-iteration clone is
+/// only needed when the handle is moved somewhere that outlives the iteration, such as a
+/// spawned task.
+///
+/// ## The Needless Clone Problem
+///
+/// ```text
+/// for line in lines {
+///     let index = Arc::clone(&index);   // atomic inc
+///     index.lookup(line);               // only needs &index
+/// }                                     // atomic dec
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// let index = Arc::new(vec![1, 2, 3]);
+/// let found = [2, 5].iter().filter(|n| index.contains(n)).count();
+/// assert_eq!(found, 1);
+/// ```
+///
+/// Mitigation: Borrow the `Arc` inside the loop, or clone it once before the loop. Keep the
+/// per-iteration clone only when it is moved into a `move` closure, an `async move` block or
+/// another owner.
+use std::sync::Arc;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1521: the clone is only used to call a method
+pub fn e1521_bad_clone_per_item(index: Arc<Vec<u64>>, wanted: &[u64]) -> usize {
+    let mut found = 0;
+    for value in wanted {
+        let index = Arc::clone(&index);
+        if index.contains(value) {
+            found += 1;
+        }
+    }
+    found
+}
+
+/// Entry point for problem demonstration
+pub fn e1521_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1521_bad_clone_per_item(Arc::new(vec![1, 2, 3]), &[2, 5]));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Borrow the Arc inside the loop
+pub fn e1521_good_borrow(index: &Arc<Vec<u64>>, wanted: &[u64]) -> usize {
+    wanted.iter().filter(|value| index.contains(value)).count()
+}
+
+/// A pending lookup that owns its handle to the shared index
+pub struct E1521Lookup {
+    index: Arc<Vec<u64>>,
+    value: u64,
+}
+
+impl E1521Lookup {
+    /// Whether the value is in the index
+    pub fn run(&self) -> bool {
+        self.index.contains(&self.value)
+    }
+}
+
+/// GOOD: Clone per iteration because each lookup takes ownership of its handle
+pub fn e1521_good_clone_into_owner(index: &Arc<Vec<u64>>, wanted: &[u64]) -> Vec<E1521Lookup> {
+    let mut lookups = Vec::with_capacity(wanted.len());
+    for value in wanted {
+        let index = Arc::clone(index);
+        lookups.push(E1521Lookup { index, value: *value });
+    }
+    lookups
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_borrow() {
+        assert_eq!(e1521_good_borrow(&Arc::new(vec![1, 2, 3]), &[2, 5]), 1);
+    }
+
+    #[test]
+    fn test_clone_into_owner() {
+        let lookups = e1521_good_clone_into_owner(&Arc::new(vec![1, 2, 3]), &[1, 5]);
+        assert_eq!(lookups.iter().map(E1521Lookup::run).collect::<Vec<_>>(), vec![true, false]);
+    }
+}
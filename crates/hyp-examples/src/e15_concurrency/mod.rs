@@ -13,3 +13,8 @@ pub mod e1510_mutex_instead_of_rwlock;
 pub mod e1511_unbounded_spawning;
 pub mod e1512_prohibit_std_thread_spawn;
 pub mod e1513_blocking_syscalls_async;
+pub mod e1514_non_send_spawn_capture;
+pub mod e1515_arc_ref_cell;
+pub mod e1519_unbounded_accept_loop;
+pub mod e1520_unbounded_channel;
+pub mod e1521_arc_clone_in_loop;
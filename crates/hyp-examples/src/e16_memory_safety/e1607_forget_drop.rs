@@ -50,9 +50,11 @@
 /// // Later: let _ = unsafe { Box::from_raw(ptr) };
 /// ```
 ///
-/// Mitigation: Avoid `mem::forget` unless you have a very specific reason. Use `ManuallyDrop`
-/// for explicit control over when destructors run. Understand that forgetting is safe but can
-/// leak resources. Use `#![warn(clippy::mem_forget)]` to detect forget calls.
+/// Mitigation: Avoid `mem::forget` unless you have a very specific reason, such as handing
+/// ownership to foreign code; list such FFI modules in the checker's `allowed_paths`. Use
+/// `ManuallyDrop` for explicit control over when destructors run, and release it with
+/// `ManuallyDrop::drop`, `take` or `into_inner`. Understand that forgetting is safe but can leak
+/// resources. Use `#![warn(clippy::mem_forget)]` to detect forget calls.
 
 use std::mem::ManuallyDrop;
 
@@ -76,6 +78,12 @@ pub fn e1607_bad_forget_file() {
     }
 }
 
+/// PROBLEM E1607: ManuallyDrop that is never dropped, taken or unwrapped leaks as well
+pub fn e1607_bad_manually_drop() -> usize {
+    let data = ManuallyDrop::new(vec![1, 2, 3]);
+    data.len()
+}
+
 /// Entry point for problem demonstration
 pub fn e1607_entry() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
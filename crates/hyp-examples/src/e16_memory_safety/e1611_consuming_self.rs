@@ -111,6 +111,7 @@ impl Builder {
     }
 
     /// GOOD: Builder pattern - returns Self
+    #[must_use]
     pub fn e1611_good_with_value(mut self, v: i32) -> Self {
         self.value = v;
         self
@@ -0,0 +1,98 @@
+/// E1613: Reference to a temporary returned from a function
+/// Severity: MED
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A function declared to return `&str` or `&[T]` must return a borrow of data that
+/// outlives the call. `&self.id.to_string()` or `&format!(..)` borrows a value created inside the
+/// function, which is dropped as soon as it returns. The compiler rejects this with "cannot
+/// return reference to temporary value" (E0515), and the usual beginner reaction - adding
+/// lifetimes - cannot fix it. Either borrow the field that already exists or return the owned
+/// value.
+///
+/// ## The Temporary Borrow Problem
+///
+/// ```text
+/// impl User {
+///     fn name(&self) -> &str {
+///         &self.name.to_string()   // error[E0515]: cannot return reference to temporary value
+///     }
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// struct User { name: String }
+///
+/// impl User {
+///     fn name(&self) -> &str {
+///         &self.name               // borrows data owned by `self`
+///     }
+/// }
+/// ```
+///
+/// Mitigation: Return references only to data owned by the arguments. When the value has to be
+/// computed, return it by value (`String`, `Vec<T>`).
+///
+/// NOTE: The bad examples below are disabled with `#[cfg(any())]` (always false) because they
+/// do not compile. Hyp parses source without evaluating `cfg`, so it still sees them.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1613: Getter returning a borrow of a freshly built String
+pub fn e1613_bad_name_getter() {
+    #[cfg(any())]
+    struct User {
+        name: String,
+    }
+
+    #[cfg(any())]
+    impl User {
+        fn name(&self) -> &str {
+            &self.name.to_string()
+        }
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1613_entry() -> Result<(), Box<dyn std::error::Error>> {
+    e1613_bad_name_getter();
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// User with a name owned by the struct
+pub struct E1613User {
+    name: String,
+}
+
+impl E1613User {
+    /// Create a user
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string() }
+    }
+
+    /// GOOD: Borrow the field itself, which lives as long as `self`
+    pub fn e1613_good_name(&self) -> &str {
+        &self.name
+    }
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name() {
+        assert_eq!(E1613User::new("ada").e1613_good_name(), "ada");
+    }
+}
@@ -12,3 +12,4 @@ pub mod e1609_invalid_slice;
 pub mod e1610_unaligned_deref;
 pub mod e1611_consuming_self;
 pub mod e1612_prohibit_custom_allocators;
+pub mod e1613_temp_borrow_return;
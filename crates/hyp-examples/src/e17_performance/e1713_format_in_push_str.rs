@@ -0,0 +1,93 @@
+/// E1713: format! appended to a String
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `out.push_str(&format!("{}={}", key, value))` reads naturally, but `format!`
+/// allocates a brand-new `String`, `push_str` copies it into `out`, and the temporary is freed
+/// right away. `String` implements `std::fmt::Write`, so `write!(out, ...)` formats straight
+/// into the buffer that already exists. In a loop or a hot path the difference is one heap
+/// allocation per call. This is reported with or without a loop (see E1703 for loops).
+///
+/// ## The Throwaway String Problem
+///
+/// ```text
+/// let mut out = String::new();
+/// out.push_str(&format!("{}={}", key, value));  // temporary String
+/// out += &format!(" ({})", unit);                 // and another
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::fmt::Write;
+///
+/// fn render(key: &str, value: u32, unit: &str) -> Result<String, std::fmt::Error> {
+///     let mut out = String::new();
+///     write!(out, "{}={} ({})", key, value, unit)?;
+///     Ok(out)
+/// }
+/// ```
+///
+/// Mitigation: Bring `std::fmt::Write` into scope and use `write!`/`writeln!` on the target
+/// String. Keep `format!` for producing a new String that is used on its own.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1713: push_str of a freshly formatted String
+pub fn e1713_bad_push_str_format(key: &str, value: u32) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("{}={}", key, value));
+    out
+}
+
+/// PROBLEM E1713: += of a freshly formatted String
+pub fn e1713_bad_add_assign_format(out: &mut String, unit: &str) {
+    *out += &format!(" ({})", unit);
+}
+
+/// Entry point for problem demonstration
+pub fn e1713_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let mut out = e1713_bad_push_str_format("timeout", 30);
+    e1713_bad_add_assign_format(&mut out, "s");
+    println!("{}", out);
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: write! formats directly into the buffer
+pub fn e1713_good_write(key: &str, value: u32, unit: &str) -> Result<String, std::fmt::Error> {
+    use std::fmt::Write;
+
+    let mut out = String::new();
+    write!(out, "{}={} ({})", key, value, unit)?;
+    Ok(out)
+}
+
+/// GOOD: format! used on its own to build a new String
+pub fn e1713_good_standalone_format(key: &str, value: u32) -> String {
+    format!("{}={}", key, value)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        assert_eq!(e1713_good_write("timeout", 30, "s"), Ok("timeout=30 (s)".to_string()));
+    }
+
+    #[test]
+    fn test_standalone_format() {
+        assert_eq!(e1713_good_standalone_format("retries", 3), "retries=3");
+    }
+}
@@ -0,0 +1,97 @@
+/// E1718: Removing or inserting at the front of a Vec
+/// Severity: MED (HIGH inside loops)
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `Vec::remove(0)` and `Vec::insert(0, x)` move every other element by one slot,
+/// so each call costs O(n). Code that keeps taking items from the front of a `Vec` is using it as
+/// a queue; doing so in a loop turns a linear job into a quadratic one that is fast in tests and
+/// slow on real input. `VecDeque` is a ring buffer with O(1) `pop_front()`/`push_front()`.
+///
+/// ## The Front Removal Problem
+///
+/// ```text
+/// while !jobs.is_empty() {
+///     let job = jobs.remove(0);    // shifts all remaining jobs every iteration
+///     run(job);
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::collections::VecDeque;
+///
+/// let mut jobs: VecDeque<u32> = VecDeque::from(vec![1, 2, 3]);
+/// while let Some(job) = jobs.pop_front() {   // O(1)
+///     println!("{job}");
+/// }
+/// ```
+///
+/// Mitigation: Use `VecDeque` for FIFO queues. If only the order matters, reverse the Vec once
+/// and work at the end with `pop()`/`push()`.
+use std::collections::VecDeque;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1718: Processing a Vec as a queue from the front
+pub fn e1718_bad_process_queue(mut jobs: Vec<u32>) -> u32 {
+    let mut total = 0;
+    while !jobs.is_empty() {
+        total += jobs.remove(0);
+    }
+    total
+}
+
+/// PROBLEM E1718: Prepending to a Vec
+pub fn e1718_bad_prepend(history: &mut Vec<String>, entry: String) {
+    history.insert(0, entry);
+}
+
+/// Entry point for problem demonstration
+pub fn e1718_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = e1718_bad_process_queue(vec![1, 2, 3]);
+    let mut history = Vec::new();
+    e1718_bad_prepend(&mut history, String::from("first"));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: VecDeque pops from the front in O(1)
+pub fn e1718_good_process_queue(mut jobs: VecDeque<u32>) -> Vec<u32> {
+    let mut processed = Vec::with_capacity(jobs.len());
+    while let Some(job) = jobs.pop_front() {
+        processed.push(job.saturating_mul(2));
+    }
+    processed
+}
+
+/// GOOD: VecDeque pushes to the front in O(1)
+pub fn e1718_good_prepend(history: &mut VecDeque<String>, entry: &str) {
+    history.push_front(entry.to_string());
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_queue() {
+        assert_eq!(e1718_good_process_queue(VecDeque::from(vec![1, 2, 3])), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut history = VecDeque::from(vec![String::from("old")]);
+        e1718_good_prepend(&mut history, "new");
+        assert_eq!(history.front().map(String::as_str), Some("new"));
+    }
+}
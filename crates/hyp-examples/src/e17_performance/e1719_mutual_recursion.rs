@@ -0,0 +1,84 @@
+/// E1719: Mutual recursion between functions
+/// Severity: MED
+/// LLM confusion: 3 (MED)
+///
+/// Description: Two functions that call each other recurse just like one function calling
+/// itself: every round trip adds stack frames, and Rust never turns those calls into jumps.
+/// Because the recursion is split across functions, neither of them looks recursive on its own,
+/// and a deep enough input overflows the stack in production rather than in review.
+///
+/// ## The Hidden Recursion Problem
+///
+/// ```text
+/// fn is_even(n: u64) -> bool { if n == 0 { true } else { is_odd(n - 1) } }
+/// fn is_odd(n: u64) -> bool { if n == 0 { false } else { is_even(n - 1) } }
+/// // is_even(10_000_000) overflows the stack
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn is_even(n: u64) -> bool {
+///     n % 2 == 0
+/// }
+/// ```
+///
+/// Mitigation: Merge the functions into one loop or state machine, or drive the work with an
+/// explicit stack so recursion depth no longer depends on the input.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1719: Parity computed by bouncing between two functions
+pub fn e1719_bad_is_even(n: u32) -> bool {
+    if n == 0 {
+        true
+    } else {
+        e1719_bad_is_odd(n - 1)
+    }
+}
+
+/// PROBLEM E1719: The other half of the cycle
+pub fn e1719_bad_is_odd(n: u32) -> bool {
+    if n == 0 {
+        false
+    } else {
+        e1719_bad_is_even(n - 1)
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1719_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1719_bad_is_even(10));
+    println!("{}", e1719_bad_is_odd(7));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: A state machine in a loop instead of two recursive functions
+pub fn e1719_good_is_even(n: u32) -> bool {
+    let mut even = true;
+    for _ in 0..n {
+        even = !even;
+    }
+    even
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_even() {
+        assert!(e1719_good_is_even(10));
+        assert!(!e1719_good_is_even(7));
+    }
+}
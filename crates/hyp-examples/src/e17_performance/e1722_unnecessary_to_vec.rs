@@ -0,0 +1,90 @@
+/// E1722: Unnecessary copy before iterating
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `for x in items.to_vec()` allocates a new `Vec`, copies every element into it,
+/// walks it once and frees it. The copy is a temporary that nothing else ever sees, so the loop
+/// could have borrowed the original. The same goes for `items.clone().iter()`: `iter()` only
+/// borrows, so the clone buys nothing at all. These copies often appear when a borrow error was
+/// "fixed" by cloning until it compiled.
+///
+/// ## The Throwaway Copy Problem
+///
+/// ```text
+/// fn print_all(names: &[String]) {
+///     for name in names.to_vec() {    // allocates and clones every String
+///         println!("{}", name);
+///     }
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn print_all(names: &[String]) {
+///     for name in names {
+///         println!("{}", name);
+///     }
+/// }
+/// ```
+///
+/// Mitigation: Iterate the original by reference. If owned items are needed, use
+/// `.iter().cloned()` so only the items are copied, not the collection.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1722: the Vec is copied only to be looped over
+pub fn e1722_bad_longest(names: &[String]) -> usize {
+    let mut longest = 0;
+    for name in names.to_vec() {
+        longest = longest.max(name.len());
+    }
+    longest
+}
+
+/// PROBLEM E1722: iter() only borrows, the clone is pointless
+pub fn e1722_bad_sum(values: &Vec<u64>) -> u64 {
+    values.clone().iter().sum()
+}
+
+/// Entry point for problem demonstration
+pub fn e1722_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1722_bad_longest(&["ab".to_string()]));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Loop over the borrowed slice
+pub fn e1722_good_longest(names: &[String]) -> usize {
+    let mut longest = 0;
+    for name in names {
+        longest = longest.max(name.len());
+    }
+    longest
+}
+
+/// GOOD: Sum the borrowed values
+pub fn e1722_good_sum(values: &[u64]) -> u64 {
+    values.iter().sum()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_and_sum() {
+        let names = vec!["a".to_string(), "abc".to_string()];
+        assert_eq!(e1722_good_longest(&names), 3);
+        assert_eq!(e1722_good_sum(&[1, 2, 3]), 6);
+    }
+}
@@ -0,0 +1,102 @@
+/// E1723: Relying on HashMap iteration order
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `HashMap` and `HashSet` iterate in an arbitrary order, and with the default
+/// randomly seeded hasher that order changes from one run to the next. Pushing entries into a
+/// `Vec` or `String` while walking the map bakes that arbitrary order into the output: a report
+/// whose lines shuffle on every run, a test that passes locally and fails in CI, a generated file
+/// that produces a diff every time it is regenerated. Everything works, just never the same way
+/// twice.
+///
+/// ## The Shuffling Output Problem
+///
+/// ```text
+/// fn report(counts: &HashMap<String, usize>) -> Vec<String> {
+///     let mut lines = Vec::new();
+///     for (word, n) in counts {
+///         lines.push(format!("{}: {}", word, n));   // order differs per run
+///     }
+///     lines
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// fn report(counts: &BTreeMap<String, usize>) -> Vec<String> {
+///     counts.iter().map(|(word, n)| format!("{}: {}", word, n)).collect()
+/// }
+/// ```
+///
+/// Mitigation: Use `BTreeMap` / `BTreeSet` when the order is observable, or sort the collected
+/// result (or the keys) before using it.
+
+use std::collections::{BTreeMap, HashMap};
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1723: the report lines come out in a different order on every run
+pub fn e1723_bad_report(text: &str) -> Vec<String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_insert(0) += 1;
+    }
+
+    let mut lines = Vec::new();
+    for (word, count) in &counts {
+        lines.push(format!("{}: {}", word, count));
+    }
+    lines
+}
+
+/// Entry point for problem demonstration
+pub fn e1723_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{:?}", e1723_bad_report("a b a c"));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: A BTreeMap iterates in key order
+pub fn e1723_good_report(counts: &BTreeMap<String, usize>) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (word, count) in counts {
+        lines.push(format!("{}: {}", word, count));
+    }
+    lines
+}
+
+/// GOOD: Keep the HashMap, but sort before the order is observed
+pub fn e1723_good_sorted_keys(counts: &HashMap<String, usize>) -> Vec<&str> {
+    let mut keys = Vec::new();
+    for key in counts.keys() {
+        keys.push(key.as_str());
+    }
+    keys.sort();
+    keys
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reports_are_ordered() {
+        let words: BTreeMap<String, usize> = [("b".to_string(), 2), ("a".to_string(), 1)].into_iter().collect();
+        assert_eq!(e1723_good_report(&words), vec!["a: 1", "b: 2"]);
+
+        let counts: HashMap<String, usize> = [("z".to_string(), 1), ("m".to_string(), 2)].into_iter().collect();
+        assert_eq!(e1723_good_sorted_keys(&counts), vec!["m", "z"]);
+    }
+}
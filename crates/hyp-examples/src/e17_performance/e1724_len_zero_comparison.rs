@@ -0,0 +1,87 @@
+/// E1724: Comparing .len() to zero
+/// Severity: LOW
+/// LLM confusion: 1 (LOW)
+///
+/// Description: `items.len() == 0` asks "how many are there?" and then compares the answer with
+/// zero, when the question was only ever "is there anything?". `is_empty()` states that directly,
+/// reads the same for every collection and string type, and for some of them (linked structures,
+/// counting chars) is cheaper than computing the length. The `> 0` and `!= 0` forms are the same
+/// question negated.
+///
+/// ## The Roundabout Question Problem
+///
+/// ```text
+/// fn describe(tags: &[String]) -> &'static str {
+///     if tags.len() == 0 { "untagged" } else { "tagged" }
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn describe(tags: &[String]) -> &'static str {
+///     if tags.is_empty() { "untagged" } else { "tagged" }
+/// }
+/// ```
+///
+/// Mitigation: Use `x.is_empty()` for `x.len() == 0` and `!x.is_empty()` for `x.len() != 0` or
+/// `x.len() > 0`.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1724: counting to find out whether there is anything
+pub fn e1724_bad_describe(tags: &[String]) -> &'static str {
+    if tags.len() == 0 {
+        "untagged"
+    } else {
+        "tagged"
+    }
+}
+
+/// PROBLEM E1724: the negated form
+pub fn e1724_bad_has_name(name: &str) -> bool {
+    name.len() > 0
+}
+
+/// Entry point for problem demonstration
+pub fn e1724_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{} {}", e1724_bad_describe(&[]), e1724_bad_has_name("x"));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: is_empty says what is asked
+pub fn e1724_good_describe(tags: &[String]) -> &'static str {
+    if tags.is_empty() {
+        "untagged"
+    } else {
+        "tagged"
+    }
+}
+
+/// GOOD: negated is_empty
+pub fn e1724_good_has_name(name: &str) -> bool {
+    !name.is_empty()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_and_has_name() {
+        assert_eq!(e1724_good_describe(&[]), "untagged");
+        assert_eq!(e1724_good_describe(&["a".to_string()]), "tagged");
+        assert!(e1724_good_has_name("x"));
+        assert!(!e1724_good_has_name(""));
+    }
+}
@@ -0,0 +1,79 @@
+/// E1725: Clone immediately consumed
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `text.clone().into_bytes()` copies the whole string and then immediately hands
+/// the copy to a method that consumes it. The copy never exists as a value anyone can see; it is
+/// only there because the consuming method needs ownership. Either the original is not used
+/// afterwards and can be consumed directly, or only part of it is needed and a borrowing method
+/// (`as_bytes()`, `as_ref()`, `iter().cloned()`) copies just that. `.clone().to_owned()` and
+/// `.clone().to_string()` are worse: the second call copies again.
+///
+/// ## The Copy-To-Throw-Away Problem
+///
+/// ```text
+/// fn checksum(text: &String) -> u32 {
+///     text.clone().into_bytes().iter().map(|b| *b as u32).sum()
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn checksum(text: &str) -> u32 {
+///     text.as_bytes().iter().map(|b| u32::from(*b)).sum()
+/// }
+/// ```
+///
+/// Mitigation: Consume the original when it is no longer needed, or borrow what the consuming
+/// method was used for instead of cloning first.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1725: the String is copied only to be turned into bytes
+pub fn e1725_bad_checksum(text: &String) -> u32 {
+    text.clone().into_bytes().iter().map(|b| u32::from(*b)).sum()
+}
+
+/// PROBLEM E1725: cloned and then copied a second time
+pub fn e1725_bad_label(name: &String) -> String {
+    name.clone().to_owned()
+}
+
+/// Entry point for problem demonstration
+pub fn e1725_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let text = "abc".to_string();
+    println!("{} {}", e1725_bad_checksum(&text), e1725_bad_label(&text));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Borrow the bytes
+pub fn e1725_good_checksum(text: &str) -> u32 {
+    text.as_bytes().iter().map(|b| u32::from(*b)).sum()
+}
+
+/// GOOD: One copy, straight from the borrowed name
+pub fn e1725_good_label(name: &str) -> String {
+    name.to_owned()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum_and_label() {
+        assert_eq!(e1725_good_checksum("ab"), 195);
+        assert_eq!(e1725_good_label("ab"), "ab");
+    }
+}
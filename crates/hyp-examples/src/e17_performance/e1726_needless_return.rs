@@ -0,0 +1,93 @@
+/// E1726: Needless return in tail position
+/// Severity: LOW
+/// LLM confusion: 1 (LOW)
+///
+/// Description: In Rust a block evaluates to its last expression, so `return x;` as the final
+/// statement of a function or closure does the same as plain `x`. The keyword is noise there,
+/// and it costs something real: when every function ends in `return`, the early exits (the
+/// `return`s that actually change control flow) no longer stand out. Code written in the style
+/// of languages without expression blocks tends to carry this pattern along.
+///
+/// ## The Habitual Return Problem
+///
+/// ```text
+/// fn area(width: u32, height: u32) -> u32 {
+///     let area = width * height;
+///     return area;
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn area(width: u32, height: u32) -> u32 {
+///     width * height
+/// }
+/// ```
+///
+/// Mitigation: End blocks with the expression and keep `return` for early exits. Clippy's
+/// `needless_return` lint reports the same pattern.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1726: return as the last statement
+#[allow(clippy::needless_return)]
+pub fn e1726_bad_area(width: u32, height: u32) -> u32 {
+    let area = width * height;
+    return area;
+}
+
+/// PROBLEM E1726: tail return inside a closure body
+#[allow(clippy::needless_return)]
+pub fn e1726_bad_doubled(values: &[u32]) -> Vec<u32> {
+    values
+        .iter()
+        .map(|value| {
+            let doubled = value * 2;
+            return doubled;
+        })
+        .collect()
+}
+
+/// Entry point for problem demonstration
+pub fn e1726_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{} {:?}", e1726_bad_area(2, 3), e1726_bad_doubled(&[1, 2]));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: The block ends with its value
+pub fn e1726_good_area(width: u32, height: u32) -> u32 {
+    width * height
+}
+
+/// GOOD: return kept for the early exit only
+pub fn e1726_good_first_even(values: &[u32]) -> Option<u32> {
+    for value in values {
+        if value % 2 == 0 {
+            return Some(*value);
+        }
+    }
+    None
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_and_first_even() {
+        assert_eq!(e1726_good_area(2, 3), 6);
+        assert_eq!(e1726_good_first_even(&[1, 4, 6]), Some(4));
+        assert_eq!(e1726_good_first_even(&[1]), None);
+    }
+}
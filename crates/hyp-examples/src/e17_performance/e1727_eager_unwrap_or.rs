@@ -0,0 +1,100 @@
+/// E1727: Call evaluated eagerly in unwrap_or
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: Function arguments are evaluated before the function runs, and `unwrap_or`,
+/// `ok_or` and `map_or` are ordinary methods. In `cached.unwrap_or(load_defaults())`,
+/// `load_defaults()` runs every time, including when `cached` is `Some` and its result is thrown
+/// away. For a cheap literal that does not matter; for a call that allocates, reads a file or
+/// formats an error message, it is work done on the happy path for nothing, and a side effect
+/// (logging, a counter) that happens when it should not. The `_else` variants take a closure and
+/// call it only when the fallback is actually needed.
+///
+/// ## The Always-Computed Fallback Problem
+///
+/// ```text
+/// fn port(configured: Option<u16>) -> u16 {
+///     configured.unwrap_or(read_port_from_env())   // reads the env even when configured
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn port(configured: Option<u16>) -> u16 {
+///     configured.unwrap_or_else(|| read_port_from_env())
+/// }
+/// # fn read_port_from_env() -> u16 { 8080 }
+/// ```
+///
+/// Mitigation: Use `unwrap_or_else`, `ok_or_else` and `map_or_else` when the fallback is a call.
+/// Keep the eager forms for literals, variables and trivial constructors such as `Vec::new()`.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+fn fallback_name() -> String {
+    "anonymous".to_string()
+}
+
+/// PROBLEM E1727: the fallback String is built even when a name is given
+#[allow(clippy::or_fun_call)]
+pub fn e1727_bad_display_name(name: Option<&str>) -> String {
+    name.map(str::to_owned).unwrap_or(fallback_name())
+}
+
+/// PROBLEM E1727: the "missing" text is built even when the item is found
+#[allow(clippy::or_fun_call)]
+pub fn e1727_bad_describe(items: &[u32], wanted: u32) -> String {
+    items
+        .iter()
+        .position(|item| *item == wanted)
+        .map_or(describe_missing(wanted), |index| index.to_string())
+}
+
+fn describe_missing(wanted: u32) -> String {
+    let mut text = wanted.to_string();
+    text.push_str(" not found");
+    text
+}
+
+/// Entry point for problem demonstration
+pub fn e1727_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{} {:?}", e1727_bad_display_name(None), e1727_bad_describe(&[1, 2], 2));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: The fallback is only built for None
+pub fn e1727_good_display_name(name: Option<&str>) -> String {
+    name.map(str::to_owned).unwrap_or_else(fallback_name)
+}
+
+/// GOOD: The "missing" text is only built when the item is missing
+pub fn e1727_good_describe(items: &[u32], wanted: u32) -> String {
+    items
+        .iter()
+        .position(|item| *item == wanted)
+        .map_or_else(|| describe_missing(wanted), |index| index.to_string())
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_name_and_describe() {
+        assert_eq!(e1727_good_display_name(Some("ada")), "ada");
+        assert_eq!(e1727_good_display_name(None), "anonymous");
+        assert_eq!(e1727_good_describe(&[1, 2], 2), "1");
+        assert_eq!(e1727_good_describe(&[1, 2], 3), "3 not found");
+    }
+}
@@ -0,0 +1,100 @@
+/// E1730: collect() into String inside loop
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `iter.collect::<String>()` allocates a fresh `String`. Inside a loop that then
+/// appends the result to an outer buffer with `push_str` or `+=`, every iteration allocates a
+/// temporary, copies it into the buffer, and frees it. The buffer can be extended from the
+/// iterator directly, with no temporary at all. This is a companion to E1703 (string
+/// concatenation in loops).
+///
+/// ## The Temporary String Problem
+///
+/// ```text
+/// let mut out = String::new();
+/// for row in rows {
+///     out.push_str(&row.iter().collect::<String>());  // one allocation per row
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// fn render(rows: &[Vec<char>]) -> String {
+///     let mut out = String::new();
+///     for row in rows {
+///         out.extend(row.iter());   // appends in place
+///     }
+///     out
+/// }
+/// ```
+///
+/// Mitigation: Use `String::extend`, `push`, or `write!` on the accumulator. A single
+/// `collect::<String>()` outside a loop is fine.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1730: Temporary String per row appended with push_str
+pub fn e1730_bad_push_str_collect(rows: &[Vec<char>]) -> String {
+    let mut out = String::new();
+    for row in rows {
+        out.push_str(&row.iter().collect::<String>());
+    }
+    out
+}
+
+/// PROBLEM E1730: Collected local appended with +=
+pub fn e1730_bad_add_assign_collect(words: &[&str]) -> String {
+    let mut out = String::new();
+    for word in words {
+        let reversed: String = word.chars().rev().collect();
+        out += &reversed;
+    }
+    out
+}
+
+/// Entry point for problem demonstration
+pub fn e1730_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = e1730_bad_push_str_collect(&[vec!['a', 'b'], vec!['c']]);
+    let _ = e1730_bad_add_assign_collect(&["ab", "cd"]);
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Extend the accumulator in place
+pub fn e1730_good_extend(rows: &[Vec<char>]) -> String {
+    let mut out = String::new();
+    for row in rows {
+        out.extend(row.iter());
+    }
+    out
+}
+
+/// GOOD: A single collect outside any loop
+pub fn e1730_good_single_collect(chars: &[char]) -> String {
+    chars.iter().collect::<String>()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extend() {
+        assert_eq!(e1730_good_extend(&[vec!['a', 'b'], vec!['c']]), "abc");
+    }
+
+    #[test]
+    fn test_single_collect() {
+        assert_eq!(e1730_good_single_collect(&['x', 'y']), "xy");
+    }
+}
@@ -0,0 +1,88 @@
+/// E1732: chars().count() compared against byte capacity
+/// Severity: MED
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `str::len()` is the length in bytes; `chars().count()` is the number of Unicode
+/// scalar values and has to walk the whole string. The two agree only for ASCII. A bounds check
+/// that compares `chars().count()` against a buffer capacity or a `MAX_BYTES` limit accepts
+/// `"ééé"` (3 chars, 6 bytes) for a 4-byte buffer. The checker matches on names such as
+/// `bytes`, `buf` and `capacity`, so it is a heuristic.
+///
+/// ## The Byte vs Char Problem
+///
+/// ```text
+/// const MAX_BYTES: usize = 4;
+/// if name.chars().count() <= MAX_BYTES {   // "ééé" passes: 3 chars, 6 bytes
+///     buf[..name.len()].copy_from_slice(name.as_bytes());   // panics
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// const MAX_BYTES: usize = 4;
+/// fn fits(name: &str) -> bool {
+///     name.len() <= MAX_BYTES
+/// }
+/// ```
+///
+/// Mitigation: Compare `.len()` against byte limits. Use `.chars().count()` only for limits that
+/// are defined in characters, and name them accordingly (`max_chars`).
+
+/// Fixed-size buffer used by the examples
+pub const E1732_MAX_BYTES: usize = 4;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1732: Character count checked against a byte limit
+pub fn e1732_bad_chars_vs_max_bytes(name: &str) -> bool {
+    name.chars().count() <= E1732_MAX_BYTES
+}
+
+/// PROBLEM E1732: Character count checked against a buffer's capacity
+pub fn e1732_bad_chars_vs_capacity(buf: &Vec<u8>, text: &str) -> bool {
+    text.chars().count() < buf.capacity()
+}
+
+/// Entry point for problem demonstration
+pub fn e1732_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = e1732_bad_chars_vs_max_bytes("ééé");
+    let _ = e1732_bad_chars_vs_capacity(&Vec::with_capacity(E1732_MAX_BYTES), "ééé");
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: Byte limits are checked with len()
+pub fn e1732_good_len_vs_max_bytes(name: &str) -> bool {
+    name.len() <= E1732_MAX_BYTES
+}
+
+/// GOOD: Character limits are checked with chars().count()
+pub fn e1732_good_chars_vs_max_chars(name: &str, max_chars: usize) -> bool {
+    name.chars().count() <= max_chars
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len_vs_max_bytes() {
+        assert!(e1732_good_len_vs_max_bytes("abcd"));
+        assert!(!e1732_good_len_vs_max_bytes("ééé"));
+    }
+
+    #[test]
+    fn test_chars_vs_max_chars() {
+        assert!(e1732_good_chars_vs_max_chars("ééé", 3));
+    }
+}
@@ -0,0 +1,97 @@
+/// E1733: Repeated map lookup with the same key
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `contains_key(&k)` followed by `get(&k)` or `insert(k, ..)` hashes the key and
+/// probes the table twice. In a hot loop over a large map that doubles the cost of every
+/// access, and for `String` keys the second lookup may also clone the key. The entry API, or a
+/// single `get` whose `Option` is matched, does the same work with one lookup.
+///
+/// ## The Double Lookup Problem
+///
+/// ```text
+/// if counts.contains_key(&word) {
+///     *counts.get_mut(&word).unwrap() += 1;   // second lookup
+/// } else {
+///     counts.insert(word, 1);                 // second lookup
+/// }
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// fn count(counts: &mut HashMap<String, u32>, word: String) {
+///     *counts.entry(word).or_insert(0) += 1;
+/// }
+/// ```
+///
+/// Mitigation: Use `entry().or_insert()`, `entry().and_modify()`, or `if let Some(v) = map.get(&k)`.
+use std::collections::HashMap;
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1733: contains_key() followed by get() on the same key
+pub fn e1733_bad_contains_then_get(prices: &HashMap<String, u32>, item: &str) -> u32 {
+    if prices.contains_key(item) {
+        prices.get(item).copied().unwrap_or_default()
+    } else {
+        0
+    }
+}
+
+/// PROBLEM E1733: get() followed by insert() on the same key
+pub fn e1733_bad_get_then_insert(counts: &mut HashMap<String, u32>, word: String) {
+    let current = counts.get(&word).copied().unwrap_or_default();
+    counts.insert(word, current.saturating_add(1));
+}
+
+/// Entry point for problem demonstration
+pub fn e1733_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let mut counts = HashMap::new();
+    e1733_bad_get_then_insert(&mut counts, String::from("a"));
+    let _ = e1733_bad_contains_then_get(&counts, "a");
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: A single lookup whose Option is used directly
+pub fn e1733_good_single_get(prices: &HashMap<String, u32>, item: &str) -> u32 {
+    prices.get(item).copied().unwrap_or_default()
+}
+
+/// GOOD: The entry API finds or creates the slot with one lookup
+pub fn e1733_good_entry(counts: &mut HashMap<u32, u32>, id: u32) {
+    let count = counts.entry(id).or_insert(0);
+    *count = count.saturating_add(1);
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_get() {
+        let prices = HashMap::from([(String::from("tea"), 3)]);
+        assert_eq!(e1733_good_single_get(&prices, "tea"), 3);
+        assert_eq!(e1733_good_single_get(&prices, "milk"), 0);
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut counts = HashMap::new();
+        e1733_good_entry(&mut counts, 7);
+        e1733_good_entry(&mut counts, 7);
+        assert_eq!(counts.get(&7), Some(&2));
+    }
+}
@@ -11,3 +11,15 @@ pub mod e1708_inefficient_data_structure;
 pub mod e1709_unnecessary_boxing;
 pub mod e1710_large_stack_allocation;
 pub mod e1712_expensive_ops_in_loop;
+pub mod e1713_format_in_push_str;
+pub mod e1718_vec_front_ops;
+pub mod e1719_mutual_recursion;
+pub mod e1722_unnecessary_to_vec;
+pub mod e1723_hash_map_order_reliance;
+pub mod e1724_len_zero_comparison;
+pub mod e1725_clone_then_consume;
+pub mod e1726_needless_return;
+pub mod e1727_eager_unwrap_or;
+pub mod e1730_collect_string_in_loop;
+pub mod e1732_len_chars_confusion;
+pub mod e1733_repeated_map_lookup;
@@ -0,0 +1,102 @@
+/// E1815: Builder method without #[must_use]
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A builder method such as `fn with_timeout(self, secs: u64) -> Self` returns a
+/// new value instead of changing the receiver in place. Calling it as a statement,
+/// `builder.with_timeout(30);`, compiles and does nothing: the updated builder is dropped on
+/// the spot. `#[must_use]` turns that silent no-op into a compiler warning.
+///
+/// ## The Dropped Builder Problem
+///
+/// ```text
+/// let builder = RequestBuilder::new();
+/// builder.clone().with_timeout(30);   // updated copy dropped, timeout never set
+/// let request = builder.build();
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// pub struct RequestBuilder { timeout: u64 }
+///
+/// impl RequestBuilder {
+///     #[must_use]
+///     pub fn with_timeout(mut self, secs: u64) -> Self {
+///         self.timeout = secs;
+///         self
+///     }
+/// }
+/// ```
+///
+/// Mitigation: Mark every public method that returns a modified copy of `self` with
+/// `#[must_use]` (clippy's `return_self_not_must_use` has the same intent).
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// Request settings built step by step
+#[derive(Debug, Clone, Default)]
+pub struct E1815RequestBuilder {
+    timeout: u64,
+    retries: u32,
+}
+
+impl E1815RequestBuilder {
+    /// PROBLEM E1815: Dropping the result silently discards the timeout
+    pub fn e1815_bad_with_timeout(mut self, secs: u64) -> Self {
+        self.timeout = secs;
+        self
+    }
+
+    /// PROBLEM E1815: Returns an updated copy, also without #[must_use]
+    pub fn e1815_bad_with_retries(&self, retries: u32) -> E1815RequestBuilder {
+        E1815RequestBuilder {
+            retries,
+            ..self.clone()
+        }
+    }
+
+    /// GOOD: The compiler warns if the returned builder is ignored
+    #[must_use]
+    pub fn e1815_good_with_timeout(mut self, secs: u64) -> Self {
+        self.timeout = secs;
+        self
+    }
+
+    /// GOOD: Copy-with-change marked #[must_use]
+    #[must_use]
+    pub fn e1815_good_with_retries(&self, retries: u32) -> Self {
+        Self {
+            retries,
+            ..self.clone()
+        }
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1815_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let builder = E1815RequestBuilder::default();
+    let _ = builder.clone().e1815_bad_with_timeout(30);
+    let _ = builder.e1815_bad_with_retries(3);
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_methods() {
+        let builder = E1815RequestBuilder::default()
+            .e1815_good_with_timeout(30)
+            .e1815_good_with_retries(3);
+        assert_eq!(builder.timeout, 30);
+        assert_eq!(builder.retries, 3);
+    }
+}
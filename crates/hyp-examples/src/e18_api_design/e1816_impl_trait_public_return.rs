@@ -0,0 +1,108 @@
+/// E1816: impl Trait returned from a public function
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: `-> impl Trait` hides the concrete return type. Inside a crate that is handy,
+/// but in a public API it means callers can never name the type: they cannot keep it in a
+/// struct field without making their own type generic, cannot put two results in one `Vec`,
+/// and cannot use inherent methods or other traits the concrete type has. For iterators,
+/// futures and closures - whose types are unnameable anyway - this is the idiomatic choice.
+/// For a crate's own domain traits it usually hides a perfectly nameable type.
+///
+/// ## The Opaque Return Problem
+///
+/// ```text
+/// pub fn open_store() -> impl Store { MemoryStore::default() }
+///
+/// struct App { store: ??? }   // the caller cannot write this field's type
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// #[derive(Default)]
+/// pub struct MemoryStore { items: Vec<String> }
+///
+/// pub fn open_store() -> MemoryStore {
+///     MemoryStore::default()
+/// }
+///
+/// struct App { store: MemoryStore }
+/// let app = App { store: open_store() };
+/// assert!(app.store.items.is_empty());
+/// ```
+///
+/// Mitigation: Return the concrete type, a public newtype around it, or `Box<dyn Trait>` when
+/// the implementation has to vary at runtime. Keep `impl Iterator`, `impl Future` and
+/// `impl Fn*`; list other traits that are meant to be opaque in `allowed_traits`.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// A key-value store
+pub trait E1816Store {
+    /// Look up a key
+    fn get(&self, key: &str) -> Option<&str>;
+}
+
+/// In-memory store
+#[derive(Debug, Default)]
+pub struct E1816MemoryStore {
+    entries: std::collections::BTreeMap<String, String>,
+}
+
+impl E1816MemoryStore {
+    /// Add an entry
+    pub fn insert(&mut self, key: &str, value: &str) {
+        self.entries.insert(key.to_string(), value.to_string());
+    }
+}
+
+impl E1816Store for E1816MemoryStore {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+}
+
+/// PROBLEM E1816: callers only ever see "some E1816Store"
+pub fn e1816_bad_open_store() -> impl E1816Store {
+    E1816MemoryStore::default()
+}
+
+/// Entry point for problem demonstration
+pub fn e1816_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{:?}", e1816_bad_open_store().get("key"));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: The concrete type is public, so callers can store it and use its own methods
+pub fn e1816_good_open_store() -> E1816MemoryStore {
+    E1816MemoryStore::default()
+}
+
+/// GOOD: Iterators are idiomatic as `impl Iterator`
+pub fn e1816_good_keys(store: &E1816MemoryStore) -> impl Iterator<Item = &str> {
+    store.entries.keys().map(String::as_str)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_store() {
+        let mut store = e1816_good_open_store();
+        store.insert("a", "1");
+        assert_eq!(store.get("a"), Some("1"));
+        assert_eq!(e1816_good_keys(&store).collect::<Vec<_>>(), vec!["a"]);
+    }
+}
@@ -0,0 +1,118 @@
+/// E1817: Public collection field
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A `pub items: Vec<T>` field hands every caller the full `Vec` API: they can push
+/// duplicates, remove entries, clear it or sort it differently, and the owning type never finds
+/// out. Any rule the struct meant to keep about its contents (sorted order, a size limit, a total
+/// kept in another field) is then only a hope. Keeping the field private and exposing a slice plus
+/// a few purpose-built methods puts the owner back in charge.
+///
+/// ## The Open Collection Problem
+///
+/// ```text
+/// pub struct Playlist {
+///     pub tracks: Vec<Track>,
+///     total_secs: u64,
+/// }
+///
+/// playlist.tracks.push(track);   // total_secs is now wrong
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// pub struct Playlist {
+///     tracks: Vec<u64>,
+///     total_secs: u64,
+/// }
+///
+/// impl Playlist {
+///     pub fn tracks(&self) -> &[u64] {
+///         &self.tracks
+///     }
+///
+///     pub fn add(&mut self, secs: u64) {
+///         self.tracks.push(secs);
+///         self.total_secs += secs;
+///     }
+/// }
+/// ```
+///
+/// Mitigation: Make collection fields private. Return `&[T]` or an iterator for reads and add
+/// methods for the mutations the type actually supports.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1817: callers can push without updating `total_secs`
+pub struct E1817BadPlaylist {
+    pub tracks: Vec<u64>,
+    total_secs: u64,
+}
+
+impl E1817BadPlaylist {
+    /// Total length of the playlist
+    pub fn total_secs(&self) -> u64 {
+        self.total_secs
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1817_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let mut playlist = E1817BadPlaylist {
+        tracks: Vec::new(),
+        total_secs: 0,
+    };
+    playlist.tracks.push(180);
+    println!("{} tracks, {}s", playlist.tracks.len(), playlist.total_secs());
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: The track list and its total can only change together
+#[derive(Debug, Default)]
+pub struct E1817Playlist {
+    tracks: Vec<u64>,
+    total_secs: u64,
+}
+
+impl E1817Playlist {
+    /// Track lengths in order
+    pub fn tracks(&self) -> &[u64] {
+        &self.tracks
+    }
+
+    /// Total length of the playlist
+    pub fn total_secs(&self) -> u64 {
+        self.total_secs
+    }
+
+    /// Append a track
+    pub fn add(&mut self, secs: u64) {
+        self.tracks.push(secs);
+        self.total_secs += secs;
+    }
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_playlist_keeps_total() {
+        let mut playlist = E1817Playlist::default();
+        playlist.add(180);
+        playlist.add(240);
+        assert_eq!(playlist.tracks(), &[180, 240]);
+        assert_eq!(playlist.total_secs(), 420);
+    }
+}
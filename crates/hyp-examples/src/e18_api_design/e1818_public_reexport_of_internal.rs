@@ -0,0 +1,118 @@
+/// E1818: Public re-export of an internal item
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A module named `internal`, `private` or `detail`, or an item whose name starts
+/// with `_`, tells readers "this is not part of the API". A `pub use` of such an item makes it
+/// public anyway: downstream code starts depending on it, and renaming or restructuring the
+/// internal module becomes a breaking change. The name promises a boundary the re-export quietly
+/// removes.
+///
+/// ## The Leaky Boundary Problem
+///
+/// ```text
+/// mod internal {
+///     pub struct ConnectionPool { .. }
+/// }
+///
+/// pub use crate::internal::ConnectionPool;   // "internal" is now public API
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// pub mod pool {
+///     pub struct ConnectionPool {
+///         size: usize,
+///     }
+/// }
+///
+/// pub use crate::pool::ConnectionPool;
+/// ```
+///
+/// Mitigation: Move items that belong to the API into a module meant to be public, or keep the
+/// re-export crate-private with `pub(crate) use`. Adjust `internal_patterns` to the naming
+/// convention the project uses for private modules.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+mod e1818_internal {
+    /// Handle to a pooled connection
+    pub struct E1818BadConnection {
+        id: u32,
+    }
+
+    impl E1818BadConnection {
+        /// Connection with the given id
+        pub fn new(id: u32) -> Self {
+            Self { id }
+        }
+
+        /// Connection id
+        pub fn id(&self) -> u32 {
+            self.id
+        }
+    }
+}
+
+// PROBLEM E1818: the internal module's type becomes public API
+pub use self::e1818_internal::E1818BadConnection;
+
+/// PROBLEM E1818: callers now build on a type from an internal module
+pub fn e1818_bad_open(id: u32) -> E1818BadConnection {
+    E1818BadConnection::new(id)
+}
+
+/// Entry point for problem demonstration
+pub fn e1818_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", e1818_bad_open(1).id());
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// Connections handed out by the pool
+pub mod e1818_pool {
+    /// Handle to a pooled connection
+    pub struct E1818Connection {
+        id: u32,
+    }
+
+    impl E1818Connection {
+        /// Connection with the given id
+        pub fn new(id: u32) -> Self {
+            Self { id }
+        }
+
+        /// Connection id
+        pub fn id(&self) -> u32 {
+            self.id
+        }
+    }
+}
+
+// GOOD: re-exported from a module that is meant to be public
+pub use self::e1818_pool::E1818Connection;
+
+/// GOOD: The returned type lives in a public module
+pub fn e1818_good_open(id: u32) -> E1818Connection {
+    E1818Connection::new(id)
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open() {
+        assert_eq!(e1818_good_open(7).id(), 7);
+    }
+}
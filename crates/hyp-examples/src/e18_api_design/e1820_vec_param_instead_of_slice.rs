@@ -0,0 +1,86 @@
+/// E1820: Vec parameter instead of slice
+/// Severity: LOW
+/// LLM confusion: 2 (LOW)
+///
+/// Description: A public function that takes `Vec<T>` by value but only reads it (iterates,
+/// indexes, asks for the length) demands ownership it never uses. Callers holding an array, a
+/// slice or part of a vector have to allocate a new `Vec`; callers that still need their vector
+/// afterwards have to clone it. `&[T]` accepts all of these, including `&Vec<T>` through deref
+/// coercion, and the body compiles unchanged because slices offer the same read methods. This is
+/// the vector counterpart of E1810 (`String` instead of `&str`).
+///
+/// ## The Handed-Over Vector Problem
+///
+/// ```text
+/// pub fn average(samples: Vec<f64>) -> f64 {
+///     samples.iter().sum::<f64>() / samples.len() as f64
+/// }
+///
+/// let avg = average(readings.clone());   // clone only to satisfy the signature
+/// ```
+///
+/// ## The Right Solution
+///
+/// ```rust
+/// pub fn average(samples: &[f64]) -> f64 {
+///     samples.iter().sum::<f64>() / samples.len() as f64
+/// }
+/// ```
+///
+/// Mitigation: Take `&[T]` for parameters that are only read, `impl IntoIterator<Item = T>` for
+/// ones that are only iterated, and `Vec<T>` only when the function stores, returns or extends
+/// the vector.
+
+// ============================================================================
+// PROBLEMATIC PATTERNS
+// ============================================================================
+
+/// PROBLEM E1820: the vector is only iterated
+pub fn e1820_bad_total(prices: Vec<u32>) -> u32 {
+    prices.iter().sum()
+}
+
+/// PROBLEM E1820: the vector is only indexed and measured
+pub fn e1820_bad_middle(values: Vec<i64>) -> Option<i64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values[values.len() / 2])
+    }
+}
+
+/// Entry point for problem demonstration
+pub fn e1820_entry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("{} {:?}", e1820_bad_total(vec![1, 2]), e1820_bad_middle(vec![3, 4, 5]));
+    Ok(())
+}
+
+// ============================================================================
+// GOOD EXAMPLES
+// ============================================================================
+
+/// GOOD: A slice accepts vectors, arrays and sub-slices
+pub fn e1820_good_total(prices: &[u32]) -> u32 {
+    prices.iter().sum()
+}
+
+/// GOOD: Same body, borrowed input
+pub fn e1820_good_middle(values: &[i64]) -> Option<i64> {
+    values.get(values.len() / 2).copied()
+}
+
+// ============================================================================
+// GOOD EXAMPLES unit tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_total_and_middle() {
+        assert_eq!(e1820_good_total(&[1, 2, 3]), 6);
+        assert_eq!(e1820_good_middle(&[3, 4, 5]), Some(4));
+        assert_eq!(e1820_good_middle(&[]), None);
+    }
+}
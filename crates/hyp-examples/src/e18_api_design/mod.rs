@@ -11,3 +11,8 @@ pub mod e1808_mutable_getter;
 pub mod e1809_fallible_new;
 pub mod e1810_string_instead_of_str;
 pub mod e1812_non_exhaustive_enum;
+pub mod e1815_missing_must_use;
+pub mod e1816_impl_trait_public_return;
+pub mod e1817_public_collection_field;
+pub mod e1818_public_reexport_of_internal;
+pub mod e1820_vec_param_instead_of_slice;
@@ -10,11 +10,15 @@ use hyp_analyzer::{
         filter_registrations, print_checker_list_from_registrations,
         print_guidelines_from_registrations, run_cli,
     },
-    find_config_file, get_all_checkers, parse_categories, print_default_config,
-    print_validation_results, run_validation, split_csv, CliOptions, CliOutputFormat,
+    checker_catalog_json, find_config_file, get_all_checkers, load_cli_config, AnalysisCache, DEFAULT_CACHE_DIR, parse_categories,
+    parse_severity_overrides, print_default_config,
+    print_validation_results, run_diff, run_explain, run_fix, run_validate_config, run_validation, split_csv, write_junit, CliOptions, CliOutputFormat,
+    ColorChoice, ReportGrouping, Severity, EXIT_CLEAN, EXIT_ERROR,
 };
 use std::path::PathBuf;
 
+mod watch;
+
 #[derive(Parser)]
 #[command(name = "hyp")]
 #[command(about = "Hyp Rust Code Analyzer - Detect code problems and anti-patterns", long_about = None)]
@@ -39,14 +43,23 @@ struct Cli {
     #[arg(long, global = true)]
     severity: Option<u8>,
 
+    /// Override checker severities (comma-separated CODE=LEVEL, e.g. E1606=high,E1702=low).
+    /// Applied before the --severity minimum filter
+    #[arg(long, global = true)]
+    severity_override: Option<String>,
+
     /// Filter by categories (comma-separated: operations, complexity, compliance)
     #[arg(long, global = true)]
     category: Option<String>,
 
-    /// Output format (text or json)
+    /// Output format (text, json, jsonl or markdown)
     #[arg(short = 'f', long, default_value = "text", global = true)]
     format: String,
 
+    /// Color severity labels in text output (auto: only on a terminal and when NO_COLOR is unset)
+    #[arg(long, default_value = "auto", global = true, value_parser = ["auto", "always", "never"])]
+    color: String,
+
     /// Verbose mode: -v for info, -vv for debug
     #[arg(short = 'v', long, action = clap::ArgAction::Count, global = true)]
     verbose: u8,
@@ -55,25 +68,151 @@ struct Cli {
     #[arg(long, global = true)]
     check_tests: bool,
 
+    /// Follow symbolic links when walking directories
+    #[arg(long, global = true)]
+    follow_symlinks: bool,
+
+    /// Include hidden (dot-prefixed) directories when walking
+    #[arg(long, global = true)]
+    include_hidden: bool,
+
+    /// Report files that fail to parse as E0001 violations (default: on for check, off for verify-examples)
+    #[arg(long, global = true, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    report_parse_errors: Option<bool>,
+
+    /// Show reported file paths relative to this directory (default: current directory)
+    #[arg(long, global = true, value_name = "DIR")]
+    relative_to: Option<PathBuf>,
+
     /// Path to configuration file (default: Hyp.toml in current or parent directories)
     #[arg(short = 'c', long, global = true)]
     config: Option<PathBuf>,
+
+    /// Warn about unknown checker names in the configuration instead of failing
+    #[arg(long, global = true)]
+    lenient_config: bool,
+
+    /// Use only the root configuration, ignoring Hyp.toml files in subdirectories
+    #[arg(long, global = true)]
+    single_config: bool,
+}
+
+impl Cli {
+    /// Options set by the global flags; subcommands override the fields their own flags control
+    fn cli_options(&self, source: Vec<PathBuf>) -> CliOptions {
+        CliOptions {
+            source,
+            config_path: self.config.clone().unwrap_or_else(find_config_file),
+            severity: self.severity,
+            categories: parse_categories(&self.category),
+            all: self.all,
+            include: self.include.as_deref().map(split_csv),
+            exclude: self.exclude.as_deref().map(split_csv),
+            verbose: self.verbose,
+            check_tests: self.check_tests,
+            follow_symlinks: self.follow_symlinks,
+            include_hidden: self.include_hidden,
+            severity_overrides: self
+                .severity_override
+                .as_deref()
+                .map(parse_severity_overrides)
+                .unwrap_or_default(),
+            report_parse_errors: self.report_parse_errors.unwrap_or(true),
+            relative_to: self.relative_to.clone(),
+            color: ColorChoice::parse_choice(&self.color).unwrap_or(ColorChoice::Auto),
+            lenient_config: self.lenient_config,
+            single_config: self.single_config,
+            ..CliOptions::default()
+        }
+    }
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Scan source code for problems
     Check {
+        /// Files, directories or glob patterns such as `src/**/*.rs` (default: `.`), or `-` to read source from stdin
+        paths: Vec<PathBuf>,
+
+        /// Exit with code 1 if any violation at or above this severity is found
+        #[arg(long, default_value = "high", value_parser = ["low", "medium", "high"])]
+        fail_on: String,
+
+        /// Group detailed text output by severity, file or code (default: flat list)
+        #[arg(long, value_parser = ["severity", "file", "code"])]
+        group_by: Option<String>,
+
+        /// Re-analyze every file instead of reusing results cached in target/hyp-cache
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Only analyze .rs files modified according to `git diff` (staged or not)
+        #[arg(long)]
+        changed: bool,
+
+        /// Print only violations: no checker banner and no summary report
+        #[arg(short, long)]
+        quiet: bool,
+
+        /// Print violation counts by severity, category and code as JSON instead of the violations
+        #[arg(long)]
+        summary_json: bool,
+
+        /// Exit with code 1 if the quality score (LOC per violation) is below this value
+        #[arg(long, value_name = "SCORE")]
+        min_quality_score: Option<f64>,
+    },
+
+    /// Analyze, then re-analyze each changed file on save and print a compact summary
+    Watch {
         /// Path to source code (file or directory)
         path: Option<PathBuf>,
+
+        /// Clear the screen and reprint all violations after each change
+        #[arg(long)]
+        clear: bool,
+    },
+
+    /// Compare two JSON violation dumps (from `check -f json`) and print added/removed violations
+    Diff {
+        /// Violations from the earlier run
+        old: PathBuf,
+
+        /// Violations from the later run
+        new: PathBuf,
     },
 
+    /// Apply checker fix-its (e.g. E1801, E1810) and print them as a unified diff
+    Fix {
+        /// Path to source code (file or directory)
+        path: Option<PathBuf>,
+
+        /// Print the diff without modifying any file
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Delete cached analysis results from target/hyp-cache
+    CleanCache,
+
     /// Print the effective TOML configuration
     PrintConfig,
 
+    /// Check the configuration file for unknown checkers and invalid settings without analyzing
+    ValidateConfig,
+
+    /// Explain a checker: what it reports, why it matters and how to fix it
+    Explain {
+        /// Checker code, e.g. E1402
+        code: String,
+    },
+
     /// List all available checkers
     List,
 
+    /// Print every checker's metadata (code, name, severity, categories, suggestions) as JSON
+    Catalog,
+
     /// Print condensed guidelines for LLMs
     Guideline,
 
@@ -82,34 +221,89 @@ enum Commands {
         /// Path to hyp-examples source directory
         #[arg(default_value = "crates/hyp-examples/src")]
         path: Option<PathBuf>,
+
+        /// Also write the per-function results as a JUnit XML report to this path
+        #[arg(long)]
+        junit: Option<PathBuf>,
     },
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
-    let categories = parse_categories(&cli.category);
 
     match &cli.command {
-        Some(Commands::Check { path }) => {
-            let source = path.clone().unwrap_or_else(|| PathBuf::from("."));
-            let config_path = cli.config.clone().unwrap_or_else(|| find_config_file());
+        Some(Commands::Check {
+            paths,
+            fail_on,
+            group_by,
+            no_cache,
+            changed,
+            quiet,
+            summary_json,
+            min_quality_score,
+        }) => {
+            let source = if paths.is_empty() { vec![PathBuf::from(".")] } else { paths.clone() };
             let opts = CliOptions {
-                source,
-                config_path,
-                severity: cli.severity,
-                categories,
-                all: cli.all,
-                include: cli.include.as_ref().map(|s| split_csv(s)),
-                exclude: cli.exclude.as_ref().map(|s| split_csv(s)),
-                format: if cli.format == "json" {
-                    CliOutputFormat::Json
-                } else {
-                    CliOutputFormat::Text
-                },
-                verbose: cli.verbose,
-                check_tests: cli.check_tests,
+                format: CliOutputFormat::parse_format(&cli.format).unwrap_or(CliOutputFormat::Text),
+                fail_on: Severity::parse_severity(fail_on).unwrap_or(Severity::High),
+                group_by: group_by.as_deref().and_then(ReportGrouping::parse_grouping),
+                use_cache: !no_cache,
+                changed_only: *changed,
+                quiet: *quiet,
+                summary_json: *summary_json,
+                min_quality_score: *min_quality_score,
+                ..cli.cli_options(source)
             };
-            run_cli(opts, get_all_checkers)?;
+            match run_cli(opts, get_all_checkers) {
+                Ok(EXIT_CLEAN) => {}
+                Ok(code) => std::process::exit(code),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(EXIT_ERROR);
+                }
+            }
+        }
+
+        Some(Commands::Watch { path, clear }) => {
+            let opts = CliOptions {
+                use_cache: true,
+                ..cli.cli_options(vec![path.clone().unwrap_or_else(|| PathBuf::from("."))])
+            };
+            if let Err(e) = watch::run_watch(&opts, *clear) {
+                eprintln!("Error: {}", e);
+                std::process::exit(EXIT_ERROR);
+            }
+        }
+
+        Some(Commands::Diff { old, new }) => {
+            let format = CliOutputFormat::parse_format(&cli.format).unwrap_or(CliOutputFormat::Text);
+            if let Err(e) = run_diff(old, new, format) {
+                eprintln!("Error: {}", e);
+                std::process::exit(EXIT_ERROR);
+            }
+        }
+
+        Some(Commands::Fix { path, dry_run }) => {
+            let opts = cli.cli_options(vec![path.clone().unwrap_or_else(|| PathBuf::from("."))]);
+            if let Err(e) = run_fix(&opts, get_all_checkers, *dry_run) {
+                eprintln!("Error: {}", e);
+                std::process::exit(EXIT_ERROR);
+            }
+        }
+
+        Some(Commands::CleanCache) => {
+            let cache = AnalysisCache::new(DEFAULT_CACHE_DIR);
+            match cache.clear() {
+                Ok(removed) => println!(
+                    "Removed {} cached result(s) from {}",
+                    removed,
+                    cache.dir().display()
+                ),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(EXIT_ERROR);
+                }
+            }
         }
 
         Some(Commands::PrintConfig) => {
@@ -121,69 +315,74 @@ fn main() -> Result<()> {
             )?;
         }
 
+        Some(Commands::ValidateConfig) => {
+            let config_path = cli.config.clone().unwrap_or_else(find_config_file);
+            let code = run_validate_config(&config_path, cli.lenient_config, get_all_checkers);
+            if code != EXIT_CLEAN {
+                std::process::exit(code);
+            }
+        }
+
+        Some(Commands::Explain { code }) => {
+            let code = run_explain(code);
+            if code != EXIT_CLEAN {
+                std::process::exit(code);
+            }
+        }
+
         Some(Commands::List) => {
-            let config_path = cli.config.clone().unwrap_or_else(|| find_config_file());
             let opts = CliOptions {
-                source: PathBuf::from("."),
-                config_path,
-                severity: cli.severity,
-                categories,
-                all: cli.all,
-                include: cli.include.as_ref().map(|s| split_csv(s)),
-                exclude: cli.exclude.as_ref().map(|s| split_csv(s)),
-                format: CliOutputFormat::Text,
-                verbose: cli.verbose,
                 check_tests: false,
+                ..cli.cli_options(vec![PathBuf::from(".")])
             };
             print_checker_list_from_registrations(&opts, get_all_checkers())?;
         }
 
+        Some(Commands::Catalog) => {
+            println!("{:#}", checker_catalog_json(&get_all_checkers()));
+        }
+
         Some(Commands::Guideline) => {
-            let config_path = cli.config.clone().unwrap_or_else(|| find_config_file());
             let opts = CliOptions {
-                source: PathBuf::from("."),
-                config_path,
-                severity: cli.severity,
-                categories,
-                all: cli.all,
-                include: cli.include.as_ref().map(|s| split_csv(s)),
-                exclude: cli.exclude.as_ref().map(|s| split_csv(s)),
-                format: CliOutputFormat::Text,
-                verbose: cli.verbose,
                 check_tests: false,
+                ..cli.cli_options(vec![PathBuf::from(".")])
             };
             print_guidelines_from_registrations(&opts, get_all_checkers())?;
         }
 
-        Some(Commands::VerifyExamples { path }) => {
+        Some(Commands::VerifyExamples { path, junit }) => {
             let source = path
                 .clone()
                 .unwrap_or_else(|| PathBuf::from("crates/hyp-examples/src"));
             println!("Validating hyp against problem examples...\n");
             println!("Source directory: {}\n", source.display());
 
+            let opts = CliOptions {
+                categories: None,
+                report_parse_errors: cli.report_parse_errors.unwrap_or(false),
+                ..cli.cli_options(vec![source.clone()])
+            };
+            // Validate the config against all checkers before --include/--exclude narrow them down
+            let all_registrations = get_all_checkers();
+            let config = load_cli_config(&opts, &all_registrations)?;
             let registrations = if cli.include.is_some() || cli.exclude.is_some() {
-                let config_path = cli.config.clone().unwrap_or_else(|| find_config_file());
-                let opts = CliOptions {
-                    source: source.clone(),
-                    config_path,
-                    severity: cli.severity,
-                    categories: None,
-                    all: cli.all,
-                    include: cli.include.as_ref().map(|s| split_csv(s)),
-                    exclude: cli.exclude.as_ref().map(|s| split_csv(s)),
-                    format: CliOutputFormat::Text,
-                    verbose: cli.verbose,
-                    check_tests: cli.check_tests,
-                };
-                filter_registrations(get_all_checkers(), &opts)
+                filter_registrations(all_registrations, &opts)
             } else {
-                get_all_checkers()
+                all_registrations
             };
 
-            let summary = run_validation(&source, || registrations)?;
+            let summary = run_validation(
+                &source,
+                config,
+                || registrations,
+                cli.report_parse_errors.unwrap_or(false),
+            )?;
             println!("Found {} problem example files\n", summary.files_processed);
             print_validation_results(&summary);
+            if let Some(junit_path) = junit {
+                write_junit(&summary, junit_path)?;
+                println!("JUnit report written to {}", junit_path.display());
+            }
 
             if !summary.all_passed() {
                 std::process::exit(1);
@@ -0,0 +1,118 @@
+//! `hyp watch`: analyze once, then re-analyze each file when it changes
+
+use anyhow::Result;
+use hyp_analyzer::{build_cli_analyzer, get_all_checkers, relativize, CliOptions, Severity, Violation, WatchState};
+use notify::{RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Events arriving within this window of each other are handled as one change
+/// (editors often write a file twice, or write a temp file and rename it)
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Pending file system events before the watcher thread waits for the main loop
+const EVENT_QUEUE: usize = 1024;
+
+/// Run the watch loop until the process is interrupted
+///
+/// After the initial analysis only the files named by file system events are
+/// re-analyzed. Each round prints the changed files' violations and a summary
+/// line; with `clear` the screen is cleared and all current violations are
+/// printed instead.
+pub fn run_watch(opts: &CliOptions, clear: bool) -> Result<()> {
+    let [source] = opts.source.as_slice() else {
+        anyhow::bail!("hyp watch takes a single path");
+    };
+    let analyzer = build_cli_analyzer(opts, get_all_checkers)?;
+    // Events carry absolute paths, so the state is keyed on the canonical root
+    let root = source.canonicalize()?;
+    let base = opts.relative_to.clone().unwrap_or_else(|| PathBuf::from("."));
+    let base = base.canonicalize().unwrap_or(base);
+
+    let mut state = WatchState::new(&analyzer, &root);
+    if clear {
+        clear_screen();
+    }
+    print_violations(state.violations(), &base);
+    println!("{}", state.summary());
+
+    let (tx, rx) = mpsc::sync_channel(EVENT_QUEUE);
+    let mut watcher = notify::recommended_watcher(move |event| {
+        // The receiver only goes away when the process exits
+        let _ = tx.send(event);
+    })?;
+    // Watch a single file through its directory: editors often replace the file instead of writing it
+    match root.parent() {
+        Some(dir) if root.is_file() => watcher.watch(dir, RecursiveMode::NonRecursive)?,
+        _ => watcher.watch(&root, RecursiveMode::Recursive)?,
+    }
+    eprintln!("Watching {} for changes (Ctrl-C to stop)", source.display());
+
+    while let Ok(event) = rx.recv() {
+        let mut changed = BTreeSet::new();
+        collect_paths(event, &mut changed);
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            collect_paths(event, &mut changed);
+        }
+
+        let mut updated = Vec::new();
+        for path in &changed {
+            match state.update(&analyzer, path) {
+                Ok(true) => updated.push(path),
+                Ok(false) => {}
+                Err(e) => eprintln!("Error: {}: {}", relativize(&path.to_string_lossy(), &base), e),
+            }
+        }
+        if updated.is_empty() {
+            continue;
+        }
+
+        if clear {
+            clear_screen();
+            print_violations(state.violations(), &base);
+        } else {
+            println!();
+            for path in updated {
+                println!("Changed: {}", relativize(&path.to_string_lossy(), &base));
+                print_violations(state.file_violations(path).iter(), &base);
+            }
+        }
+        println!("{}", state.summary());
+    }
+    Ok(())
+}
+
+/// Add the paths of a watcher event; watcher errors are reported and skipped
+fn collect_paths(event: notify::Result<notify::Event>, changed: &mut BTreeSet<PathBuf>) {
+    match event {
+        Ok(event) if !event.kind.is_access() => changed.extend(event.paths),
+        Ok(_) => {}
+        Err(e) => eprintln!("Warning: file watcher error: {}", e),
+    }
+}
+
+/// One line per violation: `path:line:column [CODE] SEVERITY message`
+fn print_violations<'a>(violations: impl Iterator<Item = &'a Violation>, base: &Path) {
+    for v in violations {
+        let severity = match v.severity {
+            Severity::High => "HIGH",
+            Severity::Medium => "MEDIUM",
+            Severity::Low => "LOW",
+        };
+        println!(
+            "{}:{}:{} [{}] {} {}",
+            relativize(&v.file_path, base),
+            v.line,
+            v.column,
+            v.code,
+            severity,
+            v.message
+        );
+    }
+}
+
+fn clear_screen() {
+    print!("\x1B[2J\x1B[1;1H");
+}